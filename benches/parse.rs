@@ -0,0 +1,90 @@
+//! Parse and lint the test fixtures many times, reporting time and heap
+//! allocations per phase. Run with `cargo bench --bench parse`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use rsx_a11y::lints;
+use rsx_a11y::parser;
+
+/// Counts heap allocations, to measure allocation pressure.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const ITERATIONS: usize = 200;
+
+fn corpus() -> Vec<(String, String)> {
+    let fixtures = [
+        include_str!("../tests/fixtures/yew_component.rs"),
+        include_str!("../tests/fixtures/leptos_component.rs"),
+    ];
+    (0..20)
+        .flat_map(|i| {
+            fixtures.iter().enumerate().map(move |(j, source)| {
+                (
+                    format!("src/components/module_{i}/file_{j}.rs"),
+                    source.repeat(5),
+                )
+            })
+        })
+        .collect()
+}
+
+fn measure<T>(mut run: impl FnMut() -> T) -> (Duration, usize, T) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let mut result = run();
+    for _ in 1..ITERATIONS {
+        result = run();
+    }
+    let elapsed = start.elapsed() / ITERATIONS as u32;
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / ITERATIONS;
+    (elapsed, allocations, result)
+}
+
+fn main() {
+    let corpus = corpus();
+    let (parse_time, parse_allocations, parsed) = measure(|| {
+        corpus
+            .iter()
+            .map(|(path, source)| parser::parse_source(source, path).unwrap())
+            .collect::<Vec<_>>()
+    });
+    let elements: usize = parsed.iter().map(Vec::len).sum();
+    let (lint_time, lint_allocations, diagnostics) = measure(|| {
+        parsed
+            .iter()
+            .map(|elements| lints::run_all_lints(elements).count())
+            .sum::<usize>()
+    });
+    println!(
+        "{} files, {} elements, {} diagnostics",
+        corpus.len(),
+        elements,
+        diagnostics
+    );
+    println!(
+        "parse: {:>10.3?}  {:>8} allocations",
+        parse_time, parse_allocations
+    );
+    println!(
+        "lint:  {:>10.3?}  {:>8} allocations",
+        lint_time, lint_allocations
+    );
+}
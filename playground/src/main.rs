@@ -192,16 +192,8 @@ fn App() -> impl IntoView {
                                         .diagnostics
                                         .iter()
                                         .map(|d| {
-                                            let severity_class = match d.severity {
-                                                Severity::Error => "severity-error",
-                                                Severity::Warning => "severity-warning",
-                                                Severity::Info => "severity-info",
-                                            };
-                                            let severity_label = match d.severity {
-                                                Severity::Error => "error",
-                                                Severity::Warning => "warning",
-                                                Severity::Info => "info",
-                                            };
+                                            let severity_label = d.severity.to_string();
+                                            let severity_class = format!("severity-{}", severity_label);
                                             let help = d.help.clone();
                                             let rule = d.rule.to_string();
                                             view! {
@@ -0,0 +1,129 @@
+//! Compile-time accessibility checks for `html!`, `view!` and `rsx!`.
+//!
+//! Put `#[rsx_a11y_macros::check]` on a function, `impl` block or module and
+//! every error-level [rsx-a11y](https://docs.rs/rsx-a11y) diagnostic in its
+//! RSX macros becomes a compile error at the offending element:
+//!
+//! ```rust,ignore
+//! #[rsx_a11y_macros::check]
+//! fn avatar() -> Html {
+//!     html! { <img src="avatar.png" /> }
+//!     //       ^^^ error: <img> element is missing an `alt` attribute. [alt-text]
+//! }
+//! ```
+//!
+//! The nearest `rsx-a11y.toml` from the crate's `CARGO_MANIFEST_DIR` is
+//! applied, so rules turned off or down to warnings there do not fail the
+//! build. Per-file `[[overrides]]` match against `src/`.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
+use rsx_a11y::config::Config;
+use rsx_a11y::lints::{self, Severity};
+use rsx_a11y::parser;
+
+/// Fail compilation on accessibility errors in the RSX macros of the
+/// annotated item.
+#[proc_macro_attribute]
+pub fn check(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    check_item(attr.into(), item.into()).into()
+}
+
+fn check_item(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if let Some(token) = attr.into_iter().next() {
+        return with_errors(
+            item,
+            vec![(token.span(), "`#[check]` takes no arguments".to_string())],
+        );
+    }
+
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+    let config = match manifest_dir.as_deref().map(Config::discover) {
+        Some(Ok(config)) => config,
+        Some(Err(e)) => return with_errors(item, vec![(Span::call_site(), e.to_string())]),
+        None => Config::default(),
+    };
+    let src_dir = manifest_dir.unwrap_or_default().join("src");
+    let resolved = config.resolve(&src_dir);
+
+    let parsed = parser::parse_tokens(item.clone(), "<item>", &config.parse_options());
+    let spans = token_spans(item.clone());
+    let errors = lints::run_all_lints_with(&parsed.elements, &resolved.options)
+        .filter_map(|d| resolved.apply(d))
+        .filter(|d| d.severity == Severity::Error)
+        .map(|d| {
+            let span = spans
+                .get(&(d.line, d.column))
+                .copied()
+                .unwrap_or_else(Span::call_site);
+            let mut message = format!("{} [{}]", d.message, d.rule);
+            if let Some(help) = d.help {
+                message.push_str("\nhelp: ");
+                message.push_str(&help);
+            }
+            (span, message)
+        })
+        .collect();
+
+    with_errors(item, errors)
+}
+
+/// Map each token's start position to its span, so diagnostics (which only
+/// carry a line and column) can point back at the element.
+fn token_spans(tokens: TokenStream) -> HashMap<(usize, usize), Span> {
+    fn walk(tokens: TokenStream, spans: &mut HashMap<(usize, usize), Span>) {
+        for token in tokens {
+            let start = token.span().start();
+            spans
+                .entry((start.line, start.column))
+                .or_insert(token.span());
+            if let TokenTree::Group(group) = token {
+                walk(group.stream(), spans);
+            }
+        }
+    }
+
+    let mut spans = HashMap::new();
+    walk(tokens, &mut spans);
+    spans
+}
+
+/// The item unchanged, followed by a `compile_error!` for each error.
+fn with_errors(item: TokenStream, errors: Vec<(Span, String)>) -> TokenStream {
+    let errors = errors
+        .into_iter()
+        .map(|(span, message)| quote_spanned! { span=> compile_error!(#message); });
+    quote! {
+        #item
+        #(#errors)*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_alt_is_a_compile_error() {
+        let output = check_item(
+            TokenStream::new(),
+            quote! { fn c() { html! { <img src="a.png" /> } } },
+        )
+        .to_string();
+        assert!(output.contains("compile_error"));
+        assert!(output.contains("[alt-text]"));
+    }
+
+    #[test]
+    fn test_clean_item_is_unchanged() {
+        let item = quote! { fn c() { html! { <img src="a.png" alt="A cat" /> } } };
+        let output = check_item(TokenStream::new(), item.clone());
+        assert_eq!(output.to_string(), item.to_string());
+    }
+}
@@ -0,0 +1,124 @@
+//! JavaScript bindings for [rsx-a11y](https://docs.rs/rsx-a11y).
+//!
+//! Build with `wasm-pack build rsx-a11y-wasm` and call the linter directly
+//! from JavaScript:
+//!
+//! ```js
+//! import init, { lintSource, rules } from "./pkg/rsx_a11y_wasm.js";
+//!
+//! await init();
+//! const report = lintSource('fn c() { html! { <img src="a.png" /> } }');
+//! // { diagnostics: [{ rule: "alt-text", severity: "error", ... }], elements_found: 1, error: null }
+//! const metadata = rules();
+//! // [{ id: "alt-text", default_severity: "error", description: "...", ... }, ...]
+//! ```
+//!
+//! Values are plain JavaScript objects built from the same JSON the CLI emits.
+
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+use wasm_bindgen::prelude::*;
+
+use rsx_a11y::lints::{self, LintDiagnostic, Rule, RuleMetadata};
+use rsx_a11y::parser;
+
+/// Result of linting a source string.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LintReport {
+    pub diagnostics: Vec<LintDiagnostic>,
+    /// Number of RSX elements found in the source.
+    pub elements_found: usize,
+    /// Parse error message, if the source could not be parsed.
+    pub error: Option<String>,
+}
+
+/// Lint a source string with every rule at its default severity.
+pub fn lint_source(code: &str) -> LintReport {
+    match parser::parse_source(code, "<input>") {
+        Ok(elements) => LintReport {
+            diagnostics: lints::run_all_lints(&elements).collect(),
+            elements_found: elements.len(),
+            error: None,
+        },
+        Err(e) => LintReport {
+            diagnostics: Vec::new(),
+            elements_found: 0,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+/// Metadata for every rule, in rule order.
+pub fn rule_metadata() -> Vec<RuleMetadata> {
+    Rule::iter().map(|rule| rule.metadata()).collect()
+}
+
+/// Convert a serializable value to a JavaScript object via JSON.
+fn to_js<T: serde::Serialize>(value: &T) -> JsValue {
+    let json = serde_json::to_string(value).unwrap_or_else(|_| "null".to_string());
+    js_sys::JSON::parse(&json).unwrap_or(JsValue::NULL)
+}
+
+/// Lint `code` and return a `LintReport` object.
+#[wasm_bindgen(js_name = lintSource)]
+pub fn lint_source_js(code: &str) -> JsValue {
+    to_js(&lint_source(code))
+}
+
+/// Lint `code` with a single rule and return a `LintReport` object.
+/// Unknown rule ids produce a report with `error` set.
+#[wasm_bindgen(js_name = lintSourceRule)]
+pub fn lint_source_rule_js(code: &str, rule: &str) -> JsValue {
+    let Ok(rule) = Rule::from_str(rule) else {
+        return to_js(&LintReport {
+            diagnostics: Vec::new(),
+            elements_found: 0,
+            error: Some(format!("Unknown rule `{}`", rule)),
+        });
+    };
+    match parser::parse_source(code, "<input>") {
+        Ok(elements) => to_js(&LintReport {
+            diagnostics: lints::run_rule(&rule, &elements),
+            elements_found: elements.len(),
+            error: None,
+        }),
+        Err(e) => to_js(&LintReport {
+            diagnostics: Vec::new(),
+            elements_found: 0,
+            error: Some(e.to_string()),
+        }),
+    }
+}
+
+/// Return metadata for every rule as an array of objects.
+#[wasm_bindgen(js_name = rules)]
+pub fn rules_js() -> JsValue {
+    to_js(&rule_metadata())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lint_source_report() {
+        let report = lint_source(r#"fn c() { html! { <img src="a.png" /> } }"#);
+        assert_eq!(report.elements_found, 1);
+        assert!(report.diagnostics.iter().any(|d| d.rule == Rule::AltText));
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["elements_found"], 1);
+
+        let report = lint_source("fn c( {");
+        assert!(report.error.is_some());
+    }
+
+    #[test]
+    fn test_rule_metadata_serializes() {
+        let metadata = rule_metadata();
+        assert_eq!(metadata.len(), Rule::iter().count());
+        let json = serde_json::to_value(&metadata[0]).unwrap();
+        assert_eq!(json["id"], "alt-text");
+        assert_eq!(json["default_severity"], "error");
+    }
+}
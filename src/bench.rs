@@ -0,0 +1,213 @@
+//! Timing of the parse and lint phases, and of each rule along with how
+//! often it fires, to find slow rules and measure performance changes.
+//!
+//! [`run`] lints a set of files repeatedly on one thread and averages the
+//! timings; [`LintRunner::timings`](crate::runner::LintRunner::timings)
+//! records them during a normal run.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
+use crate::lints::{self, LintDiagnostic, Rule};
+use crate::parser::{self, ParseOptions};
+
+/// Time spent in one rule and how often it fired.
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RuleStats {
+    /// Time spent in the rule's checks.
+    #[serde(rename = "time_ms", with = "millis")]
+    pub time: Duration,
+    /// Diagnostics the rule reported, after config levels and overrides.
+    pub hits: usize,
+}
+
+/// Time spent parsing and linting. Serialized with times in (fractional)
+/// milliseconds.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Timings {
+    /// Reading and parsing files.
+    #[serde(rename = "parse_ms", with = "millis")]
+    pub parse: Duration,
+    /// Linting parsed elements, including the rules' checks.
+    #[serde(rename = "lint_ms", with = "millis")]
+    pub lint: Duration,
+    /// Time and hits for each rule that ran.
+    pub rules: BTreeMap<Rule, RuleStats>,
+}
+
+impl Timings {
+    /// Add the times and hits in `other` to these.
+    pub fn merge(&mut self, other: &Timings) {
+        self.parse += other.parse;
+        self.lint += other.lint;
+        for (rule, stats) in &other.rules {
+            let entry = self.rules.entry(rule.clone()).or_default();
+            entry.time += stats.time;
+            entry.hits += stats.hits;
+        }
+    }
+
+    /// Count `diagnostics` as hits of their rules.
+    pub fn record_hits(&mut self, diagnostics: &[LintDiagnostic]) {
+        for diagnostic in diagnostics {
+            self.rules.entry(diagnostic.rule.clone()).or_default().hits += 1;
+        }
+    }
+
+    /// Rules and their stats, slowest first.
+    pub fn slowest_rules(&self) -> Vec<(&Rule, RuleStats)> {
+        let mut rules: Vec<_> = self
+            .rules
+            .iter()
+            .map(|(rule, stats)| (rule, *stats))
+            .collect();
+        rules.sort_by(|a, b| b.1.time.cmp(&a.1.time).then(a.0.cmp(b.0)));
+        rules
+    }
+
+    /// Average over `n` runs. Hits are kept per run, rounded down.
+    fn divide(&mut self, n: u32) {
+        self.parse /= n;
+        self.lint /= n;
+        for stats in self.rules.values_mut() {
+            stats.time /= n;
+            stats.hits /= n as usize;
+        }
+    }
+}
+
+/// Serialize a [`Duration`] as fractional milliseconds.
+mod millis {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let ms = f64::deserialize(deserializer)?;
+        Ok(Duration::try_from_secs_f64(ms / 1000.0).unwrap_or_default())
+    }
+}
+
+/// Result of [`run`].
+#[derive(Debug, Clone, Default)]
+pub struct BenchReport {
+    /// Files that were parsed, including those without RSX.
+    pub files: usize,
+    /// Elements linted per iteration.
+    pub elements: usize,
+    pub iterations: usize,
+    /// Timings of one iteration, averaged over all of them.
+    pub timings: Timings,
+}
+
+/// Parse and lint `files` `iterations` times on the current thread, with
+/// each file's rule options from `config`. Files that cannot be parsed are
+/// left out.
+///
+/// ```
+/// use rsx_a11y::bench;
+/// use rsx_a11y::config::Config;
+///
+/// let files = ["tests/fixtures/yew_component.rs".into()];
+/// let report = bench::run(&files, &Config::default(), 2);
+/// assert!(report.elements > 0);
+/// assert!(!report.timings.rules.is_empty());
+/// ```
+pub fn run(files: &[PathBuf], config: &Config, iterations: usize) -> BenchReport {
+    // Skip generated files like the runner does.
+    let parse_options = ParseOptions {
+        skip_generated: true,
+        ..config.parse_options()
+    };
+    let mut report = BenchReport {
+        iterations: iterations.max(1),
+        ..BenchReport::default()
+    };
+    for _ in 0..report.iterations {
+        report.files = 0;
+        report.elements = 0;
+        for file in files {
+            let start = Instant::now();
+            let Ok(parsed) = parser::parse_file_with(file, &parse_options) else {
+                continue;
+            };
+            report.timings.parse += start.elapsed();
+            report.files += 1;
+            report.elements += parsed.elements.len();
+
+            let resolved = config.resolve(file);
+            let start = Instant::now();
+            let diagnostics: Vec<_> = lints::run_all_lints_timed(
+                &parsed.elements,
+                &resolved.options,
+                &mut report.timings.rules,
+            )
+            .into_iter()
+            .filter_map(|d| resolved.apply(d))
+            .collect();
+            report.timings.lint += start.elapsed();
+            report.timings.record_hits(&diagnostics);
+        }
+    }
+    report.timings.divide(report.iterations as u32);
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_and_sort_timings() {
+        let ms = Duration::from_millis;
+        let stats = |time, hits| RuleStats { time, hits };
+        let mut timings = Timings {
+            parse: ms(5),
+            lint: ms(3),
+            rules: BTreeMap::from([
+                (Rule::AltText, stats(ms(1), 1)),
+                (Rule::AriaRole, stats(ms(2), 0)),
+            ]),
+        };
+        timings.merge(&Timings {
+            parse: ms(1),
+            lint: ms(1),
+            rules: BTreeMap::from([(Rule::AltText, stats(ms(4), 2))]),
+        });
+        assert_eq!((timings.parse, timings.lint), (ms(6), ms(4)));
+        assert_eq!(
+            timings.slowest_rules(),
+            [
+                (&Rule::AltText, stats(ms(5), 3)),
+                (&Rule::AriaRole, stats(ms(2), 0))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_timings_json() {
+        let timings = Timings {
+            parse: Duration::from_micros(1500),
+            lint: Duration::from_millis(2),
+            rules: BTreeMap::from([(
+                Rule::AltText,
+                RuleStats {
+                    time: Duration::from_micros(250),
+                    hits: 4,
+                },
+            )]),
+        };
+        let json = serde_json::to_string(&timings).unwrap();
+        assert_eq!(
+            json,
+            r#"{"parse_ms":1.5,"lint_ms":2.0,"rules":{"alt-text":{"time_ms":0.25,"hits":4}}}"#
+        );
+        assert_eq!(serde_json::from_str::<Timings>(&json).unwrap(), timings);
+    }
+}
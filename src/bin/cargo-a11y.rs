@@ -0,0 +1,220 @@
+//! `cargo a11y`: lint the `src/` directory of every crate in a Cargo workspace.
+
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use clap::Parser;
+
+use rsx_a11y::LintSummary;
+use rsx_a11y::config::Config;
+use rsx_a11y::diagnostics::{self, ColorChoice, OutputFormat};
+use rsx_a11y::lints::Severity;
+use rsx_a11y::runner::LintRunner;
+use rsx_a11y::workspace::{self, Package};
+
+/// Cargo passes the subcommand name (`a11y`) as the first argument.
+#[derive(Parser, Debug)]
+#[command(name = "cargo", bin_name = "cargo")]
+enum CargoCli {
+    A11y(Args),
+}
+
+/// Lint accessibility in every crate of the current Cargo workspace.
+///
+/// Each member's `src/` directory is linted with its nearest `rsx-a11y.toml`.
+#[derive(clap::Args, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Path to the workspace's Cargo.toml.
+    #[arg(long)]
+    manifest_path: Option<PathBuf>,
+
+    /// Only lint these packages (repeatable).
+    #[arg(short, long = "package", value_name = "NAME")]
+    packages: Vec<String>,
+
+    /// Lint every workspace member (the default).
+    #[arg(long, conflicts_with = "packages")]
+    workspace: bool,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "pretty")]
+    format: Format,
+
+    /// Only report diagnostics at or above this severity.
+    #[arg(long, value_enum, value_name = "LEVEL", default_value = "info")]
+    min_severity: MinSeverity,
+
+    /// Deprecated alias for `--min-severity error`.
+    #[arg(short, long, hide = true)]
+    quiet: bool,
+
+    /// When to color output. `auto` colors terminal output unless `NO_COLOR`
+    /// is set; `CLICOLOR_FORCE` forces colors.
+    #[arg(long, value_enum, value_name = "WHEN", default_value = "auto")]
+    color: Color,
+
+    /// Follow symlinked files and directories, e.g. components shared
+    /// between crates. Symlink loops are skipped.
+    #[arg(long)]
+    follow_symlinks: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Format {
+    Pretty,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum MinSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Color {
+    Auto,
+    Always,
+    Never,
+}
+
+/// Results for one workspace member, as emitted in JSON output.
+#[derive(serde::Serialize)]
+struct PackageReport {
+    package: Package,
+    summary: LintSummary,
+}
+
+fn main() {
+    let CargoCli::A11y(args) = CargoCli::parse();
+    let color = match args.color {
+        Color::Auto => ColorChoice::Auto,
+        Color::Always => ColorChoice::Always,
+        Color::Never => ColorChoice::Never,
+    };
+    color.apply(io::stdout().is_terminal());
+    let format = match args.format {
+        Format::Pretty => OutputFormat::Pretty,
+        Format::Json => OutputFormat::Json,
+    };
+    let min_severity = if args.quiet {
+        eprintln!("warning: --quiet is deprecated; use --min-severity error");
+        Severity::Error
+    } else {
+        match args.min_severity {
+            MinSeverity::Error => Severity::Error,
+            MinSeverity::Warning => Severity::Warning,
+            MinSeverity::Info => Severity::Info,
+        }
+    };
+
+    let packages = workspace::members(args.manifest_path.as_deref())
+        .and_then(|members| workspace::select(members, &args.packages))
+        .unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        });
+
+    let start_time = std::time::Instant::now();
+    let mut stdout = io::stdout().lock();
+    let mut reports = Vec::new();
+
+    for package in packages {
+        let src_dir = package.src_dir();
+        if !src_dir.is_dir() {
+            continue;
+        }
+
+        let mut summary = lint_package(&src_dir, args.follow_symlinks);
+        summary.diagnostics.retain(|d| d.severity >= min_severity);
+
+        if format == OutputFormat::Pretty {
+            diagnostics::print_diagnostics(&summary.diagnostics, format, &mut stdout);
+            for err in &summary.parse_errors {
+                eprintln!("Parse error: {}", err);
+            }
+            for err in &summary.macro_errors {
+                eprintln!("warning: {}", err);
+            }
+            let _ = writeln!(stdout, "{}", package_summary(&package, &summary));
+        }
+        reports.push(PackageReport { package, summary });
+    }
+
+    let all_diagnostics: Vec<_> = reports
+        .iter()
+        .flat_map(|r| r.summary.diagnostics.iter().cloned())
+        .collect();
+
+    if format == OutputFormat::Json {
+        let json = serde_json::to_string_pretty(&reports).unwrap_or_else(|e| {
+            eprintln!("Failed to serialize diagnostics to JSON: {}", e);
+            "[]".to_string()
+        });
+        let _ = writeln!(stdout, "{}", json);
+    }
+
+    let files_checked = reports.iter().map(|r| r.summary.files_checked).sum();
+    diagnostics::print_summary(
+        &all_diagnostics,
+        files_checked,
+        start_time.elapsed(),
+        format,
+        &mut stdout,
+    );
+    let _ = stdout.flush();
+
+    if all_diagnostics
+        .iter()
+        .any(|d| d.severity == Severity::Error)
+    {
+        process::exit(1);
+    }
+}
+
+/// Lint one crate's `src/` directory with its nearest config file.
+fn lint_package(src_dir: &Path, follow_symlinks: bool) -> LintSummary {
+    let config = Config::discover(src_dir).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    });
+    for warning in &config.warnings {
+        eprintln!("warning: {}", warning);
+    }
+    LintRunner::new()
+        .config(config)
+        .follow_symlinks(follow_symlinks)
+        .run(src_dir)
+}
+
+/// One-line result for a crate, e.g. `web: 2 errors, 1 warning, 0 infos in 3 files`.
+fn package_summary(package: &Package, summary: &LintSummary) -> String {
+    let count = |severity| {
+        summary
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    };
+    let plural = |n: usize| if n == 1 { "" } else { "s" };
+    let (errors, warnings, infos) = (
+        count(Severity::Error),
+        count(Severity::Warning),
+        count(Severity::Info),
+    );
+    format!(
+        "{}: {} error{}, {} warning{}, {} info{} in {} file{}",
+        package.name,
+        errors,
+        plural(errors),
+        warnings,
+        plural(warnings),
+        infos,
+        plural(infos),
+        summary.files_checked,
+        plural(summary.files_checked),
+    )
+}
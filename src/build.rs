@@ -0,0 +1,132 @@
+//! Build-script integration: lint the crate while it compiles.
+//!
+//! Call this from `fn main` in `build.rs`, with `rsx-a11y` as a build
+//! dependency:
+//!
+//! ```rust,no_run
+//! rsx_a11y::build::check_and_emit();
+//! ```
+//!
+//! Each diagnostic is printed as a `cargo:warning=` line, so it shows up in
+//! `cargo build` output. To fail the build on errors instead:
+//!
+//! ```rust,no_run
+//! use rsx_a11y::build::{BuildOptions, check_and_emit_with};
+//!
+//! check_and_emit_with(&BuildOptions {
+//!     fail_on_errors: true,
+//!     ..BuildOptions::default()
+//! });
+//! ```
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::lints::Severity;
+use crate::{LintSummary, check_project_with};
+
+/// Options for [`check_and_emit_with`].
+#[derive(Debug, Clone, Default)]
+pub struct BuildOptions {
+    /// Directory to lint, relative to `CARGO_MANIFEST_DIR`. Defaults to `src`.
+    pub path: Option<PathBuf>,
+    /// Panic (failing the build) if any error-level diagnostic is found.
+    pub fail_on_errors: bool,
+}
+
+/// Lint `src/` and print a `cargo:warning=` line for each diagnostic.
+/// Never fails the build.
+pub fn check_and_emit() -> LintSummary {
+    check_and_emit_with(&BuildOptions::default())
+}
+
+/// Lint the crate being built according to `options`, printing a
+/// `cargo:warning=` line for each diagnostic and `cargo:rerun-if-changed=`
+/// lines for the linted directory and config file.
+///
+/// Panics if the nearest `rsx-a11y.toml` cannot be loaded, or if
+/// `fail_on_errors` is set and errors were found.
+pub fn check_and_emit_with(options: &BuildOptions) -> LintSummary {
+    let manifest_dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default());
+    let path = manifest_dir.join(options.path.as_deref().unwrap_or(Path::new("src")));
+    let config = Config::discover(&path).unwrap_or_else(|e| panic!("{}", e));
+    let summary = check_project_with(&path, &config);
+
+    let mut stdout = std::io::stdout().lock();
+    let _ = writeln!(stdout, "cargo:rerun-if-changed={}", path.display());
+    if let Some(ref config_path) = config.path {
+        let _ = writeln!(stdout, "cargo:rerun-if-changed={}", config_path.display());
+    }
+    emit(&summary, &manifest_dir, &mut stdout);
+
+    let errors = summary
+        .diagnostics
+        .iter()
+        .filter(|d| d.severity == Severity::Error)
+        .count();
+    if options.fail_on_errors && errors > 0 {
+        panic!("rsx-a11y found {} accessibility error(s)", errors);
+    }
+    summary
+}
+
+/// Write one `cargo:warning=` line per diagnostic and parse error, with
+/// paths relative to `root`.
+fn emit(summary: &LintSummary, root: &Path, w: &mut dyn Write) {
+    for diag in &summary.diagnostics {
+        let file = Path::new(&diag.file)
+            .strip_prefix(root)
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_else(|_| diag.file.clone());
+        let _ = writeln!(
+            w,
+            "cargo:warning={}:{}:{}: {}[{}]: {}",
+            file, diag.line, diag.column, diag.severity, diag.rule, diag.message
+        );
+    }
+    for err in &summary.parse_errors {
+        let _ = writeln!(w, "cargo:warning={}", err);
+    }
+    for err in &summary.macro_errors {
+        let _ = writeln!(w, "cargo:warning={}", err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::Tag;
+    use crate::lints::{LintDiagnostic, Rule};
+    use crate::parser::HtmlElement;
+
+    #[test]
+    fn test_emit_cargo_warnings() {
+        let img = HtmlElement::builder(Tag::Img)
+            .file("/crate/src/app.rs")
+            .span(3, 8)
+            .build();
+        let summary = LintSummary {
+            diagnostics: vec![LintDiagnostic::new(
+                Rule::AltText,
+                &img,
+                "<img> element is missing an `alt` attribute.",
+            )],
+            parse_errors: Vec::new(),
+            macro_errors: Vec::new(),
+            files_checked: 1,
+            element_counts: Default::default(),
+            cancelled: false,
+            skipped_files: Vec::new(),
+            generated_files: Vec::new(),
+            timings: None,
+            passes: Vec::new(),
+        };
+        let mut out = Vec::new();
+        emit(&summary, Path::new("/crate"), &mut out);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "cargo:warning=src/app.rs:3:8: error[alt-text]: <img> element is missing an `alt` attribute.\n"
+        );
+    }
+}
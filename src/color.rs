@@ -0,0 +1,236 @@
+//! CSS color parsing and WCAG contrast ratios, used by the `color-contrast`
+//! rule.
+//!
+//! ```
+//! use rsx_a11y::color::{self, Color};
+//!
+//! let gray = Color::parse("#777").unwrap();
+//! let white = Color::parse("white").unwrap();
+//! assert!((color::contrast_ratio(gray, white) - 4.48).abs() < 0.01);
+//! ```
+
+/// An opaque sRGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+/// Named colors accepted by [`Color::parse`].
+const NAMED: &[(&str, [u8; 3])] = &[
+    ("aqua", [0, 255, 255]),
+    ("black", [0, 0, 0]),
+    ("blue", [0, 0, 255]),
+    ("brown", [165, 42, 42]),
+    ("cyan", [0, 255, 255]),
+    ("darkblue", [0, 0, 139]),
+    ("darkgray", [169, 169, 169]),
+    ("darkgreen", [0, 100, 0]),
+    ("darkgrey", [169, 169, 169]),
+    ("darkred", [139, 0, 0]),
+    ("dimgray", [105, 105, 105]),
+    ("dimgrey", [105, 105, 105]),
+    ("fuchsia", [255, 0, 255]),
+    ("gold", [255, 215, 0]),
+    ("gray", [128, 128, 128]),
+    ("green", [0, 128, 0]),
+    ("grey", [128, 128, 128]),
+    ("lightgray", [211, 211, 211]),
+    ("lightgrey", [211, 211, 211]),
+    ("lime", [0, 255, 0]),
+    ("magenta", [255, 0, 255]),
+    ("maroon", [128, 0, 0]),
+    ("navy", [0, 0, 128]),
+    ("olive", [128, 128, 0]),
+    ("orange", [255, 165, 0]),
+    ("pink", [255, 192, 203]),
+    ("purple", [128, 0, 128]),
+    ("red", [255, 0, 0]),
+    ("silver", [192, 192, 192]),
+    ("teal", [0, 128, 128]),
+    ("white", [255, 255, 255]),
+    ("whitesmoke", [245, 245, 245]),
+    ("yellow", [255, 255, 0]),
+];
+
+impl Color {
+    /// Parse a CSS color: `#rgb`, `#rrggbb`, `rgb()`, `hsl()` or a common
+    /// named color. Translucent colors (alpha below 1), `transparent`,
+    /// `currentcolor` and anything else return `None`, since their contrast
+    /// depends on what is behind them.
+    pub fn parse(value: &str) -> Option<Color> {
+        let value = value.trim().to_ascii_lowercase();
+        if let Some(hex) = value.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+        if let Some(args) = function_args(&value, &["rgb", "rgba"]) {
+            let [r, g, b] = [args[0], args[1], args[2]].map(|c| {
+                match c.strip_suffix('%') {
+                    Some(percent) => percent.parse::<f64>().ok().map(|p| p * 2.55),
+                    None => c.parse::<f64>().ok(),
+                }
+                .map(|c| c.clamp(0.0, 255.0).round() as u8)
+            });
+            return Some(Color {
+                r: r?,
+                g: g?,
+                b: b?,
+            });
+        }
+        if let Some(args) = function_args(&value, &["hsl", "hsla"]) {
+            let hue = args[0].trim_end_matches("deg").parse::<f64>().ok()?;
+            let saturation = args[1].strip_suffix('%')?.parse::<f64>().ok()? / 100.0;
+            let lightness = args[2].strip_suffix('%')?.parse::<f64>().ok()? / 100.0;
+            return Some(from_hsl(hue, saturation, lightness));
+        }
+        NAMED
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, [r, g, b])| Color {
+                r: *r,
+                g: *g,
+                b: *b,
+            })
+    }
+
+    /// Relative luminance as defined by WCAG 2.
+    pub fn luminance(self) -> f64 {
+        let channel = |c: u8| {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * channel(self.r) + 0.7152 * channel(self.g) + 0.0722 * channel(self.b)
+    }
+}
+
+/// WCAG 2 contrast ratio between two colors, from 1 to 21.
+pub fn contrast_ratio(a: Color, b: Color) -> f64 {
+    let (a, b) = (a.luminance(), b.luminance());
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digits: Vec<u8> = match hex.len() {
+        // Short forms repeat each digit; a fourth digit is alpha.
+        3 | 4 => hex
+            .chars()
+            .map(|c| u8::from_str_radix(&format!("{c}{c}"), 16).ok())
+            .collect::<Option<_>>()?,
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<_>>()?,
+        _ => return None,
+    };
+    if digits.get(3).is_some_and(|&alpha| alpha < 255) {
+        return None;
+    }
+    Some(Color {
+        r: digits[0],
+        g: digits[1],
+        b: digits[2],
+    })
+}
+
+/// The three color arguments of `name(...)`, in comma or space syntax, or
+/// `None` if the color has an alpha below 1.
+fn function_args<'a>(value: &'a str, names: &[&str]) -> Option<Vec<&'a str>> {
+    let (name, rest) = value.split_once('(')?;
+    if !names.contains(&name.trim()) {
+        return None;
+    }
+    let inner = rest.strip_suffix(')')?;
+    let (channels, alpha) = match inner.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha.trim())),
+        None => (inner, None),
+    };
+    let mut args: Vec<&str> = channels
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|a| !a.is_empty())
+        .collect();
+    let alpha = match (alpha, args.len()) {
+        (Some(alpha), 3) => Some(alpha),
+        (None, 4) => args.pop(),
+        (None, 3) => None,
+        _ => return None,
+    };
+    if let Some(alpha) = alpha {
+        let opaque = match alpha.strip_suffix('%') {
+            Some(percent) => percent.parse::<f64>().ok()? >= 100.0,
+            None => alpha.parse::<f64>().ok()? >= 1.0,
+        };
+        if !opaque {
+            return None;
+        }
+    }
+    Some(args)
+}
+
+fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Color {
+    let (s, l) = (saturation.clamp(0.0, 1.0), lightness.clamp(0.0, 1.0));
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = l - chroma / 2.0;
+    let channel = |c: f64| ((c + m) * 255.0).round() as u8;
+    Color {
+        r: channel(r),
+        g: channel(g),
+        b: channel(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_colors() {
+        let red = Some(Color { r: 255, g: 0, b: 0 });
+        for value in [
+            "red",
+            "#f00",
+            "#FF0000",
+            "#ff0000ff",
+            "rgb(255, 0, 0)",
+            "rgb(100% 0% 0%)",
+            "rgba(255,0,0,1)",
+            "hsl(0, 100%, 50%)",
+        ] {
+            assert_eq!(Color::parse(value), red, "{}", value);
+        }
+        for value in [
+            "transparent",
+            "#f008",
+            "rgba(255, 0, 0, 0.5)",
+            "rgb(1 2)",
+            "var(--x)",
+        ] {
+            assert_eq!(Color::parse(value), None, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = Color::parse("black").unwrap();
+        let white = Color::parse("white").unwrap();
+        assert!((contrast_ratio(black, white) - 21.0).abs() < 1e-9);
+        assert!((contrast_ratio(white, white) - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,805 @@
+//! Configuration file discovery and resolution.
+//!
+//! Projects can place an `rsx-a11y.toml` file next to their sources to pick a
+//! preset, change rule levels, and override levels for specific paths:
+//!
+//! ```toml
+//! preset = "recommended"
+//! # Lint these macros too, besides `html!`, `html_nested!`, `view!`, and `rsx!`.
+//! macros = ["my_html"]
+//! # Skip these generated files, besides those marked `@generated` or
+//! # `DO NOT EDIT`.
+//! generated = ["src/bindings/**"]
+//!
+//! [rules]
+//! no-autofocus = "off"
+//! anchor-is-valid = "error"
+//!
+//! [rules.anchor-ambiguous-text]
+//! level = "warning"
+//! words = ["click here", "here", "read more"]
+//!
+//! [[overrides]]
+//! files = ["src/legacy/**"]
+//! rules = { click-events-have-key-events = "off" }
+//!
+//! # Custom elements are treated like `<span>` unless declared here.
+//! [custom-elements.my-button]
+//! interactive = true
+//! role = "button"
+//!
+//! # Classes that hide content visually (`sr-only`) or entirely (`hidden`).
+//! [classes]
+//! sr-only = ["sr-only", "visually-hidden"]
+//! hidden = ["hidden"]
+//! ```
+//!
+//! See [`crate::options`] for the options each rule accepts.
+//!
+//! The configuration is resolved per file: preset first, then `[rules]`, then
+//! every matching `[[overrides]]` entry in order, and finally CLI flags.
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use strum::IntoEnumIterator;
+
+use crate::dom::{CustomElement, Tag};
+use crate::lints::{DOCS_URL, LintDiagnostic, Rule, Severity};
+use crate::options::RuleOptions;
+use crate::parser::ParseOptions;
+use crate::style::HidingClasses;
+
+/// File name searched for when discovering a configuration file.
+pub const CONFIG_FILE_NAME: &str = "rsx-a11y.toml";
+
+/// Named starting point for rule levels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Preset {
+    /// Every rule enabled at its default severity.
+    #[default]
+    Recommended,
+    /// Every rule enabled, with all warnings promoted to errors.
+    Strict,
+}
+
+/// The level a rule is configured at.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, strum::Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum RuleLevel {
+    /// The rule is disabled.
+    Off,
+    Info,
+    #[serde(alias = "warn")]
+    Warning,
+    Error,
+}
+
+impl RuleLevel {
+    /// The severity diagnostics are reported at, or `None` if the rule is off.
+    pub fn severity(&self) -> Option<Severity> {
+        match self {
+            RuleLevel::Off => None,
+            RuleLevel::Info => Some(Severity::Info),
+            RuleLevel::Warning => Some(Severity::Warning),
+            RuleLevel::Error => Some(Severity::Error),
+        }
+    }
+}
+
+impl From<Severity> for RuleLevel {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Error => RuleLevel::Error,
+            Severity::Warning => RuleLevel::Warning,
+            Severity::Info => RuleLevel::Info,
+        }
+    }
+}
+
+/// A rule entry in the config file: either a bare level (`"warn"`) or a table
+/// with an optional `level` and rule-specific options.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum RuleSetting {
+    Level(RuleLevel),
+    Detailed {
+        #[serde(default)]
+        level: Option<RuleLevel>,
+        #[serde(flatten)]
+        options: toml::Table,
+    },
+}
+
+impl RuleSetting {
+    fn level(&self) -> Option<RuleLevel> {
+        match self {
+            RuleSetting::Level(level) => Some(*level),
+            RuleSetting::Detailed { level, .. } => *level,
+        }
+    }
+
+    fn options(&self) -> Option<&toml::Table> {
+        match self {
+            RuleSetting::Level(_) => None,
+            RuleSetting::Detailed { options, .. } => Some(options).filter(|o| !o.is_empty()),
+        }
+    }
+}
+
+/// Raw contents of an `rsx-a11y.toml` file.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Preset the rule levels start from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<Preset>,
+    /// Additional macro names to lint, besides `html!`, `html_nested!`, `view!`, and `rsx!`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub macros: Vec<String>,
+    /// Glob patterns of generated files to skip, relative to the directory
+    /// containing the config file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generated: Vec<String>,
+    /// Rule settings keyed by rule id (e.g. `alt-text`).
+    #[serde(default)]
+    pub rules: std::collections::BTreeMap<String, RuleSetting>,
+    /// Path-specific rule levels, applied in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<OverrideEntry>,
+    /// How custom elements behave, keyed by tag name (e.g. `my-button`).
+    #[serde(
+        default,
+        rename = "custom-elements",
+        skip_serializing_if = "std::collections::BTreeMap::is_empty"
+    )]
+    pub custom_elements: std::collections::BTreeMap<String, CustomElement>,
+    /// Classes that hide content, replacing the defaults.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub classes: Option<HidingClasses>,
+    /// Template for rule documentation URLs, replacing
+    /// [`DOCS_URL`](crate::lints::DOCS_URL).
+    #[serde(default, rename = "docs-url", skip_serializing_if = "Option::is_none")]
+    pub docs_url: Option<String>,
+}
+
+/// A `[[overrides]]` entry: rule levels that apply only to matching files.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OverrideEntry {
+    /// Glob patterns relative to the directory containing the config file.
+    pub files: Vec<String>,
+    /// Rule settings keyed by rule id.
+    #[serde(default)]
+    pub rules: std::collections::BTreeMap<String, RuleSetting>,
+}
+
+/// Errors that can occur while loading a configuration file.
+#[derive(Debug, Clone, serde::Serialize)]
+pub enum ConfigError {
+    IoError(String, String),
+    /// The file is not valid TOML or does not match the expected schema.
+    TomlError(String, String),
+    /// A rule id in the file does not name a known rule.
+    UnknownRule(String, String),
+    /// An `[[overrides]]` glob pattern is invalid.
+    InvalidGlob(String, String),
+    /// Options given for a rule do not match its option schema.
+    InvalidOptions(String, String, String),
+    /// A `[custom-elements]` key is not a valid custom element name.
+    InvalidCustomElement(String, String),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::IoError(path, err) => write!(f, "Failed to read {}: {}", path, err),
+            ConfigError::TomlError(path, err) => write!(f, "Invalid config {}: {}", path, err),
+            ConfigError::UnknownRule(path, rule) => {
+                write!(f, "Unknown rule `{}` in {}", rule, path)
+            }
+            ConfigError::InvalidGlob(path, err) => {
+                write!(f, "Invalid glob pattern in {}: {}", path, err)
+            }
+            ConfigError::InvalidOptions(path, rule, err) => {
+                write!(f, "Invalid options for `{}` in {}: {}", rule, path, err)
+            }
+            ConfigError::InvalidCustomElement(path, name) => write!(
+                f,
+                "`{}` in {} is not a custom element name (it needs a dash, like `my-button`)",
+                name, path
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// A loaded configuration, ready to be resolved for individual files.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// Path of the config file, or `None` when using built-in defaults.
+    pub path: Option<PathBuf>,
+    /// Directory that override globs are relative to.
+    pub root: PathBuf,
+    pub file: ConfigFile,
+    /// Deprecation warnings for renamed rule ids used in the file.
+    pub warnings: Vec<String>,
+    rules: Vec<(Rule, RuleSetting)>,
+    overrides: Vec<(GlobSet, Vec<(Rule, RuleSetting)>)>,
+    generated: GlobSet,
+}
+
+impl Config {
+    /// Load and validate a configuration file.
+    pub fn load(path: &Path) -> Result<Config, ConfigError> {
+        let display = path.to_string_lossy().replace('\\', "/");
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| ConfigError::IoError(display.clone(), e.to_string()))?;
+        let file: ConfigFile = toml::from_str(&source)
+            .map_err(|e| ConfigError::TomlError(display.clone(), e.to_string()))?;
+        let root = canonicalize(path)
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        Config::from_file(file, Some(path.to_path_buf()), root)
+    }
+
+    /// Build a configuration from already-parsed file contents.
+    pub fn from_file(
+        file: ConfigFile,
+        path: Option<PathBuf>,
+        root: PathBuf,
+    ) -> Result<Config, ConfigError> {
+        let display = path
+            .as_deref()
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_else(|| CONFIG_FILE_NAME.to_string());
+
+        let parse_rules = |rules: &std::collections::BTreeMap<String, RuleSetting>,
+                           warnings: &mut Vec<String>| {
+            rules
+                .iter()
+                .map(|(id, setting)| {
+                    let (rule, renamed) = Rule::resolve(id)
+                        .ok_or_else(|| ConfigError::UnknownRule(display.clone(), id.clone()))?;
+                    if let Some(renamed) = renamed {
+                        let warning = format!("{}: {}", display, renamed.warning());
+                        if !warnings.contains(&warning) {
+                            warnings.push(warning);
+                        }
+                    }
+                    // Validate options up front so errors surface at load time.
+                    if let Some(options) = setting.options() {
+                        RuleOptions::default()
+                            .set(&rule, options.clone())
+                            .map_err(|e| {
+                                ConfigError::InvalidOptions(display.clone(), id.clone(), e)
+                            })?;
+                    }
+                    Ok((rule, setting.clone()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        if let Some(name) = file
+            .custom_elements
+            .keys()
+            .find(|name| !matches!(Tag::from_str(name), Ok(Tag::Custom(_))))
+        {
+            return Err(ConfigError::InvalidCustomElement(display, name.clone()));
+        }
+
+        let glob_set = |patterns: &[String]| {
+            let mut builder = GlobSetBuilder::new();
+            for pattern in patterns {
+                let glob = Glob::new(pattern)
+                    .map_err(|e| ConfigError::InvalidGlob(display.clone(), e.to_string()))?;
+                builder.add(glob);
+            }
+            builder
+                .build()
+                .map_err(|e| ConfigError::InvalidGlob(display.clone(), e.to_string()))
+        };
+
+        let mut warnings = Vec::new();
+        let rules = parse_rules(&file.rules, &mut warnings)?;
+        let mut overrides = Vec::new();
+        for entry in &file.overrides {
+            let set = glob_set(&entry.files)?;
+            overrides.push((set, parse_rules(&entry.rules, &mut warnings)?));
+        }
+        let generated = glob_set(&file.generated)?;
+
+        Ok(Config {
+            path,
+            root,
+            file,
+            warnings,
+            rules,
+            overrides,
+            generated,
+        })
+    }
+
+    /// Find the nearest `rsx-a11y.toml` in `start` or any of its ancestors and
+    /// load it. Returns the built-in defaults if no file is found.
+    pub fn discover(start: &Path) -> Result<Config, ConfigError> {
+        let start = canonicalize(start);
+        let mut dir = if start.is_file() {
+            start.parent().map(Path::to_path_buf)
+        } else {
+            Some(start.clone())
+        };
+
+        while let Some(current) = dir {
+            let candidate = current.join(CONFIG_FILE_NAME);
+            if candidate.is_file() {
+                return Config::load(&candidate);
+            }
+            dir = current.parent().map(Path::to_path_buf);
+        }
+
+        Ok(Config {
+            root: if start.is_file() {
+                start.parent().map(Path::to_path_buf).unwrap_or_default()
+            } else {
+                start
+            },
+            ..Config::default()
+        })
+    }
+
+    /// Parse options for files covered by this configuration.
+    pub fn parse_options(&self) -> ParseOptions {
+        let mut options = ParseOptions::default();
+        for name in &self.file.macros {
+            let name = name.trim_end_matches('!');
+            if !options.macros.iter().any(|m| m == name) {
+                options.macros.push(name.to_string());
+            }
+        }
+        options.custom_elements = self.file.custom_elements.clone();
+        if let Some(classes) = &self.file.classes {
+            options.classes = classes.clone();
+        }
+        options
+    }
+
+    /// Whether `file` matches one of the `generated` patterns.
+    pub fn is_generated(&self, file: &Path) -> bool {
+        let absolute = canonicalize(file);
+        let relative = absolute.strip_prefix(&self.root).unwrap_or(file);
+        self.generated.is_match(relative)
+    }
+
+    /// Resolve the effective rule levels for a single file.
+    pub fn resolve(&self, file: &Path) -> ResolvedConfig {
+        let preset = self.file.preset.unwrap_or_default();
+        let mut rules: Vec<ResolvedRule> = Rule::iter()
+            .map(|rule| {
+                let default = rule.default_severity();
+                let (level, origin) = match preset {
+                    _ if !rule.enabled_by_default() => (RuleLevel::Off, Origin::Preset(preset)),
+                    Preset::Recommended => (RuleLevel::from(default), Origin::Preset(preset)),
+                    Preset::Strict => {
+                        let level = if default == Severity::Info {
+                            RuleLevel::Info
+                        } else {
+                            RuleLevel::Error
+                        };
+                        (level, Origin::Preset(preset))
+                    }
+                };
+                ResolvedRule {
+                    rule,
+                    level,
+                    origin,
+                }
+            })
+            .collect();
+
+        // Option tables are merged key by key, so an override only needs to
+        // mention the options it changes.
+        let mut option_tables: Vec<(Rule, toml::Table)> = Vec::new();
+        let mut set = |rule: &Rule, setting: &RuleSetting, origin: Origin| {
+            if let Some(level) = setting.level()
+                && let Some(entry) = rules.iter_mut().find(|r| r.rule == *rule)
+            {
+                entry.level = level;
+                entry.origin = origin;
+            }
+            if let Some(options) = setting.options() {
+                match option_tables.iter_mut().find(|(r, _)| r == rule) {
+                    Some((_, table)) => table.extend(options.clone()),
+                    None => option_tables.push((rule.clone(), options.clone())),
+                }
+            }
+        };
+
+        for (rule, setting) in &self.rules {
+            set(rule, setting, Origin::ConfigFile);
+        }
+
+        let absolute = canonicalize(file);
+        let relative = absolute.strip_prefix(&self.root).unwrap_or(file);
+        let mut matched_overrides = Vec::new();
+        for (index, (globs, override_rules)) in self.overrides.iter().enumerate() {
+            if globs.is_match(relative) {
+                matched_overrides.push(index);
+                for (rule, setting) in override_rules {
+                    set(rule, setting, Origin::Override(index));
+                }
+            }
+        }
+
+        let mut options = RuleOptions::default();
+        for (rule, table) in option_tables {
+            // Tables were validated in `from_file`; merging keys cannot make
+            // them invalid.
+            let _ = options.set(&rule, table);
+        }
+
+        ResolvedConfig {
+            file: file.to_string_lossy().replace('\\', "/"),
+            config_path: self
+                .path
+                .as_deref()
+                .map(|p| p.to_string_lossy().replace('\\', "/")),
+            preset,
+            matched_overrides,
+            rules,
+            options,
+            docs_url: self
+                .file
+                .docs_url
+                .clone()
+                .unwrap_or_else(|| DOCS_URL.to_string()),
+        }
+    }
+}
+
+pub(crate) fn canonicalize(path: &Path) -> PathBuf {
+    // `std::fs::canonicalize` produces `\\?\` prefixes on Windows which break
+    // prefix comparisons against user-provided paths; strip them.
+    match std::fs::canonicalize(path) {
+        Ok(p) => {
+            let s = p.to_string_lossy();
+            match s.strip_prefix(r"\\?\") {
+                Some(stripped) => PathBuf::from(stripped),
+                None => p,
+            }
+        }
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+/// Where a resolved rule level came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case", tag = "kind", content = "value")]
+pub enum Origin {
+    /// The rule's level in the active preset.
+    Preset(Preset),
+    /// The `[rules]` table of the config file.
+    ConfigFile,
+    /// The `[[overrides]]` entry at this index.
+    Override(usize),
+    /// A command-line flag (`--only` / `--skip`).
+    CommandLine,
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Origin::Preset(Preset::Recommended) => write!(f, "preset \"recommended\""),
+            Origin::Preset(Preset::Strict) => write!(f, "preset \"strict\""),
+            Origin::ConfigFile => write!(f, "[rules]"),
+            Origin::Override(index) => write!(f, "[[overrides]] #{}", index),
+            Origin::CommandLine => write!(f, "command line"),
+        }
+    }
+}
+
+/// The effective level of one rule for one file.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ResolvedRule {
+    pub rule: Rule,
+    pub level: RuleLevel,
+    pub origin: Origin,
+}
+
+/// Fully-resolved configuration for a single file.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ResolvedConfig {
+    /// The file this configuration applies to.
+    pub file: String,
+    /// The config file that was loaded, if any.
+    pub config_path: Option<String>,
+    pub preset: Preset,
+    /// Indices of the `[[overrides]]` entries that matched this file.
+    pub matched_overrides: Vec<usize>,
+    /// Effective level of every rule.
+    pub rules: Vec<ResolvedRule>,
+    /// Effective options for configurable rules.
+    pub options: RuleOptions,
+    /// Template for the [`url`](LintDiagnostic::url) of diagnostics.
+    pub docs_url: String,
+}
+
+impl ResolvedConfig {
+    /// Restrict the enabled rules to `only` (from `--only`). Opt-in rules
+    /// named in `only` are enabled at their default severity.
+    pub fn apply_only(&mut self, only: &[Rule]) {
+        for entry in &mut self.rules {
+            if !only.contains(&entry.rule) && entry.level != RuleLevel::Off {
+                entry.level = RuleLevel::Off;
+                entry.origin = Origin::CommandLine;
+            } else if only.contains(&entry.rule)
+                && !entry.rule.enabled_by_default()
+                && matches!(entry.origin, Origin::Preset(_))
+            {
+                entry.level = RuleLevel::from(entry.rule.default_severity());
+                entry.origin = Origin::CommandLine;
+            }
+        }
+    }
+
+    /// Disable the rules in `skip` (from `--skip`).
+    pub fn apply_skip(&mut self, skip: &[Rule]) {
+        for entry in &mut self.rules {
+            if skip.contains(&entry.rule) {
+                entry.level = RuleLevel::Off;
+                entry.origin = Origin::CommandLine;
+            }
+        }
+    }
+
+    /// The effective level of `rule`.
+    pub fn level(&self, rule: &Rule) -> RuleLevel {
+        self.rules
+            .iter()
+            .find(|r| r.rule == *rule)
+            .map_or(RuleLevel::Off, |r| r.level)
+    }
+
+    /// Apply the configured level and docs URL to a diagnostic, returning
+    /// `None` if its rule is turned off. The diagnostic keeps the severity it
+    /// was reported at unless the config sets a level for its rule or the
+    /// preset changes the rule's default.
+    pub fn apply(&self, mut diagnostic: LintDiagnostic) -> Option<LintDiagnostic> {
+        let entry = self.rules.iter().find(|r| r.rule == diagnostic.rule)?;
+        let severity = entry.level.severity()?;
+        let level_set = matches!(entry.origin, Origin::ConfigFile | Origin::Override(_))
+            || entry.level != RuleLevel::from(entry.rule.default_severity());
+        if level_set {
+            diagnostic.severity = severity;
+        }
+        diagnostic.url = Some(diagnostic.rule.docs_url(&self.docs_url));
+        Some(diagnostic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(source: &str) -> Config {
+        let file: ConfigFile = toml::from_str(source).unwrap();
+        Config::from_file(file, None, PathBuf::from("/project")).unwrap()
+    }
+
+    #[test]
+    fn test_default_levels_match_rules() {
+        let resolved = Config::default().resolve(Path::new("src/app.rs"));
+        assert_eq!(resolved.level(&Rule::AltText), RuleLevel::Error);
+        assert_eq!(resolved.level(&Rule::NoAutofocus), RuleLevel::Warning);
+        assert_eq!(resolved.level(&Rule::PreferTagOverRole), RuleLevel::Info);
+    }
+
+    #[test]
+    fn test_docs_url_template() {
+        let elements =
+            crate::parser::parse_source(r#"fn c() { html! { <img src="a.png" /> } }"#, "a.rs")
+                .unwrap();
+        let diagnostic = || Rule::AltText.check_all(&elements).remove(0);
+        let resolved = Config::default().resolve(Path::new("a.rs"));
+        assert_eq!(
+            resolved.apply(diagnostic()).unwrap().url.as_deref(),
+            Some(Rule::AltText.docs_url(DOCS_URL).as_str())
+        );
+        let resolved =
+            config("docs-url = \"https://a11y.example/{rule}.html\"").resolve(Path::new("a.rs"));
+        assert_eq!(
+            resolved.apply(diagnostic()).unwrap().url.as_deref(),
+            Some("https://a11y.example/alt-text.html")
+        );
+    }
+
+    #[test]
+    fn test_apply_keeps_severity_without_configured_level() {
+        let elements =
+            crate::parser::parse_source(r#"fn c() { html! { <input autofocus=true /> } }"#, "a.rs")
+                .unwrap();
+        let diagnostic = || {
+            let mut diagnostic = Rule::NoAutofocus.check_all(&elements).remove(0);
+            diagnostic.severity = Severity::Info;
+            diagnostic
+        };
+        let severity = |source: &str| {
+            config(source)
+                .resolve(Path::new("a.rs"))
+                .apply(diagnostic())
+                .map(|d| d.severity)
+        };
+        assert_eq!(severity(""), Some(Severity::Info));
+        assert_eq!(
+            severity("[rules]\nno-autofocus = \"warning\""),
+            Some(Severity::Warning)
+        );
+        assert_eq!(severity(r#"preset = "strict""#), Some(Severity::Error));
+        assert_eq!(severity("[rules]\nno-autofocus = \"off\""), None);
+    }
+
+    #[test]
+    fn test_opt_in_rules_are_off_by_default() {
+        let rule = Rule::IdentityFieldsHaveAutocomplete;
+        let resolved = Config::default().resolve(Path::new("src/app.rs"));
+        assert_eq!(resolved.level(&rule), RuleLevel::Off);
+
+        let resolved = config(
+            r#"
+            [rules]
+            identity-fields-have-autocomplete = "error"
+            "#,
+        )
+        .resolve(Path::new("src/app.rs"));
+        assert_eq!(resolved.level(&rule), RuleLevel::Error);
+
+        let mut resolved = Config::default().resolve(Path::new("src/app.rs"));
+        resolved.apply_only(std::slice::from_ref(&rule));
+        assert_eq!(resolved.level(&rule), RuleLevel::Warning);
+    }
+
+    #[test]
+    fn test_strict_preset_promotes_warnings() {
+        let resolved = config(r#"preset = "strict""#).resolve(Path::new("src/app.rs"));
+        assert_eq!(resolved.level(&Rule::NoAutofocus), RuleLevel::Error);
+        assert_eq!(resolved.level(&Rule::PreferTagOverRole), RuleLevel::Info);
+    }
+
+    #[test]
+    fn test_rules_and_overrides() {
+        let config = config(
+            r#"
+            [rules]
+            no-autofocus = "off"
+
+            [[overrides]]
+            files = ["src/legacy/**"]
+            rules = { alt-text = "warn" }
+            "#,
+        );
+
+        let app = config.resolve(Path::new("/project/src/app.rs"));
+        assert_eq!(app.level(&Rule::NoAutofocus), RuleLevel::Off);
+        assert_eq!(app.level(&Rule::AltText), RuleLevel::Error);
+        assert!(app.matched_overrides.is_empty());
+
+        let legacy = config.resolve(Path::new("/project/src/legacy/old.rs"));
+        assert_eq!(legacy.level(&Rule::AltText), RuleLevel::Warning);
+        assert_eq!(legacy.matched_overrides, vec![0]);
+    }
+
+    #[test]
+    fn test_jsx_a11y_rule_ids_are_accepted() {
+        let config = config(
+            r#"
+            [rules]
+            "jsx-a11y/no-autofocus" = "off"
+            "#,
+        );
+        let app = config.resolve(Path::new("/project/src/app.rs"));
+        assert_eq!(app.level(&Rule::NoAutofocus), RuleLevel::Off);
+    }
+
+    #[test]
+    fn test_extra_macros() {
+        let config = config("macros = [\"my_html!\", \"view\"]");
+        assert_eq!(
+            config.parse_options().macros,
+            vec!["html", "html_nested", "view", "rsx", "my_html"]
+        );
+    }
+
+    #[test]
+    fn test_custom_elements() {
+        let config = config("[custom-elements.my-button]\ninteractive = true\nrole = \"button\"");
+        let options = config.parse_options();
+        assert_eq!(
+            options.custom_elements["my-button"],
+            CustomElement {
+                interactive: true,
+                focusable: false,
+                role: Some(crate::dom::Role::Button),
+            }
+        );
+
+        let file: ConfigFile =
+            toml::from_str("[custom-elements.button]\nfocusable = true").unwrap();
+        assert!(matches!(
+            Config::from_file(file, None, PathBuf::from(".")),
+            Err(ConfigError::InvalidCustomElement(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_hiding_classes() {
+        let options = config("[classes]\nhidden = [\"hidden\"]").parse_options();
+        assert_eq!(options.classes.hidden, ["hidden"]);
+        assert_eq!(options.classes.sr_only, ["sr-only", "visually-hidden"]);
+    }
+
+    #[test]
+    fn test_unknown_rule_is_rejected() {
+        let file: ConfigFile = toml::from_str("[rules]\nbanana = \"off\"").unwrap();
+        assert!(matches!(
+            Config::from_file(file, None, PathBuf::from(".")),
+            Err(ConfigError::UnknownRule(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_rule_options_are_merged() {
+        let config = config(
+            r#"
+            [rules.anchor-ambiguous-text]
+            level = "error"
+            words = ["mehr"]
+
+            [rules.interactive-supports-focus]
+            mode = "focusable"
+
+            [[overrides]]
+            files = ["src/de/**"]
+            rules = { anchor-ambiguous-text = { words = ["hier"] } }
+            "#,
+        );
+
+        let app = config.resolve(Path::new("/project/src/app.rs"));
+        assert_eq!(app.level(&Rule::AnchorAmbiguousText), RuleLevel::Error);
+        assert_eq!(app.options.anchor_ambiguous_text.words, vec!["mehr"]);
+        assert_eq!(
+            app.options.interactive_supports_focus.mode,
+            crate::options::FocusMode::Focusable
+        );
+
+        let de = config.resolve(Path::new("/project/src/de/app.rs"));
+        assert_eq!(de.level(&Rule::AnchorAmbiguousText), RuleLevel::Error);
+        assert_eq!(de.options.anchor_ambiguous_text.words, vec!["hier"]);
+    }
+
+    #[test]
+    fn test_invalid_rule_options_are_rejected() {
+        let file: ConfigFile = toml::from_str("[rules.alt-text]\nwords = [\"x\"]").unwrap();
+        assert!(matches!(
+            Config::from_file(file, None, PathBuf::from(".")),
+            Err(ConfigError::InvalidOptions(_, _, _))
+        ));
+    }
+
+    #[test]
+    fn test_cli_only_and_skip() {
+        let mut resolved = Config::default().resolve(Path::new("src/app.rs"));
+        resolved.apply_only(&[Rule::AltText, Rule::AriaRole]);
+        resolved.apply_skip(&[Rule::AriaRole]);
+        assert_eq!(resolved.level(&Rule::AltText), RuleLevel::Error);
+        assert_eq!(resolved.level(&Rule::AriaRole), RuleLevel::Off);
+        assert_eq!(resolved.level(&Rule::NoAutofocus), RuleLevel::Off);
+    }
+}
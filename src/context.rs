@@ -0,0 +1,178 @@
+//! Facts about an element that many rules need, computed once per element.
+//!
+//! Rules receive a [`LintContext`] instead of scanning the element's
+//! attributes themselves, so every rule sees the same role, focusability and
+//! handlers, and the scans run once rather than once per rule.
+//!
+//! ```
+//! use rsx_a11y::context::LintContext;
+//! use rsx_a11y::dom::Role;
+//! use rsx_a11y::options::RuleOptions;
+//! use rsx_a11y::parser;
+//!
+//! let elements = parser::parse_source(
+//!     r#"fn c() { html! { <div role="button" tabindex="0" onclick={go}></div> } }"#,
+//!     "a.rs",
+//! )
+//! .unwrap();
+//! let options = RuleOptions::default();
+//! let cx = LintContext::new(&elements[0], &options);
+//! assert_eq!(cx.facts.role, Some(Role::Button));
+//! assert!(cx.facts.focusable && cx.facts.has_interaction_handler());
+//! ```
+
+use crate::dom::{self, AccessibleName, Aria, AttributeName, Role};
+use crate::options::RuleOptions;
+use crate::parser::{HtmlAttribute, HtmlElement};
+
+/// Where an element may get its accessible name from, judging by the
+/// attributes and content it has (not their values).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NameSources {
+    pub aria_label: bool,
+    pub aria_labelledby: bool,
+    pub title: bool,
+    pub alt: bool,
+    /// The element has child content.
+    pub content: bool,
+}
+
+impl NameSources {
+    /// Whether `aria-label` or `aria-labelledby` names the element.
+    pub fn aria(&self) -> bool {
+        self.aria_label || self.aria_labelledby
+    }
+}
+
+/// Facts derived from an element's attributes. See [`ElementFacts::new`].
+#[derive(Debug, Clone)]
+pub struct ElementFacts<'a> {
+    /// The effective role, as [`HtmlElement::role`].
+    pub role: Option<Role>,
+    /// The role given by a static `role` attribute, with the attribute, as
+    /// [`HtmlElement::explicit_role`].
+    pub explicit_role: Option<(&'a HtmlAttribute, Role)>,
+    /// As [`HtmlElement::is_focusable`].
+    pub focusable: bool,
+    /// As [`HtmlElement::is_tabbable`].
+    pub tabbable: bool,
+    /// As [`HtmlElement::is_hidden`].
+    pub hidden: bool,
+    /// Event handler attributes, in source order.
+    pub handlers: Vec<&'a HtmlAttribute>,
+    pub name_sources: NameSources,
+}
+
+impl<'a> ElementFacts<'a> {
+    /// Compute the facts for `element`.
+    pub fn new(element: &'a HtmlElement) -> Self {
+        let explicit_role = element.explicit_role();
+        let role = explicit_role
+            .as_ref()
+            .map(|(_, role)| role.clone())
+            .or_else(|| element.implicit_role());
+        let mut name_sources = NameSources {
+            content: element.has_children,
+            ..NameSources::default()
+        };
+        let mut handlers = Vec::new();
+        for attr in &element.attributes {
+            match &attr.name {
+                AttributeName::Aria(Aria::Label) => name_sources.aria_label = true,
+                AttributeName::Aria(Aria::LabelledBy) => name_sources.aria_labelledby = true,
+                AttributeName::Title => name_sources.title = true,
+                AttributeName::Alt => name_sources.alt = true,
+                name if name.is_event_handler() => handlers.push(attr),
+                _ => {}
+            }
+        }
+        ElementFacts {
+            role,
+            explicit_role,
+            focusable: element.is_focusable(),
+            tabbable: element.is_tabbable(),
+            hidden: element.is_hidden(),
+            handlers,
+            name_sources,
+        }
+    }
+
+    /// Whether the element has a pointer activation or keyboard handler.
+    pub fn has_interaction_handler(&self) -> bool {
+        self.handlers
+            .iter()
+            .any(|h| h.name.is_interaction_handler())
+    }
+
+    /// As [`HtmlElement::has_event_handler`]: an interaction handler or a
+    /// mouse hover handler.
+    pub fn has_event_handler(&self) -> bool {
+        self.handlers.iter().any(|h| {
+            h.name.is_interaction_handler()
+                || matches!(
+                    h.name,
+                    AttributeName::OnMouseOver
+                        | AttributeName::OnMouseOut
+                        | AttributeName::OnMouseEnter
+                        | AttributeName::OnMouseLeave
+                )
+        })
+    }
+}
+
+/// What a rule checks one element with: the element, its facts and the
+/// rule options.
+#[derive(Debug, Clone)]
+pub struct LintContext<'a> {
+    pub element: &'a HtmlElement,
+    pub facts: ElementFacts<'a>,
+    pub options: &'a RuleOptions,
+    /// Elements the element's accessible name may come from, usually those
+    /// of its macro. Just the element unless set with [`Self::with_tree`].
+    pub tree: &'a [HtmlElement],
+}
+
+impl<'a> LintContext<'a> {
+    pub fn new(element: &'a HtmlElement, options: &'a RuleOptions) -> Self {
+        LintContext {
+            element,
+            facts: ElementFacts::new(element),
+            options,
+            tree: std::slice::from_ref(element),
+        }
+    }
+
+    /// Look up `aria-labelledby` targets and `<label>`s in `tree`.
+    pub fn with_tree(mut self, tree: &'a [HtmlElement]) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    /// The element's accessible name; see [`dom::accessible_name`].
+    pub fn accessible_name(&self) -> Option<AccessibleName> {
+        dom::accessible_name(self.element, self.tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::Tag;
+
+    #[test]
+    fn test_facts_match_element_methods() {
+        let element = HtmlElement::builder(Tag::Button)
+            .attr("role", "menuitem link")
+            .attr("aria-label", "Open")
+            .dynamic_attr("onclick")
+            .dynamic_attr("onmouseenter")
+            .build();
+        let facts = ElementFacts::new(&element);
+        assert_eq!(facts.role, element.role());
+        assert_eq!(facts.role, Some(Role::MenuItem));
+        assert_eq!(facts.focusable, element.is_focusable());
+        assert_eq!(facts.handlers.len(), 2);
+        assert!(facts.has_event_handler() && facts.has_interaction_handler());
+        assert!(facts.name_sources.aria() && !facts.name_sources.title);
+    }
+}
@@ -5,7 +5,12 @@
 use std::io::Write;
 use std::time::Duration;
 
+use crate::config::ResolvedConfig;
+use crate::dom::AttributeName;
+use crate::fix::Applicability;
 use crate::lints::{LintDiagnostic, Severity};
+use crate::options::RuleOptions;
+use crate::parser::{AttrValue, ParsedFile};
 #[cfg(feature = "cli")]
 use colored::*;
 
@@ -18,6 +23,38 @@ pub enum OutputFormat {
     Json,
 }
 
+/// When to color terminal output.
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color when writing to a terminal, unless `NO_COLOR` is set;
+    /// `CLICOLOR_FORCE` forces colors.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+#[cfg(feature = "cli")]
+impl ColorChoice {
+    /// Turn colors on or off for all further output, which goes to a
+    /// terminal if `is_terminal`.
+    pub fn apply(self, is_terminal: bool) {
+        let enabled = self.enabled(is_terminal, |name| std::env::var_os(name));
+        colored::control::set_override(enabled);
+    }
+
+    fn enabled(self, is_terminal: bool, env: impl Fn(&str) -> Option<std::ffi::OsString>) -> bool {
+        let set = |name| env(name).is_some_and(|value| !value.is_empty() && value != "0");
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto if set("CLICOLOR_FORCE") => true,
+            ColorChoice::Auto => is_terminal && env("NO_COLOR").is_none_or(|v| v.is_empty()),
+        }
+    }
+}
+
 /// Print diagnostics in the specified format.
 pub fn print_diagnostics(diagnostics: &[LintDiagnostic], format: OutputFormat, w: &mut dyn Write) {
     match format {
@@ -96,7 +133,7 @@ fn print_pretty(diagnostics: &[LintDiagnostic], w: &mut dyn Write) {
             Severity::Info => "info".blue().bold(),
         };
 
-        let lint_id = format!("[{}]", diag.rule.to_string()).dimmed();
+        let lint_id = format!("[{}]", diag.rule).dimmed();
 
         let _ = writeln!(
             w,
@@ -118,6 +155,18 @@ fn print_pretty(diagnostics: &[LintDiagnostic], w: &mut dyn Write) {
         if let Some(ref help) = diag.help {
             let _ = writeln!(w, "  {} {}", "help:".green().bold(), help);
         }
+        for fix in &diag.fixes {
+            let _ = writeln!(
+                w,
+                "  {} {} ({})",
+                "fix:".green().bold(),
+                fix.message,
+                applicability(fix.applicability)
+            );
+        }
+        if let Some(ref url) = diag.url {
+            let _ = writeln!(w, "  {} {}", "docs:".blue().bold(), url);
+        }
 
         let _ = writeln!(w);
     }
@@ -126,26 +175,187 @@ fn print_pretty(diagnostics: &[LintDiagnostic], w: &mut dyn Write) {
 #[cfg(not(feature = "cli"))]
 fn print_pretty(diagnostics: &[LintDiagnostic], w: &mut dyn Write) {
     for diag in diagnostics {
-        let severity_label = match diag.severity {
-            Severity::Error => "error",
-            Severity::Warning => "warning",
-            Severity::Info => "info",
-        };
+        let _ = writeln!(w, "{}: {} [{}]", diag.severity, diag.message, diag.rule);
+        let _ = writeln!(w, "  --> {}:{}:{}", diag.file, diag.line, diag.column);
+
+        if let Some(ref help) = diag.help {
+            let _ = writeln!(w, "  help: {}", help);
+        }
+        for fix in &diag.fixes {
+            let _ = writeln!(
+                w,
+                "  fix: {} ({})",
+                fix.message,
+                applicability(fix.applicability)
+            );
+        }
+        if let Some(ref url) = diag.url {
+            let _ = writeln!(w, "  docs: {}", url);
+        }
+
+        let _ = writeln!(w);
+    }
+}
+
+/// Print a resolved configuration in the specified format.
+pub fn print_config(config: &ResolvedConfig, format: OutputFormat, w: &mut dyn Write) {
+    if format == OutputFormat::Json {
+        let json = serde_json::to_string_pretty(config).unwrap_or_else(|e| {
+            eprintln!("Failed to serialize config to JSON: {}", e);
+            "{}".to_string()
+        });
+        let _ = writeln!(w, "{}", json);
+        return;
+    }
 
+    let _ = writeln!(w, "File:    {}", config.file);
+    let _ = writeln!(
+        w,
+        "Config:  {}",
+        config
+            .config_path
+            .as_deref()
+            .unwrap_or("(none, using built-in defaults)")
+    );
+    let _ = writeln!(
+        w,
+        "Preset:  {}",
+        serde_json::to_string(&config.preset)
+            .unwrap_or_default()
+            .trim_matches('"')
+    );
+    let _ = writeln!(w, "Docs:    {}", config.docs_url);
+    if !config.matched_overrides.is_empty() {
+        let matched: Vec<String> = config
+            .matched_overrides
+            .iter()
+            .map(|i| format!("#{}", i))
+            .collect();
+        let _ = writeln!(w, "Matched overrides: {}", matched.join(", "));
+    }
+    let _ = writeln!(w);
+    let _ = writeln!(w, "Rules:");
+    for entry in &config.rules {
         let _ = writeln!(
             w,
-            "{}: {} [{}]",
-            severity_label,
-            diag.message,
-            diag.rule.to_string()
+            "  {:<48} {:<8} ({})",
+            entry.rule.to_string(),
+            entry.level.to_string(),
+            entry.origin
         );
-        let _ = writeln!(w, "  --> {}:{}:{}", diag.file, diag.line, diag.column);
+    }
 
-        if let Some(ref help) = diag.help {
-            let _ = writeln!(w, "  help: {}", help);
+    if config.options != RuleOptions::default() {
+        let _ = writeln!(w);
+        let _ = writeln!(w, "Options:");
+        let options = toml::to_string_pretty(&config.options).unwrap_or_default();
+        for line in options.lines() {
+            if line.is_empty() {
+                let _ = writeln!(w);
+            } else {
+                let _ = writeln!(w, "  {}", line);
+            }
         }
+    }
+}
 
+/// Print the element tree of a parsed file, with positions and how each
+/// attribute's value is known, to debug why a rule does or does not fire.
+///
+/// Attribute values are `static` (a literal the rules can read) or `dynamic`
+/// (an expression they cannot). An attribute without a value counts as the
+/// static empty string, as in the [inventory](crate::inventory). Names the
+/// linter does not recognize are marked as such.
+pub fn print_ast(parsed: &ParsedFile, w: &mut dyn Write) {
+    let mut macro_index = None;
+    // End index (exclusive) of each open element's subtree.
+    let mut open: Vec<usize> = Vec::new();
+    for (i, element) in parsed.elements.iter().enumerate() {
+        if macro_index != Some(element.macro_index) {
+            macro_index = Some(element.macro_index);
+            open.clear();
+            let _ = write!(
+                w,
+                "{}! #{} in {}",
+                element.source_macro, element.macro_index, element.file
+            );
+            match element.component {
+                Some(ref component) => {
+                    let _ = writeln!(w, " (component `{}`)", component);
+                }
+                None => {
+                    let _ = writeln!(w);
+                }
+            }
+        }
+        open.retain(|&end| end > i);
+        let indent = "  ".repeat(open.len() + 1);
+        let _ = write!(
+            w,
+            "{}<{}> {}:{}",
+            indent, element.tag, element.line, element.column
+        );
+        if let Some(role) = element.role() {
+            let _ = write!(w, " role={}", role);
+        }
+        match element.text {
+            Some(ref text) if !text.is_empty() => {
+                let _ = write!(w, " text={:?}", text);
+            }
+            Some(_) => {}
+            None => {
+                let _ = write!(w, " text=dynamic");
+            }
+        }
+        if element.inside_interactive {
+            let _ = write!(w, " inside-interactive");
+        }
         let _ = writeln!(w);
+        for attr in &element.attributes {
+            let value = match attr.value {
+                Some(AttrValue::Static(ref value)) => format!("{:?} static", value),
+                Some(AttrValue::Dynamic) => "dynamic".to_string(),
+                None => "\"\" static".to_string(),
+            };
+            let _ = write!(
+                w,
+                "{}  @{} = {} {}:{}-{}:{}",
+                indent, attr.name, value, attr.line, attr.column, attr.end_line, attr.end_column
+            );
+            match attr.name {
+                AttributeName::Unknown(_) => {
+                    let _ = write!(w, " (unrecognized name)");
+                }
+                AttributeName::Directive(_) => {
+                    let _ = write!(w, " (directive)");
+                }
+                _ => {}
+            }
+            let _ = writeln!(w);
+        }
+        open.push(i + element.descendants + 1);
+    }
+    for component in &parsed.components {
+        let usage = if component.call {
+            format!("{}()", component.name)
+        } else {
+            format!("<{} />", component.name)
+        };
+        let _ = writeln!(
+            w,
+            "component {} {}:{}",
+            usage, component.line, component.column
+        );
+    }
+    for error in &parsed.macro_errors {
+        let _ = writeln!(w, "{}", error);
+    }
+}
+
+fn applicability(applicability: Applicability) -> &'static str {
+    match applicability {
+        Applicability::Safe => "safe",
+        Applicability::Suggested => "suggested",
     }
 }
 
@@ -156,3 +366,40 @@ fn print_json(diagnostics: &[LintDiagnostic], w: &mut dyn Write) {
     });
     let _ = writeln!(w, "{}", json);
 }
+
+#[cfg(all(test, feature = "cli"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_choice() {
+        let env = |vars: &'static [(&str, &str)]| {
+            move |name: &str| vars.iter().find(|(n, _)| *n == name).map(|(_, v)| v.into())
+        };
+        assert!(ColorChoice::Auto.enabled(true, env(&[])));
+        assert!(!ColorChoice::Auto.enabled(false, env(&[])));
+        assert!(!ColorChoice::Auto.enabled(true, env(&[("NO_COLOR", "1")])));
+        assert!(ColorChoice::Auto.enabled(false, env(&[("CLICOLOR_FORCE", "1")])));
+        assert!(!ColorChoice::Auto.enabled(false, env(&[("CLICOLOR_FORCE", "0")])));
+        assert!(ColorChoice::Always.enabled(false, env(&[("NO_COLOR", "1")])));
+        assert!(!ColorChoice::Never.enabled(true, env(&[])));
+    }
+
+    #[test]
+    fn test_print_ast_values() {
+        let mut parsed = crate::parser::parse_source_with(
+            r#"fn c() { html! { <input disabled type="text" value={v} /> } }"#,
+            "a.rs",
+            &Default::default(),
+        )
+        .unwrap();
+        // An attribute without a value, as embedders may build.
+        parsed.elements[0].attributes[0].value = None;
+        let mut out = Vec::new();
+        print_ast(&parsed, &mut out);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(r#"@disabled = "" static"#), "{}", out);
+        assert!(out.contains(r#"@type = "text" static"#), "{}", out);
+        assert!(out.contains("@value = dynamic"), "{}", out);
+    }
+}
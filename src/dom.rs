@@ -1,4 +1,17 @@
 use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::de::IntoDeserializer;
+
+use crate::parser::HtmlElement;
+
+/// Parse a name through the type's serde representation, so `rename` and
+/// `alias` attributes apply to `FromStr` as well.
+fn from_name<'de, T: Deserialize<'de>>(name: &'de str) -> Result<T, strum::ParseError> {
+    T::deserialize(IntoDeserializer::<serde::de::value::Error>::into_deserializer(name))
+        .map_err(|_| strum::ParseError::VariantNotFound)
+}
 
 /// Types of values an ARIA attribute can accept.
 #[derive(Debug, Clone)]
@@ -35,7 +48,8 @@ impl AriaValueType {
             AriaValueType::Enum(variants) => variants.contains(&value),
             AriaValueType::Integer => value.parse::<i64>().is_ok(),
             AriaValueType::Number => value.parse::<f64>().is_ok(),
-            AriaValueType::IdRef | AriaValueType::IdRefList | AriaValueType::FreeText => true,
+            AriaValueType::IdRef => !value.trim().contains(char::is_whitespace),
+            AriaValueType::IdRefList | AriaValueType::FreeText => true,
         }
     }
 
@@ -71,8 +85,13 @@ impl AriaValueType {
 ///
 /// Each variant maps to a specific `aria-*` attribute as defined in the
 /// [WAI-ARIA 1.2 specification](https://www.w3.org/TR/wai-aria-1.2/#state_prop_def).
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+/// Additions from the ARIA 1.3 draft are behind the `aria-1-3` feature.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub enum Aria {
+    /// ARIA 1.3 draft.
+    #[cfg(feature = "aria-1-3")]
+    #[serde(rename = "aria-actions")]
+    Actions,
     #[serde(rename = "aria-activedescendant")]
     ActiveDescendant,
     #[serde(rename = "aria-atomic")]
@@ -229,8 +248,13 @@ impl Aria {
             Aria::ActiveDescendant => AriaValueType::IdRef,
             Aria::Controls => AriaValueType::IdRefList,
             Aria::DescribedBy => AriaValueType::IdRefList,
-            Aria::Details => AriaValueType::IdRef,
-            Aria::ErrorMessage => AriaValueType::IdRef,
+            // ARIA 1.3 allows several details and error message elements.
+            #[cfg(feature = "aria-1-3")]
+            Aria::Details | Aria::ErrorMessage => AriaValueType::IdRefList,
+            #[cfg(not(feature = "aria-1-3"))]
+            Aria::Details | Aria::ErrorMessage => AriaValueType::IdRef,
+            #[cfg(feature = "aria-1-3")]
+            Aria::Actions => AriaValueType::IdRefList,
             Aria::FlowTo => AriaValueType::IdRefList,
             Aria::Label => AriaValueType::FreeText,
             Aria::LabelledBy => AriaValueType::IdRefList,
@@ -252,6 +276,10 @@ impl Aria {
 
     /// Whether this ARIA property is a global state/property (supported by all roles).
     pub fn is_global(&self) -> bool {
+        #[cfg(feature = "aria-1-3")]
+        if *self == Aria::Actions {
+            return true;
+        }
         matches!(
             self,
             Aria::Atomic
@@ -494,7 +522,7 @@ impl Aria {
 
 impl Display for Aria {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = serde_json::to_string(self).map_err(|_| std::fmt::Error::default())?;
+        let s = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
         write!(f, "{}", s.trim_matches('"'))
     }
 }
@@ -502,8 +530,10 @@ impl Display for Aria {
 /// WAI-ARIA roles that can be assigned to HTML elements via the `role` attribute.
 ///
 /// Covers concrete, abstract, and landmark roles from the
-/// [WAI-ARIA 1.2 specification](https://www.w3.org/TR/wai-aria-1.2/#role_definitions).
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+/// [WAI-ARIA 1.2 specification](https://www.w3.org/TR/wai-aria-1.2/#role_definitions),
+/// plus the DPub and Graphics ARIA modules, which `aria-role` only accepts
+/// when enabled in its options.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 #[non_exhaustive]
 #[serde(rename_all = "lowercase")]
 pub enum Role {
@@ -579,6 +609,98 @@ pub enum Role {
     TreeGrid,
     TreeItem,
 
+    // ── DPub ARIA roles (https://www.w3.org/TR/dpub-aria-1.1/) ─────────
+    #[serde(rename = "doc-abstract")]
+    DocAbstract,
+    #[serde(rename = "doc-acknowledgments")]
+    DocAcknowledgments,
+    #[serde(rename = "doc-afterword")]
+    DocAfterword,
+    #[serde(rename = "doc-appendix")]
+    DocAppendix,
+    #[serde(rename = "doc-backlink")]
+    DocBackLink,
+    #[serde(rename = "doc-biblioentry")]
+    DocBiblioEntry,
+    #[serde(rename = "doc-bibliography")]
+    DocBibliography,
+    #[serde(rename = "doc-biblioref")]
+    DocBiblioRef,
+    #[serde(rename = "doc-chapter")]
+    DocChapter,
+    #[serde(rename = "doc-colophon")]
+    DocColophon,
+    #[serde(rename = "doc-conclusion")]
+    DocConclusion,
+    #[serde(rename = "doc-cover")]
+    DocCover,
+    #[serde(rename = "doc-credit")]
+    DocCredit,
+    #[serde(rename = "doc-credits")]
+    DocCredits,
+    #[serde(rename = "doc-dedication")]
+    DocDedication,
+    #[serde(rename = "doc-endnote")]
+    DocEndNote,
+    #[serde(rename = "doc-endnotes")]
+    DocEndNotes,
+    #[serde(rename = "doc-epigraph")]
+    DocEpigraph,
+    #[serde(rename = "doc-epilogue")]
+    DocEpilogue,
+    #[serde(rename = "doc-errata")]
+    DocErrata,
+    #[serde(rename = "doc-example")]
+    DocExample,
+    #[serde(rename = "doc-footnote")]
+    DocFootnote,
+    #[serde(rename = "doc-foreword")]
+    DocForeword,
+    #[serde(rename = "doc-glossary")]
+    DocGlossary,
+    #[serde(rename = "doc-glossref")]
+    DocGlossRef,
+    #[serde(rename = "doc-index")]
+    DocIndex,
+    #[serde(rename = "doc-introduction")]
+    DocIntroduction,
+    #[serde(rename = "doc-noteref")]
+    DocNoteRef,
+    #[serde(rename = "doc-notice")]
+    DocNotice,
+    #[serde(rename = "doc-pagebreak")]
+    DocPageBreak,
+    #[serde(rename = "doc-pagefooter")]
+    DocPageFooter,
+    #[serde(rename = "doc-pageheader")]
+    DocPageHeader,
+    #[serde(rename = "doc-pagelist")]
+    DocPageList,
+    #[serde(rename = "doc-part")]
+    DocPart,
+    #[serde(rename = "doc-preface")]
+    DocPreface,
+    #[serde(rename = "doc-prologue")]
+    DocPrologue,
+    #[serde(rename = "doc-pullquote")]
+    DocPullQuote,
+    #[serde(rename = "doc-qna")]
+    DocQna,
+    #[serde(rename = "doc-subtitle")]
+    DocSubtitle,
+    #[serde(rename = "doc-tip")]
+    DocTip,
+    #[serde(rename = "doc-toc")]
+    DocToc,
+
+    // ── Graphics ARIA roles (https://www.w3.org/TR/graphics-aria-1.0/) ──
+    #[serde(rename = "graphics-document")]
+    GraphicsDocument,
+    #[serde(rename = "graphics-object")]
+    GraphicsObject,
+    #[serde(rename = "graphics-symbol")]
+    GraphicsSymbol,
+
     // ── Abstract roles (WAI-ARIA ontology only, never valid on elements) ──
     Command,
     Composite,
@@ -594,11 +716,15 @@ pub enum Role {
     Window,
 }
 
-impl Role {
-    pub fn from_str(name: &str) -> Option<Role> {
-        serde_json::from_str(&format!("\"{}\"", name)).ok()
+impl FromStr for Role {
+    type Err = strum::ParseError;
+
+    fn from_str(name: &str) -> Result<Role, Self::Err> {
+        from_name(name)
     }
+}
 
+impl Role {
     /// Whether this role is an abstract WAI-ARIA role.
     /// Abstract roles exist for ontology purposes only and must never be
     /// used as a `role` attribute value on an element.
@@ -620,6 +746,19 @@ impl Role {
         )
     }
 
+    /// Whether this role is from the DPub ARIA module (`doc-*`).
+    pub fn is_dpub(&self) -> bool {
+        self.to_string().starts_with("doc-")
+    }
+
+    /// Whether this role is from the Graphics ARIA module (`graphics-*`).
+    pub fn is_graphics(&self) -> bool {
+        matches!(
+            self,
+            Role::GraphicsDocument | Role::GraphicsObject | Role::GraphicsSymbol
+        )
+    }
+
     /// Required ARIA properties for this role per WAI-ARIA 1.2.
     pub fn required_aria_props(&self) -> &'static [Aria] {
         match self {
@@ -669,6 +808,10 @@ impl Role {
             Role::Button
                 | Role::Checkbox
                 | Role::Combobox
+                | Role::DocBackLink
+                | Role::DocBiblioRef
+                | Role::DocGlossRef
+                | Role::DocNoteRef
                 | Role::GridCell
                 | Role::Link
                 | Role::ListBox
@@ -693,7 +836,7 @@ impl Role {
 
 impl Display for Role {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = serde_json::to_string(self).map_err(|_| std::fmt::Error::default())?;
+        let s = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
         write!(f, "{}", s.trim_matches('"'))
     }
 }
@@ -702,45 +845,115 @@ impl Display for Role {
 ///
 /// Includes event handlers (e.g. `onclick`), global attributes (e.g. `tabindex`),
 /// and element-specific attributes (e.g. `alt`, `href`) that the linter inspects.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum AttributeName {
-    #[serde(rename = "onmouseover", alias = "on:mouseover")]
+    #[serde(rename = "onmouseover")]
     OnMouseOver,
-    #[serde(rename = "onmouseout", alias = "on:mouseout")]
+    #[serde(rename = "onmouseout")]
     OnMouseOut,
-    #[serde(rename = "onclick", alias = "on:click")]
+    #[serde(rename = "onclick")]
     OnClick,
-    #[serde(rename = "onkeydown", alias = "on:keydown")]
+    #[serde(rename = "onkeydown")]
     OnKeyDown,
-    #[serde(rename = "onkeypress", alias = "on:keypress")]
+    #[serde(rename = "onkeypress")]
     OnKeyPress,
-    #[serde(rename = "onkeyup", alias = "on:keyup")]
+    #[serde(rename = "onkeyup")]
     OnKeyUp,
-    #[serde(rename = "onfocus", alias = "on:focus")]
+    #[serde(rename = "onfocus")]
     OnFocus,
-    #[serde(rename = "onblur", alias = "on:blur")]
+    #[serde(rename = "onblur")]
     OnBlur,
-    #[serde(rename = "onchange", alias = "on:change")]
+    #[serde(rename = "onchange")]
     OnChange,
-    #[serde(rename = "oninput", alias = "on:input")]
+    #[serde(rename = "oninput")]
     OnInput,
-    #[serde(rename = "onsubmit", alias = "on:submit")]
+    #[serde(rename = "onsubmit")]
     OnSubmit,
+    #[serde(rename = "ondblclick")]
+    OnDblClick,
+    #[serde(rename = "oncontextmenu")]
+    OnContextMenu,
+    #[serde(rename = "onmousedown")]
+    OnMouseDown,
+    #[serde(rename = "onmouseup")]
+    OnMouseUp,
+    #[serde(rename = "onmouseenter")]
+    OnMouseEnter,
+    #[serde(rename = "onmouseleave")]
+    OnMouseLeave,
+    #[serde(rename = "onmousemove")]
+    OnMouseMove,
+    #[serde(rename = "ontouchstart")]
+    OnTouchStart,
+    #[serde(rename = "ontouchend")]
+    OnTouchEnd,
+    #[serde(rename = "ontouchmove")]
+    OnTouchMove,
+    #[serde(rename = "ontouchcancel")]
+    OnTouchCancel,
+    #[serde(rename = "onpointerdown")]
+    OnPointerDown,
+    #[serde(rename = "onpointerup")]
+    OnPointerUp,
+    #[serde(rename = "onpointerenter")]
+    OnPointerEnter,
+    #[serde(rename = "onpointerleave")]
+    OnPointerLeave,
+    #[serde(rename = "onpointerover")]
+    OnPointerOver,
+    #[serde(rename = "onpointerout")]
+    OnPointerOut,
+    #[serde(rename = "onpointermove")]
+    OnPointerMove,
+    #[serde(rename = "onpointercancel")]
+    OnPointerCancel,
+    #[serde(rename = "ondragstart")]
+    OnDragStart,
+    #[serde(rename = "ondrag")]
+    OnDrag,
+    #[serde(rename = "ondragend")]
+    OnDragEnd,
+    #[serde(rename = "ondragenter")]
+    OnDragEnter,
+    #[serde(rename = "ondragleave")]
+    OnDragLeave,
+    #[serde(rename = "ondragover")]
+    OnDragOver,
+    #[serde(rename = "ondrop")]
+    OnDrop,
+    #[serde(rename = "onwheel")]
+    OnWheel,
+    #[serde(rename = "onscroll")]
+    OnScroll,
+    #[serde(rename = "onfocusin")]
+    OnFocusIn,
+    #[serde(rename = "onfocusout")]
+    OnFocusOut,
     #[serde(rename = "accesskey")]
     AccessKey,
     #[serde(rename = "alt")]
     Alt,
+    #[serde(rename = "alttext")]
+    AltText,
     #[serde(rename = "autocomplete")]
     Autocomplete,
     #[serde(rename = "autofocus")]
     AutoFocus,
+    #[serde(rename = "bgcolor")]
+    BgColor,
     #[serde(rename = "class")]
     Class,
+    #[serde(rename = "dir")]
+    Dir,
+    #[serde(rename = "disabled")]
+    Disabled,
     #[serde(rename = "for", alias = "html_for")]
     For,
     #[serde(rename = "href")]
     Href,
+    #[serde(rename = "id")]
+    Id,
     #[serde(rename = "lang")]
     Lang,
     #[serde(rename = "muted")]
@@ -751,6 +964,8 @@ pub enum AttributeName {
     Scope,
     #[serde(rename = "src")]
     Src,
+    #[serde(rename = "style")]
+    Style,
     #[serde(rename = "tabindex")]
     TabIndex,
     #[serde(rename = "title")]
@@ -759,29 +974,138 @@ pub enum AttributeName {
     Type,
     #[serde(untagged)]
     Aria(Aria),
+    /// A framework directive rather than an HTML attribute: Leptos'
+    /// `node_ref`, `use:directive`, `class:name` and `style:property`, and
+    /// Dioxus' `prevent_default` and `dangerous_inner_html`.
+    // Untagged variants are tried in order, so this must precede `Unknown`.
+    #[serde(untagged, deserialize_with = "directive")]
+    Directive(String),
     #[serde(untagged)]
     /// Either a non-standard attribute or one not relevant to this crate.
     Unknown(String),
 }
 
+/// Deserialize a [`AttributeName::Directive`], rejecting other names so they
+/// fall through to [`AttributeName::Unknown`].
+fn directive<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    if is_directive(&name) {
+        Ok(name)
+    } else {
+        Err(serde::de::Error::custom("not a directive"))
+    }
+}
+
+impl FromStr for AttributeName {
+    type Err = strum::ParseError;
+
+    /// Event handlers may be written in any framework's spelling; see
+    /// [`normalize_event_handler`].
+    fn from_str(name: &str) -> Result<AttributeName, Self::Err> {
+        if is_directive(name) {
+            return Ok(AttributeName::Directive(name.to_string()));
+        }
+        match normalize_event_handler(name) {
+            Some(normalized) => match from_name::<AttributeName>(&normalized)? {
+                AttributeName::Unknown(_) => Ok(AttributeName::Unknown(name.to_string())),
+                known => Ok(known),
+            },
+            None => from_name(name),
+        }
+    }
+}
+
+/// Whether `key` is a framework directive; see [`AttributeName::Directive`].
+fn is_directive(key: &str) -> bool {
+    matches!(key, "node_ref" | "prevent_default" | "dangerous_inner_html")
+        || ["use:", "class:", "style:"].iter().any(|prefix| {
+            key.strip_prefix(prefix)
+                .is_some_and(|rest| !rest.is_empty())
+        })
+}
+
+/// The DOM spelling (`onclick`) of an event handler attribute written the way
+/// a framework spells it, or `None` if `key` is not a handler:
+///
+/// | Framework | Spelling |
+/// |-----------|----------|
+/// | Yew | `onclick` |
+/// | Leptos | `on:click`, `on:click:undelegated` |
+/// | Dioxus | `onclick`, `onclick:` (field syntax) |
+/// | Sycamore | `on:click` |
+/// | JSX-style | `onClick` |
+pub fn normalize_event_handler(key: &str) -> Option<String> {
+    let key = key.strip_suffix(':').unwrap_or(key);
+    if let Some(event) = key.strip_prefix("on:") {
+        // Leptos modifiers follow a second colon.
+        let event = event.split(':').next().unwrap_or_default();
+        return (!event.is_empty()).then(|| format!("on{}", event.to_ascii_lowercase()));
+    }
+    let event = key.strip_prefix("on")?;
+    let is_handler = event.starts_with(|c: char| c.is_ascii_uppercase())
+        || (!event.is_empty() && event.chars().all(|c| c.is_ascii_lowercase()));
+    is_handler.then(|| format!("on{}", event.to_ascii_lowercase()))
+}
+
 impl AttributeName {
-    pub fn from_str(name: &str) -> Option<AttributeName> {
-        serde_json::from_str(&format!("\"{}\"", name)).ok()
+    /// Whether this is a DOM event handler (`onclick`, `on:click`, ...).
+    /// Unrecognized names count if they look like one (`onanimationend`).
+    pub fn is_event_handler(&self) -> bool {
+        match self {
+            // `<details open>` is not a handler.
+            AttributeName::Unknown(name) => {
+                name != "open" && normalize_event_handler(name).is_some()
+            }
+            other => other.to_string().starts_with("on"),
+        }
+    }
+
+    /// Whether this handler reacts to a pointer (mouse, touch or pen)
+    /// activating the element, so keyboard users need an equivalent.
+    pub fn is_pointer_activation_handler(&self) -> bool {
+        matches!(
+            self,
+            AttributeName::OnClick
+                | AttributeName::OnDblClick
+                | AttributeName::OnContextMenu
+                | AttributeName::OnMouseDown
+                | AttributeName::OnMouseUp
+                | AttributeName::OnTouchStart
+                | AttributeName::OnTouchEnd
+                | AttributeName::OnPointerDown
+                | AttributeName::OnPointerUp
+        )
+    }
+
+    /// Whether this is a keyboard handler (`onkeydown`, `onkeyup`,
+    /// `onkeypress`).
+    pub fn is_key_handler(&self) -> bool {
+        matches!(
+            self,
+            AttributeName::OnKeyDown | AttributeName::OnKeyUp | AttributeName::OnKeyPress
+        )
+    }
+
+    /// Whether this handler makes an element interactive: pointer
+    /// activation or keyboard handlers.
+    pub fn is_interaction_handler(&self) -> bool {
+        self.is_pointer_activation_handler() || self.is_key_handler()
     }
 }
 
 impl Display for AttributeName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = serde_json::to_string(self).map_err(|_| std::fmt::Error::default())?;
+        let s = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
         write!(f, "{}", s.trim_matches('"'))
     }
 }
 
 /// HTML element tag names recognised by the linter.
 ///
-/// Covers the standard HTML5 element set. Used to match parsed elements
+/// Covers the standard HTML5 element set, plus custom elements (web
+/// components) such as `<my-button>`. Used to match parsed elements
 /// against tag-specific lint rules and implicit ARIA role mappings.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum Tag {
     A,
@@ -899,13 +1223,142 @@ pub enum Tag {
     Var,
     Video,
     Wbr,
+
+    // ── SVG elements (valid inside `<svg>`) ─────────────────────────
+    Circle,
+    #[serde(rename = "clipPath", alias = "clippath")]
+    ClipPath,
+    Defs,
+    Desc,
+    Ellipse,
+    #[serde(rename = "foreignObject", alias = "foreignobject")]
+    ForeignObject,
+    G,
+    Image,
+    Line,
+    #[serde(rename = "linearGradient", alias = "lineargradient")]
+    LinearGradient,
+    Marker,
+    Mask,
+    Path,
+    Pattern,
+    Polygon,
+    Polyline,
+    #[serde(rename = "radialGradient", alias = "radialgradient")]
+    RadialGradient,
+    Rect,
+    Stop,
+    Symbol,
+    Text,
+    #[serde(rename = "textPath", alias = "textpath")]
+    TextPath,
+    Tspan,
+    Use,
+    /// `<title>` inside `<svg>`, which names its parent graphic instead of
+    /// the document.
+    #[serde(rename = "title", skip_deserializing)]
+    SvgTitle,
+
+    // ── MathML elements (valid inside `<math>`) ─────────────────────
+    Annotation,
+    #[serde(rename = "annotation-xml")]
+    AnnotationXml,
+    Menclose,
+    Merror,
+    Mfrac,
+    Mi,
+    Mmultiscripts,
+    Mn,
+    Mo,
+    Mover,
+    Mpadded,
+    Mphantom,
+    Mprescripts,
+    Mroot,
+    Mrow,
+    Ms,
+    Mspace,
+    Msqrt,
+    Mstyle,
+    Msub,
+    Msubsup,
+    Msup,
+    Mtable,
+    Mtd,
+    Mtext,
+    Mtr,
+    Munder,
+    Munderover,
+    Semantics,
+
+    /// A custom element, whose name contains a dash (e.g. `my-button`).
+    /// Declare how it behaves under `[custom-elements]` in `rsx-a11y.toml`.
+    #[serde(untagged, deserialize_with = "custom_element_name")]
+    Custom(String),
 }
 
-impl Tag {
-    pub fn from_str(name: &str) -> Option<Tag> {
-        serde_json::from_str(&format!("\"{}\"", name)).ok()
+/// Deserialize a valid custom element name, rejecting anything else.
+fn custom_element_name<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<String, D::Error> {
+    const RESERVED: &[&str] = &[
+        "annotation-xml",
+        "color-profile",
+        "font-face",
+        "font-face-src",
+        "font-face-uri",
+        "font-face-format",
+        "font-face-name",
+        "missing-glyph",
+    ];
+    let name = String::deserialize(deserializer)?;
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.contains('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "-._".contains(c))
+        && !RESERVED.contains(&name.as_str());
+    if valid {
+        Ok(name)
+    } else {
+        Err(serde::de::Error::custom(format!(
+            "`{}` is not a custom element name",
+            name
+        )))
+    }
+}
+
+/// How a custom element behaves, declared under `[custom-elements]` in
+/// `rsx-a11y.toml`:
+///
+/// ```toml
+/// [custom-elements.my-button]
+/// interactive = true
+/// role = "button"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomElement {
+    /// Interactive and focusable like a native `<button>`.
+    #[serde(default)]
+    pub interactive: bool,
+    /// In the tab order without a `tabindex`.
+    #[serde(default)]
+    pub focusable: bool,
+    /// The implicit role the element exposes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<Role>,
+}
+
+impl FromStr for Tag {
+    type Err = strum::ParseError;
+
+    fn from_str(name: &str) -> Result<Tag, Self::Err> {
+        from_name(name)
     }
+}
 
+impl Tag {
     pub fn is_interactive(&self) -> bool {
         matches!(
             self,
@@ -926,6 +1379,75 @@ impl Tag {
         )
     }
 
+    /// Whether this is an element of the SVG vocabulary other than `<svg>`
+    /// itself (`<a>`, `<script>` and `<style>` are shared with HTML).
+    pub fn is_svg(&self) -> bool {
+        matches!(
+            self,
+            Tag::Circle
+                | Tag::ClipPath
+                | Tag::Defs
+                | Tag::Desc
+                | Tag::Ellipse
+                | Tag::ForeignObject
+                | Tag::G
+                | Tag::Image
+                | Tag::Line
+                | Tag::LinearGradient
+                | Tag::Marker
+                | Tag::Mask
+                | Tag::Path
+                | Tag::Pattern
+                | Tag::Polygon
+                | Tag::Polyline
+                | Tag::RadialGradient
+                | Tag::Rect
+                | Tag::Stop
+                | Tag::Symbol
+                | Tag::Text
+                | Tag::TextPath
+                | Tag::Tspan
+                | Tag::Use
+                | Tag::SvgTitle
+        )
+    }
+
+    /// Whether this is a MathML element other than `<math>` itself.
+    pub fn is_mathml(&self) -> bool {
+        matches!(
+            self,
+            Tag::Annotation
+                | Tag::AnnotationXml
+                | Tag::Menclose
+                | Tag::Merror
+                | Tag::Mfrac
+                | Tag::Mi
+                | Tag::Mmultiscripts
+                | Tag::Mn
+                | Tag::Mo
+                | Tag::Mover
+                | Tag::Mpadded
+                | Tag::Mphantom
+                | Tag::Mprescripts
+                | Tag::Mroot
+                | Tag::Mrow
+                | Tag::Ms
+                | Tag::Mspace
+                | Tag::Msqrt
+                | Tag::Mstyle
+                | Tag::Msub
+                | Tag::Msubsup
+                | Tag::Msup
+                | Tag::Mtable
+                | Tag::Mtd
+                | Tag::Mtext
+                | Tag::Mtr
+                | Tag::Munder
+                | Tag::Munderover
+                | Tag::Semantics
+        )
+    }
+
     pub fn is_heading(&self) -> bool {
         matches!(
             self,
@@ -1020,14 +1542,308 @@ impl Tag {
 
 impl Display for Tag {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = serde_json::to_string(self).map_err(|_| std::fmt::Error::default())?;
+        let s = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
         write!(f, "{}", s.trim_matches('"'))
     }
 }
 
+/// Where an element's accessible name comes from, in the order
+/// [`accessible_name`] tries them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameSource {
+    /// The elements referenced by `aria-labelledby`.
+    LabelledBy,
+    /// `aria-label`.
+    AriaLabel,
+    /// A `<label>` wrapping the element or pointing at its `id`.
+    Label,
+    /// `alt`, on images.
+    Alt,
+    /// `title`.
+    Title,
+    /// The element's text content.
+    Content,
+}
+
+impl Display for NameSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            NameSource::LabelledBy => "`aria-labelledby`",
+            NameSource::AriaLabel => "`aria-label`",
+            NameSource::Label => "<label>",
+            NameSource::Alt => "`alt`",
+            NameSource::Title => "`title`",
+            NameSource::Content => "content",
+        })
+    }
+}
+
+/// An element's accessible name, as computed by [`accessible_name`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessibleName {
+    pub source: NameSource,
+    /// The name with whitespace collapsed, or `None` if it is only known at
+    /// runtime.
+    pub text: Option<String>,
+}
+
+impl AccessibleName {
+    /// Whether the name is known to be empty.
+    pub fn is_empty(&self) -> bool {
+        self.text.as_deref() == Some("")
+    }
+}
+
+/// Compute `element`'s accessible name with a simplified version of the
+/// [accname](https://www.w3.org/TR/accname-1.2/) algorithm: `aria-labelledby`,
+/// then `aria-label`, then a native `<label>`, `alt` or `title`, then the
+/// element's text content.
+///
+/// `tree` holds the elements `aria-labelledby` ids and `<label>`s are looked
+/// up in, usually those of the element's macro; ids not found there may be
+/// rendered elsewhere, so they give a name only known at runtime. Empty
+/// sources are skipped, but the first one is returned (with an empty name)
+/// if nothing else names the element. `None` means nothing does.
+///
+/// ```
+/// use rsx_a11y::dom::{self, NameSource};
+/// use rsx_a11y::parser;
+///
+/// let tree = parser::parse_source(
+///     r#"fn c() { html! { <div><label for="q">{"Search"}</label><input id="q" /></div> } }"#,
+///     "a.rs",
+/// )
+/// .unwrap();
+/// let name = dom::accessible_name(&tree[2], &tree).unwrap();
+/// assert_eq!((name.source, name.text.as_deref()), (NameSource::Label, Some("Search")));
+/// ```
+pub fn accessible_name(element: &HtmlElement, tree: &[HtmlElement]) -> Option<AccessibleName> {
+    let is_image_input = element.tag == Tag::Input
+        && element
+            .static_attr(AttributeName::Type)
+            .is_some_and(|t| t.eq_ignore_ascii_case("image"));
+    let names_alt = matches!(element.tag, Tag::Img | Tag::Area) || is_image_input;
+    let names_content = !matches!(
+        element.tag,
+        Tag::Iframe | Tag::Input | Tag::Textarea | Tag::Img | Tag::Area
+    );
+    // For each source the element has: its text, or `None` if dynamic.
+    let sources = [
+        (NameSource::LabelledBy, labelledby_name(element, tree)),
+        (
+            NameSource::AriaLabel,
+            attr_text(element, AttributeName::Aria(Aria::Label)),
+        ),
+        (NameSource::Label, label_name(element, tree)),
+        (
+            NameSource::Alt,
+            names_alt
+                .then(|| attr_text(element, AttributeName::Alt))
+                .flatten(),
+        ),
+        (NameSource::Title, attr_text(element, AttributeName::Title)),
+        (
+            NameSource::Content,
+            (names_content && element.has_children).then(|| element.text.clone()),
+        ),
+    ];
+    let mut empty = None;
+    for (source, text) in sources {
+        let Some(text) = text else { continue };
+        let text = text.map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "));
+        if text.as_deref() == Some("") {
+            empty.get_or_insert(AccessibleName { source, text });
+        } else {
+            return Some(AccessibleName { source, text });
+        }
+    }
+    empty
+}
+
+/// The text of attribute `name`, `Some(None)` if it is dynamic, or `None`
+/// if the element does not have it.
+fn attr_text(element: &HtmlElement, name: AttributeName) -> Option<Option<String>> {
+    let attr = element.attributes.iter().find(|a| a.name == name)?;
+    Some(match &attr.value {
+        Some(value) => value.as_static().map(str::to_string),
+        None => Some(String::new()),
+    })
+}
+
+/// The index of the first element in `tree` with the static id `id`, for
+/// resolving id references (`aria-controls`, `aria-owns`, ...). `None`
+/// means the id is rendered elsewhere, e.g. by another component, so rules
+/// treat the reference as unknown rather than broken.
+pub fn find_by_id(tree: &[HtmlElement], id: &str) -> Option<usize> {
+    tree.iter()
+        .position(|e| e.static_attr(AttributeName::Id) == Some(id))
+}
+
+/// The text of the elements `aria-labelledby` references: their
+/// `aria-label` or content.
+fn labelledby_name(element: &HtmlElement, tree: &[HtmlElement]) -> Option<Option<String>> {
+    let ids = attr_text(element, AttributeName::Aria(Aria::LabelledBy))?;
+    let Some(ids) = ids else { return Some(None) };
+    let mut parts = Vec::new();
+    for id in ids.split_whitespace() {
+        let Some(target) = find_by_id(tree, id).map(|i| &tree[i]) else {
+            return Some(None);
+        };
+        match attr_text(target, AttributeName::Aria(Aria::Label)).unwrap_or(target.text.clone()) {
+            Some(text) => parts.push(text),
+            None => return Some(None),
+        }
+    }
+    Some(Some(parts.join(" ")))
+}
+
+/// The text of a `<label>` in `tree` that wraps a labelable `element` or
+/// names it in `for`.
+fn label_name(element: &HtmlElement, tree: &[HtmlElement]) -> Option<Option<String>> {
+    let labelable = match element.tag {
+        Tag::Button | Tag::Meter | Tag::Output | Tag::Progress | Tag::Select | Tag::Textarea => {
+            true
+        }
+        Tag::Input => element.static_attr(AttributeName::Type) != Some("hidden"),
+        _ => false,
+    };
+    if !labelable {
+        return None;
+    }
+    let id = element.static_attr(AttributeName::Id);
+    let index = tree.iter().position(|e| std::ptr::eq(e, element));
+    tree.iter()
+        .enumerate()
+        .find(|(i, label)| {
+            label.tag == Tag::Label
+                && ((id.is_some() && label.static_attr(AttributeName::For) == id)
+                    || index.is_some_and(|index| *i < index && index <= i + label.descendants))
+        })
+        .map(|(_, label)| label.text.clone())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::parse_source;
+
+    #[test]
+    fn test_accessible_name_precedence() {
+        let tree = parse_source(
+            r#"fn c() { html! { <div>
+                <h2 id="t">{"Title"}</h2>
+                <a aria-labelledby="t" aria-label="Label" title="Tip">{"Text"}</a>
+                <a aria-label="" title="Tip">{"Text"}</a>
+                <a href="/">{ name }</a>
+                <a aria-label=""><img /></a>
+                <img alt="Logo" />
+            </div> } }"#,
+            "a.rs",
+        )
+        .unwrap();
+        let name = |i: usize| {
+            let name = accessible_name(&tree[i], &tree)?;
+            Some((name.source, name.text))
+        };
+        let text = |s: &str| Some(s.to_string());
+        assert_eq!(name(2), Some((NameSource::LabelledBy, text("Title"))));
+        assert_eq!(name(3), Some((NameSource::Title, text("Tip"))));
+        assert_eq!(name(4), Some((NameSource::Content, None)));
+        assert_eq!(name(5), Some((NameSource::AriaLabel, text(""))));
+        assert_eq!(name(7), Some((NameSource::Alt, text("Logo"))));
+        assert_eq!(name(6), None);
+    }
+
+    #[test]
+    fn test_from_str_and_display_round_trip() {
+        assert_eq!("img".parse::<Tag>(), Ok(Tag::Img));
+        assert_eq!("progressbar".parse::<Role>(), Ok(Role::ProgressBar));
+        assert_eq!(Role::ProgressBar.to_string(), "progressbar");
+        assert!("banana".parse::<Role>().is_err());
+        // Aliases apply to `FromStr` as well.
+        assert_eq!(
+            "on:click".parse::<AttributeName>(),
+            Ok(AttributeName::OnClick)
+        );
+        assert_eq!(
+            "data-id".parse::<AttributeName>(),
+            Ok(AttributeName::Unknown("data-id".to_string()))
+        );
+        for directive in ["node_ref", "use:tooltip", "class:active", "style:color"] {
+            let name = directive.parse::<AttributeName>().unwrap();
+            assert_eq!(name, AttributeName::Directive(directive.to_string()));
+            assert_eq!(name.to_string(), directive);
+        }
+        assert_eq!(
+            "class:".parse::<AttributeName>(),
+            Ok(AttributeName::Unknown("class:".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_attribute_name_serde_round_trip() {
+        for name in [
+            AttributeName::OnClick,
+            AttributeName::Aria(Aria::Label),
+            AttributeName::Directive("use:tooltip".to_string()),
+            AttributeName::Directive("node_ref".to_string()),
+            AttributeName::Unknown("data-id".to_string()),
+            AttributeName::Unknown("class:".to_string()),
+        ] {
+            let json = serde_json::to_string(&name).unwrap();
+            assert_eq!(serde_json::from_str::<AttributeName>(&json).unwrap(), name);
+        }
+    }
+
+    #[test]
+    fn test_custom_element_tags() {
+        let tag = "my-button".parse::<Tag>().unwrap();
+        assert_eq!(tag, Tag::Custom("my-button".to_string()));
+        assert_eq!(tag.to_string(), "my-button");
+        assert!("banana".parse::<Tag>().is_err());
+        assert!("My-Button".parse::<Tag>().is_err());
+        assert!("font-face".parse::<Tag>().is_err());
+    }
+
+    #[test]
+    fn test_event_handler_spellings_per_framework() {
+        let click = Ok(AttributeName::OnClick);
+        // Yew
+        assert_eq!("onclick".parse::<AttributeName>(), click);
+        // Leptos
+        assert_eq!("on:click".parse::<AttributeName>(), click);
+        assert_eq!("on:click:undelegated".parse::<AttributeName>(), click);
+        // Dioxus
+        assert_eq!("onclick:".parse::<AttributeName>(), click);
+        // Sycamore
+        assert_eq!("on:click".parse::<AttributeName>(), click);
+        // JSX-style
+        assert_eq!("onClick".parse::<AttributeName>(), click);
+
+        assert_eq!(
+            normalize_event_handler("on:keydown"),
+            Some("onkeydown".to_string())
+        );
+        assert_eq!(normalize_event_handler("class"), None);
+        assert_eq!(normalize_event_handler("on:"), None);
+        assert_eq!(
+            "on:my-event".parse::<AttributeName>(),
+            Ok(AttributeName::Unknown("on:my-event".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_event_handler_names() {
+        assert_eq!(
+            "on:mouseenter".parse::<AttributeName>(),
+            Ok(AttributeName::OnMouseEnter)
+        );
+        assert!(AttributeName::OnDragStart.is_event_handler());
+        assert!(AttributeName::Unknown("onanimationend".to_string()).is_event_handler());
+        assert!(!AttributeName::Unknown("open".to_string()).is_event_handler());
+        assert!(AttributeName::OnTouchEnd.is_pointer_activation_handler());
+        assert!(!AttributeName::OnMouseMove.is_interaction_handler());
+    }
 
     #[test]
     fn test_bool_value_validation() {
@@ -0,0 +1,541 @@
+//! Translation of `eslint-plugin-jsx-a11y` settings into an rsx-a11y config.
+//!
+//! Reads an `.eslintrc.json` file and maps every `jsx-a11y/*` rule to the rule
+//! of the same name, converting ESLint levels (`0`/`"off"`, `1`/`"warn"`,
+//! `2`/`"error"`) and the options rsx-a11y understands. `extends` entries for
+//! the plugin's presets pick the matching [`Preset`]; without one, rules that
+//! are not listed are turned off, as they would be in ESLint.
+//!
+//! Anything that cannot be translated is reported as a note rather than an
+//! error, so a partial migration is always produced.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use serde_json::{Map, Value};
+use strum::IntoEnumIterator;
+
+use crate::config::{ConfigFile, OverrideEntry, Preset, RuleLevel, RuleSetting};
+use crate::dom::{Role, Tag};
+use crate::lints::{JSX_A11Y_PREFIX, Rule};
+use crate::options::ImgRedundantAltOptions;
+
+/// Result of a migration: the generated config and notes about everything
+/// that was dropped or approximated.
+#[derive(Debug, Clone, Default)]
+pub struct Migration {
+    pub config: ConfigFile,
+    pub notes: Vec<String>,
+}
+
+/// Errors that prevent a migration from being produced.
+#[derive(Debug, Clone, serde::Serialize)]
+pub enum MigrateError {
+    /// The input is not valid JSON.
+    JsonError(String),
+    /// The input is valid JSON but not an ESLint config object.
+    InvalidConfig(String),
+}
+
+impl std::fmt::Display for MigrateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MigrateError::JsonError(err) => write!(f, "Invalid JSON: {}", err),
+            MigrateError::InvalidConfig(err) => write!(f, "Invalid ESLint config: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for MigrateError {}
+
+/// Translate the contents of an `.eslintrc.json` file.
+pub fn migrate(source: &str) -> Result<Migration, MigrateError> {
+    let value: Value =
+        serde_json::from_str(source).map_err(|e| MigrateError::JsonError(e.to_string()))?;
+    let root = value
+        .as_object()
+        .ok_or_else(|| MigrateError::InvalidConfig("expected a JSON object".to_string()))?;
+
+    let mut migration = Migration::default();
+
+    migration.config.preset = preset_from_extends(root.get("extends"));
+
+    let rules = match root.get("rules") {
+        Some(rules) => translate_rules(rules, "rules", &mut migration.notes)?,
+        None => BTreeMap::new(),
+    };
+
+    // Without a plugin preset only the listed rules are active in ESLint.
+    if migration.config.preset.is_none() {
+        let unlisted = Rule::iter()
+            .filter(|rule| !rules.contains_key(&rule.to_string()))
+            .count();
+        if unlisted > 0 {
+            migration.notes.push(format!(
+                "no jsx-a11y preset is extended; {} unlisted rule(s) set to \"off\"",
+                unlisted
+            ));
+        }
+        for rule in Rule::iter() {
+            migration
+                .config
+                .rules
+                .entry(rule.to_string())
+                .or_insert(RuleSetting::Level(RuleLevel::Off));
+        }
+    }
+    migration.config.rules.extend(rules);
+
+    if let Some(overrides) = root.get("overrides") {
+        let overrides = overrides.as_array().ok_or_else(|| {
+            MigrateError::InvalidConfig("`overrides` must be an array".to_string())
+        })?;
+        for (index, entry) in overrides.iter().enumerate() {
+            let context = format!("overrides[{}]", index);
+            let files = match entry.get("files") {
+                Some(Value::String(file)) => vec![file.clone()],
+                Some(Value::Array(files)) => files
+                    .iter()
+                    .filter_map(|f| f.as_str().map(String::from))
+                    .collect(),
+                _ => {
+                    migration
+                        .notes
+                        .push(format!("{}: no `files` pattern, entry skipped", context));
+                    continue;
+                }
+            };
+            let rules = match entry.get("rules") {
+                Some(rules) => translate_rules(rules, &context, &mut migration.notes)?,
+                None => BTreeMap::new(),
+            };
+            if rules.is_empty() {
+                continue;
+            }
+            migration
+                .config
+                .overrides
+                .push(OverrideEntry { files, rules });
+        }
+    }
+
+    Ok(migration)
+}
+
+fn preset_from_extends(extends: Option<&Value>) -> Option<Preset> {
+    let names: Vec<&str> = match extends {
+        Some(Value::String(name)) => vec![name.as_str()],
+        Some(Value::Array(names)) => names.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    names.iter().rev().find_map(|name| match *name {
+        "plugin:jsx-a11y/strict" => Some(Preset::Strict),
+        "plugin:jsx-a11y/recommended" => Some(Preset::Recommended),
+        _ => None,
+    })
+}
+
+fn translate_rules(
+    rules: &Value,
+    context: &str,
+    notes: &mut Vec<String>,
+) -> Result<BTreeMap<String, RuleSetting>, MigrateError> {
+    let rules = rules
+        .as_object()
+        .ok_or_else(|| MigrateError::InvalidConfig(format!("`{}` must be an object", context)))?;
+
+    let mut translated = BTreeMap::new();
+    for (name, setting) in rules {
+        // Rules from other plugins have nothing to map to.
+        if !name.starts_with(JSX_A11Y_PREFIX) {
+            continue;
+        }
+        let Some((rule, renamed)) = Rule::resolve(name) else {
+            notes.push(format!(
+                "{}: `{}` has no rsx-a11y equivalent",
+                context, name
+            ));
+            continue;
+        };
+        if renamed.is_some() {
+            notes.push(format!(
+                "{}: `{}` is deprecated and was migrated as `{}`",
+                context, name, rule
+            ));
+        }
+
+        let (level, options) = match setting {
+            Value::Array(items) => (items.first(), items.get(1)),
+            other => (Some(other), None),
+        };
+        let Some(level) = level.and_then(level_from_value) else {
+            notes.push(format!(
+                "{}: `{}` has an invalid level, rule skipped",
+                context, name
+            ));
+            continue;
+        };
+
+        let options = match options.and_then(Value::as_object) {
+            Some(options) if level != RuleLevel::Off => {
+                translate_options(&rule, options, context, notes)
+            }
+            _ => toml::Table::new(),
+        };
+
+        let setting = if options.is_empty() {
+            RuleSetting::Level(level)
+        } else {
+            RuleSetting::Detailed {
+                level: Some(level),
+                options,
+            }
+        };
+        translated.insert(rule.to_string(), setting);
+    }
+    Ok(translated)
+}
+
+fn level_from_value(value: &Value) -> Option<RuleLevel> {
+    match value {
+        Value::Number(n) => match n.as_u64()? {
+            0 => Some(RuleLevel::Off),
+            1 => Some(RuleLevel::Warning),
+            2 => Some(RuleLevel::Error),
+            _ => None,
+        },
+        Value::String(s) => match s.as_str() {
+            "off" => Some(RuleLevel::Off),
+            "warn" => Some(RuleLevel::Warning),
+            "error" => Some(RuleLevel::Error),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Add the roles `tag` may take to the `allowed-roles` table of `table`,
+/// dropping unknown roles.
+fn allowed_roles(
+    table: &mut toml::Table,
+    tag: &str,
+    value: &Value,
+    rule_id: &str,
+    context: &str,
+    notes: &mut Vec<String>,
+) {
+    let (roles, unknown): (Vec<_>, Vec<_>) = strings(value)
+        .into_iter()
+        .partition(|r| Role::from_str(r).is_ok());
+    if !unknown.is_empty() {
+        notes.push(format!(
+            "{}: `{}` roles {:?} are not known and were dropped",
+            context, rule_id, unknown
+        ));
+    }
+    if let toml::Value::Table(allowed) = table
+        .entry("allowed-roles")
+        .or_insert_with(|| toml::Table::new().into())
+    {
+        allowed.insert(tag.to_string(), roles.into());
+    }
+}
+
+/// Map jsx-a11y rule options onto the options in [`crate::options`].
+fn translate_options(
+    rule: &Rule,
+    options: &Map<String, Value>,
+    context: &str,
+    notes: &mut Vec<String>,
+) -> toml::Table {
+    let mut table = toml::Table::new();
+    let rule_id = rule.to_string();
+
+    for (key, value) in options {
+        let handled = match (rule, key.as_str()) {
+            (Rule::AnchorAmbiguousText, "words") => {
+                table.insert("words".to_string(), string_array(value));
+                true
+            }
+            // jsx-a11y adds these words to its defaults instead of replacing them.
+            (Rule::ImgRedundantAlt, "words") => {
+                let mut words = ImgRedundantAltOptions::default().words;
+                for word in strings(value) {
+                    if !words.contains(&word) {
+                        words.push(word);
+                    }
+                }
+                table.insert("words".to_string(), words.into());
+                true
+            }
+            (Rule::AriaRole, "allowedInvalidRoles") => {
+                table.insert("allowed-extra-roles".to_string(), string_array(value));
+                true
+            }
+            (Rule::MouseEventsHaveKeyEvents, "hoverInHandlers") => {
+                table.insert("hover-in-handlers".to_string(), string_array(value));
+                true
+            }
+            (Rule::MouseEventsHaveKeyEvents, "hoverOutHandlers") => {
+                table.insert("hover-out-handlers".to_string(), string_array(value));
+                true
+            }
+            (Rule::ControlHasAssociatedLabel, "controlComponents") => {
+                let (tags, unknown): (Vec<_>, Vec<_>) = strings(value)
+                    .into_iter()
+                    .partition(|t| Tag::from_str(t).is_ok());
+                if !unknown.is_empty() {
+                    notes.push(format!(
+                        "{}: `{}` components {:?} are not HTML tags and were dropped",
+                        context, rule_id, unknown
+                    ));
+                }
+                if !tags.is_empty() {
+                    table.insert("control-tags".to_string(), tags.into());
+                }
+                true
+            }
+            (Rule::NoNoninteractiveTabindex, "roles") => {
+                let (roles, unknown): (Vec<_>, Vec<_>) = strings(value)
+                    .into_iter()
+                    .partition(|r| Role::from_str(r).is_ok());
+                if !unknown.is_empty() {
+                    notes.push(format!(
+                        "{}: `{}` roles {:?} are not known and were dropped",
+                        context, rule_id, unknown
+                    ));
+                }
+                if !roles.is_empty() {
+                    table.insert("roles".to_string(), roles.into());
+                }
+                true
+            }
+            // jsx-a11y keys the allowed roles by tag name.
+            (
+                Rule::NoInteractiveElementToNoninteractiveRole
+                | Rule::NoNoninteractiveElementToInteractiveRole,
+                tag,
+            ) => {
+                allowed_roles(&mut table, tag, value, &rule_id, context, notes);
+                true
+            }
+            (Rule::NoStaticElementInteractions, "handlers") => {
+                table.insert("handlers".to_string(), string_array(value));
+                true
+            }
+            (Rule::NoStaticElementInteractions, "allowExpressionValues") => match value {
+                Value::Bool(allow) => {
+                    table.insert("allow-expression-values".to_string(), (*allow).into());
+                    true
+                }
+                _ => false,
+            },
+            // jsx-a11y lists the roles that must be tabbable; rsx-a11y applies
+            // one mode to every role.
+            (Rule::InteractiveSupportsFocus, "tabbable") => {
+                let mode = if strings(value).is_empty() {
+                    "focusable"
+                } else {
+                    notes.push(format!(
+                        "{}: `{}` per-role `tabbable` list approximated as mode \"tabbable\"",
+                        context, rule_id
+                    ));
+                    "tabbable"
+                };
+                table.insert("mode".to_string(), mode.into());
+                true
+            }
+            _ => false,
+        };
+        if !handled {
+            notes.push(format!(
+                "{}: option `{}` of `{}` is not supported and was dropped",
+                context, key, rule_id
+            ));
+        }
+    }
+    table
+}
+
+fn strings(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| item.as_str().map(String::from))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn string_array(value: &Value) -> toml::Value {
+    strings(value).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn setting(migration: &Migration, rule: &str) -> Option<RuleSetting> {
+        migration.config.rules.get(rule).cloned()
+    }
+
+    #[test]
+    fn test_levels_translated() {
+        let migration = migrate(
+            r#"{
+                "extends": ["eslint:recommended", "plugin:jsx-a11y/recommended"],
+                "rules": {
+                    "jsx-a11y/alt-text": 2,
+                    "jsx-a11y/no-autofocus": "off",
+                    "jsx-a11y/anchor-is-valid": ["warn"],
+                    "react/jsx-key": "error"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(migration.config.preset, Some(Preset::Recommended));
+        assert_eq!(migration.config.rules.len(), 3);
+        assert_eq!(
+            setting(&migration, "alt-text"),
+            Some(RuleSetting::Level(RuleLevel::Error))
+        );
+        assert_eq!(
+            setting(&migration, "no-autofocus"),
+            Some(RuleSetting::Level(RuleLevel::Off))
+        );
+        assert_eq!(
+            setting(&migration, "anchor-is-valid"),
+            Some(RuleSetting::Level(RuleLevel::Warning))
+        );
+        assert!(migration.notes.is_empty());
+    }
+
+    #[test]
+    fn test_strict_preset_detected() {
+        let migration = migrate(r#"{ "extends": "plugin:jsx-a11y/strict" }"#).unwrap();
+        assert_eq!(migration.config.preset, Some(Preset::Strict));
+        assert!(migration.config.rules.is_empty());
+    }
+
+    #[test]
+    fn test_without_preset_unlisted_rules_off() {
+        let migration = migrate(r#"{ "rules": { "jsx-a11y/alt-text": "error" } }"#).unwrap();
+        assert_eq!(migration.config.rules.len(), Rule::iter().count());
+        assert_eq!(
+            setting(&migration, "alt-text"),
+            Some(RuleSetting::Level(RuleLevel::Error))
+        );
+        assert_eq!(
+            setting(&migration, "aria-role"),
+            Some(RuleSetting::Level(RuleLevel::Off))
+        );
+        assert_eq!(migration.notes.len(), 1);
+    }
+
+    #[test]
+    fn test_options_translated() {
+        let migration = migrate(
+            r#"{
+                "extends": ["plugin:jsx-a11y/recommended"],
+                "rules": {
+                    "jsx-a11y/anchor-ambiguous-text": ["error", { "words": ["click me"] }],
+                    "jsx-a11y/img-redundant-alt": ["warn", { "words": ["bild"], "components": ["Image"] }],
+                    "jsx-a11y/no-noninteractive-tabindex": ["error", { "roles": ["tabpanel"], "tags": [] }],
+                    "jsx-a11y/aria-role": ["error", { "allowedInvalidRoles": ["ds-card"] }],
+                    "jsx-a11y/mouse-events-have-key-events": ["error", { "hoverInHandlers": ["onMouseOver"] }],
+                    "jsx-a11y/no-static-element-interactions": ["error", { "allowExpressionValues": false, "handlers": ["onClick"] }],
+                    "jsx-a11y/no-noninteractive-element-to-interactive-role": ["error", { "li": ["menuitem", "tab"] }]
+                }
+            }"#,
+        )
+        .unwrap();
+        let Some(RuleSetting::Detailed { level, options }) =
+            setting(&migration, "anchor-ambiguous-text")
+        else {
+            panic!("expected options for anchor-ambiguous-text");
+        };
+        assert_eq!(level, Some(RuleLevel::Error));
+        assert_eq!(options["words"], toml::Value::from(vec!["click me"]));
+
+        let Some(RuleSetting::Detailed { options, .. }) = setting(&migration, "img-redundant-alt")
+        else {
+            panic!("expected options for img-redundant-alt");
+        };
+        let words = options["words"].as_array().unwrap();
+        assert!(words.contains(&"image".into()));
+        assert!(words.contains(&"bild".into()));
+
+        let Some(RuleSetting::Detailed { options, .. }) = setting(&migration, "aria-role") else {
+            panic!("expected options for aria-role");
+        };
+        assert_eq!(
+            options["allowed-extra-roles"],
+            toml::Value::from(vec!["ds-card"])
+        );
+
+        let Some(RuleSetting::Detailed { options, .. }) =
+            setting(&migration, "mouse-events-have-key-events")
+        else {
+            panic!("expected options for mouse-events-have-key-events");
+        };
+        assert_eq!(
+            options["hover-in-handlers"],
+            toml::Value::from(vec!["onMouseOver"])
+        );
+
+        let Some(RuleSetting::Detailed { options, .. }) =
+            setting(&migration, "no-static-element-interactions")
+        else {
+            panic!("expected options for no-static-element-interactions");
+        };
+        assert_eq!(options["allow-expression-values"], toml::Value::from(false));
+        assert_eq!(options["handlers"], toml::Value::from(vec!["onClick"]));
+
+        let Some(RuleSetting::Detailed { options, .. }) =
+            setting(&migration, "no-noninteractive-element-to-interactive-role")
+        else {
+            panic!("expected options for no-noninteractive-element-to-interactive-role");
+        };
+        assert_eq!(
+            options["allowed-roles"]["li"],
+            toml::Value::from(vec!["menuitem", "tab"])
+        );
+
+        // `components` and `tags` have no rsx-a11y equivalent.
+        assert_eq!(migration.notes.len(), 2);
+
+        // The result must load as a valid config.
+        let source = toml::to_string(&migration.config).unwrap();
+        let file: ConfigFile = toml::from_str(&source).unwrap();
+        assert!(Config::from_file(file, None, std::path::PathBuf::from(".")).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_rule_and_overrides() {
+        let migration = migrate(
+            r#"{
+                "extends": "plugin:jsx-a11y/recommended",
+                "rules": { "jsx-a11y/accessible-emoji": "error" },
+                "overrides": [
+                    { "files": "src/legacy/**", "rules": { "jsx-a11y/no-autofocus": 0 } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert!(migration.config.rules.is_empty());
+        assert_eq!(migration.notes.len(), 1);
+        assert_eq!(migration.config.overrides.len(), 1);
+        assert_eq!(migration.config.overrides[0].files, vec!["src/legacy/**"]);
+        assert_eq!(
+            migration.config.overrides[0].rules.get("no-autofocus"),
+            Some(&RuleSetting::Level(RuleLevel::Off))
+        );
+    }
+
+    #[test]
+    fn test_invalid_input_rejected() {
+        assert!(matches!(migrate("{"), Err(MigrateError::JsonError(_))));
+        assert!(matches!(migrate("[]"), Err(MigrateError::InvalidConfig(_))));
+    }
+}
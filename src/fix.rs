@@ -0,0 +1,374 @@
+//! Machine-applicable fixes attached to diagnostics.
+//!
+//! A [`Fix`] is a set of [`TextEdit`]s on the diagnostic's file. Positions use
+//! the same convention as diagnostics: 1-based lines and 0-based columns,
+//! counted in characters.
+//!
+//! ```
+//! use rsx_a11y::fix::{self, Position, Range, TextEdit};
+//!
+//! let source = "<div role=\"list\" id=\"a\">";
+//! let edit = TextEdit::delete(Range {
+//!     start: Position { line: 1, column: 4 },
+//!     end: Position { line: 1, column: 16 },
+//! });
+//! assert_eq!(fix::apply(source, &[edit]).unwrap(), "<div id=\"a\">");
+//! ```
+
+/// Whether a fix can be applied without review.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Applicability {
+    /// Keeps the meaning of the code; safe to apply automatically.
+    Safe,
+    /// Probably right, but needs a human to check (e.g. an empty `alt`).
+    Suggested,
+}
+
+/// A position in a source file.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub struct Position {
+    /// Line number (1-based).
+    pub line: usize,
+    /// Column number (0-based, in characters).
+    pub column: usize,
+}
+
+/// A half-open range `[start, end)` in a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// Replace the text in `range` with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TextEdit {
+    pub range: Range,
+    pub replacement: String,
+}
+
+impl TextEdit {
+    /// Insert `text` at `position`.
+    pub fn insert(position: Position, text: impl Into<String>) -> Self {
+        TextEdit {
+            range: Range {
+                start: position,
+                end: position,
+            },
+            replacement: text.into(),
+        }
+    }
+
+    /// Remove the text in `range`.
+    pub fn delete(range: Range) -> Self {
+        TextEdit {
+            range,
+            replacement: String::new(),
+        }
+    }
+}
+
+/// A change that resolves a diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Fix {
+    /// What the fix does, e.g. "Remove the redundant `role`".
+    pub message: String,
+    pub applicability: Applicability,
+    pub edits: Vec<TextEdit>,
+}
+
+/// Why edits could not be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FixError {
+    /// An edit refers to a position past the end of its line or the file.
+    OutOfBounds(String),
+    /// Two edits change the same text.
+    Overlapping(String),
+}
+
+impl std::fmt::Display for FixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FixError::OutOfBounds(position) => {
+                write!(f, "Edit position {} is outside the file", position)
+            }
+            FixError::Overlapping(position) => {
+                write!(f, "Edits overlap at {}", position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FixError {}
+
+/// Apply `edits` to `source`. Edits may come in any order but must not
+/// overlap.
+pub fn apply(source: &str, edits: &[TextEdit]) -> Result<String, FixError> {
+    let mut spans = edits
+        .iter()
+        .map(|edit| {
+            let start = offset(source, edit.range.start)?;
+            let end = offset(source, edit.range.end)?;
+            Ok((start, end.max(start), edit))
+        })
+        .collect::<Result<Vec<_>, FixError>>()?;
+    spans.sort_by_key(|&(start, end, _)| (start, end));
+
+    let mut result = String::with_capacity(source.len());
+    let mut cursor = 0;
+    for (start, end, edit) in spans {
+        if start < cursor {
+            let Position { line, column } = edit.range.start;
+            return Err(FixError::Overlapping(format!("{}:{}", line, column)));
+        }
+        result.push_str(&source[cursor..start]);
+        result.push_str(&edit.replacement);
+        cursor = end;
+    }
+    result.push_str(&source[cursor..]);
+    Ok(result)
+}
+
+/// Lines of context around each change in [`unified_diff`].
+const DIFF_CONTEXT: usize = 3;
+
+/// A unified diff (as produced by `diff -u` and accepted by `git apply`) of
+/// the changes `edits` make to the file at `path`. Empty if nothing changes.
+///
+/// ```
+/// use rsx_a11y::fix::{self, Position, TextEdit};
+///
+/// let edit = TextEdit::insert(Position { line: 1, column: 4 }, " alt=\"\"");
+/// let diff = fix::unified_diff("src/app.rs", "<img src=\"a.png\">\n", &[edit]).unwrap();
+/// assert!(diff.contains("-<img src=\"a.png\">\n+<img alt=\"\" src=\"a.png\">\n"));
+/// ```
+pub fn unified_diff(path: &str, source: &str, edits: &[TextEdit]) -> Result<String, FixError> {
+    // Reject overlapping or out-of-range edits up front.
+    apply(source, edits)?;
+
+    // Group edits that touch the same lines into changes of whole lines.
+    let mut sorted: Vec<&TextEdit> = edits.iter().collect();
+    sorted.sort_by_key(|e| (e.range.start, e.range.end));
+    let mut groups: Vec<(usize, usize, Vec<&TextEdit>)> = Vec::new();
+    for edit in sorted {
+        let (first, last) = (edit.range.start.line.max(1), edit.range.end.line.max(1));
+        match groups.last_mut() {
+            Some((_, group_last, group)) if first <= *group_last => {
+                *group_last = (*group_last).max(last);
+                group.push(edit);
+            }
+            _ => groups.push((first, last, vec![edit])),
+        }
+    }
+
+    // Lines keep their endings so `\r\n` survives and a missing final newline
+    // can be marked.
+    let lines: Vec<&str> = source.split_inclusive('\n').collect();
+    let mut changes = Vec::new();
+    for (first, last, group) in groups {
+        let last = last.min(lines.len());
+        let old = lines.get(first - 1..last).unwrap_or_default().concat();
+        let rebased: Vec<TextEdit> = group
+            .iter()
+            .map(|edit| {
+                let rebase = |p: Position| Position {
+                    line: p.line.max(1) - first + 1,
+                    column: p.column,
+                };
+                TextEdit {
+                    range: Range {
+                        start: rebase(edit.range.start),
+                        end: rebase(edit.range.end),
+                    },
+                    replacement: edit.replacement.clone(),
+                }
+            })
+            .collect();
+        let new = apply(&old, &rebased)?;
+        if new != old {
+            let new_lines: Vec<String> = new.split_inclusive('\n').map(String::from).collect();
+            changes.push((first, last, new_lines));
+        }
+    }
+    if changes.is_empty() {
+        return Ok(String::new());
+    }
+
+    let mut out = format!("--- a/{}\n+++ b/{}\n", path, path);
+    let mut delta: isize = 0;
+    let mut i = 0;
+    while i < changes.len() {
+        // Merge changes whose context overlaps into one hunk.
+        let start = changes[i].0.saturating_sub(DIFF_CONTEXT).max(1);
+        let mut end = (changes[i].1 + DIFF_CONTEXT).min(lines.len());
+        let mut j = i;
+        while j + 1 < changes.len() && changes[j + 1].0.saturating_sub(DIFF_CONTEXT) <= end + 1 {
+            j += 1;
+            end = (changes[j].1 + DIFF_CONTEXT).min(lines.len());
+        }
+
+        let mut body = String::new();
+        let (mut old_len, mut new_len) = (0, 0);
+        let mut line = start;
+        for (first, last, new_lines) in &changes[i..=j] {
+            for context in &lines[line - 1..first - 1] {
+                push_line(&mut body, ' ', context);
+            }
+            old_len += first - line;
+            new_len += first - line;
+            for removed in lines.get(first - 1..*last).unwrap_or_default() {
+                push_line(&mut body, '-', removed);
+            }
+            for added in new_lines {
+                push_line(&mut body, '+', added);
+            }
+            old_len += last + 1 - first;
+            new_len += new_lines.len();
+            line = last + 1;
+        }
+        for context in lines.get(line - 1..end).unwrap_or_default() {
+            push_line(&mut body, ' ', context);
+        }
+        old_len += (end + 1).saturating_sub(line);
+        new_len += (end + 1).saturating_sub(line);
+
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            start,
+            old_len,
+            start as isize + delta,
+            new_len
+        ));
+        out.push_str(&body);
+        delta += new_len as isize - old_len as isize;
+        i = j + 1;
+    }
+    Ok(out)
+}
+
+/// Append `line` (with its line ending, if any) to a hunk body, marking a
+/// last line that has none the way `diff -u` does.
+fn push_line(body: &mut String, prefix: char, line: &str) {
+    body.push(prefix);
+    body.push_str(line);
+    if !line.ends_with('\n') {
+        body.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+/// Byte offset of `position` in `source`.
+fn offset(source: &str, position: Position) -> Result<usize, FixError> {
+    let out_of_bounds = || FixError::OutOfBounds(format!("{}:{}", position.line, position.column));
+    let line_start = if position.line <= 1 {
+        0
+    } else {
+        source
+            .match_indices('\n')
+            .nth(position.line - 2)
+            .map(|(i, _)| i + 1)
+            .ok_or_else(out_of_bounds)?
+    };
+    let line = &source[line_start..];
+    let line = &line[..line.find('\n').unwrap_or(line.len())];
+    match line.char_indices().nth(position.column) {
+        Some((i, _)) => Ok(line_start + i),
+        None if position.column == line.chars().count() => Ok(line_start + line.len()),
+        None => Err(out_of_bounds()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(line: usize, column: usize) -> Position {
+        Position { line, column }
+    }
+
+    #[test]
+    fn test_apply_edits_in_any_order() {
+        let source = "<img src=\"é.png\">\n<div  role=\"list\">";
+        let edits = [
+            TextEdit::delete(Range {
+                start: at(2, 4),
+                end: at(2, 17),
+            }),
+            TextEdit::insert(at(1, 4), " alt=\"\""),
+        ];
+        assert_eq!(
+            apply(source, &edits).unwrap(),
+            "<img alt=\"\" src=\"é.png\">\n<div>"
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_hunks() {
+        let source: String = (1..=12).map(|i| format!("line {}\n", i)).collect();
+        let edits = [
+            TextEdit::insert(at(2, 4), "!"),
+            TextEdit::delete(Range {
+                start: at(3, 0),
+                end: at(3, 4),
+            }),
+            TextEdit::insert(at(12, 0), "last "),
+        ];
+        let diff = unified_diff("src/a.rs", &source, &edits).unwrap();
+        assert_eq!(
+            diff,
+            "--- a/src/a.rs\n+++ b/src/a.rs\n\
+             @@ -1,6 +1,6 @@\n line 1\n-line 2\n+line! 2\n-line 3\n+ 3\n line 4\n line 5\n line 6\n\
+             @@ -9,4 +9,4 @@\n line 9\n line 10\n line 11\n-line 12\n+last line 12\n"
+        );
+        assert_eq!(unified_diff("src/a.rs", &source, &[]).unwrap(), "");
+    }
+
+    #[test]
+    fn test_unified_diff_keeps_crlf() {
+        let source = "<div>\r\n<img src=\"a.png\">\r\n</div>\r\n";
+        let edits = [TextEdit::insert(at(2, 4), " alt=\"\"")];
+        assert_eq!(
+            unified_diff("src/a.rs", source, &edits).unwrap(),
+            "--- a/src/a.rs\n+++ b/src/a.rs\n\
+             @@ -1,3 +1,3 @@\n <div>\r\n-<img src=\"a.png\">\r\n+<img alt=\"\" src=\"a.png\">\r\n </div>\r\n"
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_marks_missing_final_newline() {
+        let source = "<div>\n<img src=\"a.png\">";
+        let edits = [TextEdit::insert(at(2, 4), " alt=\"\"")];
+        assert_eq!(
+            unified_diff("src/a.rs", source, &edits).unwrap(),
+            "--- a/src/a.rs\n+++ b/src/a.rs\n\
+             @@ -1,2 +1,2 @@\n <div>\n\
+             -<img src=\"a.png\">\n\\ No newline at end of file\n\
+             +<img alt=\"\" src=\"a.png\">\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_rejects_bad_edits() {
+        let source = "<div>";
+        let past_end = TextEdit::insert(at(1, 9), "x");
+        assert!(matches!(
+            apply(source, &[past_end]),
+            Err(FixError::OutOfBounds(_))
+        ));
+        let overlapping = [
+            TextEdit::delete(Range {
+                start: at(1, 0),
+                end: at(1, 3),
+            }),
+            TextEdit::insert(at(1, 2), "x"),
+        ];
+        assert!(matches!(
+            apply(source, &overlapping),
+            Err(FixError::Overlapping(_))
+        ));
+    }
+}
@@ -0,0 +1,200 @@
+//! Translated diagnostic messages, keyed by rule.
+//!
+//! A [`Catalog`] holds one message and help text per rule for a language.
+//! Built-in catalogs exist for [`BUILTIN_LANGS`]; embedders can build their
+//! own with [`Catalog::from_toml`] or [`Catalog::insert`]. Rules a catalog
+//! does not cover keep their English text.
+//!
+//! Catalog files are TOML tables keyed by rule id, where `{element}` is
+//! replaced with the element's tag name, `{rule}` with the rule id and
+//! `{message}` with the English message. Translations are generic per rule,
+//! so a message without `{message}` gets the English one appended in
+//! parentheses to keep the diagnostic's specifics (attribute names, values,
+//! referenced ids):
+//!
+//! ```toml
+//! [alt-text]
+//! message = "Dem <{element}>-Element fehlt ein Alternativtext."
+//! help = "Fügen Sie ein `alt`-Attribut hinzu."
+//! ```
+//!
+//! ```
+//! use rsx_a11y::i18n::Catalog;
+//! use rsx_a11y::lints::Rule;
+//!
+//! let catalog = Catalog::builtin("de").unwrap();
+//! assert!(catalog.get(&Rule::AltText).is_some());
+//! assert!(Catalog::builtin("xx").is_none());
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::lints::{LintDiagnostic, Rule};
+
+/// Languages with a built-in catalog. English is the language of the rules
+/// themselves, so its catalog is empty.
+pub const BUILTIN_LANGS: &[&str] = &["en", "de", "fr", "ja"];
+
+const DE: &str = include_str!("i18n/de.toml");
+const FR: &str = include_str!("i18n/fr.toml");
+const JA: &str = include_str!("i18n/ja.toml");
+
+/// The translated text of one rule.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Translation {
+    pub message: String,
+    /// Replaces the diagnostic's help; the English help is kept when unset.
+    #[serde(default)]
+    pub help: Option<String>,
+}
+
+/// Errors from [`Catalog::from_toml`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CatalogError {
+    /// The source is not valid TOML or an entry lacks a `message`.
+    Parse(String),
+    /// A table is not named after a rule id.
+    UnknownRule(String),
+}
+
+impl std::fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatalogError::Parse(err) => write!(f, "Invalid message catalog: {}", err),
+            CatalogError::UnknownRule(id) => write!(f, "Unknown rule in message catalog: {}", id),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+/// Messages for one language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Catalog {
+    pub lang: String,
+    entries: BTreeMap<Rule, Translation>,
+}
+
+impl Catalog {
+    /// An empty catalog, which leaves every message in English.
+    pub fn new(lang: &str) -> Self {
+        Catalog {
+            lang: lang.to_string(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// The built-in catalog for `lang`, if there is one.
+    pub fn builtin(lang: &str) -> Option<Self> {
+        let source = match lang {
+            "en" => return Some(Catalog::new(lang)),
+            "de" => DE,
+            "fr" => FR,
+            "ja" => JA,
+            _ => return None,
+        };
+        Some(Catalog::from_toml(lang, source).expect("built-in catalogs are valid"))
+    }
+
+    /// Parse a catalog file. Table names may be current or deprecated rule
+    /// ids, with or without the `jsx-a11y/` prefix.
+    pub fn from_toml(lang: &str, source: &str) -> Result<Self, CatalogError> {
+        let tables: BTreeMap<String, Translation> =
+            toml::from_str(source).map_err(|e| CatalogError::Parse(e.to_string()))?;
+        let mut catalog = Catalog::new(lang);
+        for (id, translation) in tables {
+            let (rule, _) = Rule::resolve(&id).ok_or(CatalogError::UnknownRule(id))?;
+            catalog.insert(rule, translation);
+        }
+        Ok(catalog)
+    }
+
+    /// Set the translation of `rule`, replacing any previous one.
+    pub fn insert(&mut self, rule: Rule, translation: Translation) {
+        self.entries.insert(rule, translation);
+    }
+
+    pub fn get(&self, rule: &Rule) -> Option<&Translation> {
+        self.entries.get(rule)
+    }
+
+    /// Replace the message and help of `diagnostic` with their translations,
+    /// keeping the English message as detail unless the translation places it.
+    pub fn translate(&self, mut diagnostic: LintDiagnostic) -> LintDiagnostic {
+        if let Some(translation) = self.entries.get(&diagnostic.rule) {
+            let fill = |text: &str| {
+                text.replace("{element}", &diagnostic.element.to_string())
+                    .replace("{rule}", diagnostic.rule.as_ref())
+                    .replace("{message}", &diagnostic.message)
+            };
+            let help = translation.help.as_deref().map(fill);
+            let mut message = fill(&translation.message);
+            if !translation.message.contains("{message}") {
+                message = format!("{} ({})", message, diagnostic.message);
+            }
+            diagnostic.message = message;
+            if help.is_some() {
+                diagnostic.help = help;
+            }
+        }
+        diagnostic
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::Tag;
+    use crate::lints::Severity;
+    use crate::parser::HtmlElement;
+    use strum::IntoEnumIterator;
+
+    fn diagnostic(rule: Rule, element: Tag) -> LintDiagnostic {
+        let element = HtmlElement::builder(element).file("a.rs").build();
+        LintDiagnostic {
+            severity: Severity::Error,
+            help: Some("English help".to_string()),
+            ..LintDiagnostic::new(rule, &element, "English message")
+        }
+    }
+
+    #[test]
+    fn test_builtin_catalogs_cover_every_rule() {
+        for lang in BUILTIN_LANGS.iter().filter(|&&lang| lang != "en") {
+            let catalog = Catalog::builtin(lang).unwrap();
+            for rule in Rule::iter() {
+                let translation = catalog.get(&rule);
+                assert!(
+                    translation.is_some_and(|t| t.help.is_some()),
+                    "{} lacks {}",
+                    lang,
+                    rule
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_translate_fills_placeholders_and_falls_back() {
+        let catalog = Catalog::from_toml(
+            "x",
+            "[\"jsx-a11y/alt-text\"]\nmessage = \"<{element}> [{rule}]\"\n",
+        )
+        .unwrap();
+        let translated = catalog.translate(diagnostic(Rule::AltText, Tag::Img));
+        assert_eq!(translated.message, "<img> [alt-text] (English message)");
+        assert_eq!(translated.help.as_deref(), Some("English help"));
+        let untouched = catalog.translate(diagnostic(Rule::Scope, Tag::Td));
+        assert_eq!(untouched.message, "English message");
+
+        let catalog =
+            Catalog::from_toml("x", "[scope]\nmessage = \"Detail: {message}\"\n").unwrap();
+        let translated = catalog.translate(diagnostic(Rule::Scope, Tag::Td));
+        assert_eq!(translated.message, "Detail: English message");
+
+        assert_eq!(
+            Catalog::from_toml("x", "[no-such-rule]\nmessage = \"\"\n"),
+            Err(CatalogError::UnknownRule("no-such-rule".to_string()))
+        );
+    }
+}
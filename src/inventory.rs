@@ -0,0 +1,160 @@
+//! Every element the parser finds, without linting, for analyses the
+//! rules do not cover (e.g. "how many buttons lack `type`?").
+//!
+//! ```
+//! use rsx_a11y::inventory::Inventory;
+//! use rsx_a11y::parser;
+//!
+//! let elements = parser::parse_source(
+//!     r#"fn c() { html! { <button onclick={go}>{"Go"}</button> } }"#,
+//!     "a.rs",
+//! )
+//! .unwrap();
+//! let mut inventory = Inventory::default();
+//! inventory.add(&elements);
+//! let buttons_without_type = inventory
+//!     .elements
+//!     .iter()
+//!     .filter(|e| e.tag.to_string() == "button" && !e.attributes.iter().any(|a| a.name == "type"))
+//!     .count();
+//! assert_eq!(buttons_without_type, 1);
+//! ```
+
+use std::path::PathBuf;
+
+use crate::dom::{Role, Tag};
+use crate::parser::{self, AttrValue, HtmlAttribute, HtmlElement, ParseError, ParseOptions};
+
+/// An attribute of an [`InventoryElement`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct InventoryAttribute {
+    pub name: String,
+    /// The value if it is a string literal, `None` if it is dynamic.
+    pub value: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl From<&HtmlAttribute> for InventoryAttribute {
+    fn from(attr: &HtmlAttribute) -> Self {
+        InventoryAttribute {
+            name: attr.name.to_string(),
+            value: match &attr.value {
+                Some(AttrValue::Static(value)) => Some(value.clone()),
+                Some(AttrValue::Dynamic) => None,
+                None => Some(String::new()),
+            },
+            line: attr.line,
+            column: attr.column,
+            end_line: attr.end_line,
+            end_column: attr.end_column,
+        }
+    }
+}
+
+/// A parsed element, with its effective role.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct InventoryElement {
+    pub file: String,
+    /// Line of the tag name (1-based).
+    pub line: usize,
+    /// Column of the tag name (0-based).
+    pub column: usize,
+    /// Line where the element ends, after its closing tag or brace.
+    pub end_line: usize,
+    /// Column just past the end of the element.
+    pub end_column: usize,
+    pub tag: Tag,
+    /// As [`HtmlElement::role`].
+    pub role: Option<Role>,
+    pub attributes: Vec<InventoryAttribute>,
+    /// As [`HtmlElement::text`].
+    pub text: Option<String>,
+    /// Macro the element was written in, e.g. `html`.
+    pub source_macro: String,
+    /// As [`HtmlElement::component`].
+    pub component: Option<String>,
+    /// Number of elements nested inside this one, which follow it.
+    pub descendants: usize,
+}
+
+impl From<&HtmlElement> for InventoryElement {
+    fn from(element: &HtmlElement) -> Self {
+        InventoryElement {
+            file: element.file.clone(),
+            line: element.line,
+            column: element.column,
+            end_line: element.end_line,
+            end_column: element.end_column,
+            tag: element.tag.clone(),
+            role: element.role(),
+            attributes: element.attributes.iter().map(Into::into).collect(),
+            text: element.text.clone(),
+            source_macro: element.source_macro.clone(),
+            component: element.component.as_deref().map(str::to_string),
+            descendants: element.descendants,
+        }
+    }
+}
+
+/// The elements of a set of files, in file and source order.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct Inventory {
+    pub elements: Vec<InventoryElement>,
+    /// Files that could not be read or parsed.
+    pub parse_errors: Vec<ParseError>,
+}
+
+impl Inventory {
+    /// Add `elements`.
+    pub fn add(&mut self, elements: &[HtmlElement]) {
+        self.elements.extend(elements.iter().map(Into::into));
+    }
+}
+
+/// Parse `files` with `options` and collect their elements. Binary files
+/// are left out.
+pub fn collect(files: &[PathBuf], options: &ParseOptions) -> Inventory {
+    let mut inventory = Inventory::default();
+    for file in files {
+        match parser::parse_file_with(file, options) {
+            Ok(parsed) => inventory.add(&parsed.elements),
+            Err(ParseError::BinaryFile(_)) => {}
+            Err(e) => inventory.parse_errors.push(e),
+        }
+    }
+    inventory
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inventory_json() {
+        let elements = parser::parse_source(
+            r#"fn card() { html! { <a href="/" class={c}>{"Home"}</a> } }"#,
+            "src/card.rs",
+        )
+        .unwrap();
+        let mut inventory = Inventory::default();
+        inventory.add(&elements);
+        let json = serde_json::to_value(&inventory).unwrap();
+        let element = &json["elements"][0];
+        assert_eq!(element["tag"], "a");
+        assert_eq!(element["role"], "link");
+        assert_eq!(element["component"], "card");
+        assert_eq!(element["text"], "Home");
+        let span = ["line", "column", "end_line", "end_column"].map(|key| &element[key]);
+        assert_eq!(span, [1, 21, 1, 54]);
+        let values: Vec<_> = element["attributes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| (a["name"].as_str().unwrap(), a["value"].as_str()))
+            .collect();
+        assert_eq!(values, [("href", Some("/")), ("class", None)]);
+    }
+}
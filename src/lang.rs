@@ -0,0 +1,325 @@
+//! BCP 47 language tag validation, used by the `lang` rule.
+//!
+//! Tags are checked against the RFC 5646 grammar. With the `lang-registry`
+//! feature (on by default), primary language, extended language and region
+//! subtags must also be registered with IANA, so `lang="xx"` and
+//! `lang="en-USA"` are rejected.
+
+#[cfg(feature = "lang-registry")]
+mod registry;
+
+/// Grandfathered tags that are valid as a whole, compared lowercase.
+const GRANDFATHERED: &[&str] = &[
+    "art-lojban",
+    "cel-gaulish",
+    "en-gb-oed",
+    "i-ami",
+    "i-bnn",
+    "i-default",
+    "i-enochian",
+    "i-hak",
+    "i-klingon",
+    "i-lux",
+    "i-mingo",
+    "i-navajo",
+    "i-pwn",
+    "i-tao",
+    "i-tay",
+    "i-tsu",
+    "no-bok",
+    "no-nyn",
+    "sgn-be-fr",
+    "sgn-be-nl",
+    "sgn-ch-de",
+    "zh-guoyu",
+    "zh-hakka",
+    "zh-min",
+    "zh-min-nan",
+    "zh-xiang",
+];
+
+/// Why a language tag is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LangError {
+    /// The tag does not follow the BCP 47 grammar.
+    Malformed(String),
+    /// The primary language subtag is not registered.
+    UnknownLanguage(String),
+    /// The extended language subtag is not registered for the language.
+    UnknownExtlang(String),
+    /// The region subtag is not registered.
+    UnknownRegion(String),
+}
+
+impl std::fmt::Display for LangError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LangError::Malformed(tag) => {
+                write!(f, "\"{}\" is not a well-formed BCP 47 language tag", tag)
+            }
+            LangError::UnknownLanguage(subtag) => {
+                write!(f, "\"{}\" is not a registered language subtag", subtag)
+            }
+            LangError::UnknownExtlang(subtag) => write!(
+                f,
+                "\"{}\" is not a registered extended language subtag",
+                subtag
+            ),
+            LangError::UnknownRegion(subtag) => {
+                write!(f, "\"{}\" is not a registered region subtag", subtag)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LangError {}
+
+/// Check that `tag` is a valid BCP 47 language tag, e.g. `en`, `en-US`,
+/// `zh-Hant-TW` or `sr-Latn-RS`.
+///
+/// ```
+/// use rsx_a11y::lang::{self, LangError};
+///
+/// assert!(lang::validate("de-CH-1996").is_ok());
+/// assert!(matches!(lang::validate("en_US"), Err(LangError::Malformed(_))));
+/// ```
+pub fn validate(tag: &str) -> Result<(), LangError> {
+    let tag = tag.trim();
+    let malformed = || LangError::Malformed(tag.to_string());
+    if GRANDFATHERED.contains(&tag.to_ascii_lowercase().as_str()) {
+        return Ok(());
+    }
+
+    let subtags: Vec<&str> = tag.split('-').collect();
+    if subtags
+        .iter()
+        .any(|s| s.is_empty() || s.len() > 8 || !s.chars().all(|c| c.is_ascii_alphanumeric()))
+    {
+        return Err(malformed());
+    }
+    let alpha = |s: &str, len: std::ops::RangeInclusive<usize>| {
+        len.contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphabetic())
+    };
+    let digits = |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+
+    let mut rest = subtags.as_slice();
+    let language = rest[0];
+    if language.eq_ignore_ascii_case("x") {
+        return if rest.len() > 1 {
+            Ok(())
+        } else {
+            Err(malformed())
+        };
+    }
+    if !alpha(language, 2..=8) {
+        return Err(malformed());
+    }
+    check_language(language)?;
+    rest = &rest[1..];
+
+    if language.len() <= 3 {
+        let mut extlangs = 0;
+        while let Some(&extlang) = rest.first()
+            && extlangs < 3
+            && alpha(extlang, 3..=3)
+        {
+            check_extlang(language, extlang)?;
+            extlangs += 1;
+            rest = &rest[1..];
+        }
+    }
+    if let Some(&script) = rest.first()
+        && alpha(script, 4..=4)
+    {
+        rest = &rest[1..];
+    }
+    if let Some(&region) = rest.first()
+        && (alpha(region, 2..=2) || digits(region, 3))
+    {
+        check_region(region)?;
+        rest = &rest[1..];
+    }
+    while let Some(&variant) = rest.first()
+        && (variant.len() >= 5
+            || (variant.len() == 4 && variant.starts_with(|c: char| c.is_ascii_digit())))
+    {
+        rest = &rest[1..];
+    }
+    while let Some(&singleton) = rest.first()
+        && singleton.len() == 1
+    {
+        let (min_len, is_private_use) = if singleton.eq_ignore_ascii_case("x") {
+            (1, true)
+        } else {
+            (2, false)
+        };
+        let count = rest[1..].iter().take_while(|s| s.len() >= min_len).count();
+        if count == 0 {
+            return Err(malformed());
+        }
+        rest = &rest[1 + count..];
+        if is_private_use {
+            break;
+        }
+    }
+    if rest.is_empty() {
+        Ok(())
+    } else {
+        Err(malformed())
+    }
+}
+
+/// Whether text in the language `tag` is written right to left, judging by
+/// its script subtag or, without one, its primary language.
+pub fn is_rtl(tag: &str) -> bool {
+    const RTL_SCRIPTS: &[&str] = &[
+        "adlm", "arab", "hebr", "mand", "nkoo", "rohg", "samr", "syrc", "thaa", "yezi",
+    ];
+    const RTL_LANGUAGES: &[&str] = &[
+        "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ji", "ks", "ps", "sd", "syr", "ug", "ur", "yi",
+    ];
+    let mut subtags = tag.trim().split('-').map(str::to_ascii_lowercase);
+    let language = subtags.next().unwrap_or_default();
+    match subtags.find(|s| s.len() == 4 && s.chars().all(|c| c.is_ascii_alphabetic())) {
+        Some(script) => RTL_SCRIPTS.contains(&script.as_str()),
+        None => RTL_LANGUAGES.contains(&language.as_str()),
+    }
+}
+
+#[cfg(feature = "lang-registry")]
+fn check_language(language: &str) -> Result<(), LangError> {
+    let lower = language.to_ascii_lowercase();
+    // `qaa`-`qtz` are reserved for private use.
+    let private_use = lower.len() == 3 && ("qaa"..="qtz").contains(&lower.as_str());
+    if private_use || registry::LANGUAGES.binary_search(&lower.as_str()).is_ok() {
+        Ok(())
+    } else {
+        Err(LangError::UnknownLanguage(language.to_string()))
+    }
+}
+
+#[cfg(feature = "lang-registry")]
+fn check_extlang(language: &str, extlang: &str) -> Result<(), LangError> {
+    /// Languages that have registered extended language subtags.
+    const PREFIXES: &[&str] = &["ar", "kok", "lv", "ms", "sgn", "sw", "uz", "zh"];
+    let lower = extlang.to_ascii_lowercase();
+    if PREFIXES.contains(&language.to_ascii_lowercase().as_str())
+        && registry::LANGUAGES.binary_search(&lower.as_str()).is_ok()
+    {
+        Ok(())
+    } else {
+        Err(LangError::UnknownExtlang(extlang.to_string()))
+    }
+}
+
+#[cfg(feature = "lang-registry")]
+fn check_region(region: &str) -> Result<(), LangError> {
+    let upper = region.to_ascii_uppercase();
+    // `AA`, `QM`-`QZ`, `XA`-`XZ` and `ZZ` are reserved for private use.
+    let private_use = upper == "AA"
+        || upper == "ZZ"
+        || ("QM"..="QZ").contains(&upper.as_str())
+        || upper.starts_with('X');
+    if private_use
+        || registry::REGIONS.binary_search(&upper.as_str()).is_ok()
+        || registry::UN_M49_REGIONS
+            .binary_search(&upper.as_str())
+            .is_ok()
+    {
+        Ok(())
+    } else {
+        Err(LangError::UnknownRegion(region.to_string()))
+    }
+}
+
+#[cfg(not(feature = "lang-registry"))]
+fn check_language(_language: &str) -> Result<(), LangError> {
+    Ok(())
+}
+
+#[cfg(not(feature = "lang-registry"))]
+fn check_extlang(_language: &str, _extlang: &str) -> Result<(), LangError> {
+    Ok(())
+}
+
+#[cfg(not(feature = "lang-registry"))]
+fn check_region(_region: &str) -> Result<(), LangError> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_formed_tags() {
+        for tag in [
+            "en",
+            "EN-us",
+            "zh-Hant-TW",
+            "sr-Latn-RS",
+            "de-CH-1996",
+            "es-419",
+            "zh-yue-HK",
+            "en-US-u-ca-gregory",
+            "en-x-pirate",
+            "x-whatever",
+            "i-klingon",
+        ] {
+            assert_eq!(validate(tag), Ok(()), "{}", tag);
+        }
+    }
+
+    #[test]
+    fn test_malformed_tags() {
+        for tag in [
+            "", "123", "en_US", "en--US", "e", "en-US-u", "en-a-b-c", "x",
+        ] {
+            assert!(
+                matches!(validate(tag), Err(LangError::Malformed(_))),
+                "{}",
+                tag
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_rtl() {
+        assert!(is_rtl("ar"));
+        assert!(is_rtl("he-IL"));
+        assert!(is_rtl("az-Arab"));
+        assert!(!is_rtl("en-US"));
+        assert!(!is_rtl("ar-Latn"));
+    }
+
+    #[cfg(feature = "lang-registry")]
+    #[test]
+    fn test_unregistered_subtags() {
+        assert_eq!(
+            validate("xx"),
+            Err(LangError::UnknownLanguage("xx".to_string()))
+        );
+        assert_eq!(
+            validate("en-USA"),
+            Err(LangError::UnknownExtlang("USA".to_string()))
+        );
+        assert_eq!(
+            validate("en-UK"),
+            Err(LangError::UnknownRegion("UK".to_string()))
+        );
+        assert_eq!(validate("qab-XA"), Ok(()));
+    }
+
+    #[cfg(feature = "lang-registry")]
+    #[test]
+    fn test_reserved_region_subtags() {
+        for tag in [
+            "en-EU", "es-EA", "es-IC", "en-UN", "en-AC", "nl-AN", "ru-SU",
+        ] {
+            assert_eq!(validate(tag), Ok(()), "{}", tag);
+        }
+        for list in [registry::REGIONS, registry::UN_M49_REGIONS] {
+            assert!(list.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+}
@@ -5,9 +5,9 @@
 //! HTML-like RSX content (via [`rstml`](https://docs.rs/rstml)), and checks
 //! for accessibility issues based on the WAI-ARIA 1.2 specification.
 //!
-//! # Supported Lints (36)
+//! # Supported Lints (60)
 //!
-//! ## Errors (10)
+//! ## Errors (11)
 //!
 //! | Lint ID | Description |
 //! |---------|-------------|
@@ -16,41 +16,65 @@
 //! | `aria-proptypes` | Invalid value for a known ARIA attribute |
 //! | `aria-role` | Unknown or abstract WAI-ARIA role |
 //! | `aria-unsupported-elements` | ARIA on elements that don't support it |
-//! | `autocomplete-valid` | Invalid `autocomplete` attribute value |
+//! | `autocomplete-valid` | Invalid `autocomplete` value, or one that does not fit the input `type` |
 //! | `lang` | Invalid BCP 47 language tag |
-//! | `no-aria-hidden-on-focusable` | `aria-hidden="true"` on a focusable element |
+//! | `no-aria-hidden-on-focusable` | `aria-hidden="true"` on or around a tabbable element |
 //! | `no-distracting-elements` | `<marquee>` or `<blink>` used |
 //! | `role-has-required-aria-props` | Missing required ARIA properties for a given role |
+//! | `tabindex-valid` | `tabindex` value is not an integer |
 //!
-//! ## Warnings (25)
+//! ## Warnings (48)
 //!
 //! | Lint ID | Description |
 //! |---------|-------------|
-//! | `anchor-ambiguous-text` | `<a>` text must not be generic ("click here", "here", etc.) |
+//! | `alt-text-length` | `alt` text longer than 150 characters (configurable, opt-in) |
+//! | `anchor-ambiguous-text` | `<a>` accessible name must not be generic ("click here", "here", etc.) |
 //! | `anchor-has-content` | `<a>` without discernible text |
 //! | `anchor-is-valid` | `<a>` with `href="#"`, empty `href`, or `javascript:void(0)` |
 //! | `aria-activedescendant-has-tabindex` | Non-interactive element with `aria-activedescendant` needs `tabindex` |
+//! | `aria-activedescendant-target` | `aria-activedescendant` target outside the element and its `aria-owns` |
+//! | `aria-modal-dialog` | `aria-modal="true"` outside a `dialog` / `alertdialog` role |
+//! | `aria-owns-valid` | `aria-owns` creating a cycle, or owning an element twice |
+//! | `aria-posinset-setsize` | `aria-posinset` out of range for `aria-setsize` |
+//! | `aria-prerequisites` | `aria-braillelabel` / `aria-roledescription` without a label / role to refine |
+//! | `button-has-content` | Button without text content or accessible name |
 //! | `click-events-have-key-events` | Click handler without keyboard handler on non-interactive element |
+//! | `color-contrast` | Inline text/background colors below WCAG AA contrast (opt-in) |
+//! | `combobox-pattern` | Combobox without `aria-expanded`, or whose `aria-controls` is not its popup |
 //! | `control-has-associated-label` | Interactive controls must have a text label |
+//! | `dir` | Invalid `dir` value, or `dir` contradicting the element's `lang` |
 //! | `heading-has-content` | Empty heading element |
 //! | `html-has-lang` | `<html>` without `lang` attribute |
+//! | `identity-fields-have-autocomplete` | Personal-data input without `autocomplete` (opt-in) |
 //! | `iframe-has-title` | `<iframe>` without `title` |
+//! | `iframe-title-unique` | `<iframe>` elements in one macro sharing a `title` |
+//! | `img-alt-filename` | `<img>` alt text is a file name or the `src` file name |
 //! | `img-redundant-alt` | `<img>` alt text contains "image", "picture", "photo" |
 //! | `interactive-supports-focus` | Element with interactive role and event handler must be focusable |
 //! | `label-has-associated-control` | `<label>` without associated form control |
+//! | `live-region-valid` | Oversized assertive live region, `role="alert"` with `aria-live="polite"`, or stray `aria-relevant` |
+//! | `math-has-alttext` | `<math>` without `alttext` or fallback content |
 //! | `media-has-caption` | `<video>` or `<audio>` without captions |
-//! | `mouse-events-have-key-events` | `onmouseover`/`onmouseout` without `onfocus`/`onblur` |
+//! | `menu-button-pattern` | Popup button without `aria-expanded`, or expanded without `aria-controls` |
+//! | `mouse-events-have-key-events` | `onmouseover`/`onmouseenter` or `onmouseout`/`onmouseleave` without `onfocus`/`onblur` |
 //! | `no-access-key` | `accesskey` attribute used |
 //! | `no-autofocus` | `autofocus` attribute used |
+//! | `no-hidden-focusable` | Element hidden by inline `style` with `tabindex >= 0` |
 //! | `no-interactive-element-to-noninteractive-role` | Interactive element assigned a non-interactive role |
 //! | `no-noninteractive-element-interactions` | Non-interactive element with event handlers |
 //! | `no-noninteractive-element-to-interactive-role` | Non-interactive element assigned an interactive role |
 //! | `no-noninteractive-tabindex` | `tabindex` on non-interactive element |
+//! | `no-onchange` | `<select>` whose only handler is `onchange` |
 //! | `no-redundant-roles` | Explicit role matches element's implicit role |
 //! | `no-static-element-interactions` | Static element with event handlers but no role |
+//! | `no-title-only-name` | Interactive element named only by `title` |
+//! | `page-has-main` | A page rendering layout landmarks but no `<main>` landmark |
+//! | `radiogroup-pattern` | Radio outside a radiogroup, unnamed, or not exactly one checked per group |
 //! | `role-supports-aria-props` | ARIA property not supported by the element's role |
 //! | `scope` | `scope` on non-`<th>` element |
+//! | `skip-link` | Navigation and `<main>` in the project but no skip link |
 //! | `tabindex-no-positive` | `tabindex` > 0 |
+//! | `tabs-pattern` | Tab not controlling a tabpanel, or tabpanel not labelled by a tab |
 //!
 //! ## Info (1)
 //!
@@ -58,16 +82,36 @@
 //! |---------|-------------|
 //! | `prefer-tag-over-role` | Prefer semantic HTML element over ARIA role |
 
+pub mod bench;
+pub mod build;
+pub mod color;
+pub mod config;
+pub mod context;
 pub mod diagnostics;
 pub mod dom;
+pub mod eslint;
+pub mod fix;
+pub mod i18n;
+pub mod inventory;
+pub mod lang;
 pub mod lints;
+pub mod options;
 pub mod parser;
 pub mod prelude;
+pub mod project;
+pub mod runner;
+pub mod score;
+pub mod source;
+pub mod style;
+pub mod testing;
+pub mod workspace;
 
+use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
 
+use config::Config;
 use lints::LintDiagnostic;
-use parser::ParseError;
+use parser::{MacroError, ParseError};
 
 /// Summary returned by [`check_project`] containing every diagnostic found,
 /// any parse errors, and the number of files that contained lintable elements.
@@ -75,14 +119,46 @@ use parser::ParseError;
 /// No filtering is applied — callers can filter `diagnostics` by
 /// [`Rule`](lints::Rule), [`Severity`](lints::Severity), file path, etc.
 /// after the fact.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct LintSummary {
     /// All lint diagnostics found, sorted by file → line → column.
     pub diagnostics: Vec<LintDiagnostic>,
     /// Files that could not be parsed.
     pub parse_errors: Vec<ParseError>,
+    /// RSX macros that could not be parsed. Other macros in the same file
+    /// are still linted.
+    #[serde(default)]
+    pub macro_errors: Vec<MacroError>,
     /// Number of files that contained at least one lintable RSX element.
     pub files_checked: usize,
+    /// Number of RSX elements in each of those files, keyed like
+    /// [`LintDiagnostic::file`].
+    #[serde(default)]
+    pub element_counts: BTreeMap<String, usize>,
+    /// Whether the scan was stopped early by a
+    /// [`CancellationToken`](runner::CancellationToken), leaving results
+    /// incomplete.
+    #[serde(default)]
+    pub cancelled: bool,
+    /// Files that were not linted because they exceed the runner's
+    /// [`max_file_size`](runner::LintRunner::max_file_size) or are not
+    /// UTF-8 text.
+    #[serde(default)]
+    pub skipped_files: Vec<runner::SkippedFile>,
+    /// Generated files that were not linted (see
+    /// [`include_generated`](runner::LintRunner::include_generated)).
+    #[serde(default)]
+    pub generated_files: Vec<String>,
+    /// Parse, lint and per-rule times, summed over all files and threads,
+    /// and how often each rule fired. Only recorded when
+    /// [`timings`](runner::LintRunner::timings) is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timings: Option<bench::Timings>,
+    /// Why elements passed the rules that can say, sorted like
+    /// `diagnostics`. Only recorded when
+    /// [`verbose_passes`](runner::LintRunner::verbose_passes) is enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub passes: Vec<lints::LintPass>,
 }
 
 /// Lint an entire project (or single file) at `path` and return a [`LintSummary`].
@@ -92,6 +168,9 @@ pub struct LintSummary {
 /// `node_modules/`, and dot-directories), parses each file for RSX macros,
 /// and runs every lint rule.
 ///
+/// Use a [`LintRunner`](runner::LintRunner) to select rules or report
+/// progress.
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -107,40 +186,43 @@ pub struct LintSummary {
 /// assert!(errors.is_empty(), "accessibility errors found: {errors:#?}");
 /// ```
 pub fn check_project(path: &Path) -> LintSummary {
-    let rust_files = collect_rust_files(path);
-    let mut diagnostics: Vec<LintDiagnostic> = Vec::new();
-    let mut parse_errors: Vec<ParseError> = Vec::new();
-    let mut files_checked: usize = 0;
-
-    for file in &rust_files {
-        match parser::parse_file(file) {
-            Ok(elements) => {
-                if !elements.is_empty() {
-                    files_checked += 1;
-                    diagnostics.extend(lints::run_all_lints(&elements));
-                }
-            }
-            Err(e) => parse_errors.push(e),
-        }
-    }
+    runner::LintRunner::new().run(path)
+}
 
-    diagnostics.sort_unstable_by(|a, b| {
-        a.file
-            .cmp(&b.file)
-            .then(a.line.cmp(&b.line))
-            .then(a.column.cmp(&b.column))
-    });
+/// Like [`check_project`], but applies `config`: rule levels, per-file
+/// overrides, rule options and extra macros. Rules turned off by the config
+/// produce no diagnostics.
+pub fn check_project_with(path: &Path, config: &Config) -> LintSummary {
+    runner::LintRunner::new().config(config.clone()).run(path)
+}
 
-    LintSummary {
-        diagnostics,
-        parse_errors,
-        files_checked,
-    }
+/// Like [`check_project`], but yields each file's results as soon as they
+/// are ready, in no particular order, so they can be shown incrementally.
+///
+/// ```rust,no_run
+/// use std::path::Path;
+///
+/// for result in rsx_a11y::check_project_iter(Path::new("src")) {
+///     for diag in &result.diagnostics {
+///         println!("{}:{}: {}", diag.file, diag.line, diag.message);
+///     }
+/// }
+/// ```
+pub fn check_project_iter(path: &Path) -> impl Iterator<Item = runner::FileResult> {
+    runner::LintRunner::new().iter(path)
 }
 
 /// Recursively collect `.rs` files from `path`, skipping common non-source
-/// directories (`target/`, `node_modules/`, dot-directories).
-fn collect_rust_files(path: &Path) -> Vec<PathBuf> {
+/// directories (`target/`, `node_modules/`, dot-directories) and symlinks.
+pub(crate) fn collect_rust_files(path: &Path) -> Vec<PathBuf> {
+    collect_rust_files_with(path, false)
+}
+
+/// Recursively collect `.rs` files from `path` like [`check_project`],
+/// skipping common non-source directories (`target/`, `node_modules/`,
+/// dot-directories) and, unless `follow_symlinks` is set, symlinks. Each
+/// directory is visited at most once, so symlink cycles terminate.
+pub fn collect_rust_files_with(path: &Path, follow_symlinks: bool) -> Vec<PathBuf> {
     if path.is_file() {
         return if path.extension().is_some_and(|ext| ext == "rs") {
             vec![path.to_path_buf()]
@@ -150,17 +232,30 @@ fn collect_rust_files(path: &Path) -> Vec<PathBuf> {
     }
 
     let mut files = Vec::new();
-    collect_rust_files_recursive(path, &mut files);
+    let mut visited = HashSet::new();
+    if let Ok(canonical) = path.canonicalize() {
+        visited.insert(canonical);
+    }
+    collect_rust_files_recursive(path, follow_symlinks, &mut visited, &mut files);
     files
 }
 
-fn collect_rust_files_recursive(dir: &Path, out: &mut Vec<PathBuf>) {
+fn collect_rust_files_recursive(
+    dir: &Path,
+    follow_symlinks: bool,
+    visited: &mut HashSet<PathBuf>,
+    out: &mut Vec<PathBuf>,
+) {
     let entries = match std::fs::read_dir(dir) {
         Ok(entries) => entries,
         Err(_) => return,
     };
 
     for entry in entries.flatten() {
+        let is_symlink = entry.file_type().is_ok_and(|t| t.is_symlink());
+        if is_symlink && !follow_symlinks {
+            continue;
+        }
         let path = entry.path();
         if path.is_dir() {
             let name = entry.file_name();
@@ -168,7 +263,12 @@ fn collect_rust_files_recursive(dir: &Path, out: &mut Vec<PathBuf>) {
             if matches!(name.as_ref(), "target" | "node_modules") || name.starts_with('.') {
                 continue;
             }
-            collect_rust_files_recursive(&path, out);
+            if let Ok(canonical) = path.canonicalize()
+                && !visited.insert(canonical)
+            {
+                continue;
+            }
+            collect_rust_files_recursive(&path, follow_symlinks, visited, out);
         } else if path.is_file() && path.extension().is_some_and(|ext| ext == "rs") {
             out.push(path);
         }
@@ -3,78 +3,267 @@
 //! Each lint checks a specific accessibility concern on parsed HTML elements
 //! found within Yew/Leptos/Dioxus macro invocations.
 
-use crate::dom::{Aria, AttributeName, Role, Tag};
-use crate::parser::{AttrValue, HtmlElement};
-use strum::{EnumIter, IntoEnumIterator, VariantArray};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::time::Instant;
+
+use crate::bench::RuleStats;
+use crate::color::{self, Color};
+use crate::context::LintContext;
+use crate::dom::{AccessibleName, Aria, AttributeName, NameSource, Role, Tag};
+use crate::fix::{Applicability, Fix, Position, Range, TextEdit};
+use crate::options::{FocusMode, NoAutofocusOptions, RuleOptions};
+use crate::parser::{AttrValue, Framework, HtmlAttribute, HtmlElement, Syntax};
+use crate::style::ClassVisibility;
+use strum::{AsRefStr, Display, EnumIter, EnumString, IntoEnumIterator, VariantArray};
 
 /// Severity level for a lint diagnostic.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+///
+/// Ordered from least to most severe, so `severity >= Severity::Warning`
+/// selects warnings and errors.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    Display,
+    EnumString,
+)]
 #[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
 pub enum Severity {
-    /// A violation that must be fixed (e.g. missing `alt` on `<img>`).
-    Error,
-    /// A likely problem that should be reviewed.
-    Warning,
     /// A suggestion for improved accessibility.
     Info,
+    /// A likely problem that should be reviewed.
+    #[strum(to_string = "warning", serialize = "warn")]
+    Warning,
+    /// A violation that must be fixed (e.g. missing `alt` on `<img>`).
+    Error,
+}
+
+/// WCAG conformance level of a success criterion, from the least to the
+/// most demanding.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    Display,
+)]
+pub enum WcagLevel {
+    A,
+    AA,
+    AAA,
+}
+
+impl WcagLevel {
+    /// The level of the WCAG 2.1 success criterion whose "Understanding"
+    /// page is at `url`, if it is one the rules link to.
+    pub fn of_criterion(url: &str) -> Option<WcagLevel> {
+        let slug = url
+            .strip_prefix("https://www.w3.org/WAI/WCAG21/Understanding/")?
+            .trim_end_matches(".html");
+        match slug {
+            "contrast-minimum" | "focus-visible" | "identify-input-purpose" | "status-messages" => {
+                Some(WcagLevel::AA)
+            }
+            "audio-description-or-media-alternative-prerecorded"
+            | "bypass-blocks"
+            | "captions-prerecorded"
+            | "focus-order"
+            | "info-and-relationships"
+            | "keyboard"
+            | "labels-or-instructions"
+            | "language-of-page"
+            | "link-purpose-in-context"
+            | "meaningful-sequence"
+            | "name-role-value"
+            | "non-text-content"
+            | "on-input"
+            | "parsing"
+            | "pause-stop-hide" => Some(WcagLevel::A),
+            _ => None,
+        }
+    }
 }
 
 /// Accessibility lint rule identifiers.
 ///
 /// Each variant corresponds to a single lint check. Rules are serialized in
-/// `kebab-case` (e.g. `alt-text`, `aria-role`) for CLI flags and JSON output.
+/// `kebab-case` (e.g. `alt-text`, `aria-role`) for CLI flags and JSON output,
+/// and ordered alphabetically by id.
 #[derive(
-    Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, EnumIter, VariantArray,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    EnumIter,
+    VariantArray,
+    Display,
+    AsRefStr,
 )]
 #[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
 pub enum Rule {
     AltText,
+    AltTextLength,
     AnchorAmbiguousText,
     AnchorHasContent,
     AnchorIsValid,
     AriaActivedescendantHasTabindex,
+    AriaActivedescendantTarget,
+    AriaModalDialog,
+    AriaOwnsValid,
+    AriaPosinsetSetsize,
+    AriaPrerequisites,
     AriaProps,
     AriaProptypes,
     AriaRole,
     AriaUnsupportedElements,
     AutocompleteValid,
+    ButtonHasContent,
     ClickEventsHaveKeyEvents,
+    ColorContrast,
+    ComboboxPattern,
     ControlHasAssociatedLabel,
+    Dir,
     HeadingHasContent,
     HtmlHasLang,
+    IdentityFieldsHaveAutocomplete,
     IframeHasTitle,
+    IframeTitleUnique,
+    ImgAltFilename,
     ImgRedundantAlt,
     InteractiveSupportsFocus,
     LabelHasAssociatedControl,
     Lang,
+    LiveRegionValid,
+    MathHasAlttext,
     MediaHasCaption,
+    MenuButtonPattern,
     MouseEventsHaveKeyEvents,
     NoAccessKey,
     NoAriaHiddenOnFocusable,
     NoAutofocus,
     NoDistractingElements,
+    NoHiddenFocusable,
     NoInteractiveElementToNoninteractiveRole,
     NoNoninteractiveElementInteractions,
     NoNoninteractiveElementToInteractiveRole,
     NoNoninteractiveTabindex,
+    NoOnchange,
     NoRedundantRoles,
     NoStaticElementInteractions,
+    NoTitleOnlyName,
+    PageHasMain,
     PreferTagOverRole,
+    RadiogroupPattern,
     RoleHasRequiredAriaProps,
     RoleSupportsAriaProps,
     Scope,
+    SkipLink,
     TabindexNoPositive,
+    TabindexValid,
+    TabsPattern,
+}
+
+/// Prefix of eslint-plugin-jsx-a11y rule ids, accepted as an alias.
+pub const JSX_A11Y_PREFIX: &str = "jsx-a11y/";
+
+/// Default template for rule documentation URLs; `{rule}` is replaced with
+/// the rule id. Configurable with `docs-url` in the config file.
+pub const DOCS_URL: &str =
+    "https://github.com/CHildebrandt/rsx-a11y/blob/main/docs/rules/{rule}.md";
+
+/// Static information about a rule, serializable for building UIs.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct RuleMetadata {
+    pub id: Rule,
+    pub default_severity: Severity,
+    /// Whether the rule runs without being enabled in a config file.
+    pub enabled_by_default: bool,
+    pub description: &'static str,
+    /// WCAG success criteria the rule relates to.
+    pub guidelines: &'static [&'static str],
+    /// Further reading.
+    pub resources: &'static [&'static str],
+    /// The rule's documentation page, from [`DOCS_URL`].
+    pub url: String,
+}
+
+/// A deprecated rule id that still resolves to its replacement.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct RenamedRule {
+    /// The old id, as it may still appear in configs and CLI flags.
+    pub id: &'static str,
+    /// The rule the old id now resolves to.
+    pub replacement: Rule,
+    /// The version in which the old id was deprecated.
+    pub since: &'static str,
+}
+
+impl RenamedRule {
+    /// Warning shown when the old id is used.
+    pub fn warning(&self) -> String {
+        format!(
+            "rule `{}` is deprecated since {}; use `{}` instead",
+            self.id, self.since, self.replacement
+        )
+    }
+}
+
+/// Rule ids that were renamed. Entries are never removed, so old configs keep
+/// working; they only produce a deprecation warning.
+pub const RENAMED_RULES: &[RenamedRule] = &[];
+
+/// Parses a rule id, also accepting the jsx-a11y form (`jsx-a11y/alt-text`)
+/// and deprecated ids from [`RENAMED_RULES`].
+impl FromStr for Rule {
+    type Err = strum::ParseError;
+
+    fn from_str(s: &str) -> Result<Rule, Self::Err> {
+        Rule::resolve(s)
+            .map(|(rule, _)| rule)
+            .ok_or(strum::ParseError::VariantNotFound)
+    }
 }
 
 impl Rule {
-    pub fn from_str(s: &str) -> Option<Rule> {
-        serde_json::from_str(&format!("\"{}\"", s)).ok()
+    /// Parse a rule id like [`Rule::from_str`], also returning the rename entry
+    /// when `s` is a deprecated id so callers can warn about it.
+    pub fn resolve(s: &str) -> Option<(Rule, Option<&'static RenamedRule>)> {
+        Rule::resolve_in(s, RENAMED_RULES)
     }
 
-    pub fn to_string(&self) -> String {
-        serde_json::to_string(self)
-            .map(|s| s.trim_matches('"').to_string())
-            .unwrap_or_else(|_| "unknown".to_string())
+    fn resolve_in(
+        s: &str,
+        renamed: &'static [RenamedRule],
+    ) -> Option<(Rule, Option<&'static RenamedRule>)> {
+        let s = s.strip_prefix(JSX_A11Y_PREFIX).unwrap_or(s);
+        if let Some(renamed) = renamed.iter().find(|r| r.id == s) {
+            return Some((renamed.replacement.clone(), Some(renamed)));
+        }
+        Rule::VARIANTS
+            .iter()
+            .find(|rule| rule.as_ref() == s)
+            .map(|rule| (rule.clone(), None))
     }
 
     pub const fn description(&self) -> &'static str {
@@ -82,6 +271,9 @@ impl Rule {
             Rule::AltText => {
                 "Enforce all elements that require alternative text have meaningful information to relay back to end user."
             }
+            Rule::AltTextLength => {
+                "Enforce alt text is short, moving long descriptions to aria-describedby or adjacent text."
+            }
             Rule::AnchorAmbiguousText => {
                 "Enforce <a> text to not exactly match \"click here\", \"here\", \"link\", or \"a link\"."
             }
@@ -90,6 +282,21 @@ impl Rule {
             Rule::AriaActivedescendantHasTabindex => {
                 "Enforce elements with aria-activedescendant are tabbable."
             }
+            Rule::AriaActivedescendantTarget => {
+                "Enforce aria-activedescendant references an element inside or owned by its element."
+            }
+            Rule::AriaModalDialog => {
+                "Enforce aria-modal is only used on elements with the dialog or alertdialog role."
+            }
+            Rule::AriaOwnsValid => {
+                "Enforce aria-owns references existing elements without cycles or a second owner."
+            }
+            Rule::AriaPosinsetSetsize => {
+                "Enforce aria-posinset is at least 1 and not greater than aria-setsize."
+            }
+            Rule::AriaPrerequisites => {
+                "Enforce aria-braillelabel, aria-brailleroledescription and aria-roledescription are only used where the attribute they refine applies."
+            }
             Rule::AriaProps => "Enforce all aria-* props are valid.",
             Rule::AriaProptypes => "Enforce ARIA state and property values are valid.",
             Rule::AriaRole => {
@@ -99,17 +306,38 @@ impl Rule {
                 "Enforce that elements that do not support ARIA roles, states, and properties do not have those attributes."
             }
             Rule::AutocompleteValid => " 	Enforce that autocomplete attributes are used correctly.",
+            Rule::ButtonHasContent => {
+                "Enforce buttons have text content or an accessible name from aria-label, aria-labelledby or title."
+            }
             Rule::ClickEventsHaveKeyEvents => {
                 "Enforce a clickable non-interactive element has at least one keyboard event listener."
             }
+            Rule::ColorContrast => {
+                "Enforce inline text and background colors have a contrast ratio of at least 4.5:1 (3:1 for large text)."
+            }
+            Rule::ComboboxPattern => {
+                "Enforce comboboxes have aria-expanded and, when expanded, aria-controls referencing their popup."
+            }
             Rule::ControlHasAssociatedLabel => {
                 "Enforce that a control (an interactive element) has a text label."
             }
+            Rule::Dir => {
+                "Enforce dir attribute has a valid value that matches the direction of the element's lang."
+            }
             Rule::HeadingHasContent => {
                 "Enforce heading (h1, h2, etc) elements contain accessible content."
             }
             Rule::HtmlHasLang => "Enforce <html> element has lang prop.",
+            Rule::IdentityFieldsHaveAutocomplete => {
+                "Enforce inputs that collect personal data (email, phone, name, address) have an autocomplete attribute."
+            }
             Rule::IframeHasTitle => "Enforce iframe elements have a title attribute.",
+            Rule::IframeTitleUnique => {
+                "Enforce iframe elements in the same macro do not share a title."
+            }
+            Rule::ImgAltFilename => {
+                "Enforce <img> alt text is not a file name, such as the image's src."
+            }
             Rule::ImgRedundantAlt => {
                 "Enforce <img> alt prop does not contain the word \"image\", \"picture\", or \"photo\"."
             }
@@ -120,9 +348,18 @@ impl Rule {
                 "Enforce that a label tag has a text label and an associated control."
             }
             Rule::Lang => "Enforce lang attribute has a valid value.",
+            Rule::LiveRegionValid => {
+                "Enforce live regions are small, consistent with their role and declared before aria-relevant."
+            }
+            Rule::MathHasAlttext => {
+                "Enforce <math> elements have alttext, an ARIA label or fallback content."
+            }
             Rule::MediaHasCaption => {
                 "Enforces that <audio> and <video> elements must have a <track> for captions."
             }
+            Rule::MenuButtonPattern => {
+                "Enforce popup and disclosure buttons have aria-expanded and, when expanded, aria-controls referencing what they show."
+            }
             Rule::MouseEventsHaveKeyEvents => {
                 "Enforce that onMouseOver/onMouseOut are accompanied by onFocus/onBlur for keyboard-only users."
             }
@@ -130,10 +367,13 @@ impl Rule {
                 "Enforce that the accessKey prop is not used on any element to avoid complications with keyboard commands used by a screen reader."
             }
             Rule::NoAriaHiddenOnFocusable => {
-                "Disallow aria-hidden=\"true\" from being set on focusable elements."
+                "Disallow aria-hidden=\"true\" on elements that are or contain focusable elements."
             }
             Rule::NoAutofocus => "Enforce autoFocus prop is not used.",
             Rule::NoDistractingElements => "Enforce distracting elements are not used.",
+            Rule::NoHiddenFocusable => {
+                "Enforce elements hidden with display: none or visibility: hidden are not put in the tab order with tabindex."
+            }
             Rule::NoInteractiveElementToNoninteractiveRole => {
                 "Interactive elements should not be assigned non-interactive roles."
             }
@@ -146,15 +386,27 @@ impl Rule {
             Rule::NoNoninteractiveTabindex => {
                 "Enforce tabIndex should only be declared on interactive elements."
             }
+            Rule::NoOnchange => {
+                "Enforce <select> elements do not act on onChange alone, which is often used to navigate or submit as soon as the selection changes."
+            }
             Rule::NoRedundantRoles => {
                 "Enforce explicit role property is not the same as implicit/default role property on element."
             }
             Rule::NoStaticElementInteractions => {
                 "Enforce that non-interactive, visible elements (such as <div>) that have click handlers use the role attribute."
             }
+            Rule::NoTitleOnlyName => {
+                "Enforce interactive elements do not rely on the title attribute as their only accessible name."
+            }
+            Rule::PageHasMain => {
+                "Enforce that an app with a header, footer or navigation has a <main> landmark."
+            }
             Rule::PreferTagOverRole => {
                 "Enforces using semantic DOM elements over the ARIA role property."
             }
+            Rule::RadiogroupPattern => {
+                "Enforce radios are grouped, named, and exactly one per radiogroup is checked."
+            }
             Rule::RoleHasRequiredAriaProps => {
                 "Enforce that elements with ARIA roles must have all required attributes for that role."
             }
@@ -162,13 +414,51 @@ impl Rule {
                 "Enforce that elements with explicit or implicit roles defined contain only aria-* properties supported by that role."
             }
             Rule::Scope => "Enforce scope prop is only used on <th> elements.",
+            Rule::SkipLink => {
+                "Enforce that an app with navigation and a <main> landmark has a link to skip to the main content."
+            }
             Rule::TabindexNoPositive => "Enforce tabIndex value is not greater than zero.",
+            Rule::TabindexValid => "Enforce tabIndex value is a valid integer.",
+            Rule::TabsPattern => {
+                "Enforce tabs control a tabpanel and tabpanels are labelled by their tab."
+            }
+        }
+    }
+
+    /// The severity this rule reports at unless configured otherwise.
+    pub const fn default_severity(&self) -> Severity {
+        match self {
+            Rule::AltText
+            | Rule::AriaProps
+            | Rule::AriaProptypes
+            | Rule::AriaRole
+            | Rule::AriaUnsupportedElements
+            | Rule::AutocompleteValid
+            | Rule::Lang
+            | Rule::NoAriaHiddenOnFocusable
+            | Rule::NoDistractingElements
+            | Rule::RoleHasRequiredAriaProps
+            | Rule::TabindexValid => Severity::Error,
+            Rule::PreferTagOverRole => Severity::Info,
+            _ => Severity::Warning,
         }
     }
 
+    /// Whether the rule runs unless turned off. Opt-in rules must be given
+    /// a level in the config file (or named in `--only`).
+    pub const fn enabled_by_default(&self) -> bool {
+        !matches!(
+            self,
+            Rule::AltTextLength | Rule::ColorContrast | Rule::IdentityFieldsHaveAutocomplete
+        )
+    }
+
     pub const fn guidelines(&self) -> &'static [&'static str] {
         match self {
             Rule::AltText => &["https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html"],
+            Rule::AltTextLength => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html"]
+            }
             Rule::AnchorAmbiguousText => &[],
             Rule::AnchorHasContent => &[
                 "https://www.w3.org/WAI/WCAG21/Understanding/link-purpose-in-context",
@@ -176,6 +466,21 @@ impl Rule {
             ],
             Rule::AnchorIsValid => &["https://www.w3.org/WAI/WCAG21/Understanding/keyboard"],
             Rule::AriaActivedescendantHasTabindex => &[""],
+            Rule::AriaActivedescendantTarget => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"]
+            }
+            Rule::AriaModalDialog => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"]
+            }
+            Rule::AriaOwnsValid => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships"]
+            }
+            Rule::AriaPosinsetSetsize => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships"]
+            }
+            Rule::AriaPrerequisites => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"]
+            }
             Rule::AriaProps => &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"],
             Rule::AriaProptypes => &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"],
             Rule::AriaRole => &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"],
@@ -185,22 +490,42 @@ impl Rule {
             Rule::AutocompleteValid => {
                 &["https://www.w3.org/WAI/WCAG21/Understanding/identify-input-purpose"]
             }
+            Rule::ButtonHasContent => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"]
+            }
             Rule::ClickEventsHaveKeyEvents => {
                 &["https://www.w3.org/WAI/WCAG21/Understanding/keyboard"]
             }
+            Rule::ColorContrast => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/contrast-minimum"]
+            }
+            Rule::ComboboxPattern => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"]
+            }
             Rule::ControlHasAssociatedLabel => &[
                 "https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships",
                 "https://www.w3.org/WAI/WCAG21/Understanding/labels-or-instructions",
                 "https://www.w3.org/WAI/WCAG21/Understanding/name-role-value",
             ],
+            Rule::Dir => &["https://www.w3.org/WAI/WCAG21/Understanding/meaningful-sequence"],
             Rule::HeadingHasContent => &[
                 "https://www.w3.org/TR/UNDERSTANDING-WCAG20/navigation-mechanisms-descriptive.html",
             ],
             Rule::HtmlHasLang => &["https://www.w3.org/WAI/WCAG21/Understanding/language-of-page"],
+            Rule::IdentityFieldsHaveAutocomplete => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/identify-input-purpose"]
+            }
             Rule::IframeHasTitle => &[
                 "https://www.w3.org/WAI/WCAG21/Understanding/bypass-blocks",
                 "https://www.w3.org/WAI/WCAG21/Understanding/name-role-value",
             ],
+            Rule::IframeTitleUnique => &[
+                "https://www.w3.org/WAI/WCAG21/Understanding/bypass-blocks",
+                "https://www.w3.org/WAI/WCAG21/Understanding/name-role-value",
+            ],
+            Rule::ImgAltFilename => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html"]
+            }
             Rule::ImgRedundantAlt => &[],
             Rule::InteractiveSupportsFocus => {
                 &["https://www.w3.org/WAI/WCAG21/Understanding/keyboard"]
@@ -211,10 +536,19 @@ impl Rule {
                 "https://www.w3.org/WAI/WCAG21/Understanding/name-role-value",
             ],
             Rule::Lang => &["https://www.w3.org/WAI/WCAG21/Understanding/language-of-page"],
+            Rule::LiveRegionValid => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/status-messages"]
+            }
+            Rule::MathHasAlttext => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html"]
+            }
             Rule::MediaHasCaption => &[
                 "https://www.w3.org/WAI/WCAG21/Understanding/captions-prerecorded.html",
                 "https://www.w3.org/WAI/WCAG21/Understanding/audio-description-or-media-alternative-prerecorded.html",
             ],
+            Rule::MenuButtonPattern => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"]
+            }
             Rule::MouseEventsHaveKeyEvents => {
                 &["https://www.w3.org/WAI/WCAG21/Understanding/keyboard"]
             }
@@ -224,6 +558,10 @@ impl Rule {
             Rule::NoDistractingElements => {
                 &["https://www.w3.org/WAI/WCAG21/Understanding/pause-stop-hide"]
             }
+            Rule::NoHiddenFocusable => &[
+                "https://www.w3.org/WAI/WCAG21/Understanding/focus-order",
+                "https://www.w3.org/WAI/WCAG21/Understanding/focus-visible",
+            ],
             Rule::NoInteractiveElementToNoninteractiveRole => {
                 &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"]
             }
@@ -236,11 +574,23 @@ impl Rule {
             Rule::NoNoninteractiveTabindex => {
                 &["https://www.w3.org/WAI/WCAG21/Understanding/keyboard"]
             }
+            Rule::NoOnchange => &["https://www.w3.org/WAI/WCAG21/Understanding/on-input"],
             Rule::NoRedundantRoles => &[],
             Rule::NoStaticElementInteractions => {
                 &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"]
             }
+            Rule::NoTitleOnlyName => {
+                &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"]
+            }
+            Rule::PageHasMain => &[
+                "https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships",
+                "https://www.w3.org/WAI/WCAG21/Understanding/bypass-blocks",
+            ],
             Rule::PreferTagOverRole => &["https://www.w3.org/TR/wai-aria-1.0/roles"],
+            Rule::RadiogroupPattern => &[
+                "https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships",
+                "https://www.w3.org/WAI/WCAG21/Understanding/name-role-value",
+            ],
             Rule::RoleHasRequiredAriaProps => {
                 &["https://www.w3.org/WAI/WCAG21/Understanding/name-role-value"]
             }
@@ -251,9 +601,18 @@ impl Rule {
                 "https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships",
                 "https://www.w3.org/WAI/WCAG21/Understanding/parsing",
             ],
+            Rule::SkipLink => &["https://www.w3.org/WAI/WCAG21/Understanding/bypass-blocks"],
             Rule::TabindexNoPositive => {
                 &["https://www.w3.org/WAI/WCAG21/Understanding/focus-order"]
             }
+            Rule::TabindexValid => &[
+                "https://www.w3.org/WAI/WCAG21/Understanding/focus-order",
+                "https://www.w3.org/WAI/WCAG21/Understanding/parsing",
+            ],
+            Rule::TabsPattern => &[
+                "https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships",
+                "https://www.w3.org/WAI/WCAG21/Understanding/name-role-value",
+            ],
         }
     }
 
@@ -265,6 +624,10 @@ impl Rule {
                 "https://dequeuniversity.com/rules/axe/3.2/input-image-alt",
                 "https://dequeuniversity.com/rules/axe/3.2/area-alt",
             ],
+            Rule::AltTextLength => &[
+                "https://webaim.org/techniques/alttext/",
+                "https://www.w3.org/WAI/tutorials/images/complex/",
+            ],
             Rule::AnchorAmbiguousText => &[
                 "https://webaim.org/techniques/hypertext/",
                 "https://dequeuniversity.com/checklists/web/links",
@@ -278,6 +641,23 @@ impl Rule {
             Rule::AriaActivedescendantHasTabindex => &[
                 "https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Techniques/Using_the_aria-activedescendant_attribute",
             ],
+            Rule::AriaActivedescendantTarget => &[
+                "https://www.w3.org/TR/wai-aria-1.2/#aria-activedescendant",
+                "https://www.w3.org/TR/wai-aria-1.2/#aria-owns",
+            ],
+            Rule::AriaModalDialog => &[
+                "https://www.w3.org/TR/wai-aria-1.2/#aria-modal",
+                "https://www.w3.org/WAI/ARIA/apg/patterns/dialog-modal/",
+            ],
+            Rule::AriaOwnsValid => &["https://www.w3.org/TR/wai-aria-1.2/#aria-owns"],
+            Rule::AriaPosinsetSetsize => &[
+                "https://www.w3.org/TR/wai-aria-1.2/#aria-posinset",
+                "https://www.w3.org/TR/wai-aria-1.2/#aria-setsize",
+            ],
+            Rule::AriaPrerequisites => &[
+                "https://www.w3.org/TR/wai-aria-1.3/#aria-braillelabel",
+                "https://www.w3.org/TR/wai-aria-1.2/#aria-roledescription",
+            ],
             Rule::AriaProps => &[],
             Rule::AriaProptypes => &[
                 "https://www.w3.org/TR/wai-aria/#states_and_properties",
@@ -296,14 +676,35 @@ impl Rule {
                 "https://dequeuniversity.com/rules/axe/3.2/autocomplete-valid",
                 "https://www.w3.org/TR/html52/sec-forms.html#autofilling-form-controls-the-autocomplete-attribute",
             ],
+            Rule::ButtonHasContent => &["https://dequeuniversity.com/rules/axe/4.4/button-name"],
             Rule::ClickEventsHaveKeyEvents => &[],
+            Rule::ColorContrast => &["https://dequeuniversity.com/rules/axe/3.2/color-contrast"],
+            Rule::ComboboxPattern => &[
+                "https://www.w3.org/TR/wai-aria-1.2/#combobox",
+                "https://www.w3.org/WAI/ARIA/apg/patterns/combobox/",
+            ],
             Rule::ControlHasAssociatedLabel => &[],
+            Rule::Dir => &[
+                "https://html.spec.whatwg.org/multipage/dom.html#the-dir-attribute",
+                "https://www.w3.org/International/questions/qa-html-dir",
+            ],
             Rule::HeadingHasContent => &["https://dequeuniversity.com/rules/axe/3.2/empty-heading"],
             Rule::HtmlHasLang => &[
                 "https://dequeuniversity.com/rules/axe/3.2/html-has-lang",
                 "https://dequeuniversity.com/rules/axe/3.2/html-lang-valid",
             ],
+            Rule::IdentityFieldsHaveAutocomplete => &[
+                "https://www.w3.org/TR/WCAG21/#input-purposes",
+                "https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#autofill",
+            ],
             Rule::IframeHasTitle => &["https://dequeuniversity.com/rules/axe/3.2/frame-title"],
+            Rule::IframeTitleUnique => {
+                &["https://dequeuniversity.com/rules/axe/4.4/frame-title-unique"]
+            }
+            Rule::ImgAltFilename => &[
+                "https://webaim.org/techniques/alttext/",
+                "https://www.w3.org/TR/WCAG20-TECHS/F30.html",
+            ],
             Rule::ImgRedundantAlt => &["https://webaim.org/techniques/alttext/"],
             Rule::InteractiveSupportsFocus => &[
                 "https://github.com/GoogleChrome/accessibility-developer-tools/wiki/Audit-Rules#ax_focus_02",
@@ -317,10 +718,23 @@ impl Rule {
                 "https://www.w3.org/International/articles/language-tags/",
                 "https://www.iana.org/assignments/language-subtag-registry/language-subtag-registry",
             ],
+            Rule::LiveRegionValid => &[
+                "https://www.w3.org/TR/wai-aria-1.2/#aria-live",
+                "https://www.w3.org/TR/wai-aria-1.2/#aria-relevant",
+                "https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Live_Regions",
+            ],
+            Rule::MathHasAlttext => &[
+                "https://w3c.github.io/mathml-core/#the-top-level-math-element",
+                "https://developer.mozilla.org/en-US/docs/Web/MathML/Element/math",
+            ],
             Rule::MediaHasCaption => &[
                 "https://dequeuniversity.com/rules/axe/2.1/audio-caption",
                 "https://dequeuniversity.com/rules/axe/2.1/video-caption",
             ],
+            Rule::MenuButtonPattern => &[
+                "https://www.w3.org/WAI/ARIA/apg/patterns/menu-button/",
+                "https://www.w3.org/WAI/ARIA/apg/patterns/disclosure/",
+            ],
             Rule::MouseEventsHaveKeyEvents => &[],
             Rule::NoAccessKey => &["https://webaim.org/techniques/keyboard/accesskey#spec"],
             Rule::NoAriaHiddenOnFocusable => &[
@@ -336,6 +750,9 @@ impl Rule {
                 "https://dequeuniversity.com/rules/axe/3.2/marquee",
                 "https://dequeuniversity.com/rules/axe/3.2/blink",
             ],
+            Rule::NoHiddenFocusable => {
+                &["https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/tabindex"]
+            }
             Rule::NoInteractiveElementToNoninteractiveRole => &[
                 "https://www.w3.org/TR/wai-aria/#states_and_properties",
                 "https://github.com/GoogleChrome/accessibility-developer-tools/wiki/Audit-Rules#ax_aria_04",
@@ -359,6 +776,10 @@ impl Rule {
             Rule::NoNoninteractiveTabindex => {
                 &["https://www.w3.org/TR/wai-aria-practices-1.1/#kbd_generalnav"]
             }
+            Rule::NoOnchange => &[
+                "https://www.w3.org/TR/WCAG20-TECHS/F37.html",
+                "https://webaim.org/techniques/forms/controls#javascript",
+            ],
             Rule::NoRedundantRoles => &[
                 "https://www.w3.org/TR/using-aria/#aria-does-nothing",
                 "https://developer.mozilla.org/en-US/docs/Web/HTML/Element/img#identifying_svg_as_an_image",
@@ -369,9 +790,18 @@ impl Rule {
                 "https://www.w3.org/TR/wai-aria-practices-1.1/#kbd_generalnav",
                 "https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Techniques/Using_the_button_role#Keyboard_and_focus",
             ],
+            Rule::NoTitleOnlyName => &[
+                "https://www.tpgi.com/using-the-html-title-attribute-updated/",
+                "https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/title#accessibility_concerns",
+            ],
+            Rule::PageHasMain => &[
+                "https://dequeuniversity.com/rules/axe/4.4/landmark-one-main",
+                "https://www.w3.org/WAI/ARIA/apg/patterns/landmarks/examples/main.html",
+            ],
             Rule::PreferTagOverRole => {
                 &["https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles"]
             }
+            Rule::RadiogroupPattern => &["https://www.w3.org/WAI/ARIA/apg/patterns/radio/"],
             Rule::RoleHasRequiredAriaProps => &[
                 "https://www.w3.org/TR/wai-aria/#roles",
                 "https://github.com/GoogleChrome/accessibility-developer-tools/wiki/Audit-Rules#ax_aria_03",
@@ -381,66 +811,210 @@ impl Rule {
                 "https://github.com/GoogleChrome/accessibility-developer-tools/wiki/Audit-Rules#ax_aria_10",
             ],
             Rule::Scope => &["https://dequeuniversity.com/rules/axe/3.5/scope-attr-valid"],
+            Rule::SkipLink => &[
+                "https://webaim.org/techniques/skipnav/",
+                "https://www.w3.org/TR/WCAG20-TECHS/G1.html",
+            ],
             Rule::TabindexNoPositive => &[
                 "https://github.com/GoogleChrome/accessibility-developer-tools/wiki/Audit-Rules#ax_focus_03",
             ],
+            Rule::TabindexValid => {
+                &["https://html.spec.whatwg.org/multipage/interaction.html#attr-tabindex"]
+            }
+            Rule::TabsPattern => &["https://www.w3.org/WAI/ARIA/apg/patterns/tabs/"],
+        }
+    }
+
+    /// The lowest (most basic) WCAG level among the rule's
+    /// [`guidelines`](Rule::guidelines), or `None` if it cites none.
+    pub fn wcag_level(&self) -> Option<WcagLevel> {
+        self.guidelines()
+            .iter()
+            .filter_map(|url| WcagLevel::of_criterion(url))
+            .min()
+    }
+
+    /// Collect the rule's static metadata.
+    pub fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: self.clone(),
+            default_severity: self.default_severity(),
+            enabled_by_default: self.enabled_by_default(),
+            description: self.description().trim(),
+            guidelines: self.guidelines(),
+            resources: self.resources(),
+            url: self.docs_url(DOCS_URL),
         }
     }
 
-    pub fn check(&self, element: &HtmlElement) -> Option<LintDiagnostic> {
+    /// The rule's documentation URL: `template` with `{rule}` replaced by the
+    /// rule id, or with the id appended if it has no `{rule}`.
+    pub fn docs_url(&self, template: &str) -> String {
+        if template.contains("{rule}") {
+            template.replace("{rule}", self.as_ref())
+        } else {
+            format!("{}{}", template, self.as_ref())
+        }
+    }
+
+    /// Check every element against this rule alone, using the default rule
+    /// options. See [`run_rule`].
+    pub fn check_all(&self, elements: &[HtmlElement]) -> Vec<LintDiagnostic> {
+        run_rule(self, elements)
+    }
+
+    /// Check a single element against this rule, using `options` for
+    /// configurable rules. The diagnostic carries the rule's fixes, if any.
+    ///
+    /// Rules that compare elements with each other (see
+    /// [`check_macro`](Rule::check_macro)) report nothing here.
+    pub fn check(&self, element: &HtmlElement, options: &RuleOptions) -> Option<LintDiagnostic> {
+        self.check_with(&LintContext::new(element, options))
+    }
+
+    /// Check a single element against this rule, reusing facts already
+    /// computed for it. See [`LintContext`].
+    pub fn check_with(&self, cx: &LintContext) -> Option<LintDiagnostic> {
+        let mut diagnostic = self.check_element(cx)?;
+        diagnostic.fixes = self.fix(cx.element).into_iter().collect();
+        diagnostic.url = Some(self.docs_url(DOCS_URL));
+        Some(diagnostic)
+    }
+
+    /// Why `cx.element` passes this rule, for the rules that look for an
+    /// accessible name. `None` if the rule does not check the element, reports
+    /// it, or cannot say.
+    pub fn explain_pass(&self, cx: &LintContext) -> Option<LintPass> {
+        let element = cx.element;
+        let checks_element = match self {
+            Rule::AnchorHasContent => element.tag == Tag::A,
+            Rule::ControlHasAssociatedLabel => is_labelled_control(element, cx.options),
+            Rule::HeadingHasContent => element.tag.is_heading(),
+            Rule::IframeHasTitle => element.tag == Tag::Iframe,
+            _ => false,
+        };
+        if !checks_element || self.check_element(cx).is_some() {
+            return None;
+        }
+        let reason = match cx.accessible_name() {
+            Some(name) if !name.is_empty() => describe_name(element, &name),
+            _ if *self == Rule::IframeHasTitle => "hidden with aria-hidden=\"true\"".to_string(),
+            _ => return None,
+        };
+        Some(LintPass {
+            rule: self.clone(),
+            file: element.file.clone(),
+            line: element.line,
+            column: element.column,
+            element: element.tag.clone(),
+            reason,
+        })
+    }
+
+    /// The fix for a diagnostic this rule reported on `element`, if the
+    /// rule offers one.
+    fn fix(&self, element: &HtmlElement) -> Option<Fix> {
+        let attribute =
+            |name: AttributeName| element.attributes.iter().position(|a| a.name == name);
+        let (message, applicability, edit) = match self {
+            Rule::AltText if element.tag == Tag::Img && attribute(AttributeName::Alt).is_none() => {
+                (
+                    "Add `alt=\"\"` to mark the image as decorative",
+                    Applicability::Suggested,
+                    insert_attribute(element, "alt", ""),
+                )
+            }
+            Rule::AriaModalDialog if element.role().is_none() => (
+                "Add `role=\"dialog\"`",
+                Applicability::Suggested,
+                insert_attribute(element, "role", "dialog"),
+            ),
+            Rule::AriaModalDialog => (
+                "Remove `aria-modal`",
+                Applicability::Suggested,
+                remove_attribute(element, attribute(AttributeName::Aria(Aria::Modal))?),
+            ),
+            Rule::MenuButtonPattern if attribute(AttributeName::Aria(Aria::Expanded)).is_none() => {
+                (
+                    "Add `aria-expanded=\"false\"`",
+                    Applicability::Suggested,
+                    insert_attribute(element, "aria-expanded", "false"),
+                )
+            }
+            Rule::NoAccessKey => (
+                "Remove `accesskey`",
+                Applicability::Suggested,
+                remove_attribute(element, attribute(AttributeName::AccessKey)?),
+            ),
+            Rule::NoAutofocus => (
+                "Remove `autofocus`",
+                Applicability::Suggested,
+                remove_attribute(element, attribute(AttributeName::AutoFocus)?),
+            ),
+            Rule::NoRedundantRoles => (
+                "Remove the redundant `role`",
+                Applicability::Safe,
+                remove_attribute(element, attribute(AttributeName::Role)?),
+            ),
+            Rule::TabindexNoPositive => (
+                "Use `tabindex=\"0\"` to follow the document order",
+                Applicability::Suggested,
+                replace_attribute(
+                    element,
+                    attribute(AttributeName::TabIndex)?,
+                    "tabindex",
+                    "0",
+                ),
+            ),
+            _ => return None,
+        };
+        Some(Fix {
+            message: message.to_string(),
+            applicability,
+            edits: vec![edit],
+        })
+    }
+
+    fn check_element(&self, cx: &LintContext) -> Option<LintDiagnostic> {
+        let (element, options, facts) = (cx.element, cx.options, &cx.facts);
         match self {
             Rule::AltText => {
-                let has_alt = element
-                    .attributes
-                    .iter()
-                    .any(|a| a.name == AttributeName::Alt);
-                let has_role_presentation = element.attributes.iter().any(|a| {
-                    a.name == AttributeName::Role
-                    && matches!(&a.value, Some(AttrValue::Static(v)) if v == "presentation" || v == "none")
-                });
-                let has_aria_label = element.attributes.iter().any(|a| {
-                    a.name == AttributeName::Aria(Aria::Label)
-                        || a.name == AttributeName::Aria(Aria::LabelledBy)
-                });
+                let has_alt = facts.name_sources.alt;
+                let has_role_presentation = matches!(
+                    facts.explicit_role,
+                    Some((_, Role::Presentation | Role::None))
+                );
+                let has_aria_label = facts.name_sources.aria();
 
                 match element.tag {
                     // <img> must have alt (or role="presentation"/"none")
-                    Tag::Img => {
-                        if !has_alt && !has_role_presentation {
-                            return Some(LintDiagnostic {
-                                rule: Rule::AltText,
-                                message: "<img> element is missing an `alt` attribute.".to_string(),
-                                severity: Severity::Error,
-                                file: element.file.clone(),
-                                line: element.line,
-                                column: element.column,
-                                element: element.tag.clone(),
+                    Tag::Img if !has_alt && !has_role_presentation => {
+                        return Some(LintDiagnostic {
                                 help: Some(
                                     "Add an `alt` attribute with descriptive text, or `alt=\"\"` for decorative images, \
                                     or `role=\"presentation\"` / `role=\"none\"`."
                                         .to_string(),
                                 ),
+                                ..LintDiagnostic::new(
+                                    Rule::AltText,
+                                    element,
+                                    "<img> element is missing an `alt` attribute.",
+                                )
                             });
-                        }
                     }
                     // <area> must have alt or aria-label/aria-labelledby
-                    Tag::Area => {
-                        if !has_alt && !has_aria_label {
-                            return Some(LintDiagnostic {
-                                rule: Rule::AltText,
-                                message: "<area> element is missing an `alt` attribute."
+                    Tag::Area if !has_alt && !has_aria_label => {
+                        return Some(LintDiagnostic {
+                            help: Some(
+                                "Add an `alt` attribute or `aria-label` / `aria-labelledby`."
                                     .to_string(),
-                                severity: Severity::Error,
-                                file: element.file.clone(),
-                                line: element.line,
-                                column: element.column,
-                                element: element.tag.clone(),
-                                help: Some(
-                                    "Add an `alt` attribute or `aria-label` / `aria-labelledby`."
-                                        .to_string(),
-                                ),
-                            });
-                        }
+                            ),
+                            ..LintDiagnostic::new(
+                                Rule::AltText,
+                                element,
+                                "<area> element is missing an `alt` attribute.",
+                            )
+                        });
                     }
                     // <input type="image"> must have alt or aria-label/aria-labelledby
                     Tag::Input => {
@@ -450,80 +1024,119 @@ impl Rule {
                         });
                         if is_image_input && !has_alt && !has_aria_label {
                             return Some(LintDiagnostic {
-                                rule: Rule::AltText,
-                                message: "<input type=\"image\"> is missing an `alt` attribute."
-                                    .to_string(),
-                                severity: Severity::Error,
-                                file: element.file.clone(),
-                                line: element.line,
-                                column: element.column,
-                                element: element.tag.clone(),
                                 help: Some(
                                     "Add an `alt` attribute or `aria-label` / `aria-labelledby`."
                                         .to_string(),
                                 ),
+                                ..LintDiagnostic::new(
+                                    Rule::AltText,
+                                    element,
+                                    "<input type=\"image\"> is missing an `alt` attribute.",
+                                )
                             });
                         }
                     }
                     // <object> must have title, aria-label/aria-labelledby or children
                     Tag::Object => {
-                        let has_title = element
-                            .attributes
-                            .iter()
-                            .any(|a| a.name == AttributeName::Title);
+                        let has_title = facts.name_sources.title;
                         if !has_title && !has_aria_label && !element.has_children {
                             return Some(LintDiagnostic {
-                                rule: Rule::AltText,
-                                message: "<object> element is missing alternative text.".to_string(),
-                                severity: Severity::Error,
-                                file: element.file.clone(),
-                                line: element.line,
-                                column: element.column,
-                                element: element.tag.clone(),
                                 help: Some(
                                     "Add a `title` attribute, `aria-label` / `aria-labelledby`, or text content.".to_string(),
                                 ),
+                                ..LintDiagnostic::new(
+                                    Rule::AltText,
+                                    element,
+                                    "<object> element is missing alternative text.",
+                                )
                             });
                         }
                     }
                     _ => {}
                 }
             }
+            Rule::AltTextLength => {
+                // Only images take their name from `alt`.
+                let is_image = match element.tag {
+                    Tag::Img | Tag::Area => true,
+                    Tag::Input => element.static_attr(AttributeName::Type) == Some("image"),
+                    _ => false,
+                };
+                if !is_image {
+                    return None;
+                }
+                let max_length = options.alt_text_length.max_length;
+                for attr in &element.attributes {
+                    if attr.name == AttributeName::Alt
+                        && let Some(AttrValue::Static(ref val)) = attr.value
+                        && val.trim().chars().count() > max_length
+                    {
+                        return Some(LintDiagnostic {
+                            line: attr.line,
+                            column: attr.column,
+                            help: Some(
+                                "Keep `alt` to a short summary and put the full description in adjacent text or an element referenced by `aria-describedby`."
+                                    .to_string(),
+                            ),
+                            ..LintDiagnostic::new(
+                                Rule::AltTextLength,
+                                element,
+                                format!(
+                                    "<{}> alt text is {} characters long (more than {}). Screen readers read it in one go, without a way to skip or navigate it.",
+                                    element.tag,
+                                    val.trim().chars().count(),
+                                    max_length
+                                ),
+                            )
+                        });
+                    }
+                }
+            }
             Rule::AnchorAmbiguousText => {
-                if element.tag != Tag::A {
+                if element.tag != Tag::A
+                    || element
+                        .attributes
+                        .iter()
+                        .any(|a| a.name == AttributeName::Aria(Aria::LabelledBy))
+                {
                     return None;
                 }
 
-                const AMBIGUOUS_TEXTS: &[&str] =
-                    &["click here", "here", "link", "a link", "learn more"];
-
-                // Check aria-label and title for ambiguous text.
-                for attr in &element.attributes {
-                    let is_label = attr.name == AttributeName::Aria(Aria::Label)
-                        || attr.name == AttributeName::Title;
-                    if is_label {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            let lower = val.to_lowercase();
-                            let trimmed = lower.trim();
-                            if AMBIGUOUS_TEXTS.contains(&trimmed) {
-                                return Some(LintDiagnostic {
-                                    rule: Rule::AnchorAmbiguousText,
-                                    message: format!(
-                                        "<a> element has ambiguous link text \"{}\". Link text should be descriptive of the link's purpose.",
-                                        val
-                                    ),
-                                    severity: Severity::Warning,
-                                    file: element.file.clone(),
-                                    line: attr.line,
-                                    column: attr.column,
-                                    element: element.tag.clone(),
-                                    help: Some(
-                                        "Use text that describes the purpose of the link, such as where the link goes or what it does.".to_string()
-                                    ),
-                                });
-                            }
+                // The accessible name: `aria-label`, else the link's content,
+                // else `title`. Dynamic parts cannot be checked.
+                let attr = |name: AttributeName| element.attributes.iter().find(|a| a.name == name);
+                let (name, line, column) = match attr(AttributeName::Aria(Aria::Label)) {
+                    Some(label) => (label.value.as_ref()?.as_static()?, label.line, label.column),
+                    None => match element.text.as_deref()? {
+                        text if !text.trim().is_empty() => (text, element.line, element.column),
+                        _ => {
+                            let title = attr(AttributeName::Title)?;
+                            (title.value.as_ref()?.as_static()?, title.line, title.column)
                         }
-                    }
+                    },
+                };
+                let normalized = normalize_link_text(name);
+                let is_ambiguous = options
+                    .anchor_ambiguous_text
+                    .words
+                    .iter()
+                    .any(|word| normalize_link_text(word) == normalized);
+                if is_ambiguous {
+                    return Some(LintDiagnostic {
+                        line,
+                        column,
+                        help: Some(
+                            "Use text that describes the purpose of the link, such as where the link goes or what it does.".to_string()
+                        ),
+                        ..LintDiagnostic::new(
+                            Rule::AnchorAmbiguousText,
+                            element,
+                            format!(
+                                "<a> element has ambiguous link text \"{}\". Link text should be descriptive of the link's purpose.",
+                                name.split_whitespace().collect::<Vec<_>>().join(" ")
+                            ),
+                        )
+                    });
                 }
             }
             Rule::AnchorHasContent => {
@@ -531,24 +1144,18 @@ impl Rule {
                     return None;
                 }
 
-                let has_accessible_name = element.attributes.iter().any(|a| {
-                    a.name == AttributeName::Aria(Aria::Label)
-                        || a.name == AttributeName::Aria(Aria::LabelledBy)
-                        || a.name == AttributeName::Title
-                });
-
-                if !element.has_children && !has_accessible_name {
+                if let Some(reason) = unnamed_reason(
+                    element,
+                    cx.accessible_name(),
+                    "<a> element is missing content.",
+                ) {
                     return Some(LintDiagnostic {
-                        rule: Rule::AnchorHasContent,
-                        message:
-                            "<a> element is missing content. Links must have discernible text."
-                                .to_string(),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some("Add text content or an `aria-label` attribute.".to_string()),
+                        ..LintDiagnostic::new(
+                            Rule::AnchorHasContent,
+                            element,
+                            format!("{} Links must have discernible text.", reason),
+                        )
                     });
                 }
             }
@@ -557,33 +1164,32 @@ impl Rule {
                     return None;
                 }
                 for attr in &element.attributes {
-                    if attr.name == AttributeName::Href {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            if val == "#" || val.is_empty() || val == "javascript:void(0)" {
-                                return Some(LintDiagnostic {
-                                    rule: Rule::AnchorIsValid,
-                                    message: format!(
-                                        "<a> element has an invalid `href` value \"{}\". \
-                                        Use a real URL or use a <button> for actions.",
-                                        val
-                                    ),
-                                    severity: Severity::Warning,
-                                    file: element.file.clone(),
-                                    line: attr.line,
-                                    column: attr.column,
-                                    element: element.tag.clone(),
-                                    help: Some(
-                                        "Use a meaningful `href`, or use a <button> element instead."
-                                            .to_string(),
-                                    ),
-                                });
-                            }
-                        }
+                    if attr.name == AttributeName::Href
+                        && let Some(AttrValue::Static(ref val)) = attr.value
+                        && (val == "#" || val.is_empty() || val == "javascript:void(0)")
+                    {
+                        return Some(LintDiagnostic {
+                            line: attr.line,
+                            column: attr.column,
+                            help: Some(
+                                "Use a meaningful `href`, or use a <button> element instead."
+                                    .to_string(),
+                            ),
+                            ..LintDiagnostic::new(
+                                Rule::AnchorIsValid,
+                                element,
+                                format!(
+                                    "<a> element has an invalid `href` value \"{}\". \
+                                            Use a real URL or use a <button> for actions.",
+                                    val
+                                ),
+                            )
+                        });
                     }
                 }
             }
             Rule::AriaActivedescendantHasTabindex => {
-                if element.tag.is_interactive() {
+                if element.is_interactive() {
                     return None;
                 }
                 let has_activedescendant = element
@@ -599,121 +1205,258 @@ impl Rule {
 
                     if !has_tabindex {
                         return Some(LintDiagnostic {
-                            rule: Rule::AriaActivedescendantHasTabindex,
-                            message: format!(
-                                "<{}> with `aria-activedescendant` must also have a `tabindex` attribute to be focusable.",
-                                element.tag
-                            ),
-                            severity: Severity::Warning,
-                            file: element.file.clone(),
-                            line: element.line,
-                            column: element.column,
-                            element: element.tag.clone(),
                             help: Some(
                                 "Add `tabindex=\"0\"` to make the element focusable.".to_string(),
                             ),
+                            ..LintDiagnostic::new(
+                                Rule::AriaActivedescendantHasTabindex,
+                                element,
+                                format!(
+                                    "<{}> with `aria-activedescendant` must also have a `tabindex` attribute to be focusable.",
+                                    element.tag
+                                ),
+                            )
                         });
                     }
                 }
             }
-            Rule::AriaProps => {
-                for attr in &element.attributes {
-                    if let AttributeName::Unknown(unknown_value) = &attr.name {
-                        if unknown_value.starts_with("aria-") {
-                            return Some(LintDiagnostic {
-                                rule: Rule::AriaProps,
-                                message: format!(
-                                    "Invalid ARIA attribute `{}` on <{}>.",
-                                    attr.name, element.tag
-                                ),
-                                severity: Severity::Error,
-                                file: element.file.clone(),
-                                line: attr.line,
-                                column: attr.column,
-                                element: element.tag.clone(),
-                                help: Some(format!(
-                                    "Did you mean one of: aria-label, aria-labelledby, aria-hidden, aria-describedby? See https://www.w3.org/TR/wai-aria-1.2/#state_prop_def for all valid attributes."
-                                )),
-                            });
-                        }
-                    }
+            Rule::AriaActivedescendantTarget => {
+                let attr = element
+                    .attributes
+                    .iter()
+                    .find(|a| a.name == AttributeName::Aria(Aria::ActiveDescendant))?;
+                let id = attr.value.as_ref()?.as_static()?.trim();
+                if id.is_empty() {
+                    return None;
                 }
+                let target = activedescendant_target(element, id, cx.tree)?;
+                return Some(LintDiagnostic {
+                    line: attr.line,
+                    column: attr.column,
+                    help: Some(
+                        "Reference an element inside this one, or list the referenced element's id in `aria-owns`."
+                            .to_string(),
+                    ),
+                    ..LintDiagnostic::new(
+                        Rule::AriaActivedescendantTarget,
+                        element,
+                        format!(
+                            "`aria-activedescendant` on <{}> references <{}> on line {}, which is neither inside it nor owned through `aria-owns`.",
+                            element.tag, target.tag, target.line
+                        ),
+                    )
+                });
             }
-            Rule::AriaProptypes => {
-                for attr in &element.attributes {
-                    match &attr.name {
-                        AttributeName::Aria(aria) => {
-                            let vtype = aria.value_type();
-                            if let Some(AttrValue::Static(ref val)) = attr.value {
-                                if !vtype.is_valid(val) {
-                                    return Some(LintDiagnostic {
-                                        rule: Rule::AriaProptypes,
-                                        message: format!(
-                                            "Invalid value \"{}\" for `{}` on <{}>. Expected {}.",
-                                            val,
-                                            attr.name,
-                                            element.tag,
-                                            vtype.expected_description()
-                                        ),
-                                        severity: Severity::Error,
-                                        file: element.file.clone(),
-                                        line: attr.line,
-                                        column: attr.column,
-                                        element: element.tag.clone(),
-                                        help: None,
-                                    });
-                                }
-                            }
+            Rule::AriaPosinsetSetsize => {
+                let integer = |aria: Aria| {
+                    element.attributes.iter().find_map(|a| match &a.value {
+                        Some(AttrValue::Static(v))
+                            if a.name == AttributeName::Aria(aria.clone()) =>
+                        {
+                            Some((a, v.trim().parse::<i64>().ok()?))
                         }
-                        _ => {}
-                    }
-                }
+                        _ => None,
+                    })
+                };
+                let posinset = integer(Aria::PosInSet);
+                let setsize = integer(Aria::SetSize);
+                let (attr, problem) = match (posinset, setsize) {
+                    (Some((attr, pos)), _) if pos < 1 => (
+                        attr,
+                        format!("`aria-posinset` must be at least 1, but is {}", pos),
+                    ),
+                    // -1 means the size of the set is unknown.
+                    (_, Some((attr, size))) if size < 1 && size != -1 => (
+                        attr,
+                        format!(
+                            "`aria-setsize` must be at least 1 (or -1 if unknown), but is {}",
+                            size
+                        ),
+                    ),
+                    (Some((attr, pos)), Some((_, size))) if size != -1 && pos > size => (
+                        attr,
+                        format!(
+                            "`aria-posinset` ({}) is greater than `aria-setsize` ({})",
+                            pos, size
+                        ),
+                    ),
+                    _ => return None,
+                };
+                return Some(LintDiagnostic {
+                    line: attr.line,
+                    column: attr.column,
+                    help: Some(
+                        "`aria-posinset` is the 1-based position of the item in a set of `aria-setsize` items."
+                            .to_string(),
+                    ),
+                    ..LintDiagnostic::new(
+                        Rule::AriaPosinsetSetsize,
+                        element,
+                        format!("{} on <{}>.", problem, element.tag),
+                    )
+                });
+            }
+            Rule::AriaPrerequisites => {
+                let has = |aria: Aria| {
+                    element
+                        .attributes
+                        .iter()
+                        .any(|a| a.name == AttributeName::Aria(aria.clone()))
+                };
+                for attr in &element.attributes {
+                    let AttributeName::Aria(ref aria) = attr.name else {
+                        continue;
+                    };
+                    let problem = match aria {
+                        Aria::BrailleLabel if !has(Aria::Label) && !has(Aria::LabelledBy) => {
+                            "`aria-braillelabel` requires an `aria-label` or `aria-labelledby`, which it is a braille version of"
+                        }
+                        Aria::BrailleRoleDescription if !has(Aria::RoleDescription) => {
+                            "`aria-brailleroledescription` requires an `aria-roledescription`, which it is a braille version of"
+                        }
+                        Aria::RoleDescription if facts.role.is_none() => {
+                            "`aria-roledescription` requires an explicit or implicit role to describe"
+                        }
+                        _ => continue,
+                    };
+                    return Some(LintDiagnostic {
+                        line: attr.line,
+                        column: attr.column,
+                        help: Some(
+                            "Add the missing attribute or role, or remove the attribute; assistive technologies ignore it otherwise."
+                                .to_string(),
+                        ),
+                        ..LintDiagnostic::new(
+                            Rule::AriaPrerequisites,
+                            element,
+                            format!("{} on <{}>.", problem, element.tag),
+                        )
+                    });
+                }
+            }
+            Rule::AriaProps => {
+                for attr in &element.attributes {
+                    if let AttributeName::Unknown(unknown_value) = &attr.name
+                        && unknown_value.starts_with("aria-")
+                        && !options
+                            .aria_props
+                            .allowed_extra_aria_attrs
+                            .iter()
+                            .any(|a| a.eq_ignore_ascii_case(unknown_value))
+                    {
+                        return Some(LintDiagnostic {
+                                line: attr.line,
+                                column: attr.column,
+                                help: Some("Did you mean one of: aria-label, aria-labelledby, aria-hidden, aria-describedby? See https://www.w3.org/TR/wai-aria-1.2/#state_prop_def for all valid attributes.".to_string()),
+                                ..LintDiagnostic::new(
+                                    Rule::AriaProps,
+                                    element,
+                                    format!(
+                                        "Invalid ARIA attribute `{}` on <{}>.",
+                                        attr.name, element.tag
+                                    ),
+                                )
+                            });
+                    }
+                }
+            }
+            Rule::AriaProptypes => {
+                for attr in &element.attributes {
+                    if let AttributeName::Aria(aria) = &attr.name {
+                        let vtype = aria.value_type();
+                        if let Some(AttrValue::Static(ref val)) = attr.value
+                            && !vtype.is_valid(val)
+                        {
+                            return Some(LintDiagnostic {
+                                line: attr.line,
+                                column: attr.column,
+                                ..LintDiagnostic::new(
+                                    Rule::AriaProptypes,
+                                    element,
+                                    format!(
+                                        "Invalid value \"{}\" for `{}` on <{}>. Expected {}.",
+                                        val,
+                                        attr.name,
+                                        element.tag,
+                                        vtype.expected_description()
+                                    ),
+                                )
+                            });
+                        }
+                    }
+                }
             }
             Rule::AriaRole => {
                 for attr in &element.attributes {
-                    if attr.name == AttributeName::Role {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            // Role can be a space-separated list of roles (fallback roles)
-                            for role_str in val.split_whitespace() {
-                                match Role::from_str(role_str) {
-                                    Some(role) if role.is_abstract() => {
-                                        return Some(LintDiagnostic {
-                                            rule: Rule::AriaRole,
-                                            message: format!(
-                                                "Abstract ARIA role \"{}\" must not be used on <{}>. \
-                                                Abstract roles are for ontology purposes only.",
-                                                role_str, element.tag
-                                            ),
-                                            severity: Severity::Error,
-                                            file: element.file.clone(),
+                    if attr.name == AttributeName::Role
+                        && let Some(AttrValue::Static(ref val)) = attr.value
+                    {
+                        // Role can be a space-separated list of roles (fallback roles)
+                        for role_str in val.split_whitespace().filter(|r| {
+                            !options.aria_role.allowed_extra_roles.iter().any(|a| a == r)
+                        }) {
+                            match Role::from_str(role_str).ok() {
+                                Some(role) if role.is_abstract() => {
+                                    return Some(LintDiagnostic {
                                             line: attr.line,
                                             column: attr.column,
-                                            element: element.tag.clone(),
                                             help: Some(
                                                 "Use a non-abstract role instead. See https://www.w3.org/TR/wai-aria-1.2/#abstract_roles"
                                                     .to_string(),
                                             ),
+                                            ..LintDiagnostic::new(
+                                                Rule::AriaRole,
+                                                element,
+                                                format!(
+                                                    "Abstract ARIA role \"{}\" must not be used on <{}>. \
+                                                    Abstract roles are for ontology purposes only.",
+                                                    role_str, element.tag
+                                                ),
+                                            )
                                         });
-                                    }
-                                    Some(_) => { /* valid concrete role */ }
-                                    // Unknown role string
-                                    None => {
-                                        return Some(LintDiagnostic {
-                                            rule: Rule::AriaRole,
-                                            message: format!(
-                                                "Invalid ARIA role \"{}\" on <{}>.",
-                                                role_str, element.tag
+                                }
+                                Some(role)
+                                    if (role.is_dpub() && !options.aria_role.dpub)
+                                        || (role.is_graphics() && !options.aria_role.graphics) =>
+                                {
+                                    let (module, option) = if role.is_dpub() {
+                                        ("DPub", "dpub")
+                                    } else {
+                                        ("Graphics", "graphics")
+                                    };
+                                    return Some(LintDiagnostic {
+                                        line: attr.line,
+                                        column: attr.column,
+                                        help: Some(format!(
+                                            "Set `{} = true` under `[rules.aria-role]` in rsx-a11y.toml to use {} ARIA roles.",
+                                            option, module
+                                        )),
+                                        ..LintDiagnostic::new(
+                                            Rule::AriaRole,
+                                            element,
+                                            format!(
+                                                "{} ARIA role \"{}\" on <{}> is not enabled.",
+                                                module, role_str, element.tag
                                             ),
-                                            severity: Severity::Error,
-                                            file: element.file.clone(),
+                                        )
+                                    });
+                                }
+                                Some(_) => { /* valid concrete role */ }
+                                // Unknown role string
+                                None => {
+                                    return Some(LintDiagnostic {
                                             line: attr.line,
                                             column: attr.column,
-                                            element: element.tag.clone(),
-                                            help: Some(format!(
-                                                "See https://www.w3.org/TR/wai-aria-1.2/#role_definitions for valid roles."
-                                            )),
+                                            help: Some("See https://www.w3.org/TR/wai-aria-1.2/#role_definitions for valid roles.".to_string()),
+                                            ..LintDiagnostic::new(
+                                                Rule::AriaRole,
+                                                element,
+                                                format!(
+                                                    "Invalid ARIA role \"{}\" on <{}>.",
+                                                    role_str, element.tag
+                                                ),
+                                            )
                                         });
-                                    }
                                 }
                             }
                         }
@@ -726,20 +1469,20 @@ impl Rule {
                         match attr.name {
                             AttributeName::Aria(_) | AttributeName::Role => {
                                 return Some(LintDiagnostic {
-                                    rule: Rule::AriaUnsupportedElements,
-                                    message: format!(
-                                        "ARIA attribute `{}` is not supported on <{}>.",
-                                        attr.name, element.tag
-                                    ),
-                                    severity: Severity::Error,
-                                    file: element.file.clone(),
                                     line: attr.line,
                                     column: attr.column,
-                                    element: element.tag.clone(),
                                     help: Some(format!(
                                         "The <{}> element does not support ARIA roles or properties.",
                                         element.tag
                                     )),
+                                    ..LintDiagnostic::new(
+                                        Rule::AriaUnsupportedElements,
+                                        element,
+                                        format!(
+                                            "ARIA attribute `{}` is not supported on <{}>.",
+                                            attr.name, element.tag
+                                        ),
+                                    )
                                 });
                             }
                             _ => {}
@@ -753,138 +1496,320 @@ impl Rule {
                     return None;
                 }
                 for attr in &element.attributes {
-                    if attr.name == AttributeName::Autocomplete {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            if !is_valid_autocomplete(val) {
-                                return Some(LintDiagnostic {
-                                    rule: Rule::AutocompleteValid,
-                                    message: format!(
-                                        "Invalid `autocomplete` value \"{}\" on <{}>.",
-                                        val, element.tag
-                                    ),
-                                    severity: Severity::Error,
-                                    file: element.file.clone(),
+                    if attr.name == AttributeName::Autocomplete
+                        && let Some(AttrValue::Static(ref val)) = attr.value
+                        && !is_valid_autocomplete(val)
+                    {
+                        return Some(LintDiagnostic {
                                     line: attr.line,
                                     column: attr.column,
-                                    element: element.tag.clone(),
                                     help: Some(
                                         "Use a valid autocomplete value such as \"name\", \"email\", \"username\", \"current-password\", \"street-address\", \"off\", etc."
                                             .to_string(),
                                     ),
+                                    ..LintDiagnostic::new(
+                                        Rule::AutocompleteValid,
+                                        element,
+                                        format!(
+                                            "Invalid `autocomplete` value \"{}\" on <{}>.",
+                                            val, element.tag
+                                        ),
+                                    )
                                 });
-                            }
+                    }
+                    if attr.name == AttributeName::Autocomplete
+                        && element.tag == Tag::Input
+                        && let Some(AttrValue::Static(ref val)) = attr.value
+                        && let Some(field) = val.split_whitespace().last()
+                    {
+                        let input_type = element.attributes.iter().find_map(|a| {
+                            (a.name == AttributeName::Type).then(|| a.value.as_ref()?.as_static())
+                        });
+                        // A dynamic `type` cannot be checked.
+                        let Some(input_type) = input_type.unwrap_or(Some("text")) else {
+                            continue;
+                        };
+                        let input_type = input_type.to_ascii_lowercase();
+                        if !autocomplete_fits_input_type(field, &input_type) {
+                            return Some(LintDiagnostic {
+                                line: attr.line,
+                                column: attr.column,
+                                help: Some(
+                                    "Use an autocomplete token that matches the kind of value the input collects, change the input `type`, or remove the attribute."
+                                        .to_string(),
+                                ),
+                                ..LintDiagnostic::new(
+                                    Rule::AutocompleteValid,
+                                    element,
+                                    format!(
+                                        "`autocomplete=\"{}\"` is not appropriate for <input type=\"{}\">.",
+                                        field, input_type
+                                    ),
+                                )
+                            });
                         }
                     }
                 }
             }
+            Rule::ButtonHasContent => {
+                // `<input type="button">` is named by its `value`.
+                if element.tag == Tag::Input || facts.role != Some(Role::Button) {
+                    return None;
+                }
+                let has_name_attr = facts.name_sources.aria() || facts.name_sources.title;
+                // `text` includes nested `<img alt>`; `None` means dynamic content.
+                let is_empty = element
+                    .text
+                    .as_deref()
+                    .is_some_and(|text| text.trim().is_empty());
+                if !has_name_attr && is_empty {
+                    return Some(LintDiagnostic {
+                        help: Some(
+                            "Add text content, an `alt` on the button's image, or an `aria-label`."
+                                .to_string(),
+                        ),
+                        ..LintDiagnostic::new(
+                            Rule::ButtonHasContent,
+                            element,
+                            format!(
+                                "<{}> button has no text content or accessible name. Screen readers will announce it as just \"button\".",
+                                element.tag
+                            ),
+                        )
+                    });
+                }
+            }
             Rule::ClickEventsHaveKeyEvents => {
                 // Interactive elements inherently handle keyboard events
-                if element.tag.is_interactive() {
+                if element.is_interactive() {
                     return None;
                 }
 
-                let has_click = element
-                    .attributes
+                let pointer_handler = facts
+                    .handlers
                     .iter()
-                    .any(|a| a.name == AttributeName::OnClick);
+                    .find(|a| a.name.is_pointer_activation_handler())?;
 
-                if !has_click {
+                // An interactive role nested in a native control is reached by
+                // keyboard through that control, which handles activation.
+                if element.inside_interactive
+                    && facts.role.as_ref().is_some_and(|r| r.is_interactive())
+                {
                     return None;
                 }
 
-                let has_key_handler = element.attributes.iter().any(|a| {
-                    a.name == AttributeName::OnKeyDown
-                        || a.name == AttributeName::OnKeyUp
-                        || a.name == AttributeName::OnKeyPress
-                });
+                let options = &options.click_events_have_key_events;
+                let has = |name: AttributeName| facts.handlers.iter().any(|a| a.name == name);
+                let (keydown, keyup) = (has(AttributeName::OnKeyDown), has(AttributeName::OnKeyUp));
+                let has_key_handler = if options.require_both {
+                    keydown && keyup
+                } else {
+                    keydown || keyup || (options.accept_keypress && has(AttributeName::OnKeyPress))
+                };
 
                 // An interactive role (e.g. role="button") changes semantics for screen
                 // readers but does NOT add keyboard behaviour. The element still needs an
                 // explicit key handler.
                 if !has_key_handler {
                     return Some(LintDiagnostic {
-                        rule: Rule::ClickEventsHaveKeyEvents,
-                        message: format!(
-                            "<{}> with click handler must also have a keyboard event handler (onkeydown, onkeyup, or onkeypress) for accessibility.",
-                            element.tag
-                        ),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some(
                             "Add an `onkeydown` or `onkeyup` handler, or use an interactive element like <button> instead."
                                 .to_string(),
                         ),
+                        ..LintDiagnostic::new(
+                            Rule::ClickEventsHaveKeyEvents,
+                            element,
+                            format!(
+                                "<{}> with {} must also have {} for accessibility.",
+                                element.tag,
+                                if pointer_handler.name == AttributeName::OnClick {
+                                    "click handler".to_string()
+                                } else {
+                                    format!("`{}` handler", pointer_handler.name)
+                                },
+                                match (options.require_both, options.accept_keypress) {
+                                    (true, _) => "both onkeydown and onkeyup handlers",
+                                    (false, true) =>
+                                        "a keyboard event handler (onkeydown, onkeyup, or onkeypress)",
+                                    (false, false) => "a keyboard event handler (onkeydown or onkeyup)",
+                                }
+                            ),
+                        )
                     });
                 }
             }
+            Rule::ColorContrast => {
+                // Screen-reader-only text is never seen, so its colors do not matter.
+                if element.class_visibility == ClassVisibility::ScreenReaderOnly {
+                    return None;
+                }
+                let style = element
+                    .static_attr(AttributeName::Style)
+                    .unwrap_or_default();
+                let foreground = crate::style::property(style, "color")?;
+                let background = crate::style::property(style, "background-color")
+                    .or_else(|| crate::style::property(style, "background"))
+                    .or_else(|| {
+                        element
+                            .static_attr(AttributeName::BgColor)
+                            .map(str::to_string)
+                    })?;
+                let ratio =
+                    color::contrast_ratio(Color::parse(&foreground)?, Color::parse(&background)?);
+                let (minimum, size) = if crate::style::is_large_text(style) {
+                    (3.0, "large text")
+                } else {
+                    (4.5, "text")
+                };
+                if ratio >= minimum {
+                    return None;
+                }
+                return Some(LintDiagnostic {
+                    help: Some(
+                        "Darken the text or lighten the background (or vice versa) until the ratio is met."
+                            .to_string(),
+                    ),
+                    ..LintDiagnostic::new(
+                        Rule::ColorContrast,
+                        element,
+                        format!(
+                            "<{}> sets `{}` text on a `{}` background, a contrast ratio of {:.2}:1; WCAG AA requires {}:1 for {}.",
+                            element.tag, foreground, background, ratio, minimum, size
+                        ),
+                    )
+                });
+            }
+            Rule::AriaModalDialog => {
+                let attr = element
+                    .attributes
+                    .iter()
+                    .find(|a| a.name == AttributeName::Aria(Aria::Modal))?;
+                let dynamic_role = element.attributes.iter().any(|a| {
+                    a.name == AttributeName::Role && !matches!(a.value, Some(AttrValue::Static(_)))
+                });
+                if attr.value != Some(AttrValue::Static("true".to_string())) || dynamic_role {
+                    return None;
+                }
+                let problem = match &facts.role {
+                    Some(Role::Dialog | Role::AlertDialog) => return None,
+                    Some(role) => format!("has no effect on the \"{}\" role", role),
+                    None => "has no effect without `role=\"dialog\"` or `role=\"alertdialog\"`"
+                        .to_string(),
+                };
+                return Some(LintDiagnostic {
+                    line: attr.line,
+                    column: attr.column,
+                    help: Some(
+                        "Give the element `role=\"dialog\"` (or `role=\"alertdialog\"`) if it is a modal dialog, or remove `aria-modal`."
+                            .to_string(),
+                    ),
+                    ..LintDiagnostic::new(
+                        Rule::AriaModalDialog,
+                        element,
+                        format!(
+                            "`aria-modal=\"true\"` on <{}> {}. Only dialogs can be modal.",
+                            element.tag, problem
+                        ),
+                    )
+                });
+            }
+            Rule::AriaOwnsValid | Rule::ComboboxPattern => {}
             Rule::ControlHasAssociatedLabel => {
                 // Per jsx-a11y: interactive controls must have a text label.
-                let is_control = matches!(
-                    element.tag,
-                    Tag::Button
-                        | Tag::Input
-                        | Tag::Select
-                        | Tag::Textarea
-                        | Tag::Meter
-                        | Tag::Output
-                        | Tag::Progress
-                );
-                if !is_control {
+                if !is_labelled_control(element, options) {
                     return None;
                 }
 
-                let has_label = element.attributes.iter().any(|a| {
-                    a.name == AttributeName::Aria(Aria::Label)
-                        || a.name == AttributeName::Aria(Aria::LabelledBy)
-                        || a.name == AttributeName::Title
-                });
-
-                // Children can contain text labels
-                if !has_label && !element.has_children {
+                if let Some(reason) = unnamed_reason(
+                    element,
+                    cx.accessible_name(),
+                    &format!("<{}> element has no associated label.", element.tag),
+                ) {
                     return Some(LintDiagnostic {
-                        rule: Rule::ControlHasAssociatedLabel,
-                        message: format!(
-                            "<{}> element has no associated label. Interactive controls must have a text label.",
-                            element.tag
-                        ),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some(
                             "Add an `aria-label`, `aria-labelledby`, or `title` attribute, or use a <label>.".to_string(),
                         ),
+                        ..LintDiagnostic::new(
+                            Rule::ControlHasAssociatedLabel,
+                            element,
+                            format!(
+                                "{} Interactive controls must have a text label.",
+                                reason
+                            ),
+                        )
                     });
                 }
             }
+            Rule::Dir => {
+                let dir = element
+                    .attributes
+                    .iter()
+                    .find(|a| a.name == AttributeName::Dir)?;
+                let value = dir.value.as_ref()?.as_static()?;
+                let diagnostic = |message: String, help: &str| LintDiagnostic {
+                    line: dir.line,
+                    column: dir.column,
+                    help: Some(help.to_string()),
+                    ..LintDiagnostic::new(Rule::Dir, element, message)
+                };
+                let value = value.trim().to_ascii_lowercase();
+                if !matches!(value.as_str(), "ltr" | "rtl" | "auto") {
+                    return Some(diagnostic(
+                        format!(
+                            "Invalid `dir` value \"{}\" on <{}>. Browsers ignore it and inherit the direction instead.",
+                            value, element.tag
+                        ),
+                        "Use \"ltr\", \"rtl\" or \"auto\".",
+                    ));
+                }
+                let lang = element
+                    .attributes
+                    .iter()
+                    .find(|a| a.name == AttributeName::Lang)
+                    .and_then(|a| a.value.as_ref()?.as_static())
+                    .filter(|lang| crate::lang::validate(lang).is_ok())?;
+                let expected = if crate::lang::is_rtl(lang) {
+                    "rtl"
+                } else {
+                    "ltr"
+                };
+                if value != "auto" && value != expected {
+                    return Some(diagnostic(
+                        format!(
+                            "<{}> has `dir=\"{}\"` but `lang=\"{}\"` is written {}.",
+                            element.tag,
+                            value,
+                            lang,
+                            if expected == "rtl" {
+                                "right to left"
+                            } else {
+                                "left to right"
+                            }
+                        ),
+                        "Make `dir` match the language's script, or use `dir=\"auto\"`.",
+                    ));
+                }
+            }
             Rule::HeadingHasContent => {
                 if !element.tag.is_heading() {
                     return None;
                 }
 
-                let has_aria_label = element.attributes.iter().any(|a| {
-                    a.name == AttributeName::Aria(Aria::Label)
-                        || a.name == AttributeName::Aria(Aria::LabelledBy)
-                });
-
-                if !element.has_children && !has_aria_label {
+                if let Some(reason) = unnamed_reason(
+                    element,
+                    cx.accessible_name(),
+                    &format!("<{}> element appears to be empty.", element.tag),
+                ) {
                     return Some(LintDiagnostic {
-                        rule: Rule::HeadingHasContent,
-                        message: format!(
-                            "<{}> element appears to be empty. Headings must have text content \
-                            for accessibility.",
-                            element.tag
-                        ),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some("Add text content or an `aria-label` attribute.".to_string()),
+                        ..LintDiagnostic::new(
+                            Rule::HeadingHasContent,
+                            element,
+                            format!(
+                                "{} Headings must have text content for accessibility.",
+                                reason
+                            ),
+                        )
                     });
                 }
             }
@@ -900,51 +1825,126 @@ impl Rule {
 
                 if !has_lang {
                     return Some(LintDiagnostic {
-                        rule: Rule::HtmlHasLang,
-                        message: "<html> element is missing a `lang` attribute.".to_string(),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some(
                             "Add a `lang` attribute (e.g., `lang=\"en\"`) to help screen readers determine the correct pronunciation."
                                 .to_string(),
                         ),
+                        ..LintDiagnostic::new(
+                            Rule::HtmlHasLang,
+                            element,
+                            "<html> element is missing a `lang` attribute.",
+                        )
                     });
                 }
             }
-            Rule::IframeHasTitle => {
-                if element.tag != Tag::Iframe {
+            Rule::IdentityFieldsHaveAutocomplete => {
+                if !matches!(element.tag, Tag::Input | Tag::Textarea) {
                     return None;
                 }
-
-                let has_title = element
+                let static_attr = |name: &str| {
+                    element.attributes.iter().find_map(|a| {
+                        (a.name.to_string() == name)
+                            .then(|| a.value.as_ref()?.as_static())
+                            .flatten()
+                    })
+                };
+                if element
                     .attributes
                     .iter()
-                    .any(|a| a.name == AttributeName::Title);
-                let has_aria = element.attributes.iter().any(|a| {
-                    a.name == AttributeName::Aria(Aria::Label)
-                        || a.name == AttributeName::Aria(Aria::LabelledBy)
+                    .any(|a| a.name == AttributeName::Autocomplete)
+                {
+                    return None;
+                }
+                let input_type = static_attr("type").unwrap_or("text").to_ascii_lowercase();
+                let token = match input_type.as_str() {
+                    "email" => Some("email"),
+                    "tel" => Some("tel"),
+                    "text" | "search" => ["name", "id"]
+                        .iter()
+                        .filter_map(|name| static_attr(name))
+                        .find_map(identity_autocomplete_token),
+                    _ => None,
+                }?;
+                return Some(LintDiagnostic {
+                    help: Some(format!(
+                        "Add `autocomplete=\"{}\"` so browsers and assistive technologies can identify and fill in the field.",
+                        token
+                    )),
+                    ..LintDiagnostic::new(
+                        Rule::IdentityFieldsHaveAutocomplete,
+                        element,
+                        format!(
+                            "<{}> appears to collect personal data but has no `autocomplete` attribute.",
+                            element.tag
+                        ),
+                    )
                 });
+            }
+            Rule::IframeHasTitle => {
+                if element.tag != Tag::Iframe {
+                    return None;
+                }
+
                 let has_hidden = element.attributes.iter().any(|a| {
                     a.name == AttributeName::Aria(Aria::Hidden)
                         && matches!(&a.value, Some(AttrValue::Static(v)) if v == "true")
                 });
+                if has_hidden {
+                    return None;
+                }
 
-                if !has_title && !has_aria && !has_hidden {
+                if let Some(reason) = unnamed_reason(
+                    element,
+                    cx.accessible_name(),
+                    "<iframe> element is missing a `title` attribute.",
+                ) {
                     return Some(LintDiagnostic {
-                        rule: Rule::IframeHasTitle,
-                        message: "<iframe> element is missing a `title` attribute.".to_string(),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some(
                             "Add a `title` attribute that describes the iframe content."
                                 .to_string(),
                         ),
+                        ..LintDiagnostic::new(Rule::IframeHasTitle, element, reason)
+                    });
+                }
+            }
+            // Checked per macro, in `check_macro`.
+            Rule::IframeTitleUnique => {}
+            Rule::ImgAltFilename => {
+                if element.tag != Tag::Img {
+                    return None;
+                }
+                let alt = element
+                    .attributes
+                    .iter()
+                    .find(|a| a.name == AttributeName::Alt)?;
+                let value = alt.value.as_ref()?.as_static()?.trim();
+                let src_name = element
+                    .attributes
+                    .iter()
+                    .find(|a| a.name == AttributeName::Src)
+                    .and_then(|a| a.value.as_ref()?.as_static())
+                    .and_then(|src| src.split(['?', '#']).next()?.rsplit('/').next())
+                    .filter(|name| !name.is_empty());
+                let matches_src = src_name.is_some_and(|name| {
+                    let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+                    value.eq_ignore_ascii_case(name) || value.eq_ignore_ascii_case(stem)
+                });
+                if matches_src || is_image_filename(value) {
+                    return Some(LintDiagnostic {
+                        line: alt.line,
+                        column: alt.column,
+                        help: Some(
+                            "Describe what the image shows, or use `alt=\"\"` if it is decorative."
+                                .to_string(),
+                        ),
+                        ..LintDiagnostic::new(
+                            Rule::ImgAltFilename,
+                            element,
+                            format!(
+                                "<img> alt text \"{}\" is a file name, which is meaningless to screen reader users.",
+                                value
+                            ),
+                        )
                     });
                 }
             }
@@ -954,30 +1954,29 @@ impl Rule {
                 }
 
                 for attr in &element.attributes {
-                    if attr.name == AttributeName::Alt {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            let lower = val.to_lowercase();
-                            let redundant_words = ["image", "picture", "photo", "icon", "graphic"];
-                            for word in &redundant_words {
-                                if lower.contains(word) {
-                                    return Some(LintDiagnostic {
-                                        rule: Rule::ImgRedundantAlt,
-                                        message: format!(
-                                            "<img> alt text contains the redundant word \"{}\". \
-                                            Screen readers already announce images as images.",
-                                            word
-                                        ),
-                                        severity: Severity::Warning,
-                                        file: element.file.clone(),
+                    if attr.name == AttributeName::Alt
+                        && let Some(AttrValue::Static(ref val)) = attr.value
+                    {
+                        let lower = val.to_lowercase();
+                        for word in &options.img_redundant_alt.words {
+                            if lower.contains(&word.to_lowercase()) {
+                                return Some(LintDiagnostic {
                                         line: attr.line,
                                         column: attr.column,
-                                        element: element.tag.clone(),
                                         help: Some(
                                             "Describe what the image shows instead of stating it's an image."
                                                 .to_string(),
                                         ),
+                                        ..LintDiagnostic::new(
+                                            Rule::ImgRedundantAlt,
+                                            element,
+                                            format!(
+                                                "<img> alt text contains the redundant word \"{}\". \
+                                                Screen readers already announce images as images.",
+                                                word
+                                            ),
+                                        )
                                     });
-                                }
                             }
                         }
                     }
@@ -985,41 +1984,47 @@ impl Rule {
             }
             Rule::InteractiveSupportsFocus => {
                 // Skip natively interactive elements (already focusable)
-                if element.tag.is_interactive() {
+                if element.is_interactive() {
                     return None;
                 }
 
                 // Check if element has an interactive role
-                let has_interactive_role = element.attributes.iter().any(|a| {
-                    a.name == AttributeName::Role
-                        && matches!(&a.value, Some(AttrValue::Static(v)) if Role::from_str(v).is_some_and(|r| r.is_interactive()))
-                });
+                let has_interactive_role = facts
+                    .explicit_role
+                    .as_ref()
+                    .is_some_and(|(_, role)| role.is_interactive());
                 if !has_interactive_role {
                     return None;
                 }
 
                 // Check if element has an event handler
-                if !element.has_event_handler() {
+                if !facts.has_event_handler() {
                     return None;
                 }
 
-                // Check if element is focusable (has tabindex)
-                if !element.is_focusable() {
+                // Check if element is focusable (has tabindex). In `focusable`
+                // mode, `tabindex="-1"` is accepted as well.
+                let is_focusable = match options.interactive_supports_focus.mode {
+                    FocusMode::Tabbable => facts.focusable,
+                    FocusMode::Focusable => element
+                        .attributes
+                        .iter()
+                        .any(|a| a.name == AttributeName::TabIndex),
+                };
+                if !is_focusable {
                     return Some(LintDiagnostic {
-                        rule: Rule::InteractiveSupportsFocus,
-                        message: format!(
-                            "<{}> with an interactive role must be focusable. Add a `tabindex` attribute.",
-                            element.tag
-                        ),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some(
                             "Add `tabindex=\"0\"` to make the element focusable, or use a natively interactive element like <button>."
                                 .to_string(),
                         ),
+                        ..LintDiagnostic::new(
+                            Rule::InteractiveSupportsFocus,
+                            element,
+                            format!(
+                                "<{}> with an interactive role must be focusable. Add a `tabindex` attribute.",
+                                element.tag
+                            ),
+                        )
                     });
                 }
             }
@@ -1037,17 +2042,15 @@ impl Rule {
                 // verify this statically, so only warn if there's no `for` and no children.
                 if !has_for && !element.has_children {
                     return Some(LintDiagnostic {
-                        rule: Rule::LabelHasAssociatedControl,
-                        message: "<label> element has no associated form control.".to_string(),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some(
                             "Add a `for` attribute linking to a form control's `id`, or nest a form control inside the label."
                                 .to_string(),
                         ),
+                        ..LintDiagnostic::new(
+                            Rule::LabelHasAssociatedControl,
+                            element,
+                            "<label> element has no associated form control.",
+                        )
                     });
                 }
             }
@@ -1055,38 +2058,129 @@ impl Rule {
                 // Per jsx-a11y: the lang attribute must have a valid BCP 47 value.
                 // This is different from html-has-lang which checks for existence.
                 for attr in &element.attributes {
-                    if attr.name == AttributeName::Lang {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            if !is_valid_lang(val) {
-                                return Some(LintDiagnostic {
-                                    rule: Rule::Lang,
-                                    message: format!(
-                                        "The `lang` attribute value \"{}\" is not a valid BCP 47 language tag.",
-                                        val
-                                    ),
-                                    severity: Severity::Error,
-                                    file: element.file.clone(),
+                    if attr.name == AttributeName::Lang
+                        && let Some(AttrValue::Static(ref val)) = attr.value
+                        && let Err(err) = crate::lang::validate(val)
+                    {
+                        return Some(LintDiagnostic {
                                     line: attr.line,
                                     column: attr.column,
-                                    element: element.tag.clone(),
                                     help: Some(
                                         "Use a valid BCP 47 language tag, e.g., \"en\", \"en-US\", \"fr\", \"de\", \"zh-Hans\".".to_string(),
                                     ),
+                                    ..LintDiagnostic::new(
+                                        Rule::Lang,
+                                        element,
+                                        format!(
+                                            "The `lang` attribute value \"{}\" is not a valid BCP 47 language tag: {}.",
+                                            val, err
+                                        ),
+                                    )
                                 });
-                            }
-                        }
                     }
                 }
             }
+            Rule::LiveRegionValid => {
+                let live = element
+                    .attributes
+                    .iter()
+                    .find(|a| a.name == AttributeName::Aria(Aria::Live));
+                let live_value = live.and_then(|a| a.value.as_ref()?.as_static());
+                let role = element.role();
+                let (attr, problem) = if let Some(live) = live
+                    && role == Some(Role::Alert)
+                    && matches!(live_value, Some("polite" | "off"))
+                {
+                    (
+                        live,
+                        format!(
+                            "`role=\"alert\"` is assertive, but `aria-live=\"{}\"` contradicts it",
+                            live_value.unwrap_or_default()
+                        ),
+                    )
+                } else if let Some(relevant) = element
+                    .attributes
+                    .iter()
+                    .find(|a| a.name == AttributeName::Aria(Aria::Relevant))
+                    && live.is_none()
+                    && !matches!(
+                        role,
+                        Some(Role::Alert | Role::Log | Role::Marquee | Role::Status | Role::Timer)
+                    )
+                {
+                    (
+                        relevant,
+                        "has `aria-relevant` but is not a live region; add `aria-live` or a live role".to_string(),
+                    )
+                } else if let Some(live) = live
+                    && live_value == Some("assertive")
+                {
+                    let max = options.live_region_valid.max_assertive_children;
+                    let children = element_children(element, cx.tree);
+                    if children <= max {
+                        return None;
+                    }
+                    (
+                        live,
+                        format!(
+                            "has {} child elements, too many for `aria-live=\"assertive\"` (at most {}); every change interrupts the user",
+                            children, max
+                        ),
+                    )
+                } else {
+                    return None;
+                };
+                return Some(LintDiagnostic {
+                    line: attr.line,
+                    column: attr.column,
+                    help: Some(
+                        "Keep assertive regions small and limited to urgent messages, use `aria-live=\"polite\"` with `role=\"status\"` instead of `role=\"alert\"`, and only set `aria-relevant` on live regions."
+                            .to_string(),
+                    ),
+                    ..LintDiagnostic::new(
+                        Rule::LiveRegionValid,
+                        element,
+                        format!("Live region <{}> {}.", element.tag, problem),
+                    )
+                });
+            }
+            Rule::MathHasAlttext => {
+                if element.tag != Tag::Math {
+                    return None;
+                }
+
+                let has_name = element.attributes.iter().any(|a| {
+                    matches!(
+                        a.name,
+                        AttributeName::AltText
+                            | AttributeName::Aria(Aria::Label)
+                            | AttributeName::Aria(Aria::LabelledBy)
+                    ) || (a.name == AttributeName::Aria(Aria::Hidden)
+                        && matches!(&a.value, Some(AttrValue::Static(v)) if v == "true"))
+                });
+                // Content only known at runtime may be the fallback.
+                let has_fallback = element.text.as_deref().is_none_or(|t| !t.trim().is_empty());
+
+                if !has_name && !has_fallback {
+                    return Some(LintDiagnostic {
+                        help: Some(
+                            "Add an `alttext` attribute with a text version of the formula, e.g. `alttext=\"x squared\"`."
+                                .to_string(),
+                        ),
+                        ..LintDiagnostic::new(
+                            Rule::MathHasAlttext,
+                            element,
+                            "<math> element has no `alttext` and no fallback content.",
+                        )
+                    });
+                }
+            }
             Rule::MediaHasCaption => {
                 if !matches!(element.tag, Tag::Video | Tag::Audio) {
                     return None;
                 }
 
-                let has_accessible_text = element.attributes.iter().any(|a| {
-                    a.name == AttributeName::Aria(Aria::Label)
-                        || a.name == AttributeName::Aria(Aria::LabelledBy)
-                });
+                let has_accessible_text = facts.name_sources.aria();
 
                 // We can't check for <track> children in our simplified model, but
                 // we warn if there's no aria-label either, as a heuristic.
@@ -1096,496 +2190,544 @@ impl Rule {
 
                 if !has_accessible_text && !is_muted {
                     return Some(LintDiagnostic {
-                        rule: Rule::MediaHasCaption,
-                        message: format!(
-                            "<{}> elements must have captions for accessibility.",
-                            element.tag
-                        ),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some(
                             "Add a <track kind=\"captions\"> child element, or use `aria-label` / `aria-labelledby` for descriptive text."
                                 .to_string(),
                         ),
+                        ..LintDiagnostic::new(
+                            Rule::MediaHasCaption,
+                            element,
+                            format!(
+                                "<{}> elements must have captions for accessibility.",
+                                element.tag
+                            ),
+                        )
                     });
                 }
             }
-            Rule::MouseEventsHaveKeyEvents => {
-                let mut has_mouse_enter = false;
-                let mut has_mouse_leave = false;
-                let mut has_on_focus = false;
-                let mut has_on_blur = false;
-                for attr in &element.attributes {
-                    if attr.name == AttributeName::OnMouseOver {
-                        has_mouse_enter = true;
-                    }
-                    if attr.name == AttributeName::OnMouseOut {
-                        has_mouse_leave = true;
-                    }
-                    if attr.name == AttributeName::OnFocus {
-                        has_on_focus = true;
-                    }
-                    if attr.name == AttributeName::OnBlur {
-                        has_on_blur = true;
-                    }
+            Rule::MenuButtonPattern => {
+                let attr = |aria: Aria| {
+                    element
+                        .attributes
+                        .iter()
+                        .find(|a| a.name == AttributeName::Aria(aria.clone()))
+                };
+                // Comboboxes are left to `combobox-pattern`.
+                if facts.role == Some(Role::Combobox) {
+                    return None;
                 }
-                if has_mouse_enter && !has_on_focus {
-                    return Some(LintDiagnostic {
-                        rule: Rule::MouseEventsHaveKeyEvents,
-                        message: format!(
-                            "<{}> has a mouseover event handler but no onfocus handler. This can cause accessibility issues for keyboard users.",
-                            element.tag
-                        ),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
-                        help: Some(
-                            "Add an `onfocus` handler that mirrors the behavior of the `onmouseover` handler."
-                                .to_string(),
-                        ),
-                    });
+                let has_popup = attr(Aria::HasPopup).is_some_and(|a| {
+                    a.value
+                        .as_ref()
+                        .and_then(|v| v.as_static())
+                        .is_none_or(|v| v.trim() != "false")
+                });
+                if !has_popup && facts.role != Some(Role::Button) {
+                    return None;
                 }
-                if has_mouse_leave && !has_on_blur {
-                    return Some(LintDiagnostic {
-                        rule: Rule::MouseEventsHaveKeyEvents,
-                        message: format!(
-                            "<{}> has a mouseout event handler but no onblur handler. This can cause accessibility issues for keyboard users.",
-                            element.tag
-                        ),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
-                        help: Some(
-                            "Add an `onblur` handler that mirrors the behavior of the `onmouseout` handler."
+                let (line, column, problem) = match attr(Aria::Expanded) {
+                    None if has_popup => (
+                        element.line,
+                        element.column,
+                        "has `aria-haspopup` but no `aria-expanded` to tell whether its popup is shown"
+                            .to_string(),
+                    ),
+                    // An `aria-controls` id missing from the macro may be
+                    // rendered elsewhere, so only its absence is reported.
+                    Some(expanded)
+                        if expanded.value == Some(AttrValue::Static("true".to_string()))
+                            && attr(Aria::Controls).is_none() =>
+                    {
+                        (
+                            expanded.line,
+                            expanded.column,
+                            "is expanded but has no `aria-controls` referencing what it shows"
                                 .to_string(),
+                        )
+                    }
+                    _ => return None,
+                };
+                return Some(LintDiagnostic {
+                    line,
+                    column,
+                    help: Some(
+                        "Set `aria-expanded` to whether the menu or content is shown, and point `aria-controls` at its id."
+                            .to_string(),
+                    ),
+                    ..LintDiagnostic::new(
+                        Rule::MenuButtonPattern,
+                        element,
+                        format!("<{}> {}.", element.tag, problem),
+                    )
+                });
+            }
+            Rule::MouseEventsHaveKeyEvents => {
+                let options = &options.mouse_events_have_key_events;
+                let find = |handlers: &[String]| {
+                    element.attributes.iter().find(|a| {
+                        handlers
+                            .iter()
+                            .any(|h| AttributeName::from_str(h).is_ok_and(|h| h == a.name))
+                    })
+                };
+                let has = |name: AttributeName| element.attributes.iter().any(|a| a.name == name);
+                let missing = if let Some(hover_in) = find(&options.hover_in_handlers)
+                    && !has(AttributeName::OnFocus)
+                {
+                    Some((hover_in, "onfocus"))
+                } else if let Some(hover_out) = find(&options.hover_out_handlers)
+                    && !has(AttributeName::OnBlur)
+                {
+                    Some((hover_out, "onblur"))
+                } else {
+                    None
+                };
+                let (handler, key_handler) = missing?;
+                return Some(LintDiagnostic {
+                    help: Some(format!(
+                        "Add an `{}` handler that mirrors the behavior of the `{}` handler.",
+                        key_handler, handler.name
+                    )),
+                    ..LintDiagnostic::new(
+                        Rule::MouseEventsHaveKeyEvents,
+                        element,
+                        format!(
+                            "<{}> has an `{}` event handler but no {} handler. This can cause accessibility issues for keyboard users.",
+                            element.tag, handler.name, key_handler
                         ),
-                    });
-                }
+                    )
+                });
             }
             Rule::NoAccessKey => {
                 for attr in &element.attributes {
                     if attr.name == AttributeName::AccessKey {
                         return Some(LintDiagnostic {
-                            rule: Rule::NoAccessKey,
-                            message: format!(
-                                "Avoid using the `accesskey` attribute on <{}>. Access keys create keyboard shortcuts that conflict with screen reader and keyboard commands.",
-                                element.tag
-                            ),
-                            severity: Severity::Warning,
-                            file: element.file.clone(),
                             line: attr.line,
                             column: attr.column,
-                            element: element.tag.clone(),
-                            help: None,
+                            ..LintDiagnostic::new(
+                                Rule::NoAccessKey,
+                                element,
+                                format!(
+                                    "Avoid using the `accesskey` attribute on <{}>. Access keys create keyboard shortcuts that conflict with screen reader and keyboard commands.",
+                                    element.tag
+                                ),
+                            )
                         });
                     }
                 }
             }
-            Rule::NoAriaHiddenOnFocusable => {
-                // Check if element is focusable (natively interactive or has tabindex >= 0)
-                if !element.is_focusable() {
+            // Checked per macro, in `check_macro`.
+            Rule::NoAriaHiddenOnFocusable => {}
+            // The dialog allowance needs the macro, from `cx.tree`.
+            Rule::NoAutofocus => {
+                let i = cx.tree.iter().position(|e| std::ptr::eq(e, element))?;
+                return autofocus_at(cx.tree, i, &options.no_autofocus);
+            }
+            Rule::NoDistractingElements => {
+                if matches!(element.tag, Tag::Marquee | Tag::Blink) {
+                    return Some(LintDiagnostic {
+                        help: Some("Use CSS animations or transitions instead.".to_string()),
+                        ..LintDiagnostic::new(
+                            Rule::NoDistractingElements,
+                            element,
+                            format!(
+                                "<{}> elements are distracting and should not be used. They can cause accessibility issues for users with visual or cognitive disabilities.",
+                                element.tag
+                            ),
+                        )
+                    });
+                }
+            }
+            Rule::NoHiddenFocusable => {
+                if !facts.hidden {
                     return None;
                 }
-                let has_aria_hidden_true = element.attributes.iter().any(|a| {
-                    a.name == AttributeName::Aria(Aria::Hidden)
-                        && matches!(&a.value, Some(AttrValue::Static(v)) if v == "true")
-                });
-                if has_aria_hidden_true {
-                    return Some(LintDiagnostic {
-                        rule: Rule::NoAriaHiddenOnFocusable,
-                        message: format!(
-                            "<{}> element is focusable but has `aria-hidden=\"true\"`, which hides it from assistive technologies.",
-                            element.tag
-                        ),
-                        severity: Severity::Error,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
-                        help: Some(
-                            "Remove `aria-hidden=\"true\"` from focusable elements, or make the element non-focusable."
-                                .to_string(),
-                        ),
-                    });
-                }
-            }
-            Rule::NoAutofocus => {
-                for attr in &element.attributes {
-                    if attr.name == AttributeName::AutoFocus {
-                        return Some(LintDiagnostic {
-                            rule: Rule::NoAutofocus,
-                            message: format!(
-                                "Avoid using the `autofocus` attribute on <{}>. Autofocus can reduce usability and accessibility for sighted and non-sighted users.",
-                                element.tag
-                            ),
-                            severity: Severity::Warning,
-                            file: element.file.clone(),
-                            line: attr.line,
-                            column: attr.column,
-                            element: element.tag.clone(),
-                            help: None,
-                        });
-                    }
-                }
-            }
-            Rule::NoDistractingElements => {
-                if matches!(element.tag, Tag::Marquee | Tag::Blink) {
-                    return Some(LintDiagnostic {
-                        rule: Rule::NoDistractingElements,
-                        message: format!(
-                            "<{}> elements are distracting and should not be used. They can cause accessibility issues for users with visual or cognitive disabilities.",
+                let tabindex = element.attributes.iter().find(|a| {
+                    a.name == AttributeName::TabIndex
+                        && matches!(&a.value, Some(AttrValue::Static(v)) if v.trim().parse::<i32>().is_ok_and(|i| i >= 0))
+                })?;
+                return Some(LintDiagnostic {
+                    line: tabindex.line,
+                    column: tabindex.column,
+                    help: Some(
+                        "Remove the `tabindex` (or use `tabindex=\"-1\"`) while the element is hidden, or stop hiding it."
+                            .to_string(),
+                    ),
+                    ..LintDiagnostic::new(
+                        Rule::NoHiddenFocusable,
+                        element,
+                        format!(
+                            "<{}> is hidden by its inline `style` or class but has a `tabindex` that puts it in the tab order.",
                             element.tag
                         ),
-                        severity: Severity::Error,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
-                        help: Some("Use CSS animations or transitions instead.".to_string()),
-                    });
-                }
+                    )
+                });
             }
             Rule::NoInteractiveElementToNoninteractiveRole => {
                 // Interactive HTML elements should not be assigned non-interactive roles.
-                if !element.tag.is_interactive() {
+                if !element.is_interactive() {
                     return None;
                 }
-                for attr in &element.attributes {
-                    if attr.name == AttributeName::Role {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            if let Some(role) = Role::from_str(val) {
-                                if !role.is_interactive() {
-                                    return Some(LintDiagnostic {
-                                        rule: Rule::NoInteractiveElementToNoninteractiveRole,
-                                        message: format!(
-                                            "Interactive element <{}> should not be assigned the non-interactive role \"{}\".",
-                                            element.tag, val
-                                        ),
-                                        severity: Severity::Warning,
-                                        file: element.file.clone(),
-                                        line: attr.line,
-                                        column: attr.column,
-                                        element: element.tag.clone(),
-                                        help: Some(
-                                            "Remove the `role` attribute or use an appropriate interactive role.".to_string(),
-                                        ),
-                                    });
-                                }
-                            }
-                        }
-                    }
+                let rule_options = &options.no_interactive_element_to_noninteractive_role;
+                if let Some((attr, role)) = &facts.explicit_role
+                    && !role.is_interactive()
+                    && !rule_options.allows(&element.tag, role)
+                    && !(rule_options.allow_presentation_when_disabled
+                        && matches!(role, Role::Presentation | Role::None)
+                        && element.is_disabled())
+                {
+                    return Some(LintDiagnostic {
+                        line: attr.line,
+                        column: attr.column,
+                        help: Some(
+                            "Remove the `role` attribute or use an appropriate interactive role."
+                                .to_string(),
+                        ),
+                        ..LintDiagnostic::new(
+                            Rule::NoInteractiveElementToNoninteractiveRole,
+                            element,
+                            format!(
+                                "Interactive element <{}> should not be assigned the non-interactive role \"{}\".",
+                                element.tag, role
+                            ),
+                        )
+                    });
                 }
             }
             Rule::NoNoninteractiveElementInteractions => {
                 // Non-interactive elements with non-interactive implicit roles
                 // should not have event handlers.
-                if element.tag.is_interactive() {
+                if element.is_interactive() {
                     return None;
                 }
 
                 // If the element has an explicit interactive role, it's fine.
-                let explicit_role = element.attributes.iter().find_map(|a| {
-                    if a.name == AttributeName::Role {
-                        a.value
-                            .as_ref()
-                            .and_then(|v| v.as_static())
-                            .and_then(Role::from_str)
-                    } else {
-                        None
-                    }
-                });
-                if explicit_role.as_ref().is_some_and(|r| r.is_interactive()) {
+                if facts
+                    .explicit_role
+                    .as_ref()
+                    .is_some_and(|(_, role)| role.is_interactive())
+                {
                     return None;
                 }
 
                 // Only applies to elements with a non-interactive implicit role
                 // (elements with no implicit role are handled by NoStaticElementInteractions)
-                if element.tag.implicit_role().is_none() {
-                    return None;
-                }
+                element.implicit_role()?;
 
-                let has_handler = element.attributes.iter().any(|a| {
-                    matches!(
-                        a.name,
-                        AttributeName::OnClick
-                            | AttributeName::OnKeyDown
-                            | AttributeName::OnKeyUp
-                            | AttributeName::OnKeyPress
-                    )
-                });
+                let has_handler = facts.has_interaction_handler();
 
                 if has_handler {
                     return Some(LintDiagnostic {
-                        rule: Rule::NoNoninteractiveElementInteractions,
-                        message: format!(
-                            "Non-interactive element <{}> should not have event handlers.",
-                            element.tag
-                        ),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some(
                             "Use an interactive element like <button> or <a>, or add an appropriate `role` attribute."
                                 .to_string(),
                         ),
+                        ..LintDiagnostic::new(
+                            Rule::NoNoninteractiveElementInteractions,
+                            element,
+                            format!(
+                                "Non-interactive element <{}> should not have event handlers.",
+                                element.tag
+                            ),
+                        )
                     });
                 }
             }
             Rule::NoNoninteractiveElementToInteractiveRole => {
                 // Non-interactive HTML elements should not be assigned interactive roles.
-                if element.tag.is_interactive() {
+                if element.is_interactive() {
                     return None;
                 }
-                for attr in &element.attributes {
-                    if attr.name == AttributeName::Role {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            if let Some(role) = Role::from_str(val) {
-                                if role.is_interactive() {
-                                    return Some(LintDiagnostic {
-                                        rule: Rule::NoNoninteractiveElementToInteractiveRole,
-                                        message: format!(
+                if let Some((attr, role)) = &facts.explicit_role
+                    && role.is_interactive()
+                    && !options
+                        .no_noninteractive_element_to_interactive_role
+                        .allows(&element.tag, role)
+                {
+                    return Some(LintDiagnostic {
+                                    line: attr.line,
+                                    column: attr.column,
+                                    help: Some(
+                                        "Use the appropriate interactive element instead, e.g., <button>, <a>, <input>."
+                                            .to_string(),
+                                    ),
+                                    ..LintDiagnostic::new(
+                                        Rule::NoNoninteractiveElementToInteractiveRole,
+                                        element,
+                                        format!(
                                             "Non-interactive element <{}> should not be assigned the interactive role \"{}\".",
-                                            element.tag, val
-                                        ),
-                                        severity: Severity::Warning,
-                                        file: element.file.clone(),
-                                        line: attr.line,
-                                        column: attr.column,
-                                        element: element.tag.clone(),
-                                        help: Some(
-                                            "Use the appropriate interactive element instead, e.g., <button>, <a>, <input>."
-                                                .to_string(),
+                                            element.tag, role
                                         ),
-                                    });
-                                }
-                            }
-                        }
-                    }
+                                    )
+                                });
                 }
             }
             Rule::NoNoninteractiveTabindex => {
-                if element.role().is_some_and(|role| role.is_interactive()) {
+                if facts.role.as_ref().is_some_and(|role| {
+                    role.is_interactive() || options.no_noninteractive_tabindex.roles.contains(role)
+                }) {
                     return None;
                 }
 
                 for attr in &element.attributes {
-                    if attr.name == AttributeName::TabIndex {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            if let Ok(index) = val.parse::<i32>() {
-                                if index >= 0 {
-                                    return Some(LintDiagnostic {
-                                        rule: Rule::NoNoninteractiveTabindex,
-                                        message: format!(
-                                            "Non-interactive element <{}> should not have `tabindex=\"{}\"`. Non-interactive elements should not be focusable.",
-                                            element.tag, index
-                                        ),
-                                        severity: Severity::Warning,
-                                        file: element.file.clone(),
-                                        line: attr.line,
-                                        column: attr.column,
-                                        element: element.tag.clone(),
-                                        help: Some(
-                                            "Remove the `tabindex` attribute, or add an interactive role."
-                                                .to_string(),
-                                        ),
-                                    });
-                                }
-                            }
-                        }
+                    if attr.name == AttributeName::TabIndex
+                        && let Some(AttrValue::Static(ref val)) = attr.value
+                        && let Ok(index) = val.trim().parse::<i32>()
+                        && index >= 0
+                    {
+                        return Some(LintDiagnostic {
+                            line: attr.line,
+                            column: attr.column,
+                            help: Some(
+                                "Remove the `tabindex` attribute, or add an interactive role."
+                                    .to_string(),
+                            ),
+                            ..LintDiagnostic::new(
+                                Rule::NoNoninteractiveTabindex,
+                                element,
+                                format!(
+                                    "Non-interactive element <{}> should not have `tabindex=\"{}\"`. Non-interactive elements should not be focusable.",
+                                    element.tag, index
+                                ),
+                            )
+                        });
                     }
                 }
             }
+            Rule::NoOnchange => {
+                if element.tag != Tag::Select {
+                    return None;
+                }
+                let onchange = element
+                    .attributes
+                    .iter()
+                    .find(|a| a.name == AttributeName::OnChange)?;
+                // Any other handler (e.g. `onblur`) suggests the change is
+                // not acted on immediately.
+                let has_other_handler = facts
+                    .handlers
+                    .iter()
+                    .any(|a| a.name != AttributeName::OnChange);
+                if !has_other_handler {
+                    return Some(LintDiagnostic {
+                        line: onchange.line,
+                        column: onchange.column,
+                        help: Some(
+                            "Keep `onchange` for updating state only, and perform the action from an explicit confirm button (e.g. <button type=\"submit\">{\"Go\"}</button>)."
+                                .to_string(),
+                        ),
+                        ..LintDiagnostic::new(
+                            Rule::NoOnchange,
+                            element,
+                            "<select> only has an `onchange` handler. Acting as soon as the selection changes (e.g. navigating) is disorienting for keyboard and screen reader users, who change the selection while browsing the options.",
+                        )
+                    });
+                }
+            }
             Rule::NoRedundantRoles => {
-                if let Some(implicit_role) = element.tag.implicit_role() {
-                    for attr in &element.attributes {
-                        if attr.name == AttributeName::Role {
-                            if let Some(AttrValue::Static(ref val)) = attr.value {
-                                if Role::from_str(val) == Some(implicit_role.clone()) {
-                                    return Some(LintDiagnostic {
-                                        rule: Rule::NoRedundantRoles,
-                                        message: format!(
-                                            "Redundant role \"{}\" on <{}>. This is the element's implicit role.",
-                                            val, element.tag
-                                        ),
-                                        severity: Severity::Warning,
-                                        file: element.file.clone(),
-                                        line: attr.line,
-                                        column: attr.column,
-                                        element: element.tag.clone(),
-                                        help: Some("Remove the `role` attribute.".to_string()),
-                                    });
-                                }
-                            }
-                        }
-                    }
+                if let Some(implicit_role) = element.implicit_role()
+                    && let Some((attr, role)) = &facts.explicit_role
+                    && *role == implicit_role
+                {
+                    return Some(LintDiagnostic {
+                        line: attr.line,
+                        column: attr.column,
+                        help: Some("Remove the `role` attribute.".to_string()),
+                        ..LintDiagnostic::new(
+                            Rule::NoRedundantRoles,
+                            element,
+                            format!(
+                                "Redundant role \"{}\" on <{}>. This is the element's implicit role.",
+                                role, element.tag
+                            ),
+                        )
+                    });
                 }
             }
             Rule::NoStaticElementInteractions => {
                 // Static HTML elements (no implicit role) with event handlers
                 // should have a `role` attribute.
-                if element.tag.is_interactive() || element.tag.implicit_role().is_some() {
+                if element.is_interactive() || element.implicit_role().is_some() {
                     return None;
                 }
 
-                // If element has an explicit role, it's no longer "static"
-                let has_role = element
-                    .attributes
-                    .iter()
-                    .any(|a| a.name == AttributeName::Role);
+                // If element has an explicit role, it's no longer "static". A role
+                // computed at runtime counts only under the optimistic policy.
+                let options = &options.no_static_element_interactions;
+                let has_role = element.attributes.iter().any(|a| {
+                    a.name == AttributeName::Role
+                        && (options.allow_expression_values
+                            || matches!(a.value, Some(AttrValue::Static(_))))
+                });
                 if has_role {
                     return None;
                 }
 
-                let has_handler = element.attributes.iter().any(|a| {
-                    matches!(
-                        a.name,
-                        AttributeName::OnClick
-                            | AttributeName::OnKeyDown
-                            | AttributeName::OnKeyUp
-                            | AttributeName::OnKeyPress
-                            | AttributeName::OnMouseOver
-                            | AttributeName::OnMouseOut
-                    )
+                let has_handler = facts.handlers.iter().any(|a| match &options.handlers {
+                    Some(handlers) => handlers
+                        .iter()
+                        .any(|h| AttributeName::from_str(h).is_ok_and(|h| h == a.name)),
+                    None => {
+                        a.name.is_interaction_handler()
+                            || matches!(
+                                a.name,
+                                AttributeName::OnMouseOver | AttributeName::OnMouseOut
+                            )
+                    }
                 });
 
                 if has_handler {
                     return Some(LintDiagnostic {
-                        rule: Rule::NoStaticElementInteractions,
-                        message: format!(
-                            "<{}> with event handler(s) must have a `role` attribute.",
-                            element.tag
-                        ),
-                        severity: Severity::Warning,
-                        file: element.file.clone(),
-                        line: element.line,
-                        column: element.column,
-                        element: element.tag.clone(),
                         help: Some(
                             "Add a `role` attribute that describes the element's purpose, or use a semantic element like <button> or <a>."
                                 .to_string(),
                         ),
+                        ..LintDiagnostic::new(
+                            Rule::NoStaticElementInteractions,
+                            element,
+                            format!(
+                                "<{}> with event handler(s) must have a `role` attribute.",
+                                element.tag
+                            ),
+                        )
+                    });
+                }
+            }
+            Rule::NoTitleOnlyName => {
+                // Form fields are usually named by a <label> elsewhere.
+                if matches!(element.tag, Tag::Input | Tag::Select | Tag::Textarea)
+                    || !facts.role.as_ref().is_some_and(|r| r.is_interactive())
+                {
+                    return None;
+                }
+                let title = element
+                    .attributes
+                    .iter()
+                    .find(|a| a.name == AttributeName::Title)?;
+                let has_other_name = facts.name_sources.aria();
+                let has_content = element
+                    .text
+                    .as_deref()
+                    .is_none_or(|text| !text.trim().is_empty());
+                if !has_other_name && !has_content {
+                    return Some(LintDiagnostic {
+                        line: title.line,
+                        column: title.column,
+                        help: Some(
+                            "Add visible text content, or an `aria-label`, to name the element."
+                                .to_string(),
+                        ),
+                        ..LintDiagnostic::new(
+                            Rule::NoTitleOnlyName,
+                            element,
+                            format!(
+                                "<{}> is only named by its `title` attribute, which is not shown to touch or keyboard users and is not reliably announced.",
+                                element.tag
+                            ),
+                        )
                     });
                 }
             }
+            // Checked for the whole project, in `project`.
+            Rule::PageHasMain => {}
             Rule::PreferTagOverRole => {
-                for attr in &element.attributes {
-                    if attr.name == AttributeName::Role {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            if let Some(role) = Role::from_str(val) {
-                                if let Some(preferred) = role.preferred_tag() {
-                                    // Don't flag if the element already IS the preferred tag
-                                    if element.tag.implicit_role().as_ref() == Some(&role) {
-                                        return None;
-                                    }
-                                    return Some(LintDiagnostic {
-                                        rule: Rule::PreferTagOverRole,
-                                        message: format!(
-                                            "Prefer using the {} element instead of `role=\"{}\"`.",
-                                            preferred, val
-                                        ),
-                                        severity: Severity::Info,
-                                        file: element.file.clone(),
-                                        line: attr.line,
-                                        column: attr.column,
-                                        element: element.tag.clone(),
-                                        help: Some(format!(
-                                            "Use {0} which has built-in semantics and keyboard behavior instead of relying on ARIA.",
-                                            preferred
-                                        )),
-                                    });
-                                }
-                            }
-                        }
+                if let Some((attr, role)) = &facts.explicit_role
+                    && let Some(preferred) = role.preferred_tag()
+                {
+                    // Don't flag if the element already IS the preferred tag
+                    if element.implicit_role().as_ref() == Some(role) {
+                        return None;
                     }
+                    return Some(LintDiagnostic {
+                        line: attr.line,
+                        column: attr.column,
+                        help: Some(format!(
+                            "Use {0} which has built-in semantics and keyboard behavior instead of relying on ARIA.",
+                            preferred
+                        )),
+                        ..LintDiagnostic::new(
+                            Rule::PreferTagOverRole,
+                            element,
+                            format!(
+                                "Prefer using the {} element instead of `role=\"{}\"`.",
+                                preferred, role
+                            ),
+                        )
+                    });
                 }
             }
             Rule::RoleHasRequiredAriaProps => {
-                let role_attr = element
-                    .attributes
-                    .iter()
-                    .find(|a| a.name == AttributeName::Role);
-                if let Some(role_attr) = role_attr {
-                    if let Some(AttrValue::Static(ref val)) = role_attr.value {
-                        if let Some(role) = Role::from_str(val) {
-                            let required = role.required_aria_props();
-                            if required.is_empty() {
-                                return None;
-                            }
+                if let Some((role_attr, role)) = &facts.explicit_role {
+                    let required = role.required_aria_props();
+                    if required.is_empty() {
+                        return None;
+                    }
 
-                            // For heading role on h1-h6, level is implicit
-                            if role == Role::Heading && element.tag.is_heading() {
-                                return None;
-                            }
+                    // For heading role on h1-h6, level is implicit
+                    if *role == Role::Heading && element.tag.is_heading() {
+                        return None;
+                    }
 
-                            let missing: Vec<&Aria> = required
+                    let missing: Vec<&Aria> = required
+                        .iter()
+                        .filter(|req| {
+                            !element
+                                .attributes
                                 .iter()
-                                .filter(|req| {
-                                    !element
-                                        .attributes
-                                        .iter()
-                                        .any(|a| a.name == AttributeName::Aria((*req).clone()))
-                                })
-                                .collect();
+                                .any(|a| a.name == AttributeName::Aria((*req).clone()))
+                        })
+                        .collect();
 
-                            if !missing.is_empty() {
-                                let missing_names: Vec<String> =
-                                    missing.iter().map(|a| format!("`{}`", a)).collect();
-                                return Some(LintDiagnostic {
-                                    rule: Rule::RoleHasRequiredAriaProps,
-                                    message: format!(
-                                        "<{}> with role=\"{}\" is missing required ARIA properties: {}.",
-                                        element.tag,
-                                        val,
-                                        missing_names.join(", ")
-                                    ),
-                                    severity: Severity::Error,
-                                    file: element.file.clone(),
-                                    line: role_attr.line,
-                                    column: role_attr.column,
-                                    element: element.tag.clone(),
-                                    help: Some(format!(
-                                        "Add the required ARIA properties for the \"{}\" role.",
-                                        val
-                                    )),
-                                });
-                            }
-                        }
+                    if !missing.is_empty() {
+                        let missing_names: Vec<String> =
+                            missing.iter().map(|a| format!("`{}`", a)).collect();
+                        return Some(LintDiagnostic {
+                            line: role_attr.line,
+                            column: role_attr.column,
+                            help: Some(format!(
+                                "Add the required ARIA properties for the \"{}\" role.",
+                                role
+                            )),
+                            ..LintDiagnostic::new(
+                                Rule::RoleHasRequiredAriaProps,
+                                element,
+                                format!(
+                                    "<{}> with role=\"{}\" is missing required ARIA properties: {}.",
+                                    element.tag,
+                                    role,
+                                    missing_names.join(", ")
+                                ),
+                            )
+                        });
                     }
                 }
             }
             Rule::RoleSupportsAriaProps => {
                 // Determine the effective role
-                let effective_role = element.role();
+                let effective_role = facts.role.clone();
                 if let Some(role) = effective_role {
                     for attr in &element.attributes {
-                        if let AttributeName::Aria(ref aria) = attr.name {
-                            if !aria.is_supported_by_role(&role) {
-                                return Some(LintDiagnostic {
-                                    rule: Rule::RoleSupportsAriaProps,
-                                    message: format!(
+                        // `aria-modal` has its own rule with a clearer message.
+                        if let AttributeName::Aria(ref aria) = attr.name
+                            && *aria != Aria::Modal
+                            && !aria.is_supported_by_role(&role)
+                        {
+                            return Some(LintDiagnostic {
+                                line: attr.line,
+                                column: attr.column,
+                                help: Some(format!(
+                                    "Remove the `{}` property, or change the role to one that supports it.",
+                                    attr.name
+                                )),
+                                ..LintDiagnostic::new(
+                                    Rule::RoleSupportsAriaProps,
+                                    element,
+                                    format!(
                                         "The `{}` property is not supported by the \"{}\" role on <{}>.",
                                         attr.name, role, element.tag
                                     ),
-                                    severity: Severity::Warning,
-                                    file: element.file.clone(),
-                                    line: attr.line,
-                                    column: attr.column,
-                                    element: element.tag.clone(),
-                                    help: Some(format!(
-                                        "Remove the `{}` property, or change the role to one that supports it.",
-                                        attr.name
-                                    )),
-                                });
-                            }
+                                )
+                            });
                         }
                     }
                 }
@@ -1598,56 +2740,183 @@ impl Rule {
                 for attr in &element.attributes {
                     if attr.name == AttributeName::Scope {
                         return Some(LintDiagnostic {
-                            rule: Rule::Scope,
-                            message: format!(
-                                "The `scope` attribute should only be used on <th> elements, not <{}>.",
-                                element.tag
-                            ),
-                            severity: Severity::Warning,
-                            file: element.file.clone(),
                             line: attr.line,
                             column: attr.column,
-                            element: element.tag.clone(),
-                            help: None,
+                            ..LintDiagnostic::new(
+                                Rule::Scope,
+                                element,
+                                format!(
+                                    "The `scope` attribute should only be used on <th> elements, not <{}>.",
+                                    element.tag
+                                ),
+                            )
                         });
                     }
                 }
             }
+            // Checked for the whole project, in `project`.
+            Rule::SkipLink => {}
             Rule::TabindexNoPositive => {
                 for attr in &element.attributes {
-                    if attr.name == AttributeName::TabIndex {
-                        if let Some(AttrValue::Static(ref val)) = attr.value {
-                            if let Ok(index) = val.parse::<i32>() {
-                                if index > 0 {
-                                    return Some(LintDiagnostic {
-                                        rule: Rule::TabindexNoPositive,
-                                        message: format!(
-                                            "Avoid using positive `tabindex` value ({}) on <{}>. This creates an unexpected tab order.",
-                                            index, element.tag
-                                        ),
-                                        severity: Severity::Warning,
-                                        file: element.file.clone(),
+                    if attr.name == AttributeName::TabIndex
+                        && let Some(AttrValue::Static(ref val)) = attr.value
+                        && let Ok(index) = val.trim().parse::<i32>()
+                        && index > 0
+                    {
+                        return Some(LintDiagnostic {
                                         line: attr.line,
                                         column: attr.column,
-                                        element: element.tag.clone(),
                                         help: Some(
                                             "Use `tabindex=\"0\"` for focusable elements or `tabindex=\"-1\"` for programmatically focusable elements."
                                                 .to_string(),
                                         ),
+                                        ..LintDiagnostic::new(
+                                            Rule::TabindexNoPositive,
+                                            element,
+                                            format!(
+                                                "Avoid using positive `tabindex` value ({}) on <{}>. This creates an unexpected tab order.",
+                                                index, element.tag
+                                            ),
+                                        )
                                     });
-                                }
-                            }
-                        }
                     }
                 }
             }
+            Rule::TabindexValid => {
+                for attr in &element.attributes {
+                    if attr.name == AttributeName::TabIndex
+                        && let Some(AttrValue::Static(ref val)) = attr.value
+                        && !is_valid_integer(val)
+                    {
+                        return Some(LintDiagnostic {
+                            line: attr.line,
+                            column: attr.column,
+                            help: Some(
+                                "Use `tabindex=\"0\"` to make the element focusable or `tabindex=\"-1\"` to focus it only from script."
+                                    .to_string(),
+                            ),
+                            ..LintDiagnostic::new(
+                                Rule::TabindexValid,
+                                element,
+                                format!(
+                                    "`tabindex` value \"{}\" on <{}> is not an integer, so browsers ignore it.",
+                                    val, element.tag
+                                ),
+                            )
+                        });
+                    }
+                }
+            }
+            Rule::RadiogroupPattern | Rule::TabsPattern => {}
         }
         None
     }
+
+    /// Check the elements of a single macro invocation against rules that
+    /// compare elements with each other. Per-element rules report nothing here.
+    pub fn check_macro(&self, elements: &[HtmlElement]) -> Vec<LintDiagnostic> {
+        let mut diagnostics = match self {
+            Rule::AriaOwnsValid => aria_owns(elements),
+            Rule::ComboboxPattern => combobox_pattern(elements),
+            Rule::RadiogroupPattern => radiogroup_pattern(elements),
+            Rule::TabsPattern => tabs_pattern(elements),
+            Rule::IframeTitleUnique => duplicate_iframe_titles(elements),
+            Rule::NoAriaHiddenOnFocusable => aria_hidden_focusable(elements),
+            _ => Vec::new(),
+        };
+        for diagnostic in &mut diagnostics {
+            diagnostic.url = Some(self.docs_url(DOCS_URL));
+        }
+        diagnostics
+    }
+
+    /// The elements [`Rule::check`] can report on, or `None` if it may
+    /// report on any element. Rules checked per macro match no element.
+    pub fn filter(&self) -> Option<RuleFilter> {
+        use AttributeName as A;
+        let filter = match self {
+            Rule::AltText => RuleFilter::tags([Tag::Img, Tag::Area, Tag::Input, Tag::Object]),
+            Rule::AltTextLength => RuleFilter::tags([Tag::Img, Tag::Area, Tag::Input]),
+            Rule::AnchorAmbiguousText | Rule::AnchorHasContent | Rule::AnchorIsValid => {
+                RuleFilter::tags([Tag::A])
+            }
+            Rule::AriaActivedescendantHasTabindex | Rule::AriaActivedescendantTarget => {
+                RuleFilter::attributes([A::Aria(Aria::ActiveDescendant)])
+            }
+            Rule::AriaModalDialog => RuleFilter::attributes([A::Aria(Aria::Modal)]),
+            Rule::AriaPosinsetSetsize
+            | Rule::AriaPrerequisites
+            | Rule::AriaProps
+            | Rule::AriaProptypes
+            | Rule::RoleSupportsAriaProps => RuleFilter {
+                any_aria: true,
+                ..RuleFilter::default()
+            },
+            Rule::AriaRole
+            | Rule::NoInteractiveElementToNoninteractiveRole
+            | Rule::NoNoninteractiveElementToInteractiveRole
+            | Rule::NoRedundantRoles
+            | Rule::PreferTagOverRole
+            | Rule::RoleHasRequiredAriaProps => RuleFilter::attributes([A::Role]),
+            Rule::AriaUnsupportedElements => RuleFilter {
+                any_aria: true,
+                ..RuleFilter::attributes([A::Role])
+            },
+            Rule::AutocompleteValid => RuleFilter::tags([Tag::Input, Tag::Select, Tag::Textarea]),
+            // Depend on custom element declarations and options.
+            Rule::ButtonHasContent | Rule::ControlHasAssociatedLabel => return None,
+            Rule::ClickEventsHaveKeyEvents
+            | Rule::InteractiveSupportsFocus
+            | Rule::MouseEventsHaveKeyEvents
+            | Rule::NoNoninteractiveElementInteractions
+            | Rule::NoStaticElementInteractions => RuleFilter {
+                any_handler: true,
+                ..RuleFilter::default()
+            },
+            Rule::ColorContrast => RuleFilter::attributes([A::Style]),
+            Rule::AriaOwnsValid
+            | Rule::ComboboxPattern
+            | Rule::RadiogroupPattern
+            | Rule::TabsPattern
+            | Rule::IframeTitleUnique
+            | Rule::NoAriaHiddenOnFocusable
+            | Rule::PageHasMain
+            | Rule::SkipLink => RuleFilter::default(),
+            Rule::Dir => RuleFilter::attributes([A::Dir]),
+            Rule::HeadingHasContent => {
+                RuleFilter::tags([Tag::H1, Tag::H2, Tag::H3, Tag::H4, Tag::H5, Tag::H6])
+            }
+            Rule::HtmlHasLang => RuleFilter::tags([Tag::Html]),
+            Rule::IdentityFieldsHaveAutocomplete => RuleFilter::tags([Tag::Input, Tag::Textarea]),
+            Rule::IframeHasTitle => RuleFilter::tags([Tag::Iframe]),
+            Rule::ImgAltFilename | Rule::ImgRedundantAlt => RuleFilter::tags([Tag::Img]),
+            Rule::LabelHasAssociatedControl => RuleFilter::tags([Tag::Label]),
+            Rule::Lang => RuleFilter::attributes([A::Lang]),
+            Rule::LiveRegionValid => {
+                RuleFilter::attributes([A::Aria(Aria::Live), A::Aria(Aria::Relevant)])
+            }
+            Rule::MathHasAlttext => RuleFilter::tags([Tag::Math]),
+            Rule::MediaHasCaption => RuleFilter::tags([Tag::Video, Tag::Audio]),
+            Rule::MenuButtonPattern => {
+                RuleFilter::attributes([A::Aria(Aria::HasPopup), A::Aria(Aria::Expanded)])
+            }
+            Rule::NoAccessKey => RuleFilter::attributes([A::AccessKey]),
+            Rule::NoAutofocus => RuleFilter::attributes([A::AutoFocus]),
+            Rule::NoDistractingElements => RuleFilter::tags([Tag::Marquee, Tag::Blink]),
+            Rule::NoHiddenFocusable
+            | Rule::NoNoninteractiveTabindex
+            | Rule::TabindexNoPositive
+            | Rule::TabindexValid => RuleFilter::attributes([A::TabIndex]),
+            Rule::NoOnchange => RuleFilter::tags([Tag::Select]),
+            Rule::NoTitleOnlyName => RuleFilter::attributes([A::Title]),
+            Rule::Scope => RuleFilter::attributes([A::Scope]),
+        };
+        Some(filter)
+    }
 }
 
 /// A lint diagnostic produced by a lint rule.
-#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct LintDiagnostic {
     /// Unique identifier for the lint rule (e.g., "invalid-aria-attribute").
     pub rule: Rule,
@@ -1665,21 +2934,474 @@ pub struct LintDiagnostic {
     pub element: Tag,
     /// Optional help text for fixing the issue.
     pub help: Option<String>,
+    /// Macro the element was written in (e.g. `html`); see
+    /// [`HtmlElement::source_macro`].
+    #[serde(default)]
+    pub source_macro: String,
+    /// Edits that resolve the issue, if the rule can suggest any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<Fix>,
+    /// Documentation page of the rule; see [`Rule::docs_url`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
 }
 
-/// Run all lint rules on a collection of parsed HTML elements.
-///
-/// Returns a lazy iterator — no allocation occurs until the caller collects
-/// or consumes the diagnostics.
-pub fn run_all_lints(elements: &[HtmlElement]) -> impl Iterator<Item = LintDiagnostic> + '_ {
-    elements
-        .iter()
-        .flat_map(|element| Rule::iter().filter_map(move |rule| rule.check(element)))
+impl LintDiagnostic {
+    /// A diagnostic of `rule` at `element`, with the rule's default severity
+    /// and no help or fixes. Set other fields with struct update syntax; the
+    /// docs [`url`](LintDiagnostic::url) is filled in when the rule runs.
+    pub fn new(rule: Rule, element: &HtmlElement, message: impl Into<String>) -> Self {
+        LintDiagnostic {
+            severity: rule.default_severity(),
+            rule,
+            message: message.into(),
+            file: element.file.clone(),
+            line: element.line,
+            column: element.column,
+            element: element.tag.clone(),
+            help: None,
+            source_macro: element.source_macro.clone(),
+            fixes: Vec::new(),
+            url: None,
+        }
+    }
+
+    /// The framework whose macro the diagnostic was found in, if known.
+    pub fn framework(&self) -> Option<Framework> {
+        Framework::from_macro(&self.source_macro)
+    }
+
+    /// A hash of the rule, position and message, which identify a
+    /// diagnostic within its file. Only stable within one process.
+    pub fn fingerprint(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        (&self.rule, self.line, self.column, &self.message).hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
-// ---------------------------------------------------------------------------
-// Helper functions for lint rules
-// ---------------------------------------------------------------------------
+/// Why an element passed a rule; see [`Rule::explain_pass`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LintPass {
+    pub rule: Rule,
+    pub file: String,
+    /// Line number (1-based).
+    pub line: usize,
+    /// Column number (0-based).
+    pub column: usize,
+    pub element: Tag,
+    /// What satisfied the rule, e.g. `named via aria-labelledby="x" ("Title")`.
+    pub reason: String,
+}
+
+/// Why each element passed the rules that can say, with `options`. Rules
+/// turned off by a config are not filtered out.
+///
+/// ```
+/// use rsx_a11y::lints::{self, Rule};
+/// use rsx_a11y::options::RuleOptions;
+/// use rsx_a11y::parser;
+///
+/// let elements = parser::parse_source(
+///     r#"fn c() { html! { <a href="/" aria-label="Home"></a> } }"#,
+///     "a.rs",
+/// )
+/// .unwrap();
+/// let passes = lints::explain_passes(&elements, &RuleOptions::default());
+/// assert_eq!(passes[0].rule, Rule::AnchorHasContent);
+/// assert_eq!(passes[0].reason, r#"named via aria-label="Home""#);
+/// ```
+pub fn explain_passes(elements: &[HtmlElement], options: &RuleOptions) -> Vec<LintPass> {
+    let mut passes = Vec::new();
+    for group in macro_groups(elements) {
+        for element in group {
+            let cx = LintContext::new(element, options).with_tree(group);
+            passes.extend(
+                RuleIndex::builtin()
+                    .candidates(&cx)
+                    .filter_map(|rule| rule.explain_pass(&cx)),
+            );
+        }
+    }
+    passes
+}
+
+/// Run all lint rules on a collection of parsed HTML elements, using the
+/// default rule options.
+///
+/// Returns a lazy iterator — no allocation occurs until the caller collects
+/// or consumes the diagnostics.
+pub fn run_all_lints(elements: &[HtmlElement]) -> impl Iterator<Item = LintDiagnostic> + '_ {
+    run_all_lints_with(elements, &DEFAULT_OPTIONS).filter(|d| d.rule.enabled_by_default())
+}
+
+static DEFAULT_OPTIONS: std::sync::LazyLock<RuleOptions> =
+    std::sync::LazyLock::new(RuleOptions::default);
+
+/// Run all lint rules on a collection of parsed HTML elements with the given
+/// rule options.
+pub fn run_all_lints_with<'a>(
+    elements: &'a [HtmlElement],
+    options: &'a RuleOptions,
+) -> impl Iterator<Item = LintDiagnostic> + 'a {
+    let per_macro = macro_groups(elements)
+        .flat_map(move |group| Rule::iter().flat_map(move |rule| rule.check_macro(group)));
+    macro_groups(elements)
+        .flat_map(|group| group.iter().map(move |element| (element, group)))
+        .flat_map(move |(element, group)| {
+            let cx = LintContext::new(element, options).with_tree(group);
+            RuleIndex::builtin()
+                .candidates(&cx)
+                .filter_map(|rule| rule.check_with(&cx))
+                .collect::<Vec<_>>()
+        })
+        .chain(per_macro)
+}
+
+/// Like [`run_all_lints_with`], adding the time each rule's checks take to
+/// `stats`. Timing every check has a cost, so only use this when the
+/// timings are wanted.
+pub fn run_all_lints_timed(
+    elements: &[HtmlElement],
+    options: &RuleOptions,
+    stats: &mut BTreeMap<Rule, RuleStats>,
+) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for group in macro_groups(elements) {
+        for element in group {
+            let cx = LintContext::new(element, options).with_tree(group);
+            for rule in RuleIndex::builtin().candidates(&cx) {
+                let start = Instant::now();
+                diagnostics.extend(rule.check_with(&cx));
+                stats.entry(rule.clone()).or_default().time += start.elapsed();
+            }
+        }
+    }
+    for group in macro_groups(elements) {
+        for rule in Rule::iter() {
+            let start = Instant::now();
+            diagnostics.extend(rule.check_macro(group));
+            stats.entry(rule).or_default().time += start.elapsed();
+        }
+    }
+    diagnostics
+}
+
+/// Run a single lint rule on a collection of parsed HTML elements, using the
+/// default rule options.
+///
+/// Useful for evaluating one rule in isolation, e.g. when toggling rules in
+/// an editor.
+///
+/// ```
+/// use rsx_a11y::lints::{self, Rule};
+/// use rsx_a11y::parser;
+///
+/// let elements = parser::parse_source(r#"fn c() { html! { <img src="a.png" /> } }"#, "a.rs").unwrap();
+/// assert_eq!(lints::run_rule(&Rule::AltText, &elements).len(), 1);
+/// assert!(lints::run_rule(&Rule::AriaRole, &elements).is_empty());
+/// ```
+pub fn run_rule(rule: &Rule, elements: &[HtmlElement]) -> Vec<LintDiagnostic> {
+    macro_groups(elements)
+        .flat_map(|group| {
+            group.iter().filter_map(move |element| {
+                rule.check_with(&LintContext::new(element, &DEFAULT_OPTIONS).with_tree(group))
+            })
+        })
+        .chain(macro_groups(elements).flat_map(|group| rule.check_macro(group)))
+        .collect()
+}
+
+/// Which elements a rule can report on, so it can be skipped for the rest:
+/// those with one of the tags or attributes, or an attribute of a listed
+/// kind. The default filter matches no element.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleFilter {
+    pub tags: Vec<Tag>,
+    pub attributes: Vec<AttributeName>,
+    /// Any `aria-*` attribute, known or not.
+    pub any_aria: bool,
+    /// Any event handler.
+    pub any_handler: bool,
+}
+
+impl RuleFilter {
+    /// Match elements with one of `tags`.
+    pub fn tags(tags: impl IntoIterator<Item = Tag>) -> Self {
+        RuleFilter {
+            tags: tags.into_iter().collect(),
+            ..RuleFilter::default()
+        }
+    }
+
+    /// Match elements with one of `attributes`.
+    pub fn attributes(attributes: impl IntoIterator<Item = AttributeName>) -> Self {
+        RuleFilter {
+            attributes: attributes.into_iter().collect(),
+            ..RuleFilter::default()
+        }
+    }
+
+    /// Whether the element in `cx` can be reported on.
+    pub fn matches(&self, cx: &LintContext) -> bool {
+        self.tags.contains(&cx.element.tag)
+            || (self.any_handler && !cx.facts.handlers.is_empty())
+            || cx
+                .element
+                .attributes
+                .iter()
+                .any(|a| self.attributes.contains(&a.name) || (self.any_aria && is_aria(&a.name)))
+    }
+}
+
+fn is_aria(name: &AttributeName) -> bool {
+    matches!(name, AttributeName::Aria(_))
+        || matches!(name, AttributeName::Unknown(n) if n.starts_with("aria-"))
+}
+
+/// Rules looked up by the tags and attributes in their [`RuleFilter`], so
+/// checking an element only visits the rules that can fire on it. Generic
+/// over the rule type so that rules defined outside this crate can be
+/// indexed the same way.
+///
+/// ```
+/// use rsx_a11y::context::LintContext;
+/// use rsx_a11y::lints::{Rule, RuleIndex};
+/// use rsx_a11y::options::RuleOptions;
+/// use rsx_a11y::parser;
+///
+/// let elements = parser::parse_source(r#"fn c() { html! { <marquee></marquee> } }"#, "a.rs").unwrap();
+/// let options = RuleOptions::default();
+/// let cx = LintContext::new(&elements[0], &options);
+/// let rules: Vec<&Rule> = RuleIndex::builtin().candidates(&cx).collect();
+/// assert!(rules.contains(&&Rule::NoDistractingElements));
+/// assert!(!rules.contains(&&Rule::AltText));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleIndex<R> {
+    rules: Vec<R>,
+    always: Vec<usize>,
+    by_tag: HashMap<Tag, Vec<usize>>,
+    by_attribute: HashMap<AttributeName, Vec<usize>>,
+    on_aria: Vec<usize>,
+    on_handler: Vec<usize>,
+}
+
+impl<R> Default for RuleIndex<R> {
+    fn default() -> Self {
+        RuleIndex {
+            rules: Vec::new(),
+            always: Vec::new(),
+            by_tag: HashMap::new(),
+            by_attribute: HashMap::new(),
+            on_aria: Vec::new(),
+            on_handler: Vec::new(),
+        }
+    }
+}
+
+static BUILTIN_INDEX: std::sync::LazyLock<RuleIndex<Rule>> = std::sync::LazyLock::new(|| {
+    let mut index = RuleIndex::default();
+    for rule in Rule::iter() {
+        let filter = rule.filter();
+        index.insert(rule, filter.as_ref());
+    }
+    index
+});
+
+impl RuleIndex<Rule> {
+    /// The index of the built-in rules.
+    pub fn builtin() -> &'static RuleIndex<Rule> {
+        &BUILTIN_INDEX
+    }
+}
+
+impl<R> RuleIndex<R> {
+    /// Add `rule`, relevant to the elements `filter` matches, or to every
+    /// element without a filter.
+    pub fn insert(&mut self, rule: R, filter: Option<&RuleFilter>) {
+        let i = self.rules.len();
+        self.rules.push(rule);
+        let Some(filter) = filter else {
+            self.always.push(i);
+            return;
+        };
+        for tag in &filter.tags {
+            self.by_tag.entry(tag.clone()).or_default().push(i);
+        }
+        for attribute in &filter.attributes {
+            self.by_attribute
+                .entry(attribute.clone())
+                .or_default()
+                .push(i);
+        }
+        if filter.any_aria {
+            self.on_aria.push(i);
+        }
+        if filter.any_handler {
+            self.on_handler.push(i);
+        }
+    }
+
+    /// The rules that can fire on the element in `cx`, in insertion order.
+    pub fn candidates<'s>(&'s self, cx: &LintContext) -> impl Iterator<Item = &'s R> + 's {
+        let mut hit = vec![false; self.rules.len()];
+        let mut mark = |indices: &[usize]| indices.iter().for_each(|&i| hit[i] = true);
+        mark(&self.always);
+        if let Some(indices) = self.by_tag.get(&cx.element.tag) {
+            mark(indices);
+        }
+        if !cx.facts.handlers.is_empty() {
+            mark(&self.on_handler);
+        }
+        for attr in &cx.element.attributes {
+            if let Some(indices) = self.by_attribute.get(&attr.name) {
+                mark(indices);
+            }
+            if is_aria(&attr.name) {
+                mark(&self.on_aria);
+            }
+        }
+        self.rules
+            .iter()
+            .zip(hit)
+            .filter_map(|(rule, hit)| hit.then_some(rule))
+    }
+}
+
+/// Split `elements` into runs that come from the same macro invocation.
+fn macro_groups(elements: &[HtmlElement]) -> impl Iterator<Item = &[HtmlElement]> {
+    elements.chunk_by(|a, b| a.file == b.file && a.macro_index == b.macro_index)
+}
+
+// ---------------------------------------------------------------------------
+// Helper functions for lint rules
+// ---------------------------------------------------------------------------
+
+/// Whether `control-has-associated-label` checks `element`.
+fn is_labelled_control(element: &HtmlElement, options: &RuleOptions) -> bool {
+    matches!(
+        element.tag,
+        Tag::Button
+            | Tag::Input
+            | Tag::Select
+            | Tag::Textarea
+            | Tag::Meter
+            | Tag::Output
+            | Tag::Progress
+    ) || options
+        .control_has_associated_label
+        .control_tags
+        .contains(&element.tag)
+}
+
+/// How `name` names `element`, e.g. `named via aria-labelledby="x" ("Title")`.
+fn describe_name(element: &HtmlElement, name: &AccessibleName) -> String {
+    let attribute = match name.source {
+        NameSource::LabelledBy => Some(AttributeName::Aria(Aria::LabelledBy)),
+        NameSource::AriaLabel => Some(AttributeName::Aria(Aria::Label)),
+        NameSource::Alt => Some(AttributeName::Alt),
+        NameSource::Title => Some(AttributeName::Title),
+        NameSource::Label | NameSource::Content => None,
+    };
+    let via = match attribute {
+        Some(attribute) => match element.static_attr(attribute.clone()) {
+            Some(value) => format!("{}=\"{}\"", attribute, value),
+            None => format!("a dynamic {}", attribute),
+        },
+        None => name.source.to_string(),
+    };
+    match (&name.text, name.source) {
+        (None, NameSource::Label | NameSource::Content) => {
+            format!("named via {} known only at runtime", via)
+        }
+        (None, _) => format!("named via {}", via),
+        (Some(_), NameSource::AriaLabel | NameSource::Alt | NameSource::Title) => {
+            format!("named via {}", via)
+        }
+        (Some(text), _) => format!("named via {} (\"{}\")", via, text),
+    }
+}
+
+/// Why `element` has no accessible name: `missing` if nothing names it, or
+/// the source that names it with an empty string. `None` if it has a name.
+fn unnamed_reason(
+    element: &HtmlElement,
+    name: Option<AccessibleName>,
+    missing: &str,
+) -> Option<String> {
+    match name {
+        Some(name) if !name.is_empty() => None,
+        Some(name) => Some(format!(
+            "<{}> element's accessible name from {} is empty.",
+            element.tag, name.source
+        )),
+        None => Some(missing.to_string()),
+    }
+}
+
+/// Position just past the element's tag name, where attributes can be
+/// inserted.
+fn tag_name_end(element: &HtmlElement) -> Position {
+    Position {
+        line: element.line,
+        column: element.column + element.tag.to_string().chars().count(),
+    }
+}
+
+fn attribute_range(attr: &HtmlAttribute) -> Range {
+    Range {
+        start: Position {
+            line: attr.line,
+            column: attr.column,
+        },
+        end: Position {
+            line: attr.end_line,
+            column: attr.end_column,
+        },
+    }
+}
+
+/// Add the attribute `name` with a static `value` before the others, in the
+/// syntax `element` is written in.
+fn insert_attribute(element: &HtmlElement, name: &str, value: &str) -> TextEdit {
+    match &element.syntax {
+        Syntax::Tag => TextEdit::insert(tag_name_end(element), format!(" {name}=\"{value}\"")),
+        Syntax::Braced { body, .. } => {
+            TextEdit::insert(*body, format!(" {}: \"{value}\",", name.replace('-', "_")))
+        }
+    }
+}
+
+/// Replace the attribute at `index` with `name` set to a static `value`.
+fn replace_attribute(element: &HtmlElement, index: usize, name: &str, value: &str) -> TextEdit {
+    let replacement = match element.syntax {
+        Syntax::Tag => format!("{name}=\"{value}\""),
+        Syntax::Braced { .. } => format!("{}: \"{value}\"", name.replace('-', "_")),
+    };
+    TextEdit {
+        range: attribute_range(&element.attributes[index]),
+        replacement,
+    }
+}
+
+/// Remove the attribute at `index` together with the whitespace before it,
+/// i.e. from the end of the previous attribute (or the tag name). In
+/// Dioxus' syntax, its comma and the whitespace after it go instead.
+fn remove_attribute(element: &HtmlElement, index: usize) -> TextEdit {
+    if let Syntax::Braced { removals, .. } = &element.syntax {
+        return TextEdit::delete(removals[index]);
+    }
+    let start = match index.checked_sub(1) {
+        Some(previous) => attribute_range(&element.attributes[previous]).end,
+        None => tag_name_end(element),
+    };
+    TextEdit::delete(Range {
+        start,
+        end: attribute_range(&element.attributes[index]).end,
+    })
+}
 
 /// Validate an autocomplete attribute value per the HTML spec.
 fn is_valid_autocomplete(value: &str) -> bool {
@@ -1750,17 +3472,17 @@ fn is_valid_autocomplete(value: &str) -> bool {
     let mut idx = 0;
 
     // Optional section-* prefix
-    if let Some(token) = tokens.get(idx) {
-        if token.starts_with("section-") {
-            idx += 1;
-        }
+    if let Some(token) = tokens.get(idx)
+        && token.starts_with("section-")
+    {
+        idx += 1;
     }
 
     // Optional shipping/billing
-    if let Some(token) = tokens.get(idx) {
-        if SECTION_PREFIXES.contains(token) {
-            idx += 1;
-        }
+    if let Some(token) = tokens.get(idx)
+        && SECTION_PREFIXES.contains(token)
+    {
+        idx += 1;
     }
 
     // Must have at least the field token
@@ -1780,34 +3502,717 @@ fn is_valid_autocomplete(value: &str) -> bool {
     VALID_TOKENS.contains(&field)
 }
 
-/// Validate a BCP 47 language tag (simplified check per jsx-a11y lang rule).
-fn is_valid_lang(lang: &str) -> bool {
-    let lang = lang.trim();
-    if lang.is_empty() {
-        return false;
+/// Whether the autofill `field` token may be used on an `<input>` of
+/// `input_type`, following the control groups of the HTML autofill spec.
+/// Text-like inputs accept every token.
+fn autocomplete_fits_input_type(field: &str, input_type: &str) -> bool {
+    if matches!(field, "on" | "off") || !is_valid_autocomplete(field) {
+        return true;
     }
+    match input_type {
+        "hidden" | "text" | "search" => true,
+        "email" => matches!(field, "email" | "username"),
+        "tel" => field == "tel" || field.starts_with("tel-"),
+        "url" => matches!(field, "url" | "photo" | "impp"),
+        "password" => matches!(field, "new-password" | "current-password" | "one-time-code"),
+        "number" => matches!(
+            field,
+            "cc-exp-month"
+                | "cc-exp-year"
+                | "bday-day"
+                | "bday-month"
+                | "bday-year"
+                | "transaction-amount"
+        ),
+        "month" => field == "cc-exp",
+        "date" => field == "bday",
+        // Unknown types fall back to text in browsers.
+        "checkbox" | "radio" | "file" | "submit" | "reset" | "button" | "image" | "range"
+        | "color" | "datetime-local" | "time" | "week" => false,
+        _ => true,
+    }
+}
 
-    let parts: Vec<&str> = lang.split('-').collect();
-    let primary = parts[0];
+/// The `autocomplete` token for a field whose `name` or `id` suggests
+/// personal data, e.g. `first_name` → `given-name`. Names are split into
+/// words at `_`, `-` and camelCase humps, and only whole words match, so
+/// `capacity` is not a city.
+fn identity_autocomplete_token(name: &str) -> Option<&'static str> {
+    // More specific patterns come first: `username` is not a person's name.
+    const PATTERNS: &[(&[&str], &str)] = &[
+        (&["email", "e mail"], "email"),
+        (&["username", "user name", "login"], "username"),
+        (&["phone", "mobile", "telephone", "tel"], "tel"),
+        (
+            &[
+                "firstname",
+                "first name",
+                "givenname",
+                "given name",
+                "forename",
+            ],
+            "given-name",
+        ),
+        (
+            &[
+                "lastname",
+                "last name",
+                "familyname",
+                "family name",
+                "surname",
+            ],
+            "family-name",
+        ),
+        (&["fullname", "full name", "yourname", "your name"], "name"),
+        (
+            &[
+                "postalcode",
+                "postal code",
+                "postcode",
+                "post code",
+                "zipcode",
+                "zip code",
+                "zip",
+            ],
+            "postal-code",
+        ),
+        (
+            &["streetaddress", "street address", "street", "address"],
+            "street-address",
+        ),
+        (&["city", "town"], "address-level2"),
+        (&["country"], "country-name"),
+        (
+            &[
+                "birthday",
+                "birthdate",
+                "birth date",
+                "dateofbirth",
+                "date of birth",
+                "dob",
+            ],
+            "bday",
+        ),
+    ];
+    let words = identifier_words(name);
+    if words == ["name"] {
+        return Some("name");
+    }
+    // Network addresses are not postal ones.
+    if words
+        .windows(2)
+        .any(|w| matches!(w[0].as_str(), "ip" | "mac") && w[1] == "address")
+    {
+        return None;
+    }
+    PATTERNS
+        .iter()
+        .find(|(phrases, _)| {
+            phrases.iter().any(|phrase| {
+                let phrase: Vec<&str> = phrase.split(' ').collect();
+                words.windows(phrase.len()).any(|w| w == phrase.as_slice())
+            })
+        })
+        .map(|(_, token)| *token)
+}
 
-    // Primary language subtag must be 2-3 ASCII letters
-    if primary.len() < 2 || primary.len() > 3 {
-        return false;
+/// The lowercase words of an identifier like `billingZip` or `first-name`.
+fn identifier_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            words.extend((!word.is_empty()).then(|| std::mem::take(&mut word)));
+            continue;
+        }
+        // A hump starts at an uppercase letter after a lowercase letter or
+        // digit, or before the last uppercase letter of an acronym (`HTTPUrl`).
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1);
+        let hump = c.is_ascii_uppercase()
+            && prev.is_some_and(|p| {
+                p.is_ascii_lowercase()
+                    || p.is_ascii_digit()
+                    || (p.is_ascii_uppercase() && next.is_some_and(char::is_ascii_lowercase))
+            });
+        if hump && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        word.push(c.to_ascii_lowercase());
     }
-    if !primary.chars().all(|c| c.is_ascii_alphabetic()) {
-        return false;
+    words.extend((!word.is_empty()).then_some(word));
+    words
+}
+
+/// Report every `<iframe>` whose static title repeats an earlier one.
+fn duplicate_iframe_titles(elements: &[HtmlElement]) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut titles: Vec<(String, &HtmlElement)> = Vec::new();
+    for element in elements.iter().filter(|e| e.tag == Tag::Iframe) {
+        let Some(title) = element
+            .attributes
+            .iter()
+            .find(|a| a.name == AttributeName::Title)
+        else {
+            continue;
+        };
+        let Some(value) = title.value.as_ref().and_then(|v| v.as_static()) else {
+            continue;
+        };
+        let normalized = value.split_whitespace().collect::<Vec<_>>().join(" ");
+        if normalized.is_empty() {
+            continue;
+        }
+        match titles
+            .iter()
+            .find(|(t, other)| t.eq_ignore_ascii_case(&normalized) && !other.excludes(element))
+        {
+            Some((_, first)) => diagnostics.push(LintDiagnostic {
+                line: title.line,
+                column: title.column,
+                help: Some(
+                    "Give each frame a title that describes its own content."
+                        .to_string(),
+                ),
+                ..LintDiagnostic::new(
+                    Rule::IframeTitleUnique,
+                    element,
+                    format!(
+                        "<iframe> title \"{}\" is also used by the <iframe> on line {}. Frame titles must be unique to tell frames apart.",
+                        normalized, first.line
+                    ),
+                )
+            }),
+            None => titles.push((normalized, element)),
+        }
     }
+    diagnostics
+}
 
-    // All remaining subtags must be non-empty and alphanumeric
-    for part in &parts[1..] {
-        if part.is_empty() || part.len() > 8 {
-            return false;
+/// `aria-owns` references that name an element with another owner, or the
+/// element itself or one of its ancestors in the accessibility tree, where
+/// owned elements hang below their owner. Ids not in the macro may be
+/// rendered elsewhere and are not checked.
+fn aria_owns(elements: &[HtmlElement]) -> Vec<LintDiagnostic> {
+    let mut dom_parent = vec![None; elements.len()];
+    for i in 0..elements.len() {
+        let end = i + subtree(elements, i).len();
+        for child in &mut dom_parent[i + 1..end] {
+            *child = Some(i);
+        }
+    }
+    // The first owner of each element, which replaces its DOM parent.
+    let mut owner: Vec<Option<usize>> = vec![None; elements.len()];
+    let mut diagnostics = Vec::new();
+    for (i, element) in elements.iter().enumerate() {
+        let Some(attr) = element
+            .attributes
+            .iter()
+            .find(|a| a.name == AttributeName::Aria(Aria::Owns))
+        else {
+            continue;
+        };
+        let Some(ids) = attr.value.as_ref().and_then(|v| v.as_static()) else {
+            continue;
+        };
+        for id in ids.split_whitespace() {
+            let problem = match crate::dom::find_by_id(elements, id) {
+                None => continue,
+                Some(target) if target == i => "references the element itself".to_string(),
+                Some(target) => match owner[target] {
+                    Some(first) if first != i => format!(
+                        "claims \"{}\", which <{}> on line {} already owns",
+                        id, elements[first].tag, elements[first].line
+                    ),
+                    Some(_) => continue,
+                    None => {
+                        // Walk up from the owner; reaching the target means
+                        // the target would end up inside itself.
+                        let mut ancestor = owner[i].or(dom_parent[i]);
+                        let mut steps = 0;
+                        while let Some(a) = ancestor
+                            && a != target
+                            && steps < elements.len()
+                        {
+                            ancestor = owner[a].or(dom_parent[a]);
+                            steps += 1;
+                        }
+                        if ancestor == Some(target) {
+                            format!(
+                                "owns <{}> on line {}, which already contains it, creating a cycle",
+                                elements[target].tag, elements[target].line
+                            )
+                        } else {
+                            owner[target] = Some(i);
+                            continue;
+                        }
+                    }
+                },
+            };
+            diagnostics.push(LintDiagnostic {
+                line: attr.line,
+                column: attr.column,
+                help: Some(
+                    "List only ids of existing elements outside this element's ancestors, and give each owned element a single owner."
+                        .to_string(),
+                ),
+                ..LintDiagnostic::new(
+                    Rule::AriaOwnsValid,
+                    element,
+                    format!("`aria-owns` on <{}> {}.", element.tag, problem),
+                )
+            });
+        }
+    }
+    diagnostics
+}
+
+/// The element at `i` and its descendants, as far as `elements` holds them
+/// (a caller may pass part of a macro).
+fn subtree(elements: &[HtmlElement], i: usize) -> &[HtmlElement] {
+    let end = (i + elements[i].descendants + 1).min(elements.len());
+    &elements[i..end]
+}
+
+/// Elements with `aria-hidden="true"` that are, or contain, an element in
+/// the tab order. Like the ACT rule, `tabindex="-1"` takes an element out of
+/// the tab order, and nested hidden elements are reported once.
+fn aria_hidden_focusable(elements: &[HtmlElement]) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut covered_until = 0;
+    for (i, element) in elements.iter().enumerate() {
+        let hidden = element.attributes.iter().any(|a| {
+            a.name == AttributeName::Aria(Aria::Hidden)
+                && matches!(&a.value, Some(AttrValue::Static(v)) if v == "true")
+        });
+        if !hidden || i < covered_until {
+            continue;
         }
-        if !part.chars().all(|c| c.is_ascii_alphanumeric()) {
+        let Some(focusable) = subtree(elements, i).iter().find(|e| e.is_tabbable()) else {
+            continue;
+        };
+        covered_until = i + element.descendants + 1;
+        let message = if std::ptr::eq(focusable, element) {
+            format!(
+                "<{}> element is focusable but has `aria-hidden=\"true\"`, which hides it from assistive technologies.",
+                element.tag
+            )
+        } else {
+            format!(
+                "<{}> has `aria-hidden=\"true\"` but contains a focusable <{}> (line {}), which keyboard users can reach but screen readers cannot announce.",
+                element.tag, focusable.tag, focusable.line
+            )
+        };
+        diagnostics.push(LintDiagnostic {
+            help: Some(
+                "Remove `aria-hidden=\"true\"`, or take the focusable elements out of the tab order with `tabindex=\"-1\"`."
+                    .to_string(),
+            ),
+            ..LintDiagnostic::new(Rule::NoAriaHiddenOnFocusable, element, message)
+        });
+    }
+    diagnostics
+}
+
+/// The element at `i` of its macro's `elements`, if it has `autofocus`.
+/// With `allow-in-dialog`, the first focusable element of the nearest
+/// enclosing dialog may take focus; elements in other branches of a
+/// conditional do not come first.
+fn autofocus_at(
+    elements: &[HtmlElement],
+    i: usize,
+    options: &NoAutofocusOptions,
+) -> Option<LintDiagnostic> {
+    let first_in_dialog = || {
+        let Some(dialog) = (0..i).rev().find(|&j| {
+            let ancestor = &elements[j];
+            j + ancestor.descendants >= i
+                && (ancestor.tag == Tag::Dialog
+                    || matches!(ancestor.role(), Some(Role::Dialog | Role::AlertDialog)))
+        }) else {
             return false;
+        };
+        subtree(elements, dialog)
+            .iter()
+            .skip(1)
+            .position(|e| e.is_focusable() && !e.is_hidden() && !e.excludes(&elements[i]))
+            .is_some_and(|first| dialog + 1 + first == i)
+    };
+    let element = &elements[i];
+    let attr = element
+        .attributes
+        .iter()
+        .find(|a| a.name == AttributeName::AutoFocus)?;
+    if options.allow_in_dialog && first_in_dialog() {
+        return None;
+    }
+    Some(LintDiagnostic {
+        line: attr.line,
+        column: attr.column,
+        fixes: Rule::NoAutofocus.fix(element).into_iter().collect(),
+        ..LintDiagnostic::new(
+            Rule::NoAutofocus,
+            element,
+            format!(
+                "Avoid using the `autofocus` attribute on <{}>. Autofocus can reduce usability and accessibility for sighted and non-sighted users.",
+                element.tag
+            ),
+        )
+    })
+}
+
+/// Check the target of `aria-activedescendant="id"` on `element` against
+/// the macro `tree`. Returns the target if it is outside the element and
+/// the elements it owns, and `None` if it is fine or cannot be known, e.g.
+/// because no element of the macro has the id. A combobox may also point
+/// into the popup it controls.
+fn activedescendant_target<'a>(
+    element: &HtmlElement,
+    id: &str,
+    tree: &'a [HtmlElement],
+) -> Option<&'a HtmlElement> {
+    let find = |id: &str| crate::dom::find_by_id(tree, id);
+    let target = find(id)?;
+    let owner = tree.iter().position(|e| std::ptr::eq(e, element))?;
+    if owner < target && target <= owner + element.descendants {
+        return None;
+    }
+    let mut referenced = vec![AttributeName::Aria(Aria::Owns)];
+    if element.role() == Some(Role::Combobox) {
+        referenced.push(AttributeName::Aria(Aria::Controls));
+    }
+    for name in referenced {
+        let Some(attr) = element.attributes.iter().find(|a| a.name == name) else {
+            continue;
+        };
+        // Owned elements only known at runtime may contain the target.
+        let ids = attr.value.as_ref().and_then(|v| v.as_static())?;
+        let owns_target = ids
+            .split_whitespace()
+            .filter_map(find)
+            .any(|root| root <= target && target <= root + tree[root].descendants);
+        if owns_target {
+            return None;
         }
     }
-    true
+    Some(&tree[target])
+}
+
+/// Check every `role="combobox"` in one macro for `aria-expanded` and, when
+/// expanded, an `aria-controls` that references its popup.
+fn combobox_pattern(elements: &[HtmlElement]) -> Vec<LintDiagnostic> {
+    fn attr(element: &HtmlElement, aria: Aria) -> Option<&HtmlAttribute> {
+        element
+            .attributes
+            .iter()
+            .find(|a| a.name == AttributeName::Aria(aria.clone()))
+    }
+    let mut diagnostics = Vec::new();
+    for element in elements
+        .iter()
+        .filter(|e| e.tag != Tag::Select && e.role() == Some(Role::Combobox))
+    {
+        let problem = match attr(element, Aria::Expanded) {
+            None => Some((
+                element.line,
+                element.column,
+                "has no `aria-expanded` to tell whether its popup is shown".to_string(),
+            )),
+            Some(expanded) if expanded.value == Some(AttrValue::Static("true".to_string())) => {
+                match attr(element, Aria::Controls) {
+                    None => Some((
+                        expanded.line,
+                        expanded.column,
+                        "is expanded but has no `aria-controls` referencing its popup".to_string(),
+                    )),
+                    Some(controls) => controls
+                        .value
+                        .as_ref()
+                        .and_then(|v| v.as_static())
+                        .and_then(|refs| {
+                            // Popups rendered elsewhere, e.g. by a child
+                            // component, are not checked.
+                            let targets: Vec<&HtmlElement> = refs
+                                .split_whitespace()
+                                .filter_map(|r| crate::dom::find_by_id(elements, r))
+                                .map(|i| &elements[i])
+                                .collect();
+                            let is_popup = |e: &&HtmlElement| {
+                                matches!(
+                                    e.role(),
+                                    Some(Role::ListBox | Role::Tree | Role::Grid | Role::Dialog)
+                                )
+                            };
+                            let target = targets.first()?;
+                            if targets.iter().any(is_popup) {
+                                return None;
+                            }
+                            let message = format!(
+                                "controls <{}> on line {}, which is not a listbox, tree, grid or dialog",
+                                target.tag, target.line
+                            );
+                            Some((controls.line, controls.column, message))
+                        }),
+                }
+            }
+            Some(_) => None,
+        };
+        if let Some((line, column, problem)) = problem {
+            diagnostics.push(LintDiagnostic {
+                line,
+                column,
+                help: Some(
+                    "Set `aria-expanded` and point `aria-controls` at the id of the popup, e.g. a `role=\"listbox\"` element."
+                        .to_string(),
+                ),
+                ..LintDiagnostic::new(
+                    Rule::ComboboxPattern,
+                    element,
+                    format!("Combobox <{}> {}.", element.tag, problem),
+                )
+            });
+        }
+    }
+    diagnostics
+}
+
+/// Number of elements directly inside `element`, which is part of `tree`.
+/// Only children within `tree` are counted.
+fn element_children(element: &HtmlElement, tree: &[HtmlElement]) -> usize {
+    let Some(start) = tree.iter().position(|e| std::ptr::eq(e, element)) else {
+        return 0;
+    };
+    let end = start + element.descendants;
+    let mut children = 0;
+    let mut child = start + 1;
+    while child <= end
+        && let Some(next) = tree.get(child)
+    {
+        children += 1;
+        child += next.descendants + 1;
+    }
+    children
+}
+
+/// Radios (by `role="radio"`) outside a radiogroup or `<fieldset>`, radios
+/// of a radiogroup without an accessible name, and radiogroups where not
+/// exactly one radio is statically `aria-checked="true"`.
+fn radiogroup_pattern(elements: &[HtmlElement]) -> Vec<LintDiagnostic> {
+    let is_radio = |e: &HtmlElement| matches!(e.explicit_role(), Some((_, Role::Radio)));
+    let is_group = |e: &HtmlElement| e.role() == Some(Role::RadioGroup);
+    // The innermost radiogroup or fieldset around each element, and whether
+    // it has any ancestor in the macro at all.
+    let mut group = vec![None; elements.len()];
+    let mut nested = vec![false; elements.len()];
+    for (i, element) in elements.iter().enumerate() {
+        let end = i + subtree(elements, i).len();
+        for n in &mut nested[i + 1..end] {
+            *n = true;
+        }
+        if is_group(element) || element.tag == Tag::Fieldset {
+            for g in &mut group[i + 1..end] {
+                *g = Some(i);
+            }
+        }
+    }
+    let help = "Put radios in a labelled `role=\"radiogroup\"`, give each radio a name, and set `aria-checked=\"true\"` on exactly one of them.";
+    let diagnostic = |element: &HtmlElement, message: String| LintDiagnostic {
+        help: Some(help.to_string()),
+        ..LintDiagnostic::new(Rule::RadiogroupPattern, element, message)
+    };
+    let mut diagnostics = Vec::new();
+    for (i, element) in elements.iter().enumerate() {
+        if is_radio(element) {
+            match group[i] {
+                // A radio at the root of its macro, e.g. the whole of a
+                // radio component, may be rendered inside a group elsewhere.
+                None if !nested[i] => {}
+                None => diagnostics.push(diagnostic(
+                    element,
+                    format!(
+                        "Radio <{}> is not inside a `role=\"radiogroup\"` or <fieldset>.",
+                        element.tag
+                    ),
+                )),
+                Some(g) if is_group(&elements[g]) => {
+                    let name = crate::dom::accessible_name(element, elements);
+                    if name.is_none_or(|name| name.is_empty()) {
+                        diagnostics.push(diagnostic(
+                            element,
+                            format!("Radio <{}> has no accessible name.", element.tag),
+                        ));
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+        if !is_group(element) {
+            continue;
+        }
+        // `None` once a radio's state is only known at runtime. Radios in
+        // different branches of a conditional are not checked together.
+        let mut checked = Some(0);
+        let mut checked_radios: Vec<&HtmlElement> = Vec::new();
+        let mut radios = 0;
+        for (j, radio) in elements
+            .iter()
+            .enumerate()
+            .skip(i + 1)
+            .take(element.descendants)
+        {
+            if !is_radio(radio) || group[j] != Some(i) {
+                continue;
+            }
+            radios += 1;
+            let state = radio
+                .attributes
+                .iter()
+                .find(|a| a.name == AttributeName::Aria(Aria::Checked))
+                .map(|a| a.value.as_ref().and_then(|v| v.as_static()));
+            checked = match state {
+                Some(None) => None,
+                Some(Some(value))
+                    if value.trim() == "true"
+                        && !checked_radios.iter().any(|r| r.excludes(radio)) =>
+                {
+                    checked_radios.push(radio);
+                    checked.map(|n| n + 1)
+                }
+                _ => checked,
+            };
+        }
+        let problem = match checked {
+            _ if radios == 0 => continue,
+            Some(0) => "has no radio with `aria-checked=\"true\"`".to_string(),
+            Some(n) if n > 1 => format!("has {} radios with `aria-checked=\"true\"`", n),
+            _ => continue,
+        };
+        diagnostics.push(diagnostic(
+            element,
+            format!(
+                "Radiogroup <{}> {}; exactly one radio should be checked.",
+                element.tag, problem
+            ),
+        ));
+    }
+    diagnostics
+}
+
+/// Tabs without an `aria-controls` naming a tabpanel, and tabpanels not
+/// named by a tab through `aria-labelledby`. References that are only known
+/// at runtime, or name ids outside the macro, are not checked.
+fn tabs_pattern(elements: &[HtmlElement]) -> Vec<LintDiagnostic> {
+    // The first element of the macro with each id in `refs`, or `None` if
+    // any id names none.
+    let resolve = |refs: &str| -> Option<Vec<&HtmlElement>> {
+        refs.split_whitespace()
+            .map(|id| crate::dom::find_by_id(elements, id).map(|i| &elements[i]))
+            .collect()
+    };
+    let mut diagnostics = Vec::new();
+    for element in elements {
+        let (kind, aria, target_role) = match element.role() {
+            Some(Role::Tab) => ("Tab", Aria::Controls, Role::TabPanel),
+            Some(Role::TabPanel) => ("Tabpanel", Aria::LabelledBy, Role::Tab),
+            _ => continue,
+        };
+        let attr = element
+            .attributes
+            .iter()
+            .find(|a| a.name == AttributeName::Aria(aria.clone()));
+        let (line, column, problem) = match attr {
+            None if target_role == Role::Tab
+                && element
+                    .attributes
+                    .iter()
+                    .any(|a| a.name == AttributeName::Aria(Aria::Label)) =>
+            {
+                continue;
+            }
+            None => (
+                element.line,
+                element.column,
+                format!(
+                    "has no `{}` referencing its {}",
+                    AttributeName::Aria(aria),
+                    target_role
+                ),
+            ),
+            Some(attr) => {
+                let Some(refs) = attr.value.as_ref().and_then(|v| v.as_static()) else {
+                    continue;
+                };
+                let problem = match resolve(refs) {
+                    None => continue,
+                    Some(targets)
+                        if targets
+                            .iter()
+                            .any(|t| t.role() == Some(target_role.clone())) =>
+                    {
+                        continue;
+                    }
+                    Some(targets) => match targets.first() {
+                        Some(target) => format!(
+                            "references <{}> on line {} in `{}`, which is not a {}",
+                            target.tag, target.line, attr.name, target_role
+                        ),
+                        None => format!("has an empty `{}`", attr.name),
+                    },
+                };
+                (attr.line, attr.column, problem)
+            }
+        };
+        diagnostics.push(LintDiagnostic {
+            line,
+            column,
+            help: Some(
+                "Give each tab an id and `aria-controls` with the id of its `role=\"tabpanel\"`, and give the tabpanel `aria-labelledby` with the id of its tab."
+                    .to_string(),
+            ),
+            ..LintDiagnostic::new(
+                Rule::TabsPattern,
+                element,
+                format!("{} <{}> {}.", kind, element.tag, problem),
+            )
+        });
+    }
+    diagnostics
+}
+
+/// Whether `text` looks like an image file name, e.g. `IMG_1234.jpg`.
+fn is_image_filename(text: &str) -> bool {
+    const EXTENSIONS: &[&str] = &[
+        "apng", "avif", "bmp", "gif", "heic", "ico", "jpeg", "jpg", "png", "svg", "tif", "tiff",
+        "webp",
+    ];
+    match text.rsplit_once('.') {
+        Some((stem, ext)) => {
+            !stem.is_empty()
+                && !stem.contains(char::is_whitespace)
+                && EXTENSIONS.iter().any(|e| e.eq_ignore_ascii_case(ext))
+        }
+        None => false,
+    }
+}
+
+/// Whether `value` is a valid HTML integer: an optional `-` and ASCII
+/// digits, surrounded by optional whitespace.
+fn is_valid_integer(value: &str) -> bool {
+    let digits = value.trim().strip_prefix('-').unwrap_or(value.trim());
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Lowercase `text`, drop punctuation and collapse whitespace, so that
+/// "Click here!" matches "click here".
+fn normalize_link_text(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 #[cfg(test)]
@@ -1820,10 +4225,161 @@ mod tests {
         run_all_lints(&elements).collect()
     }
 
+    fn lint_source_with(source: &str, options: &RuleOptions) -> Vec<LintDiagnostic> {
+        let elements = parser::parse_source(source, "test.rs").unwrap();
+        run_all_lints_with(&elements, options).collect()
+    }
+
     fn has_lint(diags: &[LintDiagnostic], id: Rule) -> bool {
         diags.iter().any(|d| d.rule == id)
     }
 
+    #[test]
+    fn test_rule_from_str_accepts_jsx_a11y_ids() {
+        assert_eq!(Rule::from_str("alt-text").ok(), Some(Rule::AltText));
+        assert_eq!(
+            Rule::from_str("jsx-a11y/alt-text").ok(),
+            Some(Rule::AltText)
+        );
+        assert_eq!(
+            Rule::from_str("jsx-a11y/img-redundant-alt").ok(),
+            Some(Rule::ImgRedundantAlt)
+        );
+        assert_eq!(Rule::from_str("react/alt-text").ok(), None);
+    }
+
+    #[test]
+    fn test_rule_display_matches_serde() {
+        for rule in Rule::iter() {
+            let json = serde_json::to_string(&rule).unwrap();
+            assert_eq!(json.trim_matches('"'), rule.to_string());
+            assert_eq!(rule.to_string().parse::<Rule>(), Ok(rule));
+        }
+    }
+
+    #[test]
+    fn test_severity_order_and_parsing() {
+        assert!(Severity::Error > Severity::Warning);
+        assert!(Severity::Warning > Severity::Info);
+        assert_eq!("warn".parse::<Severity>(), Ok(Severity::Warning));
+        assert_eq!(Severity::Warning.to_string(), "warning");
+        let mut severities = vec![Severity::Warning, Severity::Error, Severity::Info];
+        severities.sort();
+        assert_eq!(
+            severities,
+            vec![Severity::Info, Severity::Warning, Severity::Error]
+        );
+    }
+
+    #[test]
+    fn test_every_rule_has_a_docs_page() {
+        for rule in Rule::iter() {
+            let url = rule.docs_url(DOCS_URL);
+            let page = url.rsplit_once("/main/").unwrap().1;
+            assert!(
+                std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join(page)
+                    .exists(),
+                "missing {}",
+                page
+            );
+        }
+        assert_eq!(
+            Rule::Scope.docs_url("https://example.com/rules/"),
+            "https://example.com/rules/scope"
+        );
+    }
+
+    #[test]
+    fn test_wcag_levels_of_cited_criteria() {
+        for rule in Rule::iter() {
+            for url in rule
+                .guidelines()
+                .iter()
+                .filter(|url| url.contains("/WCAG21/"))
+            {
+                assert!(
+                    WcagLevel::of_criterion(url).is_some(),
+                    "no level for {}",
+                    url
+                );
+            }
+        }
+        assert_eq!(Rule::AltText.wcag_level(), Some(WcagLevel::A));
+        assert_eq!(Rule::ColorContrast.wcag_level(), Some(WcagLevel::AA));
+    }
+
+    #[test]
+    fn test_renamed_rule_resolves_to_replacement() {
+        const RENAMED: &[RenamedRule] = &[RenamedRule {
+            id: "old-alt-text",
+            replacement: Rule::AltText,
+            since: "0.2.0",
+        }];
+        let (rule, renamed) = Rule::resolve_in("jsx-a11y/old-alt-text", RENAMED).unwrap();
+        assert_eq!(rule, Rule::AltText);
+        assert_eq!(renamed.map(|r| r.id), Some("old-alt-text"));
+        assert!(renamed.unwrap().warning().contains("since 0.2.0"));
+        assert_eq!(Rule::resolve("alt-text"), Some((Rule::AltText, None)));
+        assert_eq!(Rule::resolve("old-alt-text"), None);
+
+        // Renamed ids must never shadow a live rule.
+        for renamed in RENAMED_RULES {
+            assert!(Rule::iter().all(|r| r.to_string() != renamed.id));
+        }
+    }
+
+    #[test]
+    fn test_diagnostics_round_trip_through_json() {
+        let diags = lint_source(r#"fn c() { html! { <img src="a.png" /> } }"#);
+        assert!(!diags.is_empty());
+        let json = serde_json::to_string(&diags).unwrap();
+        let parsed: Vec<LintDiagnostic> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, diags);
+    }
+
+    #[test]
+    fn test_rule_metadata() {
+        let metadata = Rule::AutocompleteValid.metadata();
+        assert_eq!(metadata.id, Rule::AutocompleteValid);
+        assert_eq!(metadata.default_severity, Severity::Error);
+        assert!(metadata.description.starts_with("Enforce"));
+    }
+
+    #[test]
+    fn test_rule_docs_match_metadata() {
+        for rule in Rule::iter() {
+            let metadata = rule.metadata();
+            let path = format!("{}/docs/rules/{}.md", env!("CARGO_MANIFEST_DIR"), rule);
+            let doc = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+            let lines: Vec<_> = doc.lines().collect();
+            assert_eq!(lines[0], format!("# {}", rule));
+            assert_eq!(lines[2], metadata.description, "{}", path);
+            let severity = if metadata.enabled_by_default {
+                format!("Default severity: {}.", metadata.default_severity)
+            } else {
+                format!(
+                    "Default severity: {} when enabled. This rule is opt-in: it is off in both presets.",
+                    metadata.default_severity
+                )
+            };
+            assert_eq!(lines[4], severity, "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_run_rule_only_reports_that_rule() {
+        let elements = parser::parse_source(
+            r#"fn c() { html! { <div><img src="a.png" /><div role="banana"></div></div> } }"#,
+            "test.rs",
+        )
+        .unwrap();
+        let diags = run_rule(&Rule::AltText, &elements);
+        assert_eq!(diags.len(), 1);
+        assert!(diags.iter().all(|d| d.rule == Rule::AltText));
+        assert_eq!(Rule::AriaRole.check_all(&elements).len(), 1);
+    }
+
     #[test]
     fn test_invalid_aria_attribute() {
         let diags = lint_source(r#"fn c() { html! { <div aria-foo="bar"></div> } }"#);
@@ -1836,6 +4392,68 @@ mod tests {
         assert!(!has_lint(&diags, Rule::AriaProps));
     }
 
+    #[test]
+    fn test_custom_elements_are_linted() {
+        let src =
+            r#"fn c() { html! { <my-button aria-pressed="maybe" onclick={go}></my-button> } }"#;
+        let diags = lint_source(src);
+        assert!(has_lint(&diags, Rule::AriaProptypes));
+        assert!(has_lint(&diags, Rule::ClickEventsHaveKeyEvents));
+
+        let mut options = parser::ParseOptions::default();
+        options.custom_elements.insert(
+            "my-button".to_string(),
+            crate::dom::CustomElement {
+                interactive: true,
+                focusable: true,
+                role: Some(Role::Button),
+            },
+        );
+        let elements = parser::parse_source_with(src, "test.rs", &options)
+            .unwrap()
+            .elements;
+        let diags: Vec<_> = run_all_lints(&elements).collect();
+        assert!(has_lint(&diags, Rule::AriaProptypes));
+        assert!(!has_lint(&diags, Rule::ClickEventsHaveKeyEvents));
+    }
+
+    #[test]
+    fn test_allowed_extra_aria_attrs() {
+        let src = r#"fn c() { html! { <div aria-x-tone="warm" role="ds-card"></div> } }"#;
+        let diags = lint_source(src);
+        assert!(has_lint(&diags, Rule::AriaProps));
+        assert!(has_lint(&diags, Rule::AriaRole));
+
+        let mut options = RuleOptions::default();
+        options.aria_props.allowed_extra_aria_attrs = vec!["aria-x-tone".to_string()];
+        options.aria_role.allowed_extra_roles = vec!["ds-card".to_string()];
+        let diags = lint_source_with(src, &options);
+        assert!(!has_lint(&diags, Rule::AriaProps));
+        assert!(!has_lint(&diags, Rule::AriaRole));
+    }
+
+    #[test]
+    fn test_aria_1_3_draft_attributes() {
+        let diags = lint_source(
+            r#"fn c() { html! { <div aria-actions="a b" aria-details="d1 d2"></div> } }"#,
+        );
+        assert_eq!(
+            has_lint(&diags, Rule::AriaProps),
+            cfg!(not(feature = "aria-1-3"))
+        );
+        assert_eq!(
+            has_lint(&diags, Rule::AriaProptypes),
+            cfg!(not(feature = "aria-1-3"))
+        );
+        let diags = lint_source(
+            r#"fn c() { html! { <input aria-invalid="true" aria-errormessage="e1 e2" /> } }"#,
+        );
+        assert_eq!(
+            has_lint(&diags, Rule::AriaProptypes),
+            cfg!(not(feature = "aria-1-3"))
+        );
+    }
+
     #[test]
     fn test_invalid_aria_value() {
         let diags = lint_source(r#"fn c() { html! { <div aria-hidden="yes"></div> } }"#);
@@ -1860,6 +4478,18 @@ mod tests {
         assert!(!has_lint(&diags, Rule::AriaRole));
     }
 
+    #[test]
+    fn test_dpub_and_graphics_roles_need_options() {
+        let src = r#"fn c() { html! { <section role="doc-chapter"><svg role="graphics-document"></svg></section> } }"#;
+        let diags = lint_source(src);
+        assert_eq!(diags.iter().filter(|d| d.rule == Rule::AriaRole).count(), 2);
+
+        let mut options = RuleOptions::default();
+        options.aria_role.dpub = true;
+        options.aria_role.graphics = true;
+        assert!(!has_lint(&lint_source_with(src, &options), Rule::AriaRole));
+    }
+
     #[test]
     fn test_abstract_role() {
         let diags = lint_source(r#"fn c() { html! { <div role="widget"></div> } }"#);
@@ -1872,6 +4502,86 @@ mod tests {
         assert!(has_lint(&diags, Rule::NoRedundantRoles));
     }
 
+    #[test]
+    fn test_role_fallback_list() {
+        // The first known, non-abstract role wins; the rest are fallbacks.
+        let diags = lint_source(
+            r#"fn c() { html! { <button role="buton widget button">{"Click"}</button> } }"#,
+        );
+        assert!(has_lint(&diags, Rule::NoRedundantRoles));
+        let diags = lint_source(r#"fn c() { html! { <div role="switch checkbox"></div> } }"#);
+        let missing = diags
+            .iter()
+            .find(|d| d.rule == Rule::RoleHasRequiredAriaProps)
+            .unwrap();
+        assert!(missing.message.contains("role=\"switch\""));
+        let diags = lint_source(r#"fn c() { html! { <span role="link button"></span> } }"#);
+        let prefer = diags
+            .iter()
+            .find(|d| d.rule == Rule::PreferTagOverRole)
+            .unwrap();
+        assert!(prefer.message.contains("<a>"));
+    }
+
+    /// Apply every fix of `rule` to `source`.
+    fn apply_fixes(source: &str, rule: Rule) -> String {
+        let edits: Vec<_> = lint_source(source)
+            .into_iter()
+            .filter(|d| d.rule == rule)
+            .flat_map(|d| d.fixes)
+            .flat_map(|f| f.edits)
+            .collect();
+        crate::fix::apply(source, &edits).unwrap()
+    }
+
+    #[test]
+    fn test_fixes() {
+        assert_eq!(
+            apply_fixes(
+                r#"fn c() { html! { <button role="button" onclick={go}>{"Click"}</button> } }"#,
+                Rule::NoRedundantRoles
+            ),
+            r#"fn c() { html! { <button onclick={go}>{"Click"}</button> } }"#
+        );
+        assert_eq!(
+            apply_fixes(
+                "fn c() { html! {\n    <input id=\"q\"\n        accesskey={key} />\n} }",
+                Rule::NoAccessKey
+            ),
+            "fn c() { html! {\n    <input id=\"q\" />\n} }"
+        );
+        assert_eq!(
+            apply_fixes(r#"fn c() { html! { <img src="a.png" /> } }"#, Rule::AltText),
+            r#"fn c() { html! { <img alt="" src="a.png" /> } }"#
+        );
+        // Dioxus' syntax: `name: value,` fields inside braces.
+        assert_eq!(
+            apply_fixes(
+                r#"fn c() { rsx! { ul { role: "list", class: "a", li { "x" } } } }"#,
+                Rule::NoRedundantRoles
+            ),
+            r#"fn c() { rsx! { ul { class: "a", li { "x" } } } }"#
+        );
+        assert_eq!(
+            apply_fixes(r#"fn c() { rsx! { img {} } }"#, Rule::AltText),
+            r#"fn c() { rsx! { img { alt: "",} } }"#
+        );
+        assert_eq!(
+            apply_fixes(
+                r#"fn c() { rsx! { div { tabindex: 3, "x" } } }"#,
+                Rule::TabindexNoPositive
+            ),
+            r#"fn c() { rsx! { div { tabindex: "0", "x" } } }"#
+        );
+        let diags = lint_source(r#"fn c() { html! { <div tabindex="3"></div> } }"#);
+        let fix = &diags
+            .iter()
+            .find(|d| d.rule == Rule::TabindexNoPositive)
+            .unwrap()
+            .fixes[0];
+        assert_eq!(fix.applicability, Applicability::Suggested);
+    }
+
     #[test]
     fn test_missing_alt_text() {
         let diags = lint_source(r#"fn c() { html! { <img src="test.png" /> } }"#);
@@ -1899,80 +4609,652 @@ mod tests {
         assert!(has_lint(&diags, Rule::NoAccessKey));
     }
 
+    #[test]
+    fn test_no_title_only_name() {
+        let diags = lint_source(
+            r#"fn c() { html! { <button title="Close" onclick={close}><svg /></button> } }"#,
+        );
+        assert!(has_lint(&diags, Rule::NoTitleOnlyName));
+    }
+
+    #[test]
+    fn test_title_with_text_or_label() {
+        let diags =
+            lint_source(r#"fn c() { html! { <button title="Close dialog">{"Close"}</button> } }"#);
+        assert!(!has_lint(&diags, Rule::NoTitleOnlyName));
+        let diags = lint_source(
+            r#"fn c() { html! { <a href="/" title="Home" aria-label="Home"><svg /></a> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::NoTitleOnlyName));
+    }
+
+    #[test]
+    fn test_no_onchange() {
+        let diags = lint_source(
+            r#"fn c() { html! { <select onchange={navigate}><option>{"Home"}</option></select> } }"#,
+        );
+        assert!(has_lint(&diags, Rule::NoOnchange));
+    }
+
+    #[test]
+    fn test_no_onchange_with_other_handler() {
+        let diags = lint_source(
+            r#"fn c() { html! { <select onchange={update} onblur={navigate}><option>{"Home"}</option></select> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::NoOnchange));
+    }
+
     #[test]
     fn test_no_autofocus() {
         let diags = lint_source(r#"fn c() { html! { <input autofocus /> } }"#);
         assert!(has_lint(&diags, Rule::NoAutofocus));
+        let input = parser::HtmlElementBuilder::new(Tag::Input)
+            .attr("autofocus", "")
+            .build();
+        let options = RuleOptions::default();
+        assert!(Rule::NoAutofocus.check(&input, &options).is_some());
+    }
+
+    #[test]
+    fn test_no_autofocus_allow_in_dialog() {
+        let src = r#"fn c() { html! {
+            <div>
+                <dialog open=true>
+                    <p>{"Delete?"}</p>
+                    <button autofocus=true>{"Cancel"}</button>
+                    <button>{"Delete"}</button>
+                </dialog>
+                <div role="dialog"><a href="/a">{"A"}</a><input autofocus=true /></div>
+                <input autofocus=true />
+            </div>
+        } }"#;
+        assert_eq!(
+            lint_source(src)
+                .iter()
+                .filter(|d| d.rule == Rule::NoAutofocus)
+                .count(),
+            3
+        );
+        let mut options = RuleOptions::default();
+        options.no_autofocus.allow_in_dialog = true;
+        let lines: Vec<usize> = lint_source_with(src, &options)
+            .iter()
+            .filter(|d| d.rule == Rule::NoAutofocus)
+            .map(|d| d.line)
+            .collect();
+        assert_eq!(lines, [8, 9]);
+        // A tree that cuts the dialog off.
+        let elements = parser::parse_source(src, "test.rs").unwrap();
+        let cx = LintContext::new(&elements[3], &options).with_tree(&elements[..4]);
+        assert!(Rule::NoAutofocus.check_with(&cx).is_none());
+        // Each branch of a conditional may focus its own first element.
+        let branches = r#"fn c() { html! {
+            <dialog open=true>
+                { if editing { html! { <input autofocus=true /> } } else { html! { <button autofocus=true>{"OK"}</button> } } }
+            </dialog>
+        } }"#;
+        assert!(!has_lint(
+            &lint_source_with(branches, &options),
+            Rule::NoAutofocus
+        ));
+    }
+
+    #[test]
+    fn test_no_distracting_elements() {
+        let diags = lint_source(r#"fn c() { html! { <marquee>{"Scrolling text"}</marquee> } }"#);
+        assert!(has_lint(&diags, Rule::NoDistractingElements));
+    }
+
+    #[test]
+    fn test_anchor_invalid_href() {
+        let diags = lint_source(r##"fn c() { html! { <a href="#">{"Link"}</a> } }"##);
+        assert!(has_lint(&diags, Rule::AnchorIsValid));
+    }
+
+    #[test]
+    fn test_anchor_valid_href() {
+        let diags = lint_source(r#"fn c() { html! { <a href="/about">{"About"}</a> } }"#);
+        assert!(!has_lint(&diags, Rule::AnchorIsValid));
+    }
+
+    #[test]
+    fn test_img_alt_filename() {
+        let diags = lint_source(r#"fn c() { html! { <img src="a.png" alt="IMG_1234.JPG" /> } }"#);
+        assert!(has_lint(&diags, Rule::ImgAltFilename));
+        let diags = lint_source(
+            r#"fn c() { html! { <img src="/img/team-2024.webp?v=2" alt="team-2024" /> } }"#,
+        );
+        assert!(has_lint(&diags, Rule::ImgAltFilename));
+    }
+
+    #[test]
+    fn test_img_alt_not_filename() {
+        let diags = lint_source(
+            r#"fn c() { html! { <img src="team.png" alt="Our team at the 2024 offsite." /> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::ImgAltFilename));
+    }
+
+    #[test]
+    fn test_no_redundant_alt() {
+        let diags =
+            lint_source(r#"fn c() { html! { <img src="test.png" alt="image of a cat" /> } }"#);
+        assert!(has_lint(&diags, Rule::ImgRedundantAlt));
+    }
+
+    #[test]
+    fn test_positive_tabindex() {
+        let diags = lint_source(r#"fn c() { html! { <div tabindex="5"></div> } }"#);
+        assert!(has_lint(&diags, Rule::TabindexNoPositive));
+    }
+
+    #[test]
+    fn test_invalid_tabindex() {
+        for value in ["abc", "1.5", "", "+1", "0x1"] {
+            let src = format!(
+                r#"fn c() {{ html! {{ <div tabindex="{}"></div> }} }}"#,
+                value
+            );
+            assert!(
+                has_lint(&lint_source(&src), Rule::TabindexValid),
+                "{}",
+                value
+            );
+        }
+        let diags = lint_source(
+            r#"fn c() { html! { <div><div tabindex="-1"></div><div tabindex=" 0 "></div></div> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::TabindexValid));
+    }
+
+    #[test]
+    fn test_click_without_keyboard() {
+        let diags = lint_source(r#"fn c() { html! { <div onclick={handler}></div> } }"#);
+        assert!(has_lint(&diags, Rule::ClickEventsHaveKeyEvents));
+    }
+
+    #[test]
+    fn test_pointer_handlers_without_keyboard() {
+        for handler in ["ondblclick", "onmousedown", "on:pointerup", "ontouchstart"] {
+            let src = format!(
+                "fn c() {{ html! {{ <div {}={{handler}}></div> }} }}",
+                handler
+            );
+            let diags = lint_source(&src);
+            assert!(
+                has_lint(&diags, Rule::ClickEventsHaveKeyEvents),
+                "{}",
+                handler
+            );
+            assert!(
+                has_lint(&diags, Rule::NoStaticElementInteractions),
+                "{}",
+                handler
+            );
+        }
+    }
+
+    #[test]
+    fn test_click_without_keyboard_with_role_button() {
+        let diags =
+            lint_source(r#"fn c() { html! { <div role="button" onclick={handler}></div> } }"#);
+        assert!(has_lint(&diags, Rule::ClickEventsHaveKeyEvents));
+    }
+
+    #[test]
+    fn test_click_events_key_handler_options() {
+        let src = r#"fn c() { html! { <div>
+            <div onclick={a} onkeypress={b}></div>
+            <div onclick={a} onkeydown={b}></div>
+            <div onclick={a} onkeydown={b} onkeyup={c}></div>
+        </div> } }"#;
+        let count = |options: &RuleOptions| {
+            lint_source_with(src, options)
+                .iter()
+                .filter(|d| d.rule == Rule::ClickEventsHaveKeyEvents)
+                .count()
+        };
+        let mut options = RuleOptions::default();
+        assert_eq!(count(&options), 0);
+        options.click_events_have_key_events.accept_keypress = false;
+        assert_eq!(count(&options), 1);
+        options.click_events_have_key_events.require_both = true;
+        assert_eq!(count(&options), 2);
+    }
+
+    #[test]
+    fn test_interactive_role_inside_native_control_ok() {
+        let diags = lint_source(
+            r#"fn c() { html! { <button><span role="button" onclick={handler}>{"x"}</span></button> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::ClickEventsHaveKeyEvents));
+    }
+
+    #[test]
+    fn test_click_on_button_ok() {
+        let diags =
+            lint_source(r#"fn c() { html! { <button onclick={handler}>{"Click"}</button> } }"#);
+        assert!(!has_lint(&diags, Rule::ClickEventsHaveKeyEvents));
+    }
+
+    #[test]
+    fn test_leptos_view_macro() {
+        let diags = lint_source(r#"fn c() { view! { <img src="test.png" /> } }"#);
+        assert!(has_lint(&diags, Rule::AltText));
+    }
+
+    #[test]
+    fn test_iframe_missing_title() {
+        let diags =
+            lint_source(r#"fn c() { html! { <iframe src="https://example.com"></iframe> } }"#);
+        assert!(has_lint(&diags, Rule::IframeHasTitle));
+    }
+
+    #[test]
+    fn test_aria_on_unsupported_element() {
+        let diags = lint_source(r#"fn c() { html! { <meta aria-label="test" /> } }"#);
+        assert!(has_lint(&diags, Rule::AriaUnsupportedElements));
+    }
+
+    // --- AltTextLength ---
+
+    #[test]
+    fn test_alt_text_length() {
+        let rule = Rule::AltTextLength;
+        let alt = "A chart of monthly revenue. ".repeat(6);
+        let source = format!(
+            r#"fn c() {{ html! {{ <div>
+                <img src="c.png" alt="{alt}" />
+                <area href="/c" alt="{alt}" />
+                <input type="image" src="c.png" alt="{alt}" />
+                <input type="text" alt="{alt}" />
+                <div alt="{alt}"></div>
+            </div> }} }}"#
+        );
+        let elements = parser::parse_source(&source, "test.rs").unwrap();
+        let tags: Vec<_> = rule
+            .check_all(&elements)
+            .into_iter()
+            .map(|d| d.element)
+            .collect();
+        assert_eq!(tags, [Tag::Img, Tag::Area, Tag::Input]);
+
+        let mut options = RuleOptions::default();
+        options.alt_text_length.max_length = 200;
+        let diags: Vec<_> = elements
+            .iter()
+            .filter_map(|e| rule.check(e, &options))
+            .collect();
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_alt_text_length_is_opt_in() {
+        assert!(!Rule::AltTextLength.enabled_by_default());
+        let source = format!(
+            r#"fn c() {{ html! {{ <img src="c.png" alt="{}" /> }} }}"#,
+            "x".repeat(200)
+        );
+        assert!(!has_lint(&lint_source(&source), Rule::AltTextLength));
+    }
+
+    // --- ButtonHasContent ---
+
+    #[test]
+    fn test_button_has_content_empty() {
+        let diags = lint_source(
+            r#"fn c() { html! { <button onclick={save}><img src="s.svg" alt="" /></button> } }"#,
+        );
+        assert!(has_lint(&diags, Rule::ButtonHasContent));
+        let diags = lint_source(r#"fn c() { html! { <div role="button" tabindex="0"></div> } }"#);
+        assert!(has_lint(&diags, Rule::ButtonHasContent));
+    }
+
+    #[test]
+    fn test_button_has_content_ok() {
+        let diags =
+            lint_source(r#"fn c() { html! { <button><img src="s.svg" alt="Save" /></button> } }"#);
+        assert!(!has_lint(&diags, Rule::ButtonHasContent));
+        let diags = lint_source(r#"fn c() { html! { <button>{label}</button> } }"#);
+        assert!(!has_lint(&diags, Rule::ButtonHasContent));
+    }
+
+    // --- Dir ---
+
+    #[test]
+    fn test_dir_invalid_value() {
+        let diags = lint_source(r#"fn c() { html! { <p dir="right">{"x"}</p> } }"#);
+        assert!(has_lint(&diags, Rule::Dir));
+    }
+
+    #[test]
+    fn test_dir_lang_mismatch() {
+        let diags = lint_source(r#"fn c() { html! { <p lang="ar" dir="ltr">{"x"}</p> } }"#);
+        assert!(has_lint(&diags, Rule::Dir));
+        let diags = lint_source(
+            r#"fn c() { html! { <div><p lang="he" dir="rtl">{"x"}</p><p lang="en" dir="auto">{"y"}</p></div> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::Dir));
+    }
+
+    // --- ColorContrast ---
+
+    #[test]
+    fn test_color_contrast() {
+        let rule = Rule::ColorContrast;
+        let diags = rule.check_all(
+            &parser::parse_source(
+                r##"fn c() { html! { <div>
+                    <p style="color: #777; background-color: #fff">{"low"}</p>
+                    <p style="color: #767676; background: white">{"ok"}</p>
+                    <td bgcolor="navy" style="color: black">{"low"}</td>
+                    <h2 style="color: #888; background-color: white; font-size: 24px">{"large"}</h2>
+                    <p style="color: rgba(0, 0, 0, 0.5); background-color: white">{"unknown"}</p>
+                </div> } }"##,
+                "test.rs",
+            )
+            .unwrap(),
+        );
+        let tags: Vec<_> = diags.iter().map(|d| d.element.to_string()).collect();
+        assert_eq!(tags, ["p", "td"]);
+        assert!(diags[0].message.contains("4.48:1"));
+    }
+
+    #[test]
+    fn test_color_contrast_is_opt_in() {
+        let diags = lint_source(
+            r#"fn c() { html! { <p style="color: #777; background-color: #fff">{"x"}</p> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::ColorContrast));
+    }
+
+    // --- IdentityFieldsHaveAutocomplete ---
+
+    #[test]
+    fn test_identity_fields_have_autocomplete() {
+        let rule = Rule::IdentityFieldsHaveAutocomplete;
+        let diags = rule.check_all(
+            &parser::parse_source(
+                r#"fn c() { html! { <form>
+                    <input type="email" />
+                    <input name="first_name" />
+                    <input name="username" autocomplete="username" />
+                    <input type="checkbox" name="email_opt_in" />
+                </form> } }"#,
+                "test.rs",
+            )
+            .unwrap(),
+        );
+        let help: Vec<_> = diags.iter().filter_map(|d| d.help.as_deref()).collect();
+        assert_eq!(diags.len(), 2);
+        assert!(help[0].contains(r#"autocomplete="email""#));
+        assert!(help[1].contains(r#"autocomplete="given-name""#));
+    }
+
+    #[test]
+    fn test_identity_autocomplete_token_matches_whole_words() {
+        for (name, token) in [
+            ("email", "email"),
+            ("userEmail", "email"),
+            ("e-mail", "email"),
+            ("firstName", "given-name"),
+            ("FIRST_NAME", "given-name"),
+            ("billing-zip", "postal-code"),
+            ("shippingCity", "address-level2"),
+            ("date_of_birth", "bday"),
+            ("HomeTel", "tel"),
+            ("name", "name"),
+        ] {
+            assert_eq!(identity_autocomplete_token(name), Some(token), "{}", name);
+        }
+        for name in [
+            "capacity",
+            "electricity",
+            "unzip",
+            "adobe",
+            "ipaddress",
+            "ip_address",
+            "macAddress",
+            "hotel",
+            "display_name",
+        ] {
+            assert_eq!(identity_autocomplete_token(name), None, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_identity_fields_have_autocomplete_is_opt_in() {
+        assert!(!Rule::IdentityFieldsHaveAutocomplete.enabled_by_default());
+        let diags = lint_source(r#"fn c() { html! { <input type="email" /> } }"#);
+        assert!(!has_lint(&diags, Rule::IdentityFieldsHaveAutocomplete));
+    }
+
+    // --- IframeTitleUnique ---
+
+    #[test]
+    fn test_iframe_title_unique() {
+        let diags = lint_source(
+            r#"fn c() { html! { <div>
+                <iframe src="/a" title="Map"></iframe>
+                <iframe src="/b" title=" map "></iframe>
+            </div> } }"#,
+        );
+        let dups: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == Rule::IframeTitleUnique)
+            .collect();
+        assert_eq!(dups.len(), 1);
+        assert_eq!(dups[0].line, 3);
+    }
+
+    #[test]
+    fn test_iframe_title_unique_per_macro() {
+        let diags = lint_source(
+            r#"fn c() { html! { <iframe src="/a" title="Map"></iframe> } }
+               fn d() { html! { <iframe src="/b" title="Map"></iframe> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::IframeTitleUnique));
+        // Frames in different branches are never shown together.
+        let diags = lint_source(
+            r#"fn c() { html! { <div>
+                { if wide { html! { <iframe src="/a" title="Map"></iframe> } } else { html! { <iframe src="/b" title="Map"></iframe> } } }
+            </div> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::IframeTitleUnique));
     }
 
-    #[test]
-    fn test_no_distracting_elements() {
-        let diags = lint_source(r#"fn c() { html! { <marquee>{"Scrolling text"}</marquee> } }"#);
-        assert!(has_lint(&diags, Rule::NoDistractingElements));
-    }
+    // --- ComboboxPattern ---
 
     #[test]
-    fn test_anchor_invalid_href() {
-        let diags = lint_source(r##"fn c() { html! { <a href="#">{"Link"}</a> } }"##);
-        assert!(has_lint(&diags, Rule::AnchorIsValid));
+    fn test_combobox_pattern_violations() {
+        for src in [
+            r#"fn c() { html! { <input role="combobox" aria-controls="list" /> } }"#,
+            r#"fn c() { html! { <input role="combobox" aria-expanded="true" /> } }"#,
+            r#"fn c() { html! { <div><input role="combobox" aria-expanded="true" aria-controls="list" /><div id="list"></div></div> } }"#,
+        ] {
+            assert!(
+                has_lint(&lint_source(src), Rule::ComboboxPattern),
+                "{}",
+                src
+            );
+        }
     }
 
     #[test]
-    fn test_anchor_valid_href() {
-        let diags = lint_source(r#"fn c() { html! { <a href="/about">{"About"}</a> } }"#);
-        assert!(!has_lint(&diags, Rule::AnchorIsValid));
+    fn test_combobox_pattern_ok() {
+        let diags = lint_source(
+            r#"fn c() { html! { <div><input role="combobox" aria-expanded="true" aria-controls="list" /><ul id="list" role="listbox"></ul><input role="combobox" aria-expanded="false" aria-controls="other" /><select></select></div> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::ComboboxPattern));
+
+        // A popup rendered by a child component is not in the macro.
+        let diags = lint_source(
+            r#"fn c() { html! { <div><input role="combobox" aria-expanded="true" aria-controls="list" /><Suggestions /></div> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::ComboboxPattern));
     }
 
+    // --- TabsPattern ---
+
     #[test]
-    fn test_no_redundant_alt() {
-        let diags =
-            lint_source(r#"fn c() { html! { <img src="test.png" alt="image of a cat" /> } }"#);
-        assert!(has_lint(&diags, Rule::ImgRedundantAlt));
+    fn test_tabs_pattern_ok() {
+        let src = r#"fn c() { html! {
+            <div>
+                <div role="tablist">
+                    <button role="tab" id="t1" aria-controls="p1">{"One"}</button>
+                    <button role="tab" id="t2" aria-controls={panel}>{"Two"}</button>
+                </div>
+                <div role="tabpanel" id="p1" aria-labelledby="t1"></div>
+                <div role="tabpanel" aria-label="Two"></div>
+            </div>
+        } }"#;
+        assert!(!has_lint(&lint_source(src), Rule::TabsPattern));
     }
 
     #[test]
-    fn test_positive_tabindex() {
-        let diags = lint_source(r#"fn c() { html! { <div tabindex="5"></div> } }"#);
-        assert!(has_lint(&diags, Rule::TabindexNoPositive));
+    fn test_tabs_pattern_half_built() {
+        let src = r#"fn c() { html! {
+            <div>
+                <button role="tab" id="t1">{"One"}</button>
+                <button role="tab" aria-controls="t1">{"Two"}</button>
+                <button role="tab" aria-controls="gone">{"Three"}</button>
+                <div role="tabpanel"></div>
+            </div>
+        } }"#;
+        let messages: Vec<String> = lint_source(src)
+            .into_iter()
+            .filter(|d| d.rule == Rule::TabsPattern)
+            .map(|d| d.message)
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "Tab <button> has no `aria-controls` referencing its tabpanel.",
+                "Tab <button> references <button> on line 3 in `aria-controls`, which is not a tabpanel.",
+                "Tabpanel <div> has no `aria-labelledby` referencing its tab.",
+            ]
+        );
     }
 
+    // --- RadiogroupPattern ---
+
     #[test]
-    fn test_click_without_keyboard() {
-        let diags = lint_source(r#"fn c() { html! { <div onclick={handler}></div> } }"#);
-        assert!(has_lint(&diags, Rule::ClickEventsHaveKeyEvents));
+    fn test_radiogroup_pattern_ok() {
+        let src = r#"fn c() { html! {
+            <div>
+                <div role="radiogroup" aria-label="Size">
+                    <div role="radio" aria-checked="true">{"Small"}</div>
+                    <div role="radio" aria-checked="false" aria-label="Large"></div>
+                </div>
+                <div role="radiogroup" aria-label="Color">
+                    <div role="radio" aria-checked={red}>{"Red"}</div>
+                    <div role="radio" aria-checked={blue}>{"Blue"}</div>
+                </div>
+                <fieldset><div role="radio" aria-checked="false"></div></fieldset>
+                <input type="radio" name="n" />
+            </div>
+        } }"#;
+        assert!(!has_lint(&lint_source(src), Rule::RadiogroupPattern));
+
+        // A radio component, rendered inside a group by its parent.
+        let src = r#"fn Option() { html! { <div role="radio" aria-checked="false">{"A"}</div> } }"#;
+        assert!(!has_lint(&lint_source(src), Rule::RadiogroupPattern));
     }
 
     #[test]
-    fn test_click_without_keyboard_with_role_button() {
-        let diags =
-            lint_source(r#"fn c() { html! { <div role="button" onclick={handler}></div> } }"#);
-        assert!(has_lint(&diags, Rule::ClickEventsHaveKeyEvents));
+    fn test_radiogroup_pattern_problems() {
+        let src = r#"fn c() { html! {
+            <div>
+                <div role="radio" aria-checked="true">{"Alone"}</div>
+                <div role="radiogroup">
+                    <div role="radio" aria-checked="true">{"A"}</div>
+                    <div role="radio" aria-checked="true"></div>
+                </div>
+                <div role="radiogroup"><div role="radio" aria-checked="false">{"B"}</div></div>
+            </div>
+        } }"#;
+        let messages: Vec<String> = lint_source(src)
+            .into_iter()
+            .filter(|d| d.rule == Rule::RadiogroupPattern)
+            .map(|d| d.message)
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "Radio <div> is not inside a `role=\"radiogroup\"` or <fieldset>.",
+                "Radiogroup <div> has 2 radios with `aria-checked=\"true\"`; exactly one radio should be checked.",
+                "Radio <div> has no accessible name.",
+                "Radiogroup <div> has no radio with `aria-checked=\"true\"`; exactly one radio should be checked.",
+            ]
+        );
+        // Radios in different branches are never checked together.
+        let src = r#"fn c() { html! {
+            <div role="radiogroup" aria-label="Size">
+                { match size {
+                    Size::Small => html! { <div role="radio" aria-checked="true">{"Small"}</div> },
+                    _ => html! { <div role="radio" aria-checked="true">{"Large"}</div> },
+                } }
+            </div>
+        } }"#;
+        assert!(!has_lint(&lint_source(src), Rule::RadiogroupPattern));
+        // Part of a macro: the radios are cut off.
+        let elements = parser::parse_source(
+            r#"fn c() { html! { <div role="radiogroup"><div role="radio" aria-checked="true"></div></div> } }"#,
+            "test.rs",
+        )
+        .unwrap();
+        let rule = Rule::RadiogroupPattern;
+        assert!(rule.check_macro(&elements[..1]).is_empty());
     }
 
+    // --- AriaPosinsetSetsize ---
+
     #[test]
-    fn test_click_on_button_ok() {
-        let diags =
-            lint_source(r#"fn c() { html! { <button onclick={handler}>{"Click"}</button> } }"#);
-        assert!(!has_lint(&diags, Rule::ClickEventsHaveKeyEvents));
+    fn test_posinset_setsize_inconsistent() {
+        for attrs in [
+            r#"aria-posinset="4" aria-setsize="3""#,
+            r#"aria-posinset="0""#,
+            r#"aria-posinset="1" aria-setsize="0""#,
+        ] {
+            let src = format!(
+                r#"fn c() {{ html! {{ <li role="option" {}>{{"x"}}</li> }} }}"#,
+                attrs
+            );
+            assert!(
+                has_lint(&lint_source(&src), Rule::AriaPosinsetSetsize),
+                "{}",
+                attrs
+            );
+        }
     }
 
     #[test]
-    fn test_leptos_view_macro() {
-        let diags = lint_source(r#"fn c() { view! { <img src="test.png" /> } }"#);
-        assert!(has_lint(&diags, Rule::AltText));
+    fn test_posinset_setsize_consistent() {
+        let diags = lint_source(
+            r#"fn c() { html! { <ul role="listbox"><li role="option" aria-posinset="3" aria-setsize="3">{"a"}</li><li role="option" aria-posinset="9" aria-setsize="-1">{"b"}</li><li role="option" aria-posinset={pos} aria-setsize="2">{"c"}</li></ul> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::AriaPosinsetSetsize));
     }
 
+    // --- AriaPrerequisites ---
+
     #[test]
-    fn test_iframe_missing_title() {
+    fn test_aria_prerequisites_missing() {
+        let diags = lint_source(
+            r#"fn c() { html! { <button aria-braillelabel="btn">{"Button"}</button> } }"#,
+        );
+        assert!(has_lint(&diags, Rule::AriaPrerequisites));
         let diags =
-            lint_source(r#"fn c() { html! { <iframe src="https://example.com"></iframe> } }"#);
-        assert!(has_lint(&diags, Rule::IframeHasTitle));
+            lint_source(r#"fn c() { html! { <div aria-roledescription="slide">{"x"}</div> } }"#);
+        assert!(has_lint(&diags, Rule::AriaPrerequisites));
     }
 
     #[test]
-    fn test_aria_on_unsupported_element() {
-        let diags = lint_source(r#"fn c() { html! { <meta aria-label="test" /> } }"#);
-        assert!(has_lint(&diags, Rule::AriaUnsupportedElements));
+    fn test_aria_prerequisites_present() {
+        let diags = lint_source(
+            r#"fn c() { html! { <section aria-roledescription="slide" aria-brailleroledescription="sld" aria-label="Intro" aria-braillelabel="intro">{"x"}</section> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::AriaPrerequisites));
     }
 
     // --- AnchorAmbiguousText ---
@@ -1992,6 +5274,37 @@ mod tests {
         assert!(!has_lint(&diags, Rule::AnchorAmbiguousText));
     }
 
+    #[test]
+    fn test_anchor_ambiguous_text_from_content() {
+        let diags = lint_source(r#"fn c() { html! { <a href="/x">{" Click  here! "}</a> } }"#);
+        assert!(has_lint(&diags, Rule::AnchorAmbiguousText));
+        let diags =
+            lint_source(r#"fn c() { html! { <a href="/x"><img src="i.png" alt="Link" /></a> } }"#);
+        assert!(has_lint(&diags, Rule::AnchorAmbiguousText));
+    }
+
+    #[test]
+    fn test_anchor_ambiguous_text_dynamic_or_labelled_content() {
+        let diags = lint_source(r#"fn c() { html! { <a href="/x">{label}</a> } }"#);
+        assert!(!has_lint(&diags, Rule::AnchorAmbiguousText));
+        let diags = lint_source(
+            r#"fn c() { html! { <a href="/x" aria-label="Pricing details">{"here"}</a> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::AnchorAmbiguousText));
+    }
+
+    #[test]
+    fn test_anchor_ambiguous_text_custom_words() {
+        let mut options = RuleOptions::default();
+        options.anchor_ambiguous_text.words = vec!["Hier klicken".to_string()];
+        let source = r#"fn c() { html! { <a href="/x" aria-label="hier klicken">{"x"}</a> } }"#;
+        assert!(has_lint(
+            &lint_source_with(source, &options),
+            Rule::AnchorAmbiguousText
+        ));
+        assert!(!has_lint(&lint_source(source), Rule::AnchorAmbiguousText));
+    }
+
     // --- AnchorHasContent ---
 
     #[test]
@@ -2036,6 +5349,158 @@ mod tests {
         assert!(!has_lint(&diags, Rule::AriaActivedescendantHasTabindex));
     }
 
+    #[test]
+    fn test_activedescendant_target_inside_or_owned() {
+        let inside = r#"fn c() { html! {
+            <ul role="listbox" tabindex="0" aria-activedescendant="o1">
+                <li id="o1" role="option">{"One"}</li>
+            </ul>
+        } }"#;
+        assert!(!has_lint(
+            &lint_source(inside),
+            Rule::AriaActivedescendantTarget
+        ));
+        let owned = r#"fn c() { html! {
+            <div>
+                <div role="listbox" tabindex="0" aria-owns="extra" aria-activedescendant="o2"></div>
+                <ul id="extra"><li id="o2" role="option">{"Two"}</li></ul>
+            </div>
+        } }"#;
+        assert!(!has_lint(
+            &lint_source(owned),
+            Rule::AriaActivedescendantTarget
+        ));
+        let combobox = r#"fn c() { html! {
+            <div>
+                <input role="combobox" aria-expanded="true" aria-controls="list" aria-activedescendant="o3" />
+                <ul id="list" role="listbox"><li id="o3" role="option">{"Three"}</li></ul>
+            </div>
+        } }"#;
+        assert!(!has_lint(
+            &lint_source(combobox),
+            Rule::AriaActivedescendantTarget
+        ));
+    }
+
+    #[test]
+    fn test_activedescendant_target_missing_or_outside() {
+        // Ids missing from the macro may be rendered elsewhere.
+        let missing =
+            r#"fn c() { html! { <div tabindex="0" aria-activedescendant="nope"></div> } }"#;
+        assert!(!has_lint(
+            &lint_source(missing),
+            Rule::AriaActivedescendantTarget
+        ));
+        let outside = r#"fn c() { html! {
+            <div>
+                <div role="listbox" tabindex="0" aria-activedescendant="o1"></div>
+                <div id="o1" role="option">{"One"}</div>
+            </div>
+        } }"#;
+        let diags = lint_source(outside);
+        let diag = diags
+            .iter()
+            .find(|d| d.rule == Rule::AriaActivedescendantTarget)
+            .unwrap();
+        assert!(diag.message.contains("references <div> on line 4"));
+        // Dynamic ids and empty values are not checked.
+        let dynamic =
+            r#"fn c() { html! { <div tabindex="0" aria-activedescendant={active}></div> } }"#;
+        assert!(!has_lint(
+            &lint_source(dynamic),
+            Rule::AriaActivedescendantTarget
+        ));
+    }
+
+    #[test]
+    fn test_aria_modal_dialog() {
+        let diags = lint_source(r#"fn c() { html! { <div aria-modal="true"></div> } }"#);
+        let diag = diags
+            .iter()
+            .find(|d| d.rule == Rule::AriaModalDialog)
+            .unwrap();
+        assert!(diag.message.contains("without `role=\"dialog\"`"));
+        assert_eq!(diag.fixes[0].message, "Add `role=\"dialog\"`");
+
+        let diags =
+            lint_source(r#"fn c() { html! { <nav role="navigation" aria-modal="true"></nav> } }"#);
+        let diag = diags
+            .iter()
+            .find(|d| d.rule == Rule::AriaModalDialog)
+            .unwrap();
+        assert!(diag.message.contains("on the \"navigation\" role"));
+        assert_eq!(diag.fixes[0].message, "Remove `aria-modal`");
+        assert!(!has_lint(&diags, Rule::RoleSupportsAriaProps));
+
+        for ok in [
+            r#"fn c() { html! { <div role="dialog" aria-modal="true"></div> } }"#,
+            r#"fn c() { html! { <dialog aria-modal="true"></dialog> } }"#,
+            r#"fn c() { html! { <div role={role} aria-modal="true"></div> } }"#,
+            r#"fn c() { html! { <div aria-modal="false"></div> } }"#,
+        ] {
+            assert!(!has_lint(&lint_source(ok), Rule::AriaModalDialog), "{}", ok);
+        }
+    }
+
+    #[test]
+    fn test_aria_owns_valid() {
+        let ok = r#"fn c() { html! {
+            <div>
+                <ul role="menu" aria-owns="m2 m3"><li id="m1" role="menuitem">{"1"}</li></ul>
+                <div id="m2" role="menuitem">{"2"}</div>
+                <div id="m3" role="menuitem">{"3"}</div>
+            </div>
+        } }"#;
+        assert!(!has_lint(&lint_source(ok), Rule::AriaOwnsValid));
+        let dynamic = r#"fn c() { html! { <div aria-owns={ids}></div> } }"#;
+        assert!(!has_lint(&lint_source(dynamic), Rule::AriaOwnsValid));
+    }
+
+    #[test]
+    fn test_aria_owns_problems() {
+        let messages = |src: &str| -> Vec<String> {
+            lint_source(src)
+                .into_iter()
+                .filter(|d| d.rule == Rule::AriaOwnsValid)
+                .map(|d| d.message)
+                .collect()
+        };
+        // "b" may be rendered by another component.
+        assert_eq!(
+            messages(r#"fn c() { html! { <div id="a" aria-owns="a b"></div> } }"#),
+            ["`aria-owns` on <div> references the element itself."]
+        );
+        let duplicate = r#"fn c() { html! {
+            <div>
+                <div aria-owns="x"></div>
+                <span aria-owns="x"></span>
+                <p id="x"></p>
+            </div>
+        } }"#;
+        assert_eq!(
+            messages(duplicate),
+            ["`aria-owns` on <span> claims \"x\", which <div> on line 3 already owns."]
+        );
+        let cycles = r#"fn c() { html! {
+            <section id="top">
+                <div id="a" aria-owns="b"></div>
+                <div id="b" aria-owns="a"></div>
+                <p aria-owns="top"></p>
+            </section>
+        } }"#;
+        assert_eq!(
+            messages(cycles),
+            [
+                "`aria-owns` on <div> owns <div> on line 3, which already contains it, creating a cycle.",
+                "`aria-owns` on <p> owns <section> on line 2, which already contains it, creating a cycle.",
+            ]
+        );
+        // Part of a macro, with the <section> cut short.
+        let elements = parser::parse_source(cycles, "test.rs").unwrap();
+        let diags = Rule::AriaOwnsValid.check_macro(&elements[..3]);
+        assert_eq!(diags.len(), 1);
+    }
+
     // --- AutocompleteValid ---
 
     #[test]
@@ -2050,6 +5515,31 @@ mod tests {
         assert!(has_lint(&diags, Rule::AutocompleteValid));
     }
 
+    #[test]
+    fn test_autocomplete_inappropriate_for_type() {
+        let diags =
+            lint_source(r#"fn c() { html! { <input type="checkbox" autocomplete="email" /> } }"#);
+        assert!(has_lint(&diags, Rule::AutocompleteValid));
+        let diags = lint_source(
+            r#"fn c() { html! { <input type="email" autocomplete="cc-exp-month" /> } }"#,
+        );
+        assert!(has_lint(&diags, Rule::AutocompleteValid));
+    }
+
+    #[test]
+    fn test_autocomplete_appropriate_for_type() {
+        let diags = lint_source(
+            r#"fn c() { html! { <div>
+                <input type="number" autocomplete="cc-exp-month" />
+                <input type="email" autocomplete="username" />
+                <input type="tel" autocomplete="shipping tel-national" />
+                <input type="checkbox" autocomplete="off" />
+                <input type={kind} autocomplete="email" />
+            </div> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::AutocompleteValid));
+    }
+
     // --- ControlHasAssociatedLabel ---
 
     #[test]
@@ -2070,6 +5560,33 @@ mod tests {
         assert!(!has_lint(&diags, Rule::ControlHasAssociatedLabel));
     }
 
+    #[test]
+    fn test_control_named_by_label_or_labelledby() {
+        let diags = lint_source(
+            r#"fn c() { html! { <form>
+                <label>{"Name"}<input /></label>
+                <label for="email">{"Email"}</label><input id="email" />
+                <span id="q-label">{"Search"}</span><input aria-labelledby="q-label" />
+                <input aria-labelledby="elsewhere" />
+            </form> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::ControlHasAssociatedLabel));
+    }
+
+    #[test]
+    fn test_empty_accessible_name_message() {
+        let diags = lint_source(
+            r#"fn c() { html! { <div><a href="/" aria-label=" "></a><input title="" /></div> } }"#,
+        );
+        let messages: Vec<_> = diags.iter().map(|d| d.message.as_str()).collect();
+        assert!(messages.contains(
+            &"<a> element's accessible name from `aria-label` is empty. Links must have discernible text."
+        ));
+        assert!(messages.contains(
+            &"<input> element's accessible name from `title` is empty. Interactive controls must have a text label."
+        ));
+    }
+
     // --- HeadingHasContent ---
 
     #[test]
@@ -2121,6 +5638,107 @@ mod tests {
         assert!(!has_lint(&diags, Rule::InteractiveSupportsFocus));
     }
 
+    #[test]
+    fn test_interactive_role_focusable_mode() {
+        let source =
+            r#"fn c() { html! { <div role="button" onclick={handler} tabindex="-1"></div> } }"#;
+        assert!(has_lint(
+            &lint_source(source),
+            Rule::InteractiveSupportsFocus
+        ));
+
+        let mut options = RuleOptions::default();
+        options.interactive_supports_focus.mode = FocusMode::Focusable;
+        assert!(!has_lint(
+            &lint_source_with(source, &options),
+            Rule::InteractiveSupportsFocus
+        ));
+    }
+
+    // --- LiveRegionValid ---
+
+    #[test]
+    fn test_live_region_problems() {
+        let message = |src: &str| {
+            lint_source(src)
+                .into_iter()
+                .find(|d| d.rule == Rule::LiveRegionValid)
+                .map(|d| d.message)
+        };
+        assert_eq!(
+            message(r#"fn c() { html! { <div role="alert" aria-live="polite">{msg}</div> } }"#)
+                .as_deref(),
+            Some(
+                "Live region <div> `role=\"alert\"` is assertive, but `aria-live=\"polite\"` contradicts it."
+            )
+        );
+        assert!(
+            message(r#"fn c() { html! { <div aria-relevant="additions">{msg}</div> } }"#)
+                .unwrap()
+                .contains("has `aria-relevant` but is not a live region")
+        );
+        let items = "<p>{a}</p>".repeat(6);
+        let big = format!(
+            r#"fn c() {{ html! {{ <section aria-live="assertive">{}</section> }} }}"#,
+            items
+        );
+        assert!(message(&big).unwrap().contains("has 6 child elements"));
+        // Checked on its own, the element's children are not known.
+        let elements = parser::parse_source(&big, "test.rs").unwrap();
+        assert!(
+            Rule::LiveRegionValid
+                .check(&elements[0], &RuleOptions::default())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_live_region_valid_passes() {
+        for src in [
+            r#"fn c() { html! { <div role="log" aria-relevant="additions">{msg}</div> } }"#,
+            r#"fn c() { html! { <div role="status" aria-live="polite">{msg}</div> } }"#,
+            r#"fn c() { html! { <div aria-live="assertive"><p>{a}</p><p>{b}</p></div> } }"#,
+        ] {
+            assert!(
+                !has_lint(&lint_source(src), Rule::LiveRegionValid),
+                "{}",
+                src
+            );
+        }
+        let items = "<p>{a}</p>".repeat(6);
+        let big = format!(
+            r#"fn c() {{ html! {{ <section aria-live="assertive">{}</section> }} }}"#,
+            items
+        );
+        let mut options = RuleOptions::default();
+        options.live_region_valid.max_assertive_children = 10;
+        assert!(!has_lint(
+            &lint_source_with(&big, &options),
+            Rule::LiveRegionValid
+        ));
+    }
+
+    // --- MathHasAlttext ---
+
+    #[test]
+    fn test_math_without_alttext() {
+        let diags = lint_source(r#"fn c() { html! { <math></math> } }"#);
+        assert!(has_lint(&diags, Rule::MathHasAlttext));
+        let diags = lint_source(r#"fn c() { html! { <math>{" "}</math> } }"#);
+        assert!(has_lint(&diags, Rule::MathHasAlttext));
+    }
+
+    #[test]
+    fn test_math_with_alttext_or_fallback_ok() {
+        let diags = lint_source(
+            r#"fn c() { html! { <div><math alttext="x squared">{formula}</math><math><msup><mi>{"x"}</mi><mn>{"2"}</mn></msup></math></div> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::MathHasAlttext));
+        // Dynamic content may render the fallback.
+        let diags = lint_source(r#"fn c() { html! { <math>{formula}</math> } }"#);
+        assert!(!has_lint(&diags, Rule::MathHasAlttext));
+    }
+
     // --- LabelHasAssociatedControl ---
 
     #[test]
@@ -2149,6 +5767,48 @@ mod tests {
         assert!(!has_lint(&diags, Rule::Lang));
     }
 
+    // --- MenuButtonPattern ---
+
+    #[test]
+    fn test_menu_button_pattern_ok() {
+        let src = r#"fn c() { html! {
+            <div>
+                <button aria-haspopup="menu" aria-expanded="true" aria-controls="m">{"File"}</button>
+                <ul id="m" role="menu"></ul>
+                <button aria-haspopup="true" aria-expanded={open}>{"Edit"}</button>
+                <button aria-expanded="false">{"More"}</button>
+                <button aria-haspopup="false">{"Plain"}</button>
+                <input role="combobox" aria-haspopup="listbox" aria-expanded="false" />
+            </div>
+        } }"#;
+        assert!(!has_lint(&lint_source(src), Rule::MenuButtonPattern));
+    }
+
+    #[test]
+    fn test_menu_button_pattern_incomplete() {
+        let src = r#"fn c() { html! {
+            <div>
+                <button aria-haspopup="menu">{"File"}</button>
+                <button aria-expanded="true">{"More"}</button>
+                <div role="button" tabindex="0" aria-expanded="true" aria-controls="gone">{"Less"}</div>
+            </div>
+        } }"#;
+        let diags: Vec<LintDiagnostic> = lint_source(src)
+            .into_iter()
+            .filter(|d| d.rule == Rule::MenuButtonPattern)
+            .collect();
+        let messages: Vec<&str> = diags.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "<button> has `aria-haspopup` but no `aria-expanded` to tell whether its popup is shown.",
+                "<button> is expanded but has no `aria-controls` referencing what it shows.",
+            ]
+        );
+        assert_eq!(diags[0].fixes[0].message, "Add `aria-expanded=\"false\"`");
+        assert!(diags[1].fixes.is_empty());
+    }
+
     // --- MediaHasCaption ---
 
     #[test]
@@ -2193,6 +5853,36 @@ mod tests {
         assert!(has_lint(&diags, Rule::MouseEventsHaveKeyEvents));
     }
 
+    #[test]
+    fn test_mouseenter_and_leave_need_focus_and_blur() {
+        let diags = lint_source(
+            r#"fn c() { html! { <div><div onmouseenter={a}></div><div onmouseleave={a} onblur={b}></div></div> } }"#,
+        );
+        let messages: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == Rule::MouseEventsHaveKeyEvents)
+            .map(|d| d.message.as_str())
+            .collect();
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("`onmouseenter`"));
+    }
+
+    #[test]
+    fn test_hover_in_handlers_option() {
+        let mut options = RuleOptions::default();
+        options.mouse_events_have_key_events.hover_in_handlers = vec!["onPointerEnter".to_string()];
+        let diags = lint_source_with(
+            r#"fn c() { html! { <div><div onmouseover={a}></div><div onpointerenter={a}></div></div> } }"#,
+            &options,
+        );
+        let flagged: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == Rule::MouseEventsHaveKeyEvents)
+            .map(|d| d.message.contains("onpointerenter"))
+            .collect();
+        assert_eq!(flagged, [true]);
+    }
+
     // --- NoAriaHiddenOnFocusable ---
 
     #[test]
@@ -2208,6 +5898,36 @@ mod tests {
         assert!(!has_lint(&diags, Rule::NoAriaHiddenOnFocusable));
     }
 
+    #[test]
+    fn test_aria_hidden_with_focusable_descendant() {
+        let diags = lint_source(
+            r#"fn c() { html! { <div aria-hidden="true"><span aria-hidden="true"><a href="/">{"x"}</a></span></div> } }"#,
+        );
+        let hidden: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == Rule::NoAriaHiddenOnFocusable)
+            .collect();
+        assert_eq!(hidden.len(), 1);
+        assert_eq!(hidden[0].element, Tag::Div);
+        assert!(hidden[0].message.contains("<a>"));
+        // Part of a macro: the <a> is cut off.
+        let elements = parser::parse_source(
+            r#"fn c() { html! { <div aria-hidden="true"><span><a href="/">{"x"}</a></span></div> } }"#,
+            "test.rs",
+        )
+        .unwrap();
+        let rule = Rule::NoAriaHiddenOnFocusable;
+        assert!(rule.check_macro(&elements[..2]).is_empty());
+    }
+
+    #[test]
+    fn test_aria_hidden_with_negative_tabindex_ok() {
+        let diags = lint_source(
+            r#"fn c() { html! { <div aria-hidden="true"><button tabindex="-1">{"x"}</button><a href="/" aria-hidden="true" tabindex="-1"></a></div> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::NoAriaHiddenOnFocusable));
+    }
+
     // --- NoInteractiveElementToNoninteractiveRole ---
 
     #[test]
@@ -2228,6 +5948,46 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_disabled_presentational_control_ok() {
+        let source =
+            r#"fn c() { html! { <button disabled=true role="presentation">{"x"}</button> } }"#;
+        assert!(!has_lint(
+            &lint_source(source),
+            Rule::NoInteractiveElementToNoninteractiveRole
+        ));
+        let mut options = RuleOptions::default();
+        options
+            .no_interactive_element_to_noninteractive_role
+            .allow_presentation_when_disabled = false;
+        assert!(has_lint(
+            &lint_source_with(source, &options),
+            Rule::NoInteractiveElementToNoninteractiveRole
+        ));
+
+        let source = r#"fn c() { html! { <button role="none">{"x"}</button> } }"#;
+        assert!(has_lint(
+            &lint_source(source),
+            Rule::NoInteractiveElementToNoninteractiveRole
+        ));
+        options
+            .no_interactive_element_to_noninteractive_role
+            .allowed_roles
+            .insert("button".to_string(), vec![Role::None]);
+        assert!(!has_lint(
+            &lint_source_with(source, &options),
+            Rule::NoInteractiveElementToNoninteractiveRole
+        ));
+        // `extra-allowed-roles` keeps the defaults.
+        let rule = Rule::NoInteractiveElementToNoninteractiveRole;
+        let mut options = RuleOptions::default();
+        let table = toml::from_str("extra-allowed-roles = { button = [\"none\"] }").unwrap();
+        options.set(&rule, table).unwrap();
+        assert!(!has_lint(&lint_source_with(source, &options), rule));
+        let rule_options = &options.no_interactive_element_to_noninteractive_role;
+        assert!(rule_options.allows(&Tag::Canvas, &Role::Img));
+    }
+
     // --- NoNoninteractiveElementInteractions ---
 
     #[test]
@@ -2265,6 +6025,42 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_widget_pattern_exceptions() {
+        let source = r#"fn c() { html! {
+            <ul role="menu"><li role="menuitem">{"Open"}</li></ul>
+            <ol role="listbox"><li role="option">{"A"}</li></ol>
+            <table role="grid"><tr><td role="gridcell">{"1"}</td></tr></table>
+        } }"#;
+        assert!(!has_lint(
+            &lint_source(source),
+            Rule::NoNoninteractiveElementToInteractiveRole
+        ));
+
+        let source = r#"fn c() { html! { <li role="button">{"x"}</li> } }"#;
+        assert!(has_lint(
+            &lint_source(source),
+            Rule::NoNoninteractiveElementToInteractiveRole
+        ));
+        let mut options = RuleOptions::default();
+        options
+            .no_noninteractive_element_to_interactive_role
+            .allowed_roles
+            .insert("li".to_string(), vec![Role::Button]);
+        assert!(!has_lint(
+            &lint_source_with(source, &options),
+            Rule::NoNoninteractiveElementToInteractiveRole
+        ));
+        // `extra-allowed-roles` keeps the defaults.
+        let rule = Rule::NoNoninteractiveElementToInteractiveRole;
+        let mut options = RuleOptions::default();
+        let table = toml::from_str("extra-allowed-roles = { li = [\"button\"] }").unwrap();
+        options.set(&rule, table).unwrap();
+        assert!(!has_lint(&lint_source_with(source, &options), rule.clone()));
+        let menu = r#"fn c() { html! { <ul role="menu"><li role="menuitem">{"Open"}</li></ul> } }"#;
+        assert!(!has_lint(&lint_source_with(menu, &options), rule));
+    }
+
     // --- NoNoninteractiveTabindex ---
 
     #[test]
@@ -2279,6 +6075,54 @@ mod tests {
         assert!(!has_lint(&diags, Rule::NoNoninteractiveTabindex));
     }
 
+    #[test]
+    fn test_tabindex_on_allowed_role_ok() {
+        let source = r#"fn c() { html! { <div role="tabpanel" tabindex="0"></div> } }"#;
+        assert!(has_lint(
+            &lint_source(source),
+            Rule::NoNoninteractiveTabindex
+        ));
+
+        let mut options = RuleOptions::default();
+        options.no_noninteractive_tabindex.roles = vec![Role::TabPanel];
+        assert!(!has_lint(
+            &lint_source_with(source, &options),
+            Rule::NoNoninteractiveTabindex
+        ));
+    }
+
+    // --- NoHiddenFocusable ---
+
+    #[test]
+    fn test_hidden_element_with_tabindex() {
+        let diags = lint_source(
+            r#"fn c() { html! { <button style="display: none" tabindex="0">{"x"}</button> } }"#,
+        );
+        assert!(has_lint(&diags, Rule::NoHiddenFocusable));
+    }
+
+    #[test]
+    fn test_hidden_element_without_tabindex_ok() {
+        let diags = lint_source(
+            r#"fn c() { html! { <div><div style="visibility: hidden" tabindex="-1"></div><div style="color: red" tabindex="0"></div></div> } }"#,
+        );
+        assert!(!has_lint(&diags, Rule::NoHiddenFocusable));
+    }
+
+    #[test]
+    fn test_hidden_class_with_tabindex() {
+        let mut options = parser::ParseOptions::default();
+        options.classes.hidden.push("d-none".to_string());
+        let elements = parser::parse_source_with(
+            r#"fn c() { html! { <div class="card d-none" tabindex="0"></div> } }"#,
+            "test.rs",
+            &options,
+        )
+        .unwrap()
+        .elements;
+        assert_eq!(Rule::NoHiddenFocusable.check_all(&elements).len(), 1);
+    }
+
     // --- NoStaticElementInteractions ---
 
     #[test]
@@ -2294,6 +6138,25 @@ mod tests {
         assert!(!has_lint(&diags, Rule::NoStaticElementInteractions));
     }
 
+    #[test]
+    fn test_static_element_interactions_options() {
+        let src = r#"fn c() { html! { <div><div role={role} onclick={a}></div><div onmouseover={a}></div></div> } }"#;
+        let count = |options: &RuleOptions| {
+            lint_source_with(src, options)
+                .iter()
+                .filter(|d| d.rule == Rule::NoStaticElementInteractions)
+                .count()
+        };
+        let mut options = RuleOptions::default();
+        assert_eq!(count(&options), 1);
+        options
+            .no_static_element_interactions
+            .allow_expression_values = false;
+        assert_eq!(count(&options), 2);
+        options.no_static_element_interactions.handlers = Some(vec!["onClick".to_string()]);
+        assert_eq!(count(&options), 1);
+    }
+
     // --- PreferTagOverRole ---
 
     #[test]
@@ -2355,4 +6218,42 @@ mod tests {
         let diags = lint_source(r#"fn c() { html! { <th scope="col">{"Header"}</th> } }"#);
         assert!(!has_lint(&diags, Rule::Scope));
     }
+
+    // --- RuleIndex ---
+
+    #[test]
+    fn test_rule_index_skips_only_rules_that_cannot_fire() {
+        let sources = [
+            include_str!("../tests/fixtures/yew_component.rs"),
+            include_str!("../tests/fixtures/leptos_component.rs"),
+            r#"fn c() { html! {
+                <div aria-foo="x" role="button" dir="up" lang="xx" style="color: #777; background: #888">
+                    <button role="presentation" disabled=true></button>
+                    <input type="button" accesskey="s" tabindex="2" />
+                    <span onmouseover={show} title="t" aria-hidden="true" tabindex="0">{"x"}</span>
+                    <td scope="row"></td>
+                </div>
+            } }"#,
+        ];
+        let options = RuleOptions::default();
+        for source in sources {
+            let elements = parser::parse_source(source, "test.rs").unwrap();
+            let indexed: Vec<_> = elements
+                .iter()
+                .flat_map(|element| {
+                    let cx = LintContext::new(element, &options);
+                    RuleIndex::builtin()
+                        .candidates(&cx)
+                        .filter_map(|rule| rule.check_with(&cx))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            let all: Vec<_> = elements
+                .iter()
+                .flat_map(|element| Rule::iter().filter_map(|rule| rule.check(element, &options)))
+                .collect();
+            assert!(!all.is_empty());
+            assert_eq!(indexed, all);
+        }
+    }
 }
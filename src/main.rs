@@ -1,25 +1,39 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::File;
-use std::io::{self, BufWriter, Write};
+use std::io::{self, BufWriter, IsTerminal, Write};
 use std::path::{Path, PathBuf};
 use std::process;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
-use clap::Parser;
-use rayon::prelude::*;
+use clap::builder::PossibleValuesParser;
+use clap::{CommandFactory, Parser};
+use colored::Colorize;
 use strum::IntoEnumIterator;
-use walkdir::WalkDir;
 
-use rsx_a11y::diagnostics::{self, OutputFormat};
-use rsx_a11y::lints::{self, LintDiagnostic, Rule};
-use rsx_a11y::parser;
+use rsx_a11y::config::Config;
+use rsx_a11y::diagnostics::{self, ColorChoice, OutputFormat};
+use rsx_a11y::lints::{self, LintDiagnostic, Rule, Severity};
+use rsx_a11y::parser::{self, ParseOptions};
+use rsx_a11y::runner::{LintRunner, ProgressHandler};
+use rsx_a11y::{LintSummary, workspace};
+use rsx_a11y::{bench, eslint, fix, i18n, inventory, score};
 
 /// rsx-a11y: Lint ARIA and accessibility attributes in Rust web frameworks.
 ///
 /// Checks `html!` (Yew), `view!` (Leptos), and `rsx!` (Dioxus) macros for
 /// accessibility issues based on the WAI-ARIA specifications.
 #[derive(Parser, Debug)]
-#[command(name = "rsx-a11y", version, about, long_about = None)]
+#[command(
+    name = "rsx-a11y",
+    version,
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true
+)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Path to a Rust file or directory to lint.
     #[arg(default_value = ".")]
     path: PathBuf,
@@ -28,33 +42,270 @@ struct Cli {
     #[arg(long, value_enum, default_value = "pretty")]
     format: Format,
 
-    /// Only show errors (hide warnings and info).
-    #[arg(short, long)]
+    /// Only report diagnostics at or above this severity.
+    #[arg(long, value_enum, value_name = "LEVEL", default_value = "info")]
+    min_severity: MinSeverity,
+
+    /// Deprecated alias for `--min-severity error`.
+    #[arg(short, long, hide = true)]
     quiet: bool,
 
+    /// Log more on stderr: `-v` lists skipped files and the config in use,
+    /// `-vv` also logs each file as it is linted.
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// When to color output. `auto` colors terminal output unless `NO_COLOR`
+    /// is set; `CLICOLOR_FORCE` forces colors.
+    #[arg(long, value_enum, value_name = "WHEN", default_value = "auto")]
+    color: Color,
+
     /// List all available lint rules and exit.
     #[arg(long)]
     list_rules: bool,
 
     /// Specific lint rules to enable (comma-separated). If not set, all rules are enabled.
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, value_delimiter = ',', value_parser = RuleIdParser, hide_possible_values = true)]
     only: Option<Vec<String>>,
 
     /// Lint rules to disable (comma-separated).
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, value_delimiter = ',', value_parser = RuleIdParser, hide_possible_values = true)]
     skip: Option<Vec<String>>,
 
-    /// Write diagnostic output to a file instead of stdout (useful for snapshot testing).
+    /// Write diagnostic output to a file instead of stdout (useful for
+    /// snapshot testing). A pretty summary is still printed to stdout.
     #[arg(long)]
     out_file: Option<PathBuf>,
+
+    /// Also write a report in FORMAT to PATH (`-` for stdout), e.g.
+    /// `json=report.json` (repeatable).
+    #[arg(long = "output", value_name = "FORMAT=PATH", value_parser = parse_output)]
+    outputs: Vec<OutputTarget>,
+
+    /// Path to a config file. If not set, `rsx-a11y.toml` is searched for in PATH and its ancestors.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Print the fully-resolved configuration for PATH and exit.
+    #[arg(long)]
+    print_config: bool,
+
+    /// Print the parsed element tree of the file at PATH, with positions
+    /// and attribute value kinds, and exit. For debugging rules.
+    #[arg(long, hide = true, conflicts_with = "print_config")]
+    dump_ast: bool,
+
+    /// Skip files larger than this many bytes (suffixes K, M and G are
+    /// accepted; 0 means no limit).
+    #[arg(long, value_name = "SIZE", default_value = "10M", value_parser = parse_size)]
+    max_file_size: u64,
+
+    /// Follow symlinked files and directories. Symlink loops are skipped and
+    /// files reachable through several links are linted once.
+    #[arg(long)]
+    follow_symlinks: bool,
+
+    /// Lint generated files (marked `@generated` or `DO NOT EDIT`, or
+    /// matching the config's `generated` patterns) instead of skipping them.
+    #[arg(long)]
+    include_generated: bool,
+
+    /// Also lint RSX written inside `macro_rules!` wrappers around `view!`,
+    /// `html!` or `rsx!`, with the macro's `$arguments` as dynamic values.
+    #[arg(long)]
+    expand: bool,
+
+    /// Apply fixes that are safe to make without review, then report the
+    /// remaining diagnostics.
+    #[arg(long, conflicts_with = "print_config")]
+    fix: bool,
+
+    /// With --fix, print a unified diff of the changes instead of writing
+    /// them (e.g. to review in CI or pipe to `git apply`).
+    #[arg(long, requires = "fix")]
+    dry_run: bool,
+
+    /// Walk through each fixable diagnostic, show the proposed change, and
+    /// ask whether to apply it.
+    #[arg(long, conflicts_with_all = ["format", "out_file", "outputs", "print_config", "fix"])]
+    fix_interactive: bool,
+
+    /// Lint the `src/` directory of every Cargo workspace member at PATH.
+    #[arg(long)]
+    workspace: bool,
+
+    /// Lint only this workspace member's `src/` directory (repeatable).
+    #[arg(
+        short,
+        long = "package",
+        value_name = "NAME",
+        conflicts_with = "workspace"
+    )]
+    packages: Vec<String>,
+
+    /// Print parse, lint and per-rule times on stderr after the report; with
+    /// `--format json` they are the report's `timings` field.
+    #[arg(long)]
+    stats: bool,
+
+    /// Print on stderr why elements passed the rules that can say, such as
+    /// which attribute gave a link its accessible name; with `--format json`
+    /// they are the report's `passes` field.
+    #[arg(long)]
+    verbose_passes: bool,
+
+    /// Language of diagnostic messages and help; rules without a
+    /// translation stay in English.
+    #[arg(long, default_value = "en", value_parser = PossibleValuesParser::new(i18n::BUILTIN_LANGS))]
+    lang: String,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Translate eslint-plugin-jsx-a11y settings from an `.eslintrc.json` file into an rsx-a11y config.
+    MigrateEslint {
+        /// Path to the ESLint config file.
+        input: PathBuf,
+
+        /// Write the config to a file instead of stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Print a shell completion script, e.g. `rsx-a11y completions bash > /etc/bash_completion.d/rsx-a11y`.
+    Completions {
+        /// Shell to generate completions for.
+        shell: clap_complete::Shell,
+    },
+    /// Lint the files at PATH several times and report average parse, lint and per-rule times.
+    Bench {
+        /// Path to a Rust file or directory to lint.
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Number of times to lint the files.
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Path to a config file. If not set, `rsx-a11y.toml` is searched for in PATH and its ancestors.
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Follow symlinked files and directories.
+        #[arg(long)]
+        follow_symlinks: bool,
+    },
+    /// Lint the files at PATH and print an accessibility score from 0 to 100, overall and per file.
+    Score {
+        /// Path to a Rust file or directory to lint.
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output format.
+        #[arg(long, value_enum, default_value = "pretty")]
+        format: Format,
+
+        /// Path to a config file. If not set, `rsx-a11y.toml` is searched for in PATH and its ancestors.
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Exit with status 1 if the overall score is below this.
+        #[arg(long, value_name = "SCORE")]
+        min: Option<f64>,
+
+        /// Follow symlinked files and directories.
+        #[arg(long)]
+        follow_symlinks: bool,
+    },
+    /// List every parsed element with its tag, attributes, role and position, without linting.
+    Inventory {
+        /// Path to a Rust file or directory.
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output format.
+        #[arg(long, value_enum, default_value = "pretty")]
+        format: Format,
+
+        /// Path to a config file. If not set, `rsx-a11y.toml` is searched for in PATH and its ancestors.
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Follow symlinked files and directories.
+        #[arg(long)]
+        follow_symlinks: bool,
+    },
+}
+
+/// Accepts any rule id (including eslint-style and deprecated ones, which are
+/// resolved later) but offers the current ids to shell completion.
+#[derive(Debug, Clone, Copy)]
+struct RuleIdParser;
+
+impl clap::builder::TypedValueParser for RuleIdParser {
+    type Value = String;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<String, clap::Error> {
+        clap::builder::StringValueParser::new().parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(
+        &self,
+    ) -> Option<Box<dyn Iterator<Item = clap::builder::PossibleValue> + '_>> {
+        Some(Box::new(Rule::iter().map(|rule| {
+            clap::builder::PossibleValue::new(rule.to_string()).help(rule.description())
+        })))
+    }
 }
 
+/// Scans with at least this many files show a progress bar instead of a
+/// "Scanning N file(s)..." line.
+const PROGRESS_BAR_MIN_FILES: usize = 200;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 enum Format {
     Pretty,
     Json,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum MinSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl From<MinSeverity> for Severity {
+    fn from(s: MinSeverity) -> Self {
+        match s {
+            MinSeverity::Error => Severity::Error,
+            MinSeverity::Warning => Severity::Warning,
+            MinSeverity::Info => Severity::Info,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Color {
+    Auto,
+    Always,
+    Never,
+}
+
+impl From<Color> for ColorChoice {
+    fn from(c: Color) -> Self {
+        match c {
+            Color::Auto => ColorChoice::Auto,
+            Color::Always => ColorChoice::Always,
+            Color::Never => ColorChoice::Never,
+        }
+    }
+}
+
 impl From<Format> for OutputFormat {
     fn from(f: Format) -> Self {
         match f {
@@ -66,12 +317,67 @@ impl From<Format> for OutputFormat {
 
 fn main() {
     let cli = Cli::parse();
+    ColorChoice::from(cli.color).apply(cli.out_file.is_none() && io::stdout().is_terminal());
+
+    match cli.command {
+        Some(Command::MigrateEslint { input, output }) => {
+            migrate_eslint(&input, output.as_deref());
+            process::exit(0);
+        }
+        Some(Command::Completions { shell }) => {
+            clap_complete::generate(shell, &mut Cli::command(), "rsx-a11y", &mut io::stdout());
+            process::exit(0);
+        }
+        Some(Command::Bench {
+            path,
+            iterations,
+            config,
+            follow_symlinks,
+        }) => {
+            bench(&path, iterations, config.as_deref(), follow_symlinks);
+            process::exit(0);
+        }
+        Some(Command::Score {
+            path,
+            format,
+            config,
+            min,
+            follow_symlinks,
+        }) => {
+            score(&path, format, config.as_deref(), min, follow_symlinks);
+        }
+        Some(Command::Inventory {
+            path,
+            format,
+            config,
+            follow_symlinks,
+        }) => {
+            inventory(&path, format, config.as_deref(), follow_symlinks);
+            process::exit(0);
+        }
+        None => {}
+    }
 
     if cli.list_rules {
         println!("Available lint rules:");
         println!();
         for rule in Rule::iter() {
-            println!("  {}", rule.to_string());
+            if rule.enabled_by_default() {
+                println!("  {}", rule);
+            } else {
+                println!("  {} (opt-in)", rule);
+            }
+        }
+        if !lints::RENAMED_RULES.is_empty() {
+            println!();
+            println!("Deprecated rule ids:");
+            println!();
+            for renamed in lints::RENAMED_RULES {
+                println!(
+                    "  {} -> {} (since {})",
+                    renamed.id, renamed.replacement, renamed.since
+                );
+            }
         }
         process::exit(0);
     }
@@ -87,20 +393,113 @@ fn main() {
     // Refuse to scan filesystem roots — almost certainly a mistake.
     // On Windows, "/" resolves to the current drive root (e.g. C:\), not
     // the current directory. Use "." for the current directory instead.
-    if let Ok(canonical) = dunce::canonicalize(path) {
-        if canonical.parent().is_none() {
+    if let Ok(canonical) = dunce::canonicalize(path)
+        && canonical.parent().is_none()
+    {
+        eprintln!(
+            "Error: '{}' resolves to filesystem root '{}'. Did you mean '.'?",
+            path.display(),
+            canonical.display()
+        );
+        process::exit(1);
+    }
+
+    let config = match cli.config {
+        Some(ref config_path) => Config::load(config_path),
+        None => Config::discover(path),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    });
+
+    for warning in &config.warnings {
+        eprintln!("warning: {}", warning);
+    }
+    if cli.verbose >= 1 {
+        match config.path {
+            Some(ref config_path) => eprintln!("Using config {}", config_path.display()),
+            None => eprintln!("No config file found; using defaults"),
+        }
+    }
+
+    let only: Option<Vec<Rule>> = cli.only.as_deref().map(parse_rule_ids);
+    let skip: Option<Vec<Rule>> = cli.skip.as_deref().map(parse_rule_ids);
+
+    if cli.print_config {
+        let mut resolved = config.resolve(path);
+        if let Some(ref only) = only {
+            resolved.apply_only(only);
+        }
+        if let Some(ref skip) = skip {
+            resolved.apply_skip(skip);
+        }
+        let mut stdout = io::stdout().lock();
+        diagnostics::print_config(&resolved, format, &mut stdout);
+        process::exit(0);
+    }
+
+    if cli.dump_ast {
+        if !path.is_file() {
             eprintln!(
-                "Error: '{}' resolves to filesystem root '{}'. Did you mean '.'?",
-                path.display(),
-                canonical.display()
+                "Error: --dump-ast needs a single file, not '{}'.",
+                path.display()
             );
             process::exit(1);
         }
+        let parse_options = ParseOptions {
+            expand_macro_rules: cli.expand,
+            ..config.parse_options()
+        };
+        match parser::parse_file_with(path, &parse_options) {
+            Ok(parsed) => diagnostics::print_ast(&parsed, &mut io::stdout().lock()),
+            Err(e) => {
+                eprintln!("Parse error: {}", e);
+                process::exit(1);
+            }
+        }
+        process::exit(0);
     }
 
+    let min_severity = if cli.quiet {
+        eprintln!("warning: --quiet is deprecated; use --min-severity error");
+        Severity::Error
+    } else {
+        cli.min_severity.into()
+    };
+
     let start_time = std::time::Instant::now();
 
-    let rust_files = collect_rust_files(path);
+    // Files of each selected workspace member, keyed by diagnostic file path.
+    let mut file_packages: HashMap<String, String> = HashMap::new();
+    // Canonical paths already collected, so linked files are linted once.
+    let mut seen = HashSet::new();
+    let rust_files = if cli.workspace || !cli.packages.is_empty() {
+        let manifest_path = if path.is_dir() {
+            path.join("Cargo.toml")
+        } else {
+            path.to_path_buf()
+        };
+        let packages = workspace::members(Some(&manifest_path))
+            .and_then(|members| workspace::select(members, &cli.packages))
+            .unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            });
+        let mut files = Vec::new();
+        for package in packages {
+            for file in collect_rust_files(&package.src_dir(), cli.follow_symlinks, &mut seen) {
+                file_packages.insert(
+                    file.to_string_lossy().replace('\\', "/"),
+                    package.name.clone(),
+                );
+                files.push(file);
+            }
+        }
+        files
+    } else {
+        collect_rust_files(path, cli.follow_symlinks, &mut seen)
+    };
 
     if rust_files.is_empty() {
         if format == OutputFormat::Pretty {
@@ -109,186 +508,904 @@ fn main() {
         process::exit(0);
     }
 
-    if format == OutputFormat::Pretty {
-        eprintln!("Scanning {} file(s)...", rust_files.len());
+    let mut runner = LintRunner::new()
+        .config(config)
+        .include_generated(cli.include_generated)
+        .expand_macro_rules(cli.expand)
+        .timings(cli.stats)
+        .verbose_passes(cli.verbose_passes);
+    if cli.max_file_size > 0 {
+        runner = runner.max_file_size(cli.max_file_size);
+    }
+    if let Some(only) = only {
+        runner = runner.only(only);
+    }
+    if let Some(skip) = skip {
+        runner = runner.skip(skip);
+    }
+    // Only draw a progress bar for large scans on an interactive terminal.
+    let progress_bar = ProgressBar::new();
+    if cli.verbose >= 2 {
+        runner = runner.progress(&FileLog);
+    } else if format == OutputFormat::Pretty {
+        if rust_files.len() >= PROGRESS_BAR_MIN_FILES && io::stderr().is_terminal() {
+            runner = runner.progress(&progress_bar);
+        } else {
+            eprintln!("Scanning {} file(s)...", rust_files.len());
+        }
     }
 
-    let CliLintSummary {
-        diagnostics: all_diagnostics,
+    let LintSummary {
+        diagnostics: mut all_diagnostics,
         parse_errors,
+        macro_errors,
         files_checked,
-    } = parse_files(
-        &rust_files,
-        cli.only
-            .as_ref()
-            .map(|only| only.iter().filter_map(|s| Rule::from_str(s)).collect()),
-        cli.skip
-            .as_ref()
-            .map(|skip| skip.iter().filter_map(|s| Rule::from_str(s)).collect()),
-        cli.quiet,
-    );
+        skipped_files,
+        generated_files,
+        timings,
+        passes,
+        ..
+    } = runner.run_files(&rust_files);
+    all_diagnostics.retain(|d| d.severity >= min_severity);
+    let catalog =
+        i18n::Catalog::builtin(&cli.lang).expect("--lang only accepts built-in languages");
+    all_diagnostics = all_diagnostics
+        .into_iter()
+        .map(|d| catalog.translate(d))
+        .collect();
 
-    // Build writer: either a file or stdout.
-    let mut writer: Box<dyn Write> = match cli.out_file {
-        Some(ref path) => {
-            let file = File::create(path).unwrap_or_else(|e| {
-                eprintln!("Error: could not create '{}': {}", path.display(), e);
-                process::exit(1);
-            });
-            Box::new(BufWriter::new(file))
+    if cli.fix_interactive {
+        if !io::stdin().is_terminal() {
+            eprintln!("Error: --fix-interactive needs an interactive terminal.");
+            process::exit(1);
         }
-        None => Box::new(BufWriter::new(io::stdout().lock())),
-    };
+        fix_interactively(&all_diagnostics);
+        process::exit(0);
+    }
+
+    // The main report goes to --out-file or stdout; with --out-file, stdout
+    // still gets a pretty summary unless an --output already writes there.
+    let mut targets = vec![(
+        OutputTarget {
+            format: cli.format,
+            path: cli.out_file.clone(),
+        },
+        false,
+    )];
+    targets.extend(cli.outputs.iter().map(|target| (target.clone(), false)));
+    if cli.out_file.is_some() && !targets.iter().any(|(t, _)| t.path.is_none()) {
+        let stdout = OutputTarget {
+            format: Format::Pretty,
+            path: None,
+        };
+        targets.push((stdout, true));
+    }
+    let mut outputs: Vec<Output> = targets
+        .into_iter()
+        .map(|(target, summary_only)| Output::create(target, summary_only))
+        .collect();
+    let writer = &mut outputs[0].writer;
+
+    if cli.fix {
+        let fixes = safe_fixes(&all_diagnostics);
+        if cli.dry_run {
+            for (file, (source, edits, _)) in &fixes {
+                match fix::unified_diff(file, source, edits) {
+                    Ok(diff) => {
+                        let _ = write!(writer, "{}", diff);
+                    }
+                    Err(e) => eprintln!("Error: could not fix '{}': {}", file, e),
+                }
+            }
+            let _ = writer.flush();
+            process::exit(0);
+        }
+        let mut fixed = Vec::new();
+        let mut files_changed = 0;
+        for (file, (source, edits, indices)) in &fixes {
+            if write_fixes(file, source, edits) == 1 {
+                files_changed += 1;
+                fixed.extend(indices);
+            }
+        }
+        if format == OutputFormat::Pretty {
+            eprintln!(
+                "Fixed {} issue{} in {} file{}.",
+                fixed.len(),
+                if fixed.len() == 1 { "" } else { "s" },
+                files_changed,
+                if files_changed == 1 { "" } else { "s" }
+            );
+        }
+        let mut index = 0;
+        all_diagnostics.retain(|_| {
+            index += 1;
+            !fixed.contains(&(index - 1))
+        });
+    }
 
-    diagnostics::print_diagnostics(&all_diagnostics, format, &mut *writer);
+    let color = ColorChoice::from(cli.color);
+    for output in outputs.iter_mut().filter(|o| !o.summary_only) {
+        color.apply(output.is_terminal);
+        if output.format == OutputFormat::Json {
+            print_json_report(
+                &all_diagnostics,
+                &file_packages,
+                timings.as_ref(),
+                cli.verbose_passes.then_some(passes.as_slice()),
+                &mut *output.writer,
+            );
+        } else {
+            diagnostics::print_diagnostics(&all_diagnostics, output.format, &mut *output.writer);
+        }
+    }
 
     if format == OutputFormat::Pretty {
         for err in &parse_errors {
             eprintln!("Parse error: {}", err);
         }
+        for err in &macro_errors {
+            eprintln!("warning: {}", err);
+        }
+    }
+    if cli.verbose >= 1 {
+        for skipped in &skipped_files {
+            eprintln!("Skipped {}: {}", skipped.file, skipped.reason);
+        }
+        for file in &generated_files {
+            eprintln!("Skipped {}: generated", file);
+        }
+    }
+    if format == OutputFormat::Pretty {
+        if cli.verbose == 0 && !skipped_files.is_empty() {
+            eprintln!(
+                "Skipped {} file{} that {} too large or not UTF-8 text (use -v to list them).",
+                skipped_files.len(),
+                if skipped_files.len() == 1 { "" } else { "s" },
+                if skipped_files.len() == 1 {
+                    "is"
+                } else {
+                    "are"
+                }
+            );
+        }
+        if !generated_files.is_empty() {
+            eprintln!(
+                "Skipped {} generated file{} (use --include-generated to lint them).",
+                generated_files.len(),
+                if generated_files.len() == 1 { "" } else { "s" }
+            );
+        }
     }
 
-    diagnostics::print_summary(
-        &all_diagnostics,
-        files_checked,
-        start_time.elapsed(),
-        format,
-        &mut *writer,
-    );
+    let elapsed = start_time.elapsed();
+    for output in &mut outputs {
+        color.apply(output.is_terminal);
+        diagnostics::print_summary(
+            &all_diagnostics,
+            files_checked,
+            elapsed,
+            output.format,
+            &mut *output.writer,
+        );
+        // `process::exit` skips destructors, so flush buffered output explicitly.
+        let _ = output.writer.flush();
+    }
+    // With --format json, stats and passes are part of the JSON report.
+    if format != OutputFormat::Json {
+        if let Some(ref timings) = timings {
+            eprintln!();
+            eprintln!("Times summed over all files and threads:");
+            print_timings(timings);
+        }
+        if cli.verbose_passes {
+            for pass in &passes {
+                eprintln!(
+                    "{}:{}:{}: pass[{}]: <{}> {}",
+                    pass.file, pass.line, pass.column, pass.rule, pass.element, pass.reason
+                );
+            }
+        }
+    }
 
     // Exit with non-zero if there are errors
     let has_errors = all_diagnostics
         .iter()
-        .any(|d| d.severity == lints::Severity::Error);
+        .any(|d| d.severity == Severity::Error);
     if has_errors {
         process::exit(1);
     }
 }
 
-struct CliLintSummary {
-    diagnostics: Vec<LintDiagnostic>,
-    parse_errors: Vec<String>,
-    files_checked: usize,
-}
-
-fn parse_files(
-    rust_files: &[PathBuf],
-    only: Option<Vec<Rule>>,
-    skip: Option<Vec<Rule>>,
-    only_errors: bool,
-) -> CliLintSummary {
-    let files_checked = AtomicUsize::new(0);
-
-    // Process files in parallel with rayon.
-    // Use fold + reduce to accumulate diagnostics directly, avoiding an
-    // intermediate Vec<Result<…>> allocation.
-    let (mut all_diagnostics, parse_errors) = rust_files
-        .par_iter()
-        .fold(
-            || (Vec::new(), Vec::new()),
-            |(mut diags, mut errors), file| {
-                match parser::parse_file(file) {
-                    Ok(elements) => {
-                        if !elements.is_empty() {
-                            files_checked.fetch_add(1, Ordering::Relaxed);
-
-                            // Build a lazy iterator chain — filters run without
-                            // allocating an intermediate Vec.
-                            let file_diags = lints::run_all_lints(&elements)
-                                .filter(|d| {
-                                    only.as_ref()
-                                        .map_or(true, |only| only.iter().any(|o| *o == d.rule))
-                                })
-                                .filter(|d| {
-                                    skip.as_ref()
-                                        .map_or(true, |skip| !skip.iter().any(|o| *o == d.rule))
-                                })
-                                .filter(|d| !only_errors || d.severity == lints::Severity::Error);
-
-                            diags.extend(file_diags);
-                        }
+/// A report destination from `--output FORMAT=PATH`.
+#[derive(Debug, Clone)]
+struct OutputTarget {
+    format: Format,
+    /// `None` for stdout.
+    path: Option<PathBuf>,
+}
+
+/// Parse an `--output` value such as `json=report.json` or `pretty=-`.
+fn parse_output(value: &str) -> Result<OutputTarget, String> {
+    let (format, path) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected FORMAT=PATH, got \"{}\"", value))?;
+    let format = <Format as clap::ValueEnum>::from_str(format, true)
+        .map_err(|_| format!("unknown format \"{}\" (expected pretty or json)", format))?;
+    let path = (path != "-").then(|| PathBuf::from(path));
+    Ok(OutputTarget { format, path })
+}
+
+/// An open report destination.
+struct Output {
+    format: OutputFormat,
+    writer: Box<dyn Write>,
+    /// Whether `writer` is a terminal, for coloring.
+    is_terminal: bool,
+    /// Only print the summary, not the diagnostics.
+    summary_only: bool,
+}
+
+impl Output {
+    /// Open `target`, exiting if its file cannot be created.
+    fn create(target: OutputTarget, summary_only: bool) -> Self {
+        let (writer, is_terminal): (Box<dyn Write>, bool) = match target.path {
+            Some(ref path) => {
+                let file = File::create(path).unwrap_or_else(|e| {
+                    eprintln!("Error: could not create '{}': {}", path.display(), e);
+                    process::exit(1);
+                });
+                (Box::new(BufWriter::new(file)), false)
+            }
+            None => (
+                Box::new(BufWriter::new(io::stdout())),
+                io::stdout().is_terminal(),
+            ),
+        };
+        Output {
+            format: target.format.into(),
+            writer,
+            is_terminal,
+            summary_only,
+        }
+    }
+}
+
+/// Print the JSON report: an object holding the `diagnostics` array, with
+/// the `timings` of `--stats` and the `passes` of `--verbose-passes` next to
+/// it. In workspace mode each diagnostic has a `package` field naming the
+/// member it was found in.
+fn print_json_report(
+    diagnostics: &[LintDiagnostic],
+    file_packages: &HashMap<String, String>,
+    timings: Option<&bench::Timings>,
+    passes: Option<&[lints::LintPass]>,
+    w: &mut dyn Write,
+) {
+    #[derive(serde::Serialize)]
+    struct PackageDiagnostic<'a> {
+        /// Only present in workspace mode, `null` for files outside packages.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        package: Option<Option<&'a str>>,
+        #[serde(flatten)]
+        diagnostic: &'a LintDiagnostic,
+    }
+
+    #[derive(serde::Serialize)]
+    struct Report<'a> {
+        diagnostics: Vec<PackageDiagnostic<'a>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        timings: Option<&'a bench::Timings>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        passes: Option<&'a [lints::LintPass]>,
+    }
+
+    let diagnostics: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| PackageDiagnostic {
+            package: (!file_packages.is_empty())
+                .then(|| file_packages.get(&diagnostic.file).map(String::as_str)),
+            diagnostic,
+        })
+        .collect();
+    let report = Report {
+        diagnostics,
+        timings,
+        passes,
+    };
+    let json = serde_json::to_string_pretty(&report).unwrap_or_else(|e| {
+        eprintln!("Failed to serialize diagnostics to JSON: {}", e);
+        "{ \"diagnostics\": [] }".to_string()
+    });
+    let _ = writeln!(w, "{}", json);
+}
+
+/// Parse a `--max-file-size` value such as `4096`, `512K` or `10M`.
+fn parse_size(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let (digits, multiplier) = match value.char_indices().last() {
+        Some((i, 'K' | 'k')) => (&value[..i], 1 << 10),
+        Some((i, 'M' | 'm')) => (&value[..i], 1 << 20),
+        Some((i, 'G' | 'g')) => (&value[..i], 1 << 30),
+        _ => (value, 1),
+    };
+    digits
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .ok_or_else(|| {
+            format!(
+                "invalid size \"{}\" (expected e.g. 4096, 512K or 10M)",
+                value
+            )
+        })
+}
+
+/// Parse `--only` / `--skip` rule ids, warning about deprecated ones.
+fn parse_rule_ids(ids: &[String]) -> Vec<Rule> {
+    ids.iter()
+        .filter_map(|id| {
+            let (rule, renamed) = Rule::resolve(id)?;
+            if let Some(renamed) = renamed {
+                eprintln!("warning: {}", renamed.warning());
+            }
+            Some(rule)
+        })
+        .collect()
+}
+
+/// Run the `migrate-eslint` subcommand, exiting on failure.
+fn migrate_eslint(input: &Path, output: Option<&Path>) {
+    let source = std::fs::read_to_string(input).unwrap_or_else(|e| {
+        eprintln!("Error: could not read '{}': {}", input.display(), e);
+        process::exit(1);
+    });
+    let migration = eslint::migrate(&source).unwrap_or_else(|e| {
+        eprintln!("Error: {}: {}", input.display(), e);
+        process::exit(1);
+    });
+    let body = toml::to_string_pretty(&migration.config).unwrap_or_else(|e| {
+        eprintln!("Error: could not serialize config: {}", e);
+        process::exit(1);
+    });
+    let contents = format!(
+        "# Generated by `rsx-a11y migrate-eslint` from {}\n\n{}",
+        input.display(),
+        body
+    );
+
+    for note in &migration.notes {
+        eprintln!("note: {}", note);
+    }
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, contents).unwrap_or_else(|e| {
+                eprintln!("Error: could not write '{}': {}", path.display(), e);
+                process::exit(1);
+            });
+            eprintln!("Wrote {}", path.display());
+        }
+        None => print!("{}", contents),
+    }
+}
+
+/// The `bench` subcommand.
+fn bench(path: &Path, iterations: usize, config_path: Option<&Path>, follow_symlinks: bool) {
+    if !path.exists() {
+        eprintln!("Error: path '{}' does not exist.", path.display());
+        process::exit(1);
+    }
+    let config = match config_path {
+        Some(config_path) => Config::load(config_path),
+        None => Config::discover(path),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    });
+    let files = collect_rust_files(path, follow_symlinks, &mut HashSet::new());
+    if files.is_empty() {
+        eprintln!("No Rust files found in '{}'.", path.display());
+        process::exit(1);
+    }
+
+    let report = bench::run(&files, &config, iterations);
+    eprintln!(
+        "{} file{}, {} element{}, {} iteration{}; average per iteration:",
+        report.files,
+        if report.files == 1 { "" } else { "s" },
+        report.elements,
+        if report.elements == 1 { "" } else { "s" },
+        report.iterations,
+        if report.iterations == 1 { "" } else { "s" }
+    );
+    print_timings(&report.timings);
+}
+
+fn score(
+    path: &Path,
+    format: Format,
+    config_path: Option<&Path>,
+    min: Option<f64>,
+    follow_symlinks: bool,
+) {
+    if !path.exists() {
+        eprintln!("Error: path '{}' does not exist.", path.display());
+        process::exit(1);
+    }
+    let config = match config_path {
+        Some(config_path) => Config::load(config_path),
+        None => Config::discover(path),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    });
+    let files = collect_rust_files(path, follow_symlinks, &mut HashSet::new());
+    let summary = LintRunner::new().config(config).run_files(&files);
+    let report = score::score(&summary);
+    let mut out = io::stdout().lock();
+    match format {
+        Format::Json => match serde_json::to_string_pretty(&report) {
+            Ok(json) => {
+                let _ = writeln!(out, "{}", json);
+            }
+            Err(e) => {
+                eprintln!("Failed to serialize score to JSON: {}", e);
+                process::exit(1);
+            }
+        },
+        Format::Pretty => {
+            let _ = writeln!(
+                out,
+                "Score: {:.1} ({} element{}, {} diagnostic{})",
+                report.score,
+                report.elements,
+                if report.elements == 1 { "" } else { "s" },
+                report.diagnostics,
+                if report.diagnostics == 1 { "" } else { "s" }
+            );
+            if !report.files.is_empty() {
+                let _ = writeln!(out);
+            }
+            for file in &report.files {
+                let _ = writeln!(
+                    out,
+                    "  {:>5.1}  {} ({} element{}, {} diagnostic{})",
+                    file.score,
+                    file.file,
+                    file.elements,
+                    if file.elements == 1 { "" } else { "s" },
+                    file.diagnostics,
+                    if file.diagnostics == 1 { "" } else { "s" }
+                );
+            }
+        }
+    }
+    let _ = out.flush();
+    if let Some(min) = min
+        && report.score < min
+    {
+        eprintln!("Score {:.1} is below the minimum of {}.", report.score, min);
+        process::exit(1);
+    }
+    process::exit(0);
+}
+
+fn inventory(path: &Path, format: Format, config_path: Option<&Path>, follow_symlinks: bool) {
+    if !path.exists() {
+        eprintln!("Error: path '{}' does not exist.", path.display());
+        process::exit(1);
+    }
+    let config = match config_path {
+        Some(config_path) => Config::load(config_path),
+        None => Config::discover(path),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    });
+    let files = collect_rust_files(path, follow_symlinks, &mut HashSet::new());
+    let parse_options = ParseOptions {
+        skip_generated: true,
+        ..config.parse_options()
+    };
+    let inventory = inventory::collect(&files, &parse_options);
+    let mut out = io::stdout().lock();
+    match format {
+        Format::Json => match serde_json::to_string_pretty(&inventory) {
+            Ok(json) => {
+                let _ = writeln!(out, "{}", json);
+            }
+            Err(e) => {
+                eprintln!("Failed to serialize inventory to JSON: {}", e);
+                process::exit(1);
+            }
+        },
+        Format::Pretty => {
+            for element in &inventory.elements {
+                let mut line = format!(
+                    "{}:{}:{}: <{}",
+                    element.file, element.line, element.column, element.tag
+                );
+                for attr in &element.attributes {
+                    match &attr.value {
+                        Some(value) if value.is_empty() => line += &format!(" {}", attr.name),
+                        Some(value) => line += &format!(" {}=\"{}\"", attr.name, value),
+                        None => line += &format!(" {}={{..}}", attr.name),
                     }
-                    Err(e) => errors.push(e.to_string()),
                 }
-                (diags, errors)
-            },
-        )
-        .reduce(
-            || (Vec::new(), Vec::new()),
-            |(mut d1, mut e1), (d2, e2)| {
-                d1.extend(d2);
-                e1.extend(e2);
-                (d1, e1)
-            },
+                line.push('>');
+                if let Some(ref role) = element.role {
+                    line += &format!(" role: {}", role);
+                }
+                if writeln!(out, "{}", line).is_err() {
+                    break;
+                }
+            }
+            for err in &inventory.parse_errors {
+                eprintln!("Parse error: {}", err);
+            }
+        }
+    }
+}
+
+/// Print phase and per-rule times and hits on stderr, slowest rule first.
+fn print_timings(timings: &bench::Timings) {
+    let ms = |d: std::time::Duration| d.as_secs_f64() * 1000.0;
+    eprintln!("  parse  {:>10.3}ms", ms(timings.parse));
+    eprintln!("  lint   {:>10.3}ms", ms(timings.lint));
+    let rules = timings.slowest_rules();
+    let width = rules
+        .iter()
+        .map(|(rule, _)| rule.to_string().len())
+        .max()
+        .unwrap_or(0);
+    eprintln!();
+    eprintln!(
+        "  {:<width$}  {:>12}  {:>6}",
+        "rule",
+        "time",
+        "hits",
+        width = width
+    );
+    for (rule, stats) in rules {
+        eprintln!(
+            "  {:<width$}  {:>10.3}ms  {:>6}",
+            rule.to_string(),
+            ms(stats.time),
+            stats.hits,
+            width = width
         );
+    }
+}
 
-    // Sort diagnostics by file, then line, then column
-    all_diagnostics.sort_unstable_by(|a, b| {
-        a.file
-            .cmp(&b.file)
-            .then(a.line.cmp(&b.line))
-            .then(a.column.cmp(&b.column))
-    });
-    CliLintSummary {
-        diagnostics: all_diagnostics,
-        parse_errors,
-        files_checked: files_checked.load(Ordering::Relaxed),
+/// Logs each file on stderr as it is linted (`-vv`).
+struct FileLog;
+
+impl ProgressHandler for FileLog {
+    fn start(&self, total_files: usize) {
+        eprintln!("Scanning {} file(s)...", total_files);
+    }
+
+    fn file_finished(&self, file: &Path, diagnostics: usize) {
+        eprintln!(
+            "Linted {} ({} diagnostic{})",
+            file.display(),
+            diagnostics,
+            if diagnostics == 1 { "" } else { "s" }
+        );
+    }
+}
+
+/// Draws a progress bar on stderr while files are linted.
+struct ProgressBar {
+    total: AtomicUsize,
+    done: AtomicUsize,
+}
+
+impl ProgressBar {
+    const WIDTH: usize = 30;
+
+    fn new() -> Self {
+        ProgressBar {
+            total: AtomicUsize::new(0),
+            done: AtomicUsize::new(0),
+        }
+    }
+
+    fn draw(&self, done: usize, total: usize) {
+        let filled = Self::WIDTH * done / total.max(1);
+        eprint!(
+            "\r[{}{}] {}/{} files",
+            "=".repeat(filled),
+            " ".repeat(Self::WIDTH - filled),
+            done,
+            total
+        );
+    }
+}
+
+impl ProgressHandler for ProgressBar {
+    fn start(&self, total_files: usize) {
+        self.total.store(total_files, Ordering::Relaxed);
+        self.draw(0, total_files);
+    }
+
+    fn file_finished(&self, _file: &Path, _diagnostics: usize) {
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        let total = self.total.load(Ordering::Relaxed);
+        // Redraw only when the bar or the final count changes.
+        let step = (total / Self::WIDTH).max(1);
+        if done.is_multiple_of(step) || done == total {
+            self.draw(done, total);
+        }
+    }
+
+    fn finish(&self, _summary: &LintSummary) {
+        // Clear the bar so it doesn't mix with diagnostics.
+        eprint!("\r{}\r", " ".repeat(Self::WIDTH + 32));
+    }
+}
+
+/// The source and the edits of every safe fix, per file, with the indices of
+/// the diagnostics they fix. Fixes that overlap an earlier one are left out,
+/// as are files that cannot be read.
+fn safe_fixes(
+    diagnostics: &[LintDiagnostic],
+) -> BTreeMap<String, (String, Vec<fix::TextEdit>, Vec<usize>)> {
+    let mut fixes: BTreeMap<String, (String, Vec<fix::TextEdit>, Vec<usize>)> = BTreeMap::new();
+    let mut unreadable: HashSet<&str> = HashSet::new();
+    for (index, diagnostic) in diagnostics.iter().enumerate() {
+        let Some(safe) = diagnostic
+            .fixes
+            .iter()
+            .find(|f| f.applicability == fix::Applicability::Safe)
+        else {
+            continue;
+        };
+        if unreadable.contains(diagnostic.file.as_str()) {
+            continue;
+        }
+        if !fixes.contains_key(&diagnostic.file) {
+            match std::fs::read_to_string(&diagnostic.file) {
+                Ok(source) => {
+                    fixes.insert(diagnostic.file.clone(), (source, Vec::new(), Vec::new()));
+                }
+                Err(e) => {
+                    eprintln!("Error: could not read '{}': {}", diagnostic.file, e);
+                    unreadable.insert(&diagnostic.file);
+                    continue;
+                }
+            }
+        }
+        let (source, edits, indices) = fixes.get_mut(&diagnostic.file).unwrap();
+        let mut candidate = edits.clone();
+        candidate.extend(safe.edits.iter().cloned());
+        if fix::apply(source, &candidate).is_ok() {
+            *edits = candidate;
+            indices.push(index);
+        }
+    }
+    fixes
+}
+
+/// What to do with the remaining fixes of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleDecision {
+    AcceptAll,
+    SkipAll,
+}
+
+/// Offer each fix in turn and write the accepted ones to disk.
+fn fix_interactively(diagnostics: &[LintDiagnostic]) {
+    let mut by_file: BTreeMap<&str, Vec<&LintDiagnostic>> = BTreeMap::new();
+    for diagnostic in diagnostics.iter().filter(|d| !d.fixes.is_empty()) {
+        by_file
+            .entry(&diagnostic.file)
+            .or_default()
+            .push(diagnostic);
+    }
+    if by_file.is_empty() {
+        println!("No fixable diagnostics.");
+        return;
+    }
+
+    let mut decisions: HashMap<Rule, RuleDecision> = HashMap::new();
+    let (mut applied, mut files_changed) = (0usize, 0usize);
+    'files: for (file, diagnostics) in by_file {
+        let source = match std::fs::read_to_string(file) {
+            Ok(source) => source,
+            Err(e) => {
+                eprintln!("Error: could not read '{}': {}", file, e);
+                continue;
+            }
+        };
+        let mut accepted: Vec<fix::TextEdit> = Vec::new();
+        for diagnostic in diagnostics {
+            let fix = &diagnostic.fixes[0];
+            let accept = match decisions.get(&diagnostic.rule) {
+                Some(RuleDecision::AcceptAll) => true,
+                Some(RuleDecision::SkipAll) => false,
+                None => {
+                    let Ok(fixed) = fix::apply(&source, &fix.edits) else {
+                        continue;
+                    };
+                    println!(
+                        "{} {}:{}:{} {} {}",
+                        "==>".blue().bold(),
+                        diagnostic.file,
+                        diagnostic.line,
+                        diagnostic.column,
+                        diagnostic.message,
+                        format!("[{}]", diagnostic.rule).dimmed()
+                    );
+                    print_change(&source, &fixed);
+                    match prompt(&fix.message, &diagnostic.rule) {
+                        Answer::Yes => true,
+                        Answer::No => false,
+                        Answer::All => {
+                            decisions.insert(diagnostic.rule.clone(), RuleDecision::AcceptAll);
+                            true
+                        }
+                        Answer::Skip => {
+                            decisions.insert(diagnostic.rule.clone(), RuleDecision::SkipAll);
+                            false
+                        }
+                        Answer::Quit => {
+                            files_changed += write_fixes(file, &source, &accepted);
+                            applied += accepted.len();
+                            break 'files;
+                        }
+                    }
+                }
+            };
+            if !accept {
+                continue;
+            }
+            let mut edits = accepted.clone();
+            edits.extend(fix.edits.iter().cloned());
+            if fix::apply(&source, &edits).is_ok() {
+                accepted = edits;
+            } else {
+                println!("  Skipped: overlaps a fix that was already accepted.");
+            }
+        }
+        applied += accepted.len();
+        files_changed += write_fixes(file, &source, &accepted);
+    }
+    println!(
+        "Applied {} edit{} in {} file{}.",
+        applied,
+        if applied == 1 { "" } else { "s" },
+        files_changed,
+        if files_changed == 1 { "" } else { "s" }
+    );
+}
+
+/// Apply `edits` to `file`, returning the number of files changed (0 or 1).
+fn write_fixes(file: &str, source: &str, edits: &[fix::TextEdit]) -> usize {
+    if edits.is_empty() {
+        return 0;
+    }
+    let result = fix::apply(source, edits)
+        .map_err(|e| e.to_string())
+        .and_then(|fixed| std::fs::write(file, fixed).map_err(|e| e.to_string()));
+    match result {
+        Ok(()) => 1,
+        Err(e) => {
+            eprintln!("Error: could not fix '{}': {}", file, e);
+            0
+        }
     }
 }
 
-/// Collect all `.rs` files from a path (file or directory).
+/// Print the lines that differ between `old` and `new`, with line numbers.
+fn print_change(old: &str, new: &str) {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let prefix = old_lines
+        .iter()
+        .zip(&new_lines)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old_lines[prefix..]
+        .iter()
+        .rev()
+        .zip(new_lines[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    for (i, line) in old_lines[prefix..old_lines.len() - suffix]
+        .iter()
+        .enumerate()
+    {
+        println!("{:>5} {}", prefix + i + 1, format!("- {}", line).red());
+    }
+    for line in &new_lines[prefix..new_lines.len() - suffix] {
+        println!("{:>5} {}", "", format!("+ {}", line).green());
+    }
+}
+
+/// An answer to the per-fix prompt.
+enum Answer {
+    Yes,
+    No,
+    All,
+    Skip,
+    Quit,
+}
+
+fn prompt(message: &str, rule: &Rule) -> Answer {
+    loop {
+        print!(
+            "{} {} [y]es, [n]o, [a]ll {rule}, [s]kip {rule}, [q]uit: ",
+            "fix:".green().bold(),
+            message
+        );
+        let _ = io::stdout().flush();
+        let mut line = String::new();
+        if io::stdin().read_line(&mut line).unwrap_or(0) == 0 {
+            return Answer::Quit;
+        }
+        match line.trim() {
+            "y" | "yes" => return Answer::Yes,
+            "n" | "no" => return Answer::No,
+            "a" | "all" => return Answer::All,
+            "s" | "skip" => return Answer::Skip,
+            "q" | "quit" => return Answer::Quit,
+            _ => {}
+        }
+    }
+}
+
+/// Collect all `.rs` files from a path (file or directory) with the
+/// library's walker, skipping files whose canonical path is already in
+/// `seen`.
 ///
-/// All returned paths are guaranteed to be descendants of `path`.
-/// Paths are returned relative to the current working directory when possible.
-fn collect_rust_files(path: &Path) -> Vec<PathBuf> {
+/// All returned paths are descendants of `path`; with `follow_symlinks` they
+/// may lead outside it through a link. Paths are returned relative to the
+/// current working directory when possible.
+fn collect_rust_files(
+    path: &Path,
+    follow_symlinks: bool,
+    seen: &mut HashSet<PathBuf>,
+) -> Vec<PathBuf> {
     if path.is_file() {
-        if path.extension().map_or(false, |ext| ext == "rs") {
-            return vec![path.to_path_buf()];
-        }
-        return Vec::new();
+        return rsx_a11y::collect_rust_files_with(path, follow_symlinks);
     }
 
-    // Canonicalize the root so we can verify every result is a true descendant.
+    // Walk from the canonical root so paths can be made relative to cwd.
+    // dunce keeps the prefix handling of both consistent on Windows
+    // (std::canonicalize adds a \\?\ prefix).
     let root = match dunce::canonicalize(path).or_else(|_| path.canonicalize()) {
         Ok(r) => r,
         Err(_) => return Vec::new(),
     };
-
-    // Also canonicalize cwd so we can produce relative display paths.
     let cwd = std::env::current_dir()
         .ok()
         .and_then(|d| dunce::canonicalize(&d).ok());
 
-    WalkDir::new(&root)
-        .follow_links(false)
+    rsx_a11y::collect_rust_files_with(&root, follow_symlinks)
         .into_iter()
-        .filter_entry(|entry| {
-            if entry.file_type().is_dir() {
-                let name = entry.file_name().to_string_lossy();
-                return !matches!(name.as_ref(), "target" | "node_modules")
-                    && !name.starts_with('.');
-            }
-            true
-        })
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| {
-            entry.file_type().is_file() && entry.path().extension().map_or(false, |ext| ext == "rs")
-        })
-        .filter_map(|entry| {
-            // Hard check: the file's canonical path must start with root.
-            // Use dunce::canonicalize here too so both sides have consistent
-            // prefix handling on Windows (std::canonicalize adds \\?\ prefix).
-            let canonical = dunce::canonicalize(entry.path()).ok()?;
-            if !canonical.starts_with(&root) {
+        .filter_map(|file| {
+            let canonical = dunce::canonicalize(&file).ok()?;
+            // Keep a linked path, which is under root.
+            let file = if follow_symlinks {
+                file
+            } else {
+                canonical.clone()
+            };
+            if !seen.insert(canonical) {
                 return None;
             }
             // Return a relative path when possible for cleaner output.
-            if let Some(ref cwd) = cwd {
-                if let Ok(rel) = canonical.strip_prefix(cwd) {
-                    return Some(rel.to_path_buf());
-                }
+            if let Some(ref cwd) = cwd
+                && let Ok(rel) = file.strip_prefix(cwd)
+            {
+                return Some(rel.to_path_buf());
             }
-            Some(canonical)
+            Some(file)
         })
         .collect()
 }
@@ -0,0 +1,482 @@
+//! Typed per-rule options.
+//!
+//! Options are set in `rsx-a11y.toml` by using a table instead of a plain
+//! level for a rule:
+//!
+//! ```toml
+//! [rules.anchor-ambiguous-text]
+//! level = "warning"
+//! words = ["click here", "here", "mehr erfahren"]
+//!
+//! [rules.interactive-supports-focus]
+//! mode = "focusable"
+//! ```
+//!
+//! Every rule that accepts options has a dedicated struct below; the defaults
+//! reproduce the built-in behavior of the rule.
+
+use std::collections::BTreeMap;
+
+use crate::dom::{Role, Tag};
+use crate::lints::Rule;
+
+/// Options for every configurable rule, passed to [`Rule::check`].
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct RuleOptions {
+    pub alt_text_length: AltTextLengthOptions,
+    pub anchor_ambiguous_text: AnchorAmbiguousTextOptions,
+    pub aria_props: AriaPropsOptions,
+    pub aria_role: AriaRoleOptions,
+    pub click_events_have_key_events: ClickEventsHaveKeyEventsOptions,
+    pub control_has_associated_label: ControlHasAssociatedLabelOptions,
+    pub img_redundant_alt: ImgRedundantAltOptions,
+    pub interactive_supports_focus: InteractiveSupportsFocusOptions,
+    pub live_region_valid: LiveRegionValidOptions,
+    pub mouse_events_have_key_events: MouseEventsHaveKeyEventsOptions,
+    pub no_autofocus: NoAutofocusOptions,
+    pub no_interactive_element_to_noninteractive_role:
+        NoInteractiveElementToNoninteractiveRoleOptions,
+    pub no_noninteractive_element_to_interactive_role:
+        NoNoninteractiveElementToInteractiveRoleOptions,
+    pub no_noninteractive_tabindex: NoNoninteractiveTabindexOptions,
+    pub no_static_element_interactions: NoStaticElementInteractionsOptions,
+}
+
+impl RuleOptions {
+    /// Set the options for `rule` from a config table.
+    ///
+    /// Returns an error if the rule takes no options or the table does not
+    /// match the rule's option schema.
+    pub fn set(&mut self, rule: &Rule, table: toml::Table) -> Result<(), String> {
+        let value = toml::Value::Table(table);
+        match rule {
+            Rule::AltTextLength => {
+                self.alt_text_length = value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::AnchorAmbiguousText => {
+                self.anchor_ambiguous_text = value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::AriaProps => self.aria_props = value.try_into().map_err(|e| e.to_string())?,
+            Rule::AriaRole => self.aria_role = value.try_into().map_err(|e| e.to_string())?,
+            Rule::ClickEventsHaveKeyEvents => {
+                self.click_events_have_key_events = value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::ControlHasAssociatedLabel => {
+                self.control_has_associated_label = value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::ImgRedundantAlt => {
+                self.img_redundant_alt = value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::InteractiveSupportsFocus => {
+                self.interactive_supports_focus = value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::LiveRegionValid => {
+                self.live_region_valid = value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::MouseEventsHaveKeyEvents => {
+                self.mouse_events_have_key_events = value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::NoAutofocus => self.no_autofocus = value.try_into().map_err(|e| e.to_string())?,
+            Rule::NoInteractiveElementToNoninteractiveRole => {
+                self.no_interactive_element_to_noninteractive_role =
+                    value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::NoNoninteractiveElementToInteractiveRole => {
+                self.no_noninteractive_element_to_interactive_role =
+                    value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::NoNoninteractiveTabindex => {
+                self.no_noninteractive_tabindex = value.try_into().map_err(|e| e.to_string())?
+            }
+            Rule::NoStaticElementInteractions => {
+                self.no_static_element_interactions = value.try_into().map_err(|e| e.to_string())?
+            }
+            _ => {
+                return Err(format!("rule `{}` does not take options", rule));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Options for `alt-text-length`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct AltTextLengthOptions {
+    /// Longest allowed `alt` text, in characters.
+    #[serde(default = "AltTextLengthOptions::default_max_length")]
+    pub max_length: usize,
+}
+
+impl AltTextLengthOptions {
+    fn default_max_length() -> usize {
+        150
+    }
+}
+
+impl Default for AltTextLengthOptions {
+    fn default() -> Self {
+        AltTextLengthOptions {
+            max_length: Self::default_max_length(),
+        }
+    }
+}
+
+/// Options for `anchor-ambiguous-text`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnchorAmbiguousTextOptions {
+    /// Link texts considered ambiguous (compared ignoring case, punctuation
+    /// and extra whitespace).
+    #[serde(default = "AnchorAmbiguousTextOptions::default_words")]
+    pub words: Vec<String>,
+}
+
+impl AnchorAmbiguousTextOptions {
+    fn default_words() -> Vec<String> {
+        ["click here", "here", "link", "a link", "learn more"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+}
+
+impl Default for AnchorAmbiguousTextOptions {
+    fn default() -> Self {
+        AnchorAmbiguousTextOptions {
+            words: Self::default_words(),
+        }
+    }
+}
+
+/// Options for `aria-props`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct AriaPropsOptions {
+    /// Unknown `aria-*` attributes to accept, e.g. from a design system's
+    /// experimental semantics.
+    #[serde(default)]
+    pub allowed_extra_aria_attrs: Vec<String>,
+}
+
+/// Options for `aria-role`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct AriaRoleOptions {
+    /// Unknown role names to accept, e.g. a design system's custom roles.
+    #[serde(default)]
+    pub allowed_extra_roles: Vec<String>,
+    /// Accept DPub ARIA roles (`doc-chapter`, `doc-footnote`, ...).
+    #[serde(default)]
+    pub dpub: bool,
+    /// Accept Graphics ARIA roles (`graphics-document`, `graphics-object`,
+    /// `graphics-symbol`).
+    #[serde(default)]
+    pub graphics: bool,
+}
+
+/// Options for `click-events-have-key-events`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ClickEventsHaveKeyEventsOptions {
+    /// Accept `onkeypress` as the keyboard alternative. The `keypress` event
+    /// is deprecated, so strict projects may turn this off.
+    #[serde(default = "ClickEventsHaveKeyEventsOptions::default_accept_keypress")]
+    pub accept_keypress: bool,
+    /// Require both `onkeydown` and `onkeyup` instead of either one.
+    #[serde(default)]
+    pub require_both: bool,
+}
+
+impl ClickEventsHaveKeyEventsOptions {
+    fn default_accept_keypress() -> bool {
+        true
+    }
+}
+
+impl Default for ClickEventsHaveKeyEventsOptions {
+    fn default() -> Self {
+        ClickEventsHaveKeyEventsOptions {
+            accept_keypress: Self::default_accept_keypress(),
+            require_both: false,
+        }
+    }
+}
+
+/// Options for `img-redundant-alt`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImgRedundantAltOptions {
+    /// Words that make `alt` text redundant (matched case-insensitively).
+    #[serde(default = "ImgRedundantAltOptions::default_words")]
+    pub words: Vec<String>,
+}
+
+impl ImgRedundantAltOptions {
+    fn default_words() -> Vec<String> {
+        ["image", "picture", "photo", "icon", "graphic"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+}
+
+impl Default for ImgRedundantAltOptions {
+    fn default() -> Self {
+        ImgRedundantAltOptions {
+            words: Self::default_words(),
+        }
+    }
+}
+
+/// Options for `control-has-associated-label`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ControlHasAssociatedLabelOptions {
+    /// Additional tags to treat as controls that need a label.
+    #[serde(default)]
+    pub control_tags: Vec<Tag>,
+}
+
+/// Options for `mouse-events-have-key-events`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct MouseEventsHaveKeyEventsOptions {
+    /// Handlers that need a matching `onfocus`, in any framework's
+    /// spelling (e.g. `onMouseOver`, `on:mouseenter`).
+    #[serde(default = "MouseEventsHaveKeyEventsOptions::default_hover_in_handlers")]
+    pub hover_in_handlers: Vec<String>,
+    /// Handlers that need a matching `onblur`.
+    #[serde(default = "MouseEventsHaveKeyEventsOptions::default_hover_out_handlers")]
+    pub hover_out_handlers: Vec<String>,
+}
+
+impl MouseEventsHaveKeyEventsOptions {
+    fn default_hover_in_handlers() -> Vec<String> {
+        vec!["onmouseover".to_string(), "onmouseenter".to_string()]
+    }
+
+    fn default_hover_out_handlers() -> Vec<String> {
+        vec!["onmouseout".to_string(), "onmouseleave".to_string()]
+    }
+}
+
+impl Default for MouseEventsHaveKeyEventsOptions {
+    fn default() -> Self {
+        MouseEventsHaveKeyEventsOptions {
+            hover_in_handlers: Self::default_hover_in_handlers(),
+            hover_out_handlers: Self::default_hover_out_handlers(),
+        }
+    }
+}
+
+/// Options for `no-autofocus`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct NoAutofocusOptions {
+    /// Allow `autofocus` on the first focusable element of a `<dialog>` or
+    /// `role="dialog"`, where moving focus into the dialog is expected.
+    #[serde(default)]
+    pub allow_in_dialog: bool,
+}
+
+/// Options for `no-interactive-element-to-noninteractive-role`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct NoInteractiveElementToNoninteractiveRoleOptions {
+    /// Roles each tag may take, keyed by tag name. Defaults to jsx-a11y's
+    /// exceptions, `<canvas role="img">` and a presentational `<tr>`.
+    #[serde(default = "NoInteractiveElementToNoninteractiveRoleOptions::default_allowed_roles")]
+    pub allowed_roles: BTreeMap<String, Vec<Role>>,
+    /// Roles each tag may take in addition to `allowed_roles`, so a config
+    /// can extend the defaults without repeating them.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra_allowed_roles: BTreeMap<String, Vec<Role>>,
+    /// Allow `role="presentation"` / `role="none"` on a `disabled` element,
+    /// which is not focusable and so can be presentational.
+    #[serde(
+        default = "NoInteractiveElementToNoninteractiveRoleOptions::default_allow_presentation_when_disabled"
+    )]
+    pub allow_presentation_when_disabled: bool,
+}
+
+impl NoInteractiveElementToNoninteractiveRoleOptions {
+    fn default_allowed_roles() -> BTreeMap<String, Vec<Role>> {
+        BTreeMap::from([
+            ("canvas".to_string(), vec![Role::Img]),
+            ("tr".to_string(), vec![Role::None, Role::Presentation]),
+        ])
+    }
+
+    fn default_allow_presentation_when_disabled() -> bool {
+        true
+    }
+
+    /// Whether `tag` may take `role`.
+    pub fn allows(&self, tag: &Tag, role: &Role) -> bool {
+        let tag = tag.to_string();
+        [&self.allowed_roles, &self.extra_allowed_roles]
+            .iter()
+            .any(|allowed| allowed.get(&tag).is_some_and(|roles| roles.contains(role)))
+    }
+}
+
+impl Default for NoInteractiveElementToNoninteractiveRoleOptions {
+    fn default() -> Self {
+        NoInteractiveElementToNoninteractiveRoleOptions {
+            allowed_roles: Self::default_allowed_roles(),
+            extra_allowed_roles: BTreeMap::new(),
+            allow_presentation_when_disabled: Self::default_allow_presentation_when_disabled(),
+        }
+    }
+}
+
+/// Options for `no-noninteractive-element-to-interactive-role`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct NoNoninteractiveElementToInteractiveRoleOptions {
+    /// Roles each tag may take, keyed by tag name. Defaults to the ARIA
+    /// widget patterns jsx-a11y allows, like `<li role="menuitem">`.
+    #[serde(default = "NoNoninteractiveElementToInteractiveRoleOptions::default_allowed_roles")]
+    pub allowed_roles: BTreeMap<String, Vec<Role>>,
+    /// Roles each tag may take in addition to `allowed_roles`, so a config
+    /// can extend the defaults without repeating them.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra_allowed_roles: BTreeMap<String, Vec<Role>>,
+}
+
+impl NoNoninteractiveElementToInteractiveRoleOptions {
+    fn default_allowed_roles() -> BTreeMap<String, Vec<Role>> {
+        let list = || {
+            vec![
+                Role::ListBox,
+                Role::Menu,
+                Role::Menubar,
+                Role::RadioGroup,
+                Role::TabList,
+                Role::Tree,
+                Role::TreeGrid,
+            ]
+        };
+        BTreeMap::from([
+            (
+                "fieldset".to_string(),
+                vec![Role::RadioGroup, Role::Presentation],
+            ),
+            (
+                "li".to_string(),
+                vec![
+                    Role::MenuItem,
+                    Role::MenuItemRadio,
+                    Role::MenuItemCheckbox,
+                    Role::Option,
+                    Role::Row,
+                    Role::Tab,
+                    Role::TreeItem,
+                ],
+            ),
+            ("ol".to_string(), list()),
+            ("table".to_string(), vec![Role::Grid]),
+            ("td".to_string(), vec![Role::GridCell]),
+            ("ul".to_string(), list()),
+        ])
+    }
+
+    /// Whether `tag` may take `role`.
+    pub fn allows(&self, tag: &Tag, role: &Role) -> bool {
+        let tag = tag.to_string();
+        [&self.allowed_roles, &self.extra_allowed_roles]
+            .iter()
+            .any(|allowed| allowed.get(&tag).is_some_and(|roles| roles.contains(role)))
+    }
+}
+
+impl Default for NoNoninteractiveElementToInteractiveRoleOptions {
+    fn default() -> Self {
+        NoNoninteractiveElementToInteractiveRoleOptions {
+            allowed_roles: Self::default_allowed_roles(),
+            extra_allowed_roles: BTreeMap::new(),
+        }
+    }
+}
+
+/// Options for `no-noninteractive-tabindex`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoNoninteractiveTabindexOptions {
+    /// Roles that may have a non-negative `tabindex` (e.g. `tabpanel`).
+    #[serde(default)]
+    pub roles: Vec<Role>,
+}
+
+/// Options for `no-static-element-interactions`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct NoStaticElementInteractionsOptions {
+    /// Treat a `role` computed at runtime (`role={role}`) as a role. When
+    /// off, such elements are checked as if they had no role.
+    #[serde(default = "NoStaticElementInteractionsOptions::default_allow_expression_values")]
+    pub allow_expression_values: bool,
+    /// Handlers that need a role, in any framework's spelling. Defaults to
+    /// pointer activation, keyboard and mouseover/mouseout handlers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handlers: Option<Vec<String>>,
+}
+
+impl NoStaticElementInteractionsOptions {
+    fn default_allow_expression_values() -> bool {
+        true
+    }
+}
+
+impl Default for NoStaticElementInteractionsOptions {
+    fn default() -> Self {
+        NoStaticElementInteractionsOptions {
+            allow_expression_values: Self::default_allow_expression_values(),
+            handlers: None,
+        }
+    }
+}
+
+/// How strictly `interactive-supports-focus` checks focusability.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FocusMode {
+    /// The element must be in the tab order (`tabindex >= 0`).
+    #[default]
+    Tabbable,
+    /// Any `tabindex` is enough, including `tabindex="-1"`.
+    Focusable,
+}
+
+/// Options for `interactive-supports-focus`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InteractiveSupportsFocusOptions {
+    #[serde(default)]
+    pub mode: FocusMode,
+}
+
+/// Options for `live-region-valid`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct LiveRegionValidOptions {
+    /// Most child elements an `aria-live="assertive"` region may have.
+    #[serde(default = "LiveRegionValidOptions::default_max_assertive_children")]
+    pub max_assertive_children: usize,
+}
+
+impl LiveRegionValidOptions {
+    fn default_max_assertive_children() -> usize {
+        5
+    }
+}
+
+impl Default for LiveRegionValidOptions {
+    fn default() -> Self {
+        LiveRegionValidOptions {
+            max_assertive_children: Self::default_max_assertive_children(),
+        }
+    }
+}
@@ -3,11 +3,21 @@
 //! Finds `html!`, `view!`, and `rsx!` macro invocations in Rust code, then
 //! parses the HTML-like token streams within to extract elements and attributes.
 
+use quote::ToTokens;
 use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
 use syn::{spanned::Spanned, visit::Visit};
 
-use crate::dom::{AttributeName, Role, Tag};
-use rstml::node::{Node, NodeAttribute};
+use std::collections::BTreeMap;
+
+use crate::dom::{Aria, AttributeName, CustomElement, Role, Tag};
+use crate::fix::{Position, Range};
+use crate::source::{FileSystem, SourceProvider};
+use crate::style::{ClassVisibility, HidingClasses};
+use rstml::node::{KeyedAttribute, Node, NodeAttribute};
+
+mod dioxus;
 
 /// Represents an HTML element found in a macro invocation.
 #[derive(Debug, Clone, serde::Serialize)]
@@ -20,61 +30,240 @@ pub struct HtmlElement {
     pub is_self_closing: bool,
     /// Whether the element has child content (text or nested elements).
     pub has_children: bool,
+    /// Static text content of the element, with nested elements contributing
+    /// their `aria-label` (or `alt` for `<img>`). `None` if any part of it is
+    /// only known at runtime.
+    pub text: Option<String>,
     /// Line number in the source file (1-based).
     pub line: usize,
     /// Column number in the source file (0-based).
     pub column: usize,
+    /// Line where the element ends, after its closing tag or brace.
+    pub end_line: usize,
+    /// Column just past the end of the element.
+    pub end_column: usize,
     /// The source file path.
     pub file: String,
+    /// Name of the macro the element was written in (e.g. `html`), without
+    /// the `!` or any path prefix.
+    pub source_macro: String,
+    /// Index of that macro invocation among those in the file, so rules can
+    /// compare the elements of one macro.
+    pub macro_index: usize,
+    /// How the element behaves if it is a declared [`Tag::Custom`] element.
+    pub custom_element: Option<CustomElement>,
+    /// Whether a configured class hides the element (see [`HidingClasses`]).
+    pub class_visibility: ClassVisibility,
+    /// Whether the element is nested inside a natively interactive element
+    /// of the same macro, like a `<span>` inside a `<button>`.
+    pub inside_interactive: bool,
+    /// Number of elements nested inside this one. They directly follow it
+    /// in the element list, so its subtree is `elements[i..=i + descendants]`.
+    pub descendants: usize,
+    /// Name of the component whose body contains the macro: the function
+    /// (or the name given to `#[function_component(Name)]`), or the type of
+    /// a Yew `impl Component`. `None` outside functions.
+    pub component: Option<Arc<str>>,
+    /// How the element is written, which fixes have to follow.
+    #[serde(skip)]
+    pub syntax: Syntax,
+    /// The branches of `if`/`else` and `match` expressions in its macro
+    /// that the element is rendered in, outermost first.
+    #[serde(skip)]
+    pub branches: Vec<Branch>,
+}
+
+/// One branch of a conditional (`if`/`else`, `match`) in a macro. Elements
+/// in different branches of the same conditional are never rendered
+/// together, see [`HtmlElement::excludes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Branch {
+    /// Index of the conditional among those in the macro.
+    pub conditional: usize,
+    /// Index of the branch (or `match` arm) in the conditional.
+    pub arm: usize,
+}
+
+/// How an element is written.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Syntax {
+    /// A tag: `<div class="a">`.
+    #[default]
+    Tag,
+    /// Dioxus' element syntax: `div { class: "a", }`.
+    Braced {
+        /// Just after the `{`.
+        body: Position,
+        /// What removing each attribute deletes: the attribute, its comma
+        /// and the whitespace up to the next item.
+        removals: Vec<Range>,
+    },
 }
 
 impl HtmlElement {
+    /// The framework whose macro the element was written in, if known.
+    pub fn framework(&self) -> Option<Framework> {
+        Framework::from_macro(&self.source_macro)
+    }
+
+    /// Whether the element and `other` are in different branches of one
+    /// conditional, so that at most one of them is rendered.
+    pub fn excludes(&self, other: &HtmlElement) -> bool {
+        self.branches.iter().any(|a| {
+            other
+                .branches
+                .iter()
+                .any(|b| a.conditional == b.conditional && a.arm != b.arm)
+        })
+    }
+
     /// The effective role: explicit `role` attribute takes precedence,
     /// falling back to the tag's implicit role.
     pub fn role(&self) -> Option<Role> {
-        self.attributes
+        self.explicit_role()
+            .map(|(_, role)| role)
+            .or_else(|| self.implicit_role())
+    }
+
+    /// The role given by a static `role` attribute, with the attribute. The
+    /// value may list fallbacks (`role="switch checkbox"`); as in browsers,
+    /// the first token that names a known, non-abstract role wins.
+    pub fn explicit_role(&self) -> Option<(&HtmlAttribute, Role)> {
+        let attr = self
+            .attributes
             .iter()
-            .find_map(|attr| {
-                if attr.name == AttributeName::Role {
-                    attr.value
-                        .as_ref()
-                        .and_then(|v| v.as_static())
-                        .and_then(Role::from_str)
-                } else {
-                    None
-                }
-            })
-            .or_else(|| self.tag.implicit_role())
+            .find(|a| a.name == AttributeName::Role)?;
+        let role = attr
+            .value
+            .as_ref()?
+            .as_static()?
+            .split_whitespace()
+            .find_map(|token| Role::from_str(token).ok().filter(|r| !r.is_abstract()))?;
+        Some((attr, role))
+    }
+
+    /// The role the element has without a `role` attribute, from its tag or
+    /// its custom element declaration.
+    pub fn implicit_role(&self) -> Option<Role> {
+        match &self.custom_element {
+            Some(custom) => custom.role.clone(),
+            None => self.tag.implicit_role(),
+        }
+    }
+
+    /// Whether the element is natively interactive, like `<button>` or a
+    /// custom element declared `interactive`.
+    pub fn is_interactive(&self) -> bool {
+        match &self.custom_element {
+            Some(custom) => custom.interactive,
+            None => self.tag.is_interactive(),
+        }
     }
 
     /// Whether the element is focusable (natively interactive or has tabindex >= 0).
     pub fn is_focusable(&self) -> bool {
-        self.tag.is_interactive()
+        self.is_interactive()
+            || self.custom_element.as_ref().is_some_and(|c| c.focusable)
             || self.attributes.iter().any(|a| {
                 a.name == AttributeName::TabIndex
                     && match &a.value {
-                        Some(AttrValue::Static(v)) => v.parse::<i32>().map_or(false, |i| i >= 0),
+                        Some(AttrValue::Static(v)) => v.trim().parse::<i32>().is_ok_and(|i| i >= 0),
                         _ => true, // dynamic value; assume possibly focusable
                     }
             })
     }
 
+    /// Whether the element is in the sequential (Tab key) focus order: a
+    /// focusable element that is not hidden and has no negative `tabindex`.
+    pub fn is_tabbable(&self) -> bool {
+        let negative_tabindex = self.attributes.iter().any(|a| {
+            a.name == AttributeName::TabIndex
+                && matches!(&a.value, Some(AttrValue::Static(v)) if v.trim().parse::<i32>().is_ok_and(|i| i < 0))
+        });
+        self.is_focusable() && !negative_tabindex && !self.is_hidden()
+    }
+
+    /// Whether a static inline `style` hides the element (`display: none` or
+    /// `visibility: hidden`).
+    pub fn is_hidden_by_style(&self) -> bool {
+        self.attributes.iter().any(|a| {
+            a.name == AttributeName::Style
+                && a.value
+                    .as_ref()
+                    .and_then(|v| v.as_static())
+                    .is_some_and(crate::style::is_hidden)
+        })
+    }
+
+    /// The value of the attribute `name`, if it is present and static.
+    pub fn static_attr(&self, name: AttributeName) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.name == name)
+            .and_then(|a| a.value.as_ref())
+            .and_then(|v| v.as_static())
+    }
+
+    /// Whether the element has a static `disabled` attribute (bare, or with
+    /// any value but `false`).
+    pub fn is_disabled(&self) -> bool {
+        self.attributes.iter().any(|a| {
+            a.name == AttributeName::Disabled
+                && match &a.value {
+                    None => true,
+                    Some(value) => value.as_static().is_some_and(|v| v != "false"),
+                }
+        })
+    }
+
+    /// Whether the element is hidden from everyone by its inline style or
+    /// by a configured hiding class.
+    pub fn is_hidden(&self) -> bool {
+        self.class_visibility == ClassVisibility::Hidden || self.is_hidden_by_style()
+    }
+
     /// Whether there is an explicit event handler on this element.
     pub fn has_event_handler(&self) -> bool {
         self.attributes.iter().any(|a| {
-            matches!(
-                a.name,
-                AttributeName::OnClick
-                    | AttributeName::OnKeyDown
-                    | AttributeName::OnKeyUp
-                    | AttributeName::OnKeyPress
-                    | AttributeName::OnMouseOver
-                    | AttributeName::OnMouseOut
-            )
+            a.name.is_interaction_handler()
+                || matches!(
+                    a.name,
+                    AttributeName::OnMouseOver
+                        | AttributeName::OnMouseOut
+                        | AttributeName::OnMouseEnter
+                        | AttributeName::OnMouseLeave
+                )
         })
     }
 }
 
+/// UI framework inferred from the macro an element was written in.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, strum::Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Framework {
+    /// `html!` and `html_nested!`
+    Yew,
+    /// `view!`
+    Leptos,
+    /// `rsx!`
+    Dioxus,
+}
+
+impl Framework {
+    /// The framework for a built-in macro name. Custom macros have none.
+    pub fn from_macro(name: &str) -> Option<Framework> {
+        match name {
+            "html" | "html_nested" => Some(Framework::Yew),
+            "view" => Some(Framework::Leptos),
+            "rsx" => Some(Framework::Dioxus),
+            _ => None,
+        }
+    }
+}
+
 /// Represents an attribute on an HTML element.
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct HtmlAttribute {
@@ -86,6 +275,10 @@ pub struct HtmlAttribute {
     pub line: usize,
     /// Column number in the source file (0-based).
     pub column: usize,
+    /// Line where the attribute, including its value, ends.
+    pub end_line: usize,
+    /// Column just past the end of the attribute, including its value.
+    pub end_column: usize,
 }
 
 /// Represents the value of an HTML attribute.
@@ -107,53 +300,458 @@ impl AttrValue {
     }
 }
 
+/// Builder for [`HtmlElement`], for constructing elements directly in rule
+/// tests instead of going through [`parse_source`].
+///
+/// ```
+/// use rsx_a11y::dom::Tag;
+/// use rsx_a11y::parser::HtmlElementBuilder;
+///
+/// let img = HtmlElementBuilder::new(Tag::Img)
+///     .attr("src", "cat.png")
+///     .dynamic_attr("alt")
+///     .self_closing(true)
+///     .span(3, 8)
+///     .build();
+/// assert_eq!(img.attributes.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtmlElementBuilder {
+    element: HtmlElement,
+}
+
+impl HtmlElementBuilder {
+    /// Start an element with no attributes or children at `test.rs:1:0`, as
+    /// if written in `html!`.
+    pub fn new(tag: Tag) -> Self {
+        HtmlElementBuilder {
+            element: HtmlElement {
+                tag,
+                attributes: Vec::new(),
+                is_self_closing: false,
+                has_children: false,
+                text: Some(String::new()),
+                line: 1,
+                column: 0,
+                end_line: 1,
+                end_column: 0,
+                file: "test.rs".to_string(),
+                source_macro: "html".to_string(),
+                macro_index: 0,
+                custom_element: None,
+                class_visibility: ClassVisibility::Visible,
+                inside_interactive: false,
+                descendants: 0,
+                component: None,
+                syntax: Syntax::Tag,
+                branches: Vec::new(),
+            },
+        }
+    }
+
+    /// Add an attribute with a static value. The name is parsed the same way
+    /// as in macros, so unknown names become [`AttributeName::Unknown`].
+    pub fn attr(self, name: &str, value: &str) -> Self {
+        self.push_attr(name, AttrValue::Static(value.to_string()))
+    }
+
+    /// Add an attribute with a dynamic value (e.g. `alt={alt}`).
+    pub fn dynamic_attr(self, name: &str) -> Self {
+        self.push_attr(name, AttrValue::Dynamic)
+    }
+
+    fn push_attr(mut self, name: &str, value: AttrValue) -> Self {
+        self.element.attributes.push(HtmlAttribute {
+            name: attribute_name(name.to_string()),
+            value: Some(value),
+            line: self.element.line,
+            column: self.element.column,
+            end_line: self.element.line,
+            end_column: self.element.column,
+        });
+        self
+    }
+
+    pub fn self_closing(mut self, is_self_closing: bool) -> Self {
+        self.element.is_self_closing = is_self_closing;
+        self
+    }
+
+    pub fn children(mut self, has_children: bool) -> Self {
+        self.element.has_children = has_children;
+        self
+    }
+
+    /// Set the element's static text content, marking it as having children.
+    pub fn text(mut self, text: &str) -> Self {
+        self.element.text = Some(text.to_string());
+        self.element.has_children = true;
+        self
+    }
+
+    /// Set the element's position. Attributes added afterwards share it.
+    pub fn span(mut self, line: usize, column: usize) -> Self {
+        self.element.line = line;
+        self.element.column = column;
+        self.element.end_line = line;
+        self.element.end_column = column;
+        self
+    }
+
+    pub fn file(mut self, file: impl Into<String>) -> Self {
+        self.element.file = file.into();
+        self
+    }
+
+    pub fn source_macro(mut self, name: impl Into<String>) -> Self {
+        self.element.source_macro = name.into();
+        self
+    }
+
+    pub fn component(mut self, name: impl Into<Arc<str>>) -> Self {
+        self.element.component = Some(name.into());
+        self
+    }
+
+    pub fn build(self) -> HtmlElement {
+        self.element
+    }
+}
+
+impl HtmlElement {
+    /// Shorthand for [`HtmlElementBuilder::new`].
+    pub fn builder(tag: Tag) -> HtmlElementBuilder {
+        HtmlElementBuilder::new(tag)
+    }
+}
+
+/// Map an attribute key to its [`AttributeName`], keeping unknown names.
+fn attribute_name(key: String) -> AttributeName {
+    AttributeName::from_str(&key).unwrap_or(AttributeName::Unknown(key))
+}
+
 /// Normalize a path to use forward slashes consistently.
 fn normalize_path(path: &Path) -> String {
     path.to_string_lossy().replace('\\', "/")
 }
 
+/// Macro names whose contents are linted by default: Yew's `html!` and
+/// `html_nested!`, Leptos' `view!`, and Dioxus' `rsx!`.
+pub const DEFAULT_MACROS: &[&str] = &["html", "html_nested", "view", "rsx"];
+
+/// Options controlling how source files are parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Macro names that may contain RSX. Files that invoke none of them are
+    /// skipped without being parsed.
+    pub macros: Vec<String>,
+    /// Declarations of custom elements, keyed by tag name.
+    pub custom_elements: BTreeMap<String, CustomElement>,
+    /// Classes that hide content visually or entirely.
+    pub classes: HidingClasses,
+    /// Skip files marked as generated (see [`is_generated`]). Off by
+    /// default, so parsing a single file always yields its elements;
+    /// [`LintRunner`](crate::runner::LintRunner) turns it on unless told to
+    /// include generated files.
+    pub skip_generated: bool,
+    /// Also lint RSX in the transcribers of `macro_rules!` definitions,
+    /// with metavariables (`$title`) standing for dynamic values.
+    pub expand_macro_rules: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            macros: DEFAULT_MACROS.iter().map(|m| m.to_string()).collect(),
+            custom_elements: BTreeMap::new(),
+            classes: HidingClasses::default(),
+            skip_generated: false,
+            expand_macro_rules: false,
+        }
+    }
+}
+
+/// Cheap scan for an invocation of one of `macros` (`name!`, including
+/// path-qualified forms like `yew::html!`).
+///
+/// May report false positives (e.g. inside comments or strings), never false
+/// negatives, so it is safe to skip the full parse when it returns `false`.
+pub fn may_contain_rsx(source: &str, macros: &[String]) -> bool {
+    let bytes = source.as_bytes();
+    source.match_indices('!').any(|(bang, _)| {
+        if bytes.get(bang + 1) == Some(&b'=') {
+            return false;
+        }
+        let before = source[..bang].trim_end();
+        let ident_start = before
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map_or(0, |i| i + 1);
+        let ident = &before[ident_start..];
+        !ident.is_empty() && macros.iter().any(|m| m == ident)
+    })
+}
+
+/// Whether the comments at the top of a file mark it as generated, with
+/// `@generated` or `DO NOT EDIT` (as in Go's "Code generated ... DO NOT
+/// EDIT." convention).
+///
+/// ```
+/// use rsx_a11y::parser::is_generated;
+///
+/// assert!(is_generated("// @generated by build.rs\nfn app() {}"));
+/// assert!(!is_generated("fn app() {}\n// DO NOT EDIT"));
+/// ```
+pub fn is_generated(source: &str) -> bool {
+    source
+        .lines()
+        .map(str::trim)
+        .take_while(|line| {
+            line.is_empty()
+                || line.starts_with("//")
+                || line.starts_with("/*")
+                || line.starts_with('*')
+                || line.starts_with("#!")
+        })
+        .any(|line| line.contains("@generated") || line.contains("DO NOT EDIT"))
+}
+
+/// An RSX macro invocation whose contents could not be parsed.
+///
+/// Other macros in the same file are still linted; this is reported as a
+/// warning alongside their diagnostics.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MacroError {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    /// Name of the macro, without the `!` (e.g. `html`).
+    pub macro_name: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for MacroError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid RSX in `{}!` at {}:{}:{}: {}",
+            self.macro_name, self.file, self.line, self.column, self.message
+        )
+    }
+}
+
+/// A component used in RSX, like `<MyCard title="x" />`: an element whose
+/// name starts with an uppercase letter (after any path, as in `ui::Card`).
+/// Functions called in the content, like `{ header() }`, are recorded too,
+/// since they may render elements of their own.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ComponentUse {
+    /// The name as written, e.g. `Card` or `ui::Card`.
+    pub name: String,
+    pub line: usize,
+    pub column: usize,
+    /// Index of the macro invocation among those in the file.
+    pub macro_index: usize,
+    /// The component whose body contains the usage, as
+    /// [`HtmlElement::component`].
+    pub component: Option<Arc<str>>,
+    /// Index in the file's elements of the element the component is nested
+    /// in, if any.
+    pub parent: Option<usize>,
+    /// Index in the file's elements where the component's children start;
+    /// they are `elements[children..children + descendants]`.
+    pub children: usize,
+    /// Number of elements passed to the component as children.
+    pub descendants: usize,
+    /// Whether the component is called as a function, like `{ header() }`,
+    /// rather than used as an element.
+    pub call: bool,
+}
+
+impl ComponentUse {
+    /// The name without any path, e.g. `Card` for `ui::Card`.
+    pub fn short_name(&self) -> &str {
+        self.name.rsplit("::").next().unwrap_or(&self.name).trim()
+    }
+}
+
+/// Elements extracted from one source file, plus any macros that failed to parse.
+#[derive(Debug, Clone, Default)]
+pub struct ParsedFile {
+    pub elements: Vec<HtmlElement>,
+    /// Components used in the file's macros, in source order.
+    pub components: Vec<ComponentUse>,
+    /// Functions declared as components with `#[component]` or
+    /// `#[function_component]`, by their [`HtmlElement::component`] name.
+    pub declared_components: Vec<Arc<str>>,
+    pub macro_errors: Vec<MacroError>,
+    /// Whether the file was skipped for being generated.
+    pub generated: bool,
+}
+
+impl ParsedFile {
+    /// Keep only the elements, failing if every RSX macro in the file was invalid.
+    fn into_elements(self) -> Result<Vec<HtmlElement>, ParseError> {
+        match self.macro_errors.first() {
+            Some(first) if self.elements.is_empty() => Err(ParseError::RstmlError(
+                first.file.clone(),
+                self.macro_errors
+                    .iter()
+                    .map(|e| e.message.as_str())
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )),
+            _ => Ok(self.elements),
+        }
+    }
+}
+
 /// Parse a Rust source file and extract all HTML elements from supported macros.
 ///
 /// Performs a cheap pre-filter before doing the expensive `syn` parse.
+/// Returns [`ParseError::RstmlError`] only if no macro in the file could be
+/// parsed; use [`parse_file_with`] to get per-macro errors.
 pub fn parse_file(path: &Path) -> Result<Vec<HtmlElement>, ParseError> {
+    parse_file_with(path, &ParseOptions::default())?.into_elements()
+}
+
+/// Parse a Rust source file with the given options.
+///
+/// Files that do not invoke any of `options.macros` are skipped without a
+/// `syn` parse and yield no elements.
+pub fn parse_file_with(path: &Path, options: &ParseOptions) -> Result<ParsedFile, ParseError> {
+    parse_file_from(path, options, &FileSystem)
+}
+
+/// Like [`parse_file_with`], reading the file through `sources` (e.g. an
+/// editor's unsaved buffers) instead of from disk.
+pub fn parse_file_from(
+    path: &Path,
+    options: &ParseOptions,
+    sources: &dyn SourceProvider,
+) -> Result<ParsedFile, ParseError> {
     let file_path = normalize_path(path);
-    let source = std::fs::read_to_string(path)
-        .map_err(|e| ParseError::IoError(file_path.clone(), e.to_string()))?;
+    let source = sources.read(path).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::InvalidData {
+            ParseError::BinaryFile(file_path.clone())
+        } else {
+            ParseError::IoError(file_path.clone(), e.to_string())
+        }
+    })?;
+    if source.contains('\0') {
+        return Err(ParseError::BinaryFile(file_path));
+    }
 
-    parse_source(&source, &file_path)
+    if options.skip_generated && is_generated(&source) {
+        return Ok(ParsedFile {
+            generated: true,
+            ..ParsedFile::default()
+        });
+    }
+    if !may_contain_rsx(&source, &options.macros) {
+        return Ok(ParsedFile::default());
+    }
+
+    parse_source_with(&source, &file_path, options)
 }
 
 /// Parse Rust source code and extract HTML elements from supported macros.
+///
+/// Like [`parse_file`], invalid macros are only an error if none could be
+/// parsed.
 pub fn parse_source(source: &str, file_path: &str) -> Result<Vec<HtmlElement>, ParseError> {
-    let syntax_tree = syn::parse_file(source)
-        .map_err(|e| ParseError::SynError(file_path.to_string(), e.to_string()))?;
+    parse_source_with(source, file_path, &ParseOptions::default())?.into_elements()
+}
 
+/// Parse Rust source code, recording each invocation of `options.macros`
+/// that is not valid RSX as a [`MacroError`].
+///
+/// If the file is not valid Rust syntax (e.g. nightly-only syntax or a
+/// half-written file), the source is lexed into tokens instead and macro
+/// invocations are found by scanning for `name! (...)`. The syntax error is
+/// only returned if that fallback finds no elements either.
+pub fn parse_source_with(
+    source: &str,
+    file_path: &str,
+    options: &ParseOptions,
+) -> Result<ParsedFile, ParseError> {
     let mut visitor = MacroVisitor {
         elements: Vec::new(),
-        file_path: file_path.to_string(),
-        rstml_errors: Vec::new(),
+        components: Vec::new(),
+        file_path: file_path.into(),
+        macros: &options.macros,
+        custom_elements: &options.custom_elements,
+        classes: &options.classes,
+        expand_macro_rules: options.expand_macro_rules,
+        macro_errors: Vec::new(),
+        macro_count: 0,
+        component: None,
+        declared_components: Vec::new(),
     };
 
-    visitor.visit_file(&syntax_tree);
-
-    // If no elements were found but rstml reported errors, surface them.
-    if visitor.elements.is_empty() && !visitor.rstml_errors.is_empty() {
-        return Err(ParseError::RstmlError(
-            file_path.to_string(),
-            visitor.rstml_errors.join("; "),
-        ));
+    match syn::parse_file(source) {
+        Ok(syntax_tree) => visitor.visit_file(&syntax_tree),
+        Err(e) => {
+            let syn_error = ParseError::SynError(file_path.to_string(), e.to_string());
+            let tokens = source
+                .parse::<proc_macro2::TokenStream>()
+                .map_err(|_| syn_error.clone())?;
+            visitor.scan_tokens(tokens);
+            if visitor.elements.is_empty() {
+                return Err(syn_error);
+            }
+        }
     }
 
-    Ok(visitor.elements)
+    Ok(ParsedFile {
+        elements: visitor.elements,
+        components: visitor.components,
+        declared_components: visitor.declared_components,
+        macro_errors: visitor.macro_errors,
+        generated: false,
+    })
+}
+
+/// Extract HTML elements from the macro invocations in a token stream, such
+/// as the item an attribute macro is applied to.
+///
+/// Positions come from the tokens' spans.
+pub fn parse_tokens(
+    tokens: proc_macro2::TokenStream,
+    file_path: &str,
+    options: &ParseOptions,
+) -> ParsedFile {
+    let mut visitor = MacroVisitor {
+        elements: Vec::new(),
+        components: Vec::new(),
+        file_path: file_path.into(),
+        macros: &options.macros,
+        custom_elements: &options.custom_elements,
+        classes: &options.classes,
+        expand_macro_rules: options.expand_macro_rules,
+        macro_errors: Vec::new(),
+        macro_count: 0,
+        component: None,
+        declared_components: Vec::new(),
+    };
+    visitor.scan_tokens(tokens);
+    ParsedFile {
+        elements: visitor.elements,
+        components: visitor.components,
+        declared_components: visitor.declared_components,
+        macro_errors: visitor.macro_errors,
+        generated: false,
+    }
 }
 
 /// Errors that can occur during parsing.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum ParseError {
     IoError(String, String),
     SynError(String, String),
     /// RSX/HTML content inside a macro could not be parsed.
     RstmlError(String, String),
+    /// The file is not UTF-8 text (invalid UTF-8 or NUL bytes).
+    BinaryFile(String),
 }
 
 impl std::fmt::Display for ParseError {
@@ -164,42 +762,507 @@ impl std::fmt::Display for ParseError {
             ParseError::RstmlError(path, err) => {
                 write!(f, "Invalid RSX in {}: {}", path, err)
             }
+            ParseError::BinaryFile(path) => write!(f, "{} is not UTF-8 text", path),
         }
     }
 }
 
+impl std::error::Error for ParseError {}
+
 /// AST visitor that finds macro invocations.
-struct MacroVisitor {
+struct MacroVisitor<'a> {
     elements: Vec<HtmlElement>,
+    components: Vec<ComponentUse>,
     file_path: String,
-    /// Errors from rstml when parsing macro token streams.
-    rstml_errors: Vec<String>,
+    /// Macro names whose parse failures are reported.
+    macros: &'a [String],
+    custom_elements: &'a BTreeMap<String, CustomElement>,
+    classes: &'a HidingClasses,
+    expand_macro_rules: bool,
+    macro_errors: Vec<MacroError>,
+    /// Number of macro invocations that produced elements so far.
+    macro_count: usize,
+    /// The component being visited, see [`HtmlElement::component`].
+    component: Option<Arc<str>>,
+    /// See [`ParsedFile::declared_components`].
+    declared_components: Vec<Arc<str>>,
 }
 
-impl<'ast> Visit<'ast> for MacroVisitor {
-    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
-        match rstml::parse2(mac.tokens.clone()) {
-            Ok(nodes) => {
-                let mut elements = Vec::new();
-                collect_elements_from_nodes(&mut elements, &nodes, &self.file_path);
-                self.elements.append(&mut elements);
+/// Elements and component usages collected from one macro invocation.
+#[derive(Default)]
+struct Collected {
+    elements: Vec<HtmlElement>,
+    components: Vec<ComponentUse>,
+    /// Index of the element being collected into.
+    parent: Option<usize>,
+    /// Macros outside the content (in attribute values, or not valid RSX),
+    /// to be collected as invocations of their own.
+    detached: Vec<(proc_macro2::Ident, proc_macro2::TokenStream)>,
+    /// Number of conditionals seen so far, and the branches being collected
+    /// into.
+    conditionals: usize,
+    branches: Vec<Branch>,
+}
+
+impl Collected {
+    /// Collect the elements added by `f` into `arm` of `conditional`.
+    fn branch(&mut self, conditional: usize, arm: usize, f: impl FnOnce(&mut Self)) {
+        self.branches.push(Branch { conditional, arm });
+        f(self);
+        self.branches.pop();
+    }
+
+    /// Start a new conditional and return its index.
+    fn conditional(&mut self) -> usize {
+        self.conditionals += 1;
+        self.conditionals - 1
+    }
+}
+
+impl MacroVisitor<'_> {
+    /// Parse the tokens of one macro invocation as RSX.
+    ///
+    /// Only invocations of `macros` are parsed, the same names
+    /// [`may_contain_rsx`] looks for; other macros (`vec!`, `format!`, ...)
+    /// are only searched for RSX macros inside them.
+    ///
+    /// RSX nested in the content (`{move || view! { ... }}`, the branches of
+    /// an `if`) belongs to the invocation, under the enclosing element. Other
+    /// nested macros (`renderer={html! { ... }}`, `vec![view! { ... }]`) are
+    /// collected afterwards as invocations of their own. `macro_rules!`
+    /// templates are left to [`ParseOptions::expand_macro_rules`].
+    fn collect_macro(&mut self, name: &proc_macro2::Ident, tokens: proc_macro2::TokenStream) {
+        if name == "macro_rules" {
+            return;
+        }
+        if !self.macros.iter().any(|m| name == m) {
+            self.scan_tokens(tokens);
+            return;
+        }
+        let macro_name = name.to_string();
+        let nested = tokens.clone();
+        let mut collected = Collected::default();
+        let parsed = collect_rsx(
+            &mut collected,
+            &macro_name,
+            tokens,
+            &self.file_path,
+            false,
+            false,
+            self.classes,
+        );
+        match parsed {
+            Ok(()) => {
+                let offset = self.elements.len();
+                for usage in &mut collected.components {
+                    usage.macro_index = self.macro_count;
+                    usage.component = self.component.clone();
+                    usage.parent = usage.parent.map(|i| i + offset);
+                    usage.children += offset;
+                }
+                if !collected.elements.is_empty() {
+                    for element in &mut collected.elements {
+                        element.macro_index = self.macro_count;
+                        element.component = self.component.clone();
+                        if let Tag::Custom(name) = &element.tag {
+                            element.custom_element = self.custom_elements.get(name).cloned();
+                        }
+                    }
+                    self.macro_count += 1;
+                }
+                self.elements.append(&mut collected.elements);
+                self.components.append(&mut collected.components);
+                for (name, tokens) in collected.detached {
+                    self.collect_macro(&name, tokens);
+                }
+                return;
             }
             Err(err) => {
-                self.rstml_errors.push(err.to_string());
+                // Errors without a real location point at the macro name.
+                let error_start = err.span().start();
+                let start = if error_start.line == 0 {
+                    name.span().start()
+                } else {
+                    error_start
+                };
+                self.macro_errors.push(MacroError {
+                    file: self.file_path.clone(),
+                    line: start.line,
+                    column: start.column,
+                    macro_name: macro_name.to_string(),
+                    message: err.to_string(),
+                });
+            }
+        }
+        self.scan_tokens(nested);
+    }
+
+    /// Find macro invocations (`ident ! group`) in raw tokens: in files that
+    /// `syn` could not parse, and in the tokens of other macros.
+    fn scan_tokens(&mut self, tokens: proc_macro2::TokenStream) {
+        use proc_macro2::TokenTree;
+
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let mut i = 0;
+        while i < tokens.len() {
+            if let (
+                TokenTree::Ident(name),
+                Some(TokenTree::Punct(bang)),
+                Some(TokenTree::Group(group)),
+            ) = (&tokens[i], tokens.get(i + 1), tokens.get(i + 2))
+                && bang.as_char() == '!'
+            {
+                self.collect_macro(name, group.stream());
+                i += 3;
+                continue;
+            }
+            if let TokenTree::Group(group) = &tokens[i] {
+                self.scan_tokens(group.stream());
             }
+            i += 1;
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for MacroVisitor<'_> {
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if let Some(segment) = mac.path.segments.last() {
+            self.collect_macro(&segment.ident, mac.tokens.clone());
         }
         syn::visit::visit_macro(self, mac);
     }
+
+    fn visit_item_macro(&mut self, item: &'ast syn::ItemMacro) {
+        if self.expand_macro_rules && item.mac.path.is_ident("macro_rules") {
+            // Templates are not complete RSX on their own (`<$tag>`), so
+            // only the elements of those that parse are kept.
+            let errors = self.macro_errors.len();
+            self.scan_tokens(placeholder_metavars(item.mac.tokens.clone()));
+            self.macro_errors.truncate(errors);
+        }
+        syn::visit::visit_item_macro(self, item);
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        let name = component_name(&item.attrs, &item.sig.ident);
+        if item.attrs.iter().any(is_component_attr) {
+            self.declared_components.push(name.clone());
+        }
+        let outer = self.component.replace(name);
+        syn::visit::visit_item_fn(self, item);
+        self.component = outer;
+    }
+
+    fn visit_item_impl(&mut self, item: &'ast syn::ItemImpl) {
+        // Yew struct components render in `impl Component for Name`.
+        let is_component = item
+            .trait_
+            .as_ref()
+            .and_then(|(_, path, _)| path.segments.last())
+            .is_some_and(|segment| segment.ident == "Component");
+        let name = match &*item.self_ty {
+            syn::Type::Path(path) if is_component => path.path.segments.last(),
+            _ => None,
+        };
+        let Some(name) = name else {
+            syn::visit::visit_item_impl(self, item);
+            return;
+        };
+        let outer = self.component.replace(name.ident.to_string().into());
+        // Methods of the impl belong to the component, not their own names.
+        for impl_item in &item.items {
+            match impl_item {
+                syn::ImplItem::Fn(method) => syn::visit::visit_block(self, &method.block),
+                other => syn::visit::visit_impl_item(self, other),
+            }
+        }
+        self.component = outer;
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast syn::ImplItemFn) {
+        let name = component_name(&item.attrs, &item.sig.ident);
+        if item.attrs.iter().any(is_component_attr) {
+            self.declared_components.push(name.clone());
+        }
+        let outer = self.component.replace(name);
+        syn::visit::visit_impl_item_fn(self, item);
+        self.component = outer;
+    }
+}
+
+/// The component a function defines: the name in `#[function_component(Name)]`
+/// or `#[component(Name)]`, or else the function's own name.
+fn component_name(attrs: &[syn::Attribute], ident: &syn::Ident) -> Arc<str> {
+    attrs
+        .iter()
+        .filter(|attr| is_component_attr(attr))
+        .find_map(|attr| attr.parse_args::<syn::Ident>().ok())
+        .unwrap_or_else(|| ident.clone())
+        .to_string()
+        .into()
+}
+
+/// Whether `attr` is `#[function_component]` or `#[component]`, with or
+/// without a name.
+fn is_component_attr(attr: &syn::Attribute) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|s| s.ident == "function_component" || s.ident == "component")
+}
+
+/// Parse `tokens`, the content of a `macro_name!` invocation, as RSX and
+/// collect its elements into `acc`.
+fn collect_rsx(
+    acc: &mut Collected,
+    macro_name: &str,
+    tokens: proc_macro2::TokenStream,
+    file_path: &str,
+    in_svg: bool,
+    inside_interactive: bool,
+    classes: &HidingClasses,
+) -> syn::Result<()> {
+    match Framework::from_macro(macro_name) {
+        Some(Framework::Dioxus) if dioxus::is_rsx_syntax(&tokens) => {
+            let nodes = dioxus::parse(tokens)?;
+            dioxus::collect_elements(
+                acc,
+                &nodes,
+                file_path,
+                macro_name,
+                in_svg,
+                inside_interactive,
+                classes,
+            );
+        }
+        framework => {
+            let tokens = match framework {
+                Some(Framework::Yew) => yew_to_rstml(tokens),
+                _ => tokens,
+            };
+            let nodes = rstml::parse2(tokens)?;
+            collect_elements_from_nodes(
+                acc,
+                &nodes,
+                file_path,
+                macro_name,
+                in_svg,
+                inside_interactive,
+                classes,
+            );
+        }
+    }
+    Ok(())
 }
 
-/// Recursively collect HtmlElements from rstml nodes.
-fn collect_elements_from_nodes(acc: &mut Vec<HtmlElement>, nodes: &[Node], file_path: &str) {
+/// Collect the RSX macros in `tokens`, an expression in the content of an
+/// element (a block, or the branches of an `if`, `match` or closure), as
+/// content of that element. Macros that are not valid RSX are detached.
+///
+/// The branches of an `if`/`else` or `match` are collected as [`Branch`]es
+/// of one conditional. If `tokens` are not valid Rust, every macro in them
+/// is collected as if always rendered.
+fn collect_nested(
+    acc: &mut Collected,
+    tokens: proc_macro2::TokenStream,
+    file_path: &str,
+    in_svg: bool,
+    inside_interactive: bool,
+    classes: &HidingClasses,
+) {
+    use syn::parse::Parser;
+
+    let mut nested = Nested {
+        acc,
+        file_path,
+        in_svg,
+        inside_interactive,
+        classes,
+    };
+    match syn::Block::parse_within.parse2(tokens.clone()) {
+        Ok(stmts) => stmts.iter().for_each(|stmt| nested.visit_stmt(stmt)),
+        Err(_) => for_each_macro(tokens, &mut |name, tokens| nested.collect(name, tokens)),
+    }
+}
+
+/// Visits an expression for [`collect_nested`].
+struct Nested<'a> {
+    acc: &'a mut Collected,
+    file_path: &'a str,
+    in_svg: bool,
+    inside_interactive: bool,
+    classes: &'a HidingClasses,
+}
+
+impl Nested<'_> {
+    fn collect(&mut self, name: proc_macro2::Ident, tokens: proc_macro2::TokenStream) {
+        // Parsing fails before anything is collected.
+        let parsed = collect_rsx(
+            self.acc,
+            &name.to_string(),
+            tokens.clone(),
+            self.file_path,
+            self.in_svg,
+            self.inside_interactive,
+            self.classes,
+        );
+        if parsed.is_err() {
+            self.acc.detached.push((name, tokens));
+        }
+    }
+
+    fn branch(&mut self, conditional: usize, arm: usize, f: impl FnOnce(&mut Self)) {
+        self.acc.branches.push(Branch { conditional, arm });
+        f(self);
+        self.acc.branches.pop();
+    }
+}
+
+impl<'ast> Visit<'ast> for Nested<'_> {
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if let Some(segment) = mac.path.segments.last() {
+            self.collect(segment.ident.clone(), mac.tokens.clone());
+        }
+    }
+
+    fn visit_expr_if(&mut self, mut expr: &'ast syn::ExprIf) {
+        let conditional = self.acc.conditional();
+        for arm in 0.. {
+            self.visit_expr(&expr.cond);
+            self.branch(conditional, arm, |v| v.visit_block(&expr.then_branch));
+            match expr.else_branch.as_ref().map(|(_, e)| &**e) {
+                Some(syn::Expr::If(next)) => expr = next,
+                Some(other) => {
+                    self.branch(conditional, arm + 1, |v| v.visit_expr(other));
+                    break;
+                }
+                None => break,
+            }
+        }
+    }
+
+    fn visit_expr_match(&mut self, expr: &'ast syn::ExprMatch) {
+        self.visit_expr(&expr.expr);
+        let conditional = self.acc.conditional();
+        for (arm, body) in expr.arms.iter().enumerate() {
+            self.branch(conditional, arm, |v| v.visit_arm(body));
+        }
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if let syn::Expr::Path(func) = &*call.func
+            && func.qself.is_none()
+        {
+            let segments = &func.path.segments;
+            let start = segments
+                .first()
+                .map_or(call.span(), |s| s.ident.span())
+                .start();
+            self.acc.components.push(ComponentUse {
+                name: segments
+                    .iter()
+                    .map(|s| s.ident.to_string())
+                    .collect::<Vec<_>>()
+                    .join("::"),
+                line: start.line,
+                column: start.column,
+                macro_index: 0,
+                component: None,
+                parent: self.acc.parent,
+                children: self.acc.elements.len(),
+                descendants: 0,
+                call: true,
+            });
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+}
+
+/// Detach the macros in `tokens`, e.g. an attribute value, to be collected as
+/// invocations of their own.
+fn detach_nested(acc: &mut Collected, tokens: proc_macro2::TokenStream) {
+    for_each_macro(tokens, &mut |name, tokens| {
+        acc.detached.push((name, tokens))
+    });
+}
+
+/// Call `f` with the name and tokens of each macro invocation (`ident !
+/// group`) in `tokens`, outside of other invocations.
+fn for_each_macro(
+    tokens: proc_macro2::TokenStream,
+    f: &mut impl FnMut(proc_macro2::Ident, proc_macro2::TokenStream),
+) {
+    use proc_macro2::TokenTree;
+
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(name) if matches!(tokens.peek(), Some(TokenTree::Punct(bang)) if bang.as_char() == '!') =>
+            {
+                tokens.next();
+                if let Some(TokenTree::Group(group)) =
+                    tokens.next_if(|t| matches!(t, TokenTree::Group(_)))
+                {
+                    f(name, group.stream());
+                }
+            }
+            TokenTree::Group(group) => for_each_macro(group.stream(), f),
+            _ => {}
+        }
+    }
+}
+
+/// Recursively collect HtmlElements from rstml nodes. `in_svg` is set inside
+/// `<svg>` (but not its `<foreignObject>`), where `<title>` is an SVG title,
+/// and `inside_interactive` inside natively interactive elements.
+fn collect_elements_from_nodes(
+    acc: &mut Collected,
+    nodes: &[Node],
+    file_path: &str,
+    source_macro: &str,
+    in_svg: bool,
+    inside_interactive: bool,
+    classes: &HidingClasses,
+) {
     for node in nodes {
         match node {
             Node::Element(node_element) => {
-                if let Some(tag) = Tag::from_str(&node_element.name().to_string()) {
+                let tag = Tag::from_str(&node_element.name().to_string());
+                let children_in_svg = match tag {
+                    Ok(Tag::Svg) => true,
+                    Ok(Tag::ForeignObject) => false,
+                    _ => in_svg,
+                };
+                let children_inside_interactive =
+                    inside_interactive || tag.as_ref().is_ok_and(Tag::is_interactive);
+                let is_element = tag.is_ok();
+                let name = node_element.name().to_string();
+                let is_component = !is_element && is_component_name(&name);
+                if is_component {
+                    let start = node_element.name().span().start();
+                    acc.components.push(ComponentUse {
+                        name: name.replace(' ', ""),
+                        line: start.line,
+                        column: start.column,
+                        macro_index: 0,
+                        component: None,
+                        parent: acc.parent,
+                        children: acc.elements.len(),
+                        descendants: 0,
+                        call: false,
+                    });
+                }
+                let usage = acc.components.len();
+                for attr in node_element.attributes() {
+                    detach_nested(acc, attr.to_token_stream());
+                }
+                if let Ok(mut tag) = tag {
+                    if in_svg && tag == Tag::Title {
+                        tag = Tag::SvgTitle;
+                    }
                     let line_column = node_element.name().span().start();
-                    let element = HtmlElement {
+                    let end = node_element.span().end();
+                    let mut element = HtmlElement {
                         tag,
                         attributes: node_element
                             .attributes()
@@ -210,49 +1273,730 @@ fn collect_elements_from_nodes(acc: &mut Vec<HtmlElement>, nodes: &[Node], file_
                             })
                             .map(|keyed_attribute| {
                                 let line_column = keyed_attribute.key.span().start();
+                                let end = keyed_attribute.span().end();
                                 let attr_key = keyed_attribute.key.to_string();
                                 HtmlAttribute {
-                                    name: AttributeName::from_str(&attr_key)
-                                        .unwrap_or(AttributeName::Unknown(attr_key)),
-                                    value: Some(
-                                        keyed_attribute
-                                            .value_literal_string()
-                                            .map(AttrValue::Static)
-                                            .unwrap_or(AttrValue::Dynamic),
-                                    ),
+                                    name: attribute_name(attr_key),
+                                    value: Some(attr_value(keyed_attribute)),
                                     line: line_column.line,
                                     column: line_column.column,
+                                    end_line: end.line,
+                                    end_column: end.column,
                                 }
                             })
                             .collect(),
                         is_self_closing: node_element.close_tag.is_none(),
                         has_children: !node_element.children.is_empty(),
+                        text: static_text(&node_element.children, classes),
                         line: line_column.line,
                         column: line_column.column,
+                        end_line: end.line,
+                        end_column: end.column,
                         file: file_path.to_string(),
+                        source_macro: source_macro.to_string(),
+                        macro_index: 0,
+                        custom_element: None,
+                        class_visibility: ClassVisibility::Visible,
+                        inside_interactive,
+                        descendants: 0,
+                        component: None,
+                        syntax: Syntax::Tag,
+                        branches: acc.branches.clone(),
                     };
-                    acc.push(element);
+                    element.class_visibility =
+                        classes.visibility(&static_classes(node_element.attributes()));
+                    if has_inner_html(&element.attributes) {
+                        element.has_children = true;
+                        element.text = None;
+                    }
+                    acc.elements.push(element);
+                }
+                let index = acc.elements.len();
+                let outer = acc.parent;
+                if is_element {
+                    acc.parent = Some(index - 1);
                 }
                 // Recurse into children
-                collect_elements_from_nodes(acc, &node_element.children, file_path);
+                collect_elements_from_nodes(
+                    acc,
+                    &node_element.children,
+                    file_path,
+                    source_macro,
+                    children_in_svg,
+                    children_inside_interactive,
+                    classes,
+                );
+                acc.parent = outer;
+                if is_element {
+                    acc.elements[index - 1].descendants = acc.elements.len() - index;
+                } else if is_component {
+                    acc.components[usage - 1].descendants = acc.elements.len() - index;
+                }
             }
             Node::Fragment(fragment) => {
-                collect_elements_from_nodes(acc, &fragment.children, file_path);
+                collect_elements_from_nodes(
+                    acc,
+                    &fragment.children,
+                    file_path,
+                    source_macro,
+                    in_svg,
+                    inside_interactive,
+                    classes,
+                );
             }
+            Node::Block(block) => collect_nested(
+                acc,
+                block.to_token_stream(),
+                file_path,
+                in_svg,
+                inside_interactive,
+                classes,
+            ),
             _ => {}
         }
     }
 }
 
+/// The value of `attr`: static for a literal, also when braced (`{"x"}`,
+/// `{false}`), and for Yew's `classes!` of string literals.
+fn attr_value(attr: &KeyedAttribute) -> AttrValue {
+    attr.value_literal_string()
+        .or_else(|| attr.value().and_then(static_expr))
+        .map(AttrValue::Static)
+        .unwrap_or(AttrValue::Dynamic)
+}
+
+/// The text `expr` always evaluates to, if it is a literal, a block holding
+/// only one, or `classes!` of literals.
+fn static_expr(expr: &syn::Expr) -> Option<String> {
+    let classes = |mac: &syn::Macro| {
+        if mac.path.segments.last()?.ident != "classes" {
+            return None;
+        }
+        let args = mac
+            .parse_body_with(
+                syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated,
+            )
+            .ok()?;
+        let classes = args.iter().map(static_expr).collect::<Option<Vec<_>>>()?;
+        Some(
+            classes
+                .join(" ")
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    };
+    match expr {
+        syn::Expr::Lit(expr) => match &expr.lit {
+            syn::Lit::Str(lit) => Some(lit.value()),
+            syn::Lit::Char(lit) => Some(lit.value().to_string()),
+            syn::Lit::Int(lit) => Some(lit.base10_digits().to_string()),
+            syn::Lit::Float(lit) => Some(lit.base10_digits().to_string()),
+            syn::Lit::Bool(lit) => Some(lit.value.to_string()),
+            _ => None,
+        },
+        syn::Expr::Block(expr) => match expr.block.stmts.as_slice() {
+            [syn::Stmt::Expr(expr, None)] => static_expr(expr),
+            [syn::Stmt::Macro(stmt)] if stmt.semi_token.is_none() => classes(&stmt.mac),
+            _ => None,
+        },
+        syn::Expr::Macro(expr) => classes(&expr.mac),
+        _ => None,
+    }
+}
+
+/// Whether `attributes` set the element's content from an HTML string, as
+/// Dioxus' `dangerous_inner_html` does.
+fn has_inner_html(attributes: &[HtmlAttribute]) -> bool {
+    attributes.iter().any(
+        |a| matches!(&a.name, AttributeName::Directive(name) if name == "dangerous_inner_html"),
+    )
+}
+
+/// The classes an element always has: its static `class`, plus the Leptos
+/// `class:name` toggles that are statically `true`.
+fn static_classes(attributes: &[NodeAttribute]) -> String {
+    let mut classes = Vec::new();
+    for attr in attributes {
+        let NodeAttribute::Attribute(attr) = attr else {
+            continue;
+        };
+        let key = attr.key.to_string();
+        match (attribute_name(key), attr_value(attr)) {
+            (AttributeName::Class, AttrValue::Static(class)) => classes.push(class),
+            (AttributeName::Directive(key), AttrValue::Static(on)) if on == "true" => {
+                if let Some(class) = key.strip_prefix("class:") {
+                    classes.push(class.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    classes.join(" ")
+}
+
+/// Replace the metavariables of `macro_rules!` rules so the RSX macros in
+/// their transcribers parse: `$title` becomes the block `{title}`, and a
+/// repetition `$( ... ),*` its content, once.
+fn placeholder_metavars(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+
+    let mut tokens = tokens.into_iter().peekable();
+    let mut out = Vec::new();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(dollar) if dollar.as_char() == '$' => match tokens.next() {
+                Some(TokenTree::Ident(name)) if name == "crate" => out.push(TokenTree::Ident(name)),
+                Some(TokenTree::Ident(name)) => {
+                    let span = name.span();
+                    let mut block =
+                        Group::new(Delimiter::Brace, TokenStream::from(TokenTree::Ident(name)));
+                    block.set_span(span);
+                    out.push(TokenTree::Group(block));
+                }
+                Some(TokenTree::Group(repetition)) => {
+                    out.extend(placeholder_metavars(repetition.stream()));
+                    // An optional separator, then the repetition operator.
+                    let is_operator = |t: &TokenTree| matches!(t, TokenTree::Punct(p) if "*+?".contains(p.as_char()));
+                    if tokens.next_if(is_operator).is_none() {
+                        tokens.next();
+                        tokens.next_if(is_operator);
+                    }
+                }
+                Some(other) => out.extend([TokenTree::Punct(dollar), other]),
+                None => out.push(TokenTree::Punct(dollar)),
+            },
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), placeholder_metavars(group.stream()));
+                replaced.set_span(group.span());
+                out.push(TokenTree::Group(replaced));
+            }
+            other => out.push(other),
+        }
+    }
+    out.into_iter().collect()
+}
+
+/// Rewrite Yew's own syntax, which rstml does not accept: drop the `~` put
+/// before attributes to set them as properties (`<input ~value={v} />`), and
+/// the `for` of iterator blocks (`{ for items.iter().map(..) }`).
+fn yew_to_rstml(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    use proc_macro2::{Delimiter, Group, TokenTree};
+
+    tokens
+        .into_iter()
+        .filter(|t| !matches!(t, TokenTree::Punct(p) if p.as_char() == '~'))
+        .map(|t| match t {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                let mut inner = group.stream().into_iter().peekable();
+                let is_for = inner.next_if(|t| matches!(t, TokenTree::Ident(i) if i == "for"));
+                // `{ for x in xs {} }` is a Rust loop, not an iterator block.
+                let tokens: proc_macro2::TokenStream = inner.collect();
+                let is_loop = || {
+                    tokens
+                        .clone()
+                        .into_iter()
+                        .any(|t| matches!(t, TokenTree::Ident(i) if i == "in"))
+                };
+                if is_for.is_none() || is_loop() {
+                    return TokenTree::Group(group);
+                }
+                let mut block = Group::new(Delimiter::Brace, tokens);
+                block.set_span(group.span());
+                TokenTree::Group(block)
+            }
+            t => t,
+        })
+        .collect()
+}
+
+/// Whether `name`, written where a tag goes, names a component: its last
+/// path segment starts with an uppercase letter.
+fn is_component_name(name: &str) -> bool {
+    name.rsplit("::")
+        .next()
+        .is_some_and(|s| s.trim().starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+/// Static text of `nodes`, or `None` if any of it is dynamic. Nested elements
+/// contribute their `aria-label` (or `alt` for `<img>`) instead of their
+/// content, and nothing if they are `aria-hidden` or hidden by an inline
+/// `style`. Components render unknown content, so they count as dynamic.
+fn static_text(nodes: &[Node], classes: &HidingClasses) -> Option<String> {
+    let mut parts = Vec::new();
+    for node in nodes {
+        match node {
+            Node::Text(text) => parts.push(text.value_string()),
+            Node::RawText(text) => parts.push(text.to_string_best()),
+            Node::Block(block) => {
+                let block = block.try_block()?;
+                match block.stmts.as_slice() {
+                    [syn::Stmt::Expr(syn::Expr::Lit(expr), None)] => match &expr.lit {
+                        syn::Lit::Str(lit) => parts.push(lit.value()),
+                        syn::Lit::Char(lit) => parts.push(lit.value().to_string()),
+                        syn::Lit::Int(lit) => parts.push(lit.to_string()),
+                        _ => return None,
+                    },
+                    _ => return None,
+                }
+            }
+            Node::Fragment(fragment) => parts.push(static_text(&fragment.children, classes)?),
+            Node::Element(element) => {
+                let name = element.name().to_string();
+                if Tag::from_str(&name).is_err() && is_component_name(&name) {
+                    return None;
+                }
+                let attr = |name: AttributeName| {
+                    element.attributes().iter().find_map(|attr| match attr {
+                        NodeAttribute::Attribute(attr)
+                            if attribute_name(attr.key.to_string()) == name =>
+                        {
+                            Some(attr.value_literal_string())
+                        }
+                        _ => None,
+                    })
+                };
+                if attr(AttributeName::Aria(Aria::Hidden)) == Some(Some("true".to_string()))
+                    || attr(AttributeName::Style)
+                        .flatten()
+                        .is_some_and(|style| crate::style::is_hidden(&style))
+                    || classes.visibility(&static_classes(element.attributes()))
+                        == ClassVisibility::Hidden
+                {
+                    continue;
+                }
+                let is_img = name == "img";
+                let label = attr(AttributeName::Aria(Aria::Label))
+                    .or_else(|| is_img.then(|| attr(AttributeName::Alt)).flatten());
+                match label {
+                    Some(label) => parts.push(label?),
+                    None => parts.push(static_text(&element.children, classes)?),
+                }
+            }
+            Node::Comment(_) | Node::Doctype(_) | Node::Custom(_) => {}
+        }
+    }
+    Some(parts.join(" "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::dom::Aria;
 
     fn parse_test(source: &str) -> Vec<HtmlElement> {
         parse_source(source, "test.rs").unwrap()
     }
 
+    #[test]
+    fn test_parse_error_works_with_question_mark() {
+        fn parse() -> Result<usize, Box<dyn std::error::Error>> {
+            Ok(parse_source("fn c( {", "test.rs")?.len())
+        }
+        let err = parse().unwrap_err();
+        assert!(err.to_string().starts_with("Failed to parse test.rs"));
+    }
+
+    #[test]
+    fn test_svg_children() {
+        let elements = parse_test(
+            r#"fn c() { html! { <svg><title>{"Close"}</title><g><path d="M0" /></g><foreignObject><title>{"x"}</title></foreignObject></svg> } }"#,
+        );
+        let tags: Vec<Tag> = elements.into_iter().map(|e| e.tag).collect();
+        assert_eq!(
+            tags,
+            [
+                Tag::Svg,
+                Tag::SvgTitle,
+                Tag::G,
+                Tag::Path,
+                Tag::ForeignObject,
+                Tag::Title
+            ]
+        );
+        assert_eq!(Tag::SvgTitle.to_string(), "title");
+    }
+
+    #[test]
+    fn test_static_text_content() {
+        let elements = parse_test(
+            r#"fn c() { html! {
+                <p>{"Read"} <b>{"more"}</b> <img alt="news" /> <i aria-hidden="true">{"x"}</i> <s style="display: none">{"y"}</s></p>
+                <p>{"Hi, "}{name}</p>
+                <p>{"Go"}<Icon /></p>
+            } }"#,
+        );
+        assert_eq!(elements[0].text.as_deref(), Some("Read more news"));
+        assert_eq!(elements[2].text.as_deref(), Some(""));
+        assert_eq!(elements[5].text, None);
+        // Components render unknown content.
+        assert_eq!(elements[6].text, None);
+    }
+
+    #[test]
+    fn test_descendant_counts() {
+        let elements = parse_test(
+            r#"fn c() { html! { <ul><li><a href="/">{"x"}</a></li><Item /><li></li></ul> } }"#,
+        );
+        let counts: Vec<_> = elements.iter().map(|e| e.descendants).collect();
+        assert_eq!(counts, [3, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_hiding_classes() {
+        let mut options = ParseOptions::default();
+        options.classes.hidden.push("hidden".to_string());
+        let elements = parse_source_with(
+            r#"fn c() { html! {
+                <a><span class="hidden">{"Menu"}</span><span class="sr-only">{"Open menu"}</span></a>
+            } }"#,
+            "test.rs",
+            &options,
+        )
+        .unwrap()
+        .elements;
+        assert_eq!(elements[0].text.as_deref(), Some("Open menu"));
+        assert!(elements[1].is_hidden());
+        assert_eq!(
+            elements[2].class_visibility,
+            ClassVisibility::ScreenReaderOnly
+        );
+    }
+
+    #[test]
+    fn test_invalid_rust_syntax_falls_back_to_token_scan() {
+        let elements = parse_test(
+            r#"
+            fn component() {
+                let x = 1 +;
+                html! { <img src="a.png" /> }
+            }
+        "#,
+        );
+        assert_eq!(elements.len(), 1);
+        assert_eq!(elements[0].tag, Tag::Img);
+        assert_eq!(elements[0].line, 4);
+    }
+
+    #[test]
+    fn test_unlexable_or_empty_fallback_is_an_error() {
+        assert!(matches!(
+            parse_source("fn c() { html! { <img /> }", "test.rs"),
+            Err(ParseError::SynError(_, _))
+        ));
+        assert!(matches!(
+            parse_source("fn c() { let x = 1 +; }", "test.rs"),
+            Err(ParseError::SynError(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_macro_does_not_hide_others() {
+        let source = r#"
+            fn a() { html! { <img src="a.png" /> } }
+            fn b() { html! { <div> } }
+            fn c() { let v = vec![1, 2]; }
+        "#;
+        let parsed = parse_source_with(source, "test.rs", &ParseOptions::default()).unwrap();
+        assert_eq!(parsed.elements.len(), 1);
+        assert_eq!(parsed.macro_errors.len(), 1);
+        let err = &parsed.macro_errors[0];
+        assert_eq!(err.macro_name, "html");
+        assert_eq!(err.line, 3);
+        assert_eq!(err.file, "test.rs");
+
+        // The element-only API still fails when nothing could be parsed.
+        assert!(matches!(
+            parse_source("fn b() { html! { <div> } }", "test.rs"),
+            Err(ParseError::RstmlError(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_source_macro_and_framework() {
+        let options = ParseOptions {
+            macros: vec!["html".into(), "view".into(), "my_rsx".into()],
+            ..ParseOptions::default()
+        };
+        let elements = parse_source_with(
+            r#"
+            fn a() { yew::html! { <img /> } }
+            fn b() { view! { <div><span /></div> } }
+            fn c() { my_rsx! { <p /> } }
+        "#,
+            "test.rs",
+            &options,
+        )
+        .unwrap()
+        .elements;
+        let macros: Vec<_> = elements.iter().map(|e| e.source_macro.as_str()).collect();
+        assert_eq!(macros, ["html", "view", "view", "my_rsx"]);
+        assert_eq!(elements[0].framework(), Some(Framework::Yew));
+        assert_eq!(elements[1].framework(), Some(Framework::Leptos));
+        assert_eq!(elements[3].framework(), None);
+        assert_eq!(Framework::Dioxus.to_string(), "dioxus");
+    }
+
+    #[test]
+    fn test_yew_attribute_spellings() {
+        let elements = parse_test(
+            r#"fn c() { html! {
+                <input ~value={v} disabled=true tabindex={1} class={classes!("btn", "sr-only large")} />
+                <div class={classes!("a", active.then_some("on"))} onkeydown={Callback::from(|_| ())} />
+            } }"#,
+        );
+        let values: Vec<Vec<_>> = elements
+            .iter()
+            .map(|e| {
+                e.attributes
+                    .iter()
+                    .map(|a| (a.name.to_string(), a.value.clone()))
+                    .collect()
+            })
+            .collect();
+        let s = |v: &str| Some(AttrValue::Static(v.to_string()));
+        assert_eq!(
+            values,
+            [
+                vec![
+                    ("value".to_string(), Some(AttrValue::Dynamic)),
+                    ("disabled".to_string(), s("true")),
+                    ("tabindex".to_string(), s("1")),
+                    ("class".to_string(), s("btn sr-only large")),
+                ],
+                vec![
+                    ("class".to_string(), Some(AttrValue::Dynamic)),
+                    ("onkeydown".to_string(), Some(AttrValue::Dynamic)),
+                ],
+            ]
+        );
+        assert!(elements[0].is_disabled());
+        // `~` is only stripped for Yew.
+        assert!(parse_source(r#"fn c() { view! { <input ~value="x" /> } }"#, "a.rs").is_err());
+    }
+
+    #[test]
+    fn test_expand_macro_rules() {
+        let source = r#"
+            macro_rules! card {
+                ($title:expr, $($item:expr),*) => {
+                    view! {
+                        <img src=$title />
+                        <ul>$(<li>{$item}</li>)*</ul>
+                    }
+                };
+                ($tag:ident) => { view! { <$tag></$tag> } };
+            }
+        "#;
+        let options = ParseOptions::default();
+        let parsed = parse_source_with(source, "a.rs", &options).unwrap();
+        assert!(parsed.elements.is_empty());
+
+        let options = ParseOptions {
+            expand_macro_rules: true,
+            ..ParseOptions::default()
+        };
+        let parsed = parse_source_with(source, "a.rs", &options).unwrap();
+        let tags: Vec<_> = parsed.elements.iter().map(|e| e.tag.to_string()).collect();
+        assert_eq!(tags, ["img", "ul", "li"]);
+        assert_eq!(
+            parsed.elements[0].attributes[0].value,
+            Some(AttrValue::Dynamic)
+        );
+        assert!(parsed.elements[2].has_children);
+        // `<$tag>` is not markup on its own and is skipped silently.
+        assert!(parsed.macro_errors.is_empty());
+
+        // Templates are only collected with the option, and only once.
+        let source = "macro_rules! icon { () => { view! { <img /> } } }";
+        let parsed = parse_source_with(source, "a.rs", &options).unwrap();
+        assert_eq!(parsed.elements.len(), 1);
+        let parsed = parse_source_with(source, "a.rs", &ParseOptions::default()).unwrap();
+        assert!(parsed.elements.is_empty());
+    }
+
+    #[test]
+    fn test_nested_macros() {
+        let source = r#"fn c() { html! {
+            <Comp renderer={html! { <img /> }}>
+                <p>{ items.iter().map(|i| html_nested! { <span /> }).collect::<Html>() }</p>
+            </Comp>
+        } }"#;
+        let elements = parse_test(source);
+        let tags: Vec<_> = elements
+            .iter()
+            .map(|e| (e.tag.to_string(), e.macro_index))
+            .collect();
+        // Content nested in `<p>` belongs to its invocation.
+        assert_eq!(
+            tags,
+            [("p".into(), 0), ("span".into(), 0), ("img".into(), 1)]
+        );
+        assert_eq!(elements[0].descendants, 1);
+        assert_eq!(elements[1].framework(), Some(Framework::Yew));
+        // The token fallback finds them once as well.
+        let broken = format!("{source} fn d() -> {{}}");
+        let parsed = parse_source_with(&broken, "a.rs", &ParseOptions::default()).unwrap();
+        assert_eq!(parsed.elements.len(), 3);
+    }
+
+    #[test]
+    fn test_content_in_expressions() {
+        let elements = parse_test(
+            r#"
+            fn a() { html! { <ul>{ for items.iter().map(|i| html! { <li>{i}</li> }) }</ul> } }
+            fn b() { view! {
+                <Show when=move || open.get() fallback=|| view! { <p>"Closed"</p> }>
+                    <button>
+                        {move || match state.get() {
+                            State::Busy => view! { <span>"Saving"</span> }.into_any(),
+                            _ => view! { <a href="/">"Home"</a> }.into_any(),
+                        }}
+                    </button>
+                </Show>
+            } }
+        "#,
+        );
+        let tags: Vec<_> = elements
+            .iter()
+            .map(|e| (e.tag.to_string(), e.macro_index, e.descendants))
+            .collect();
+        assert_eq!(
+            tags,
+            [
+                ("ul".into(), 0, 1),
+                ("li".into(), 0, 0),
+                ("button".into(), 1, 2),
+                ("span".into(), 1, 0),
+                ("a".into(), 1, 0),
+                ("p".into(), 2, 0),
+            ]
+        );
+        assert!(elements[0].has_children);
+        assert!(elements[4].inside_interactive);
+        // The arms of the `match` are never rendered together.
+        assert!(elements[3].excludes(&elements[4]));
+        assert!(!elements[2].excludes(&elements[3]));
+        assert!(!elements[0].excludes(&elements[1]));
+    }
+
+    #[test]
+    fn test_conditional_branches() {
+        let elements = parse_test(
+            r#"fn c() { html! {
+                <div>
+                    { if a { html! { <img /> } } else if b { html! { <hr /> } } else { html! { <br /> } } }
+                    { if c { html! { <p>{ match d { 0 => html! { <i /> }, _ => html! { <b /> } } }</p> } } }
+                </div>
+            } }"#,
+        );
+        let tags: Vec<_> = elements.iter().map(|e| e.tag.to_string()).collect();
+        assert_eq!(tags, ["div", "img", "hr", "br", "p", "i", "b"]);
+        assert!(elements[1].excludes(&elements[2]));
+        assert!(elements[2].excludes(&elements[3]));
+        assert!(!elements[1].excludes(&elements[4]));
+        assert!(!elements[4].excludes(&elements[5]));
+        assert!(elements[5].excludes(&elements[6]));
+        assert!(!elements[3].excludes(&elements[6]));
+    }
+
+    #[test]
+    fn test_parse_tokens() {
+        let tokens: proc_macro2::TokenStream =
+            quote::quote! { fn c() { html! { <img src="a.png" /> } } };
+        let parsed = parse_tokens(tokens, "test.rs", &ParseOptions::default());
+        assert_eq!(parsed.elements.len(), 1);
+        assert_eq!(parsed.elements[0].tag, Tag::Img);
+    }
+
+    #[test]
+    fn test_may_contain_rsx() {
+        let macros = ParseOptions::default().macros;
+        assert!(may_contain_rsx("fn c() { html! { <div /> } }", &macros));
+        assert!(may_contain_rsx(
+            "fn c() { leptos::view ! { <div /> } }",
+            &macros
+        ));
+        assert!(!may_contain_rsx(
+            "fn c() { if a != b { println!(\"{}\", vec![1]); } }",
+            &macros
+        ));
+        assert!(!may_contain_rsx("fn c() { my_html!() }", &macros));
+        assert!(may_contain_rsx(
+            "fn c() { my_html!() }",
+            &["my_html".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_parse_file_skips_files_without_rsx_macros() {
+        let dir = std::env::temp_dir().join(format!("rsx-a11y-prefilter-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("widget.rs");
+        std::fs::write(&file, "fn c() { custom! { <img src=\"a.png\" /> } }").unwrap();
+
+        assert!(parse_file(&file).unwrap().is_empty());
+        let options = ParseOptions {
+            macros: vec!["custom".to_string()],
+            ..ParseOptions::default()
+        };
+        assert_eq!(parse_file_with(&file, &options).unwrap().elements.len(), 1);
+        // Parsing the source directly agrees with the scan.
+        let source = std::fs::read_to_string(&file).unwrap();
+        assert!(parse_source(&source, "widget.rs").unwrap().is_empty());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_builder_matches_parsed_element() {
+        let parsed =
+            parse_test(r#"fn c() { html! { <a href="/home" on:click={go}>{"Home"}</a> } }"#);
+        let built = HtmlElement::builder(Tag::A)
+            .attr("href", "/home")
+            .dynamic_attr("on:click")
+            .children(true)
+            .build();
+        assert_eq!(parsed[0].tag, built.tag);
+        assert_eq!(parsed[0].has_children, built.has_children);
+        assert_eq!(parsed[0].is_self_closing, built.is_self_closing);
+        let names = |e: &HtmlElement| {
+            e.attributes
+                .iter()
+                .map(|a| a.name.clone())
+                .collect::<Vec<_>>()
+        };
+        let values = |e: &HtmlElement| {
+            e.attributes
+                .iter()
+                .map(|a| a.value.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&parsed[0]), names(&built));
+        assert_eq!(values(&parsed[0]), values(&built));
+        assert_eq!(built.attributes[1].name, AttributeName::OnClick);
+    }
+
+    #[test]
+    fn test_builder_keeps_unknown_attributes_and_span() {
+        let built = HtmlElement::builder(Tag::Div)
+            .span(4, 12)
+            .file("src/app.rs")
+            .attr("data-id", "1")
+            .build();
+        assert_eq!((built.line, built.column), (4, 12));
+        assert_eq!(built.file, "src/app.rs");
+        assert_eq!(
+            built.attributes[0].name,
+            AttributeName::Unknown("data-id".into())
+        );
+        assert_eq!(
+            (built.attributes[0].line, built.attributes[0].column),
+            (4, 12)
+        );
+    }
+
     #[test]
     fn test_parse_simple_div() {
         let elements = parse_test(
@@ -372,8 +2116,40 @@ mod tests {
         assert!(
             btn.attributes
                 .iter()
-                .any(|a| a.name == AttributeName::Unknown("class:active".into()))
+                .any(|a| a.name == AttributeName::Directive("class:active".into()))
+        );
+    }
+
+    #[test]
+    fn test_leptos_directives() {
+        let mut options = ParseOptions::default();
+        options.classes.hidden.push("hidden".to_string());
+        let elements = parse_source_with(
+            r#"fn c() { view! {
+                <a node_ref=link use:tooltip=("Menu") style:color="red">
+                    <span class:hidden=true>{"Menu"}</span>
+                    <span class:sr-only={true}>{"Open menu"}</span>
+                    <span class:hidden=move || closed.get()>{"Close"}</span>
+                </a>
+            } }"#,
+            "test.rs",
+            &options,
+        )
+        .unwrap()
+        .elements;
+        assert!(
+            elements[0]
+                .attributes
+                .iter()
+                .all(|a| matches!(a.name, AttributeName::Directive(_)))
         );
+        assert_eq!(elements[0].text.as_deref(), Some("Open menu Close"));
+        assert!(elements[1].is_hidden());
+        assert_eq!(
+            elements[2].class_visibility,
+            ClassVisibility::ScreenReaderOnly
+        );
+        assert_eq!(elements[3].class_visibility, ClassVisibility::Visible);
     }
 
     #[test]
@@ -463,4 +2239,48 @@ mod tests {
             attr.value
         );
     }
+
+    #[test]
+    fn test_components_and_their_usages() {
+        let parsed = parse_source_with(
+            r#"
+            #[function_component(Form)]
+            fn form() -> Html {
+                html! { <form><ui::Field label="Email"><input id="email" /></ui::Field><Submit /></form> }
+            }
+
+            impl Component for Counter {
+                fn view(&self, ctx: &Context<Self>) -> Html { html! { <button>{"+1"}</button> } }
+            }
+        "#,
+            "src/app.rs",
+            &ParseOptions::default(),
+        )
+        .unwrap();
+        let components: Vec<_> = parsed
+            .elements
+            .iter()
+            .map(|e| (e.tag.clone(), e.component.as_deref()))
+            .collect();
+        assert_eq!(
+            components,
+            [
+                (Tag::Form, Some("Form")),
+                (Tag::Input, Some("Form")),
+                (Tag::Button, Some("Counter")),
+            ]
+        );
+
+        let [field, submit] = &parsed.components[..] else {
+            panic!("expected two usages, got {:?}", parsed.components);
+        };
+        assert_eq!(
+            (field.name.as_str(), field.short_name()),
+            ("ui::Field", "Field")
+        );
+        assert_eq!(field.parent, Some(0));
+        assert_eq!((field.children, field.descendants), (1, 1));
+        assert_eq!(submit.component.as_deref(), Some("Form"));
+        assert_eq!((submit.parent, submit.descendants), (Some(0), 0));
+    }
 }
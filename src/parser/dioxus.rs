@@ -0,0 +1,546 @@
+//! Dioxus' own `rsx!` syntax, where elements are written as Rust-like
+//! blocks instead of tags:
+//!
+//! ```text
+//! rsx! {
+//!     div { class: "card", onclick: move |_| open(),
+//!         img { src: "cat.png", alt: "A cat" }
+//!         if expanded { p { "Details: {details}" } }
+//!     }
+//! }
+//! ```
+//!
+//! Attribute names are mapped to their HTML spelling (`aria_label` is
+//! `aria-label`, `r#type` is `type`); text with `{}` interpolation is
+//! dynamic, like an expression.
+
+use std::str::FromStr;
+
+use quote::ToTokens;
+use syn::ext::IdentExt;
+use syn::parse::{ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{Expr, Ident, LitStr, Token, braced};
+
+use super::{
+    AttrValue, Collected, ComponentUse, HtmlAttribute, HtmlElement, Syntax, attribute_name,
+    collect_nested, detach_nested, has_inner_html, is_component_name, static_expr,
+};
+use crate::dom::{Aria, AttributeName, Tag};
+use crate::fix::{Position, Range};
+use crate::style::{ClassVisibility, HidingClasses};
+
+/// One piece of `rsx!` content.
+pub(super) enum RsxNode {
+    Element(RsxElement),
+    /// Text, or `None` if it interpolates values.
+    Text(Option<String>),
+    /// The content of each branch of an `if`, or of a `for`, which may or
+    /// may not be rendered.
+    Conditional(Vec<Vec<RsxNode>>),
+    /// A `{expr}` block.
+    Expr(proc_macro2::TokenStream),
+}
+
+/// An element or component with its attributes (or props) and children.
+pub(super) struct RsxElement {
+    name: syn::Path,
+    attributes: Vec<HtmlAttribute>,
+    /// The expressions of attributes and spreads, which may hold macros.
+    values: Vec<proc_macro2::TokenStream>,
+    children: Vec<RsxNode>,
+    /// Just after the `{`, and what removing each attribute deletes.
+    body: Position,
+    /// Just after the closing `}`.
+    end: Position,
+    removals: Vec<Range>,
+}
+
+/// Parse the tokens of an `rsx!` invocation.
+pub(super) fn parse(tokens: proc_macro2::TokenStream) -> syn::Result<Vec<RsxNode>> {
+    nodes.parse2(tokens)
+}
+
+/// Whether `tokens` are written in Dioxus syntax rather than as tags.
+pub(super) fn is_rsx_syntax(tokens: &proc_macro2::TokenStream) -> bool {
+    !matches!(
+        tokens.clone().into_iter().next(),
+        Some(proc_macro2::TokenTree::Punct(p)) if p.as_char() == '<'
+    )
+}
+
+fn nodes(input: ParseStream) -> syn::Result<Vec<RsxNode>> {
+    let mut nodes = Vec::new();
+    while !input.is_empty() {
+        nodes.push(node(input)?);
+        input.parse::<Option<Token![,]>>()?;
+    }
+    Ok(nodes)
+}
+
+fn node(input: ParseStream) -> syn::Result<RsxNode> {
+    if input.peek(LitStr) {
+        let text: LitStr = input.parse()?;
+        return Ok(RsxNode::Text(format_text(&text.value())));
+    }
+    if input.peek(Token![if]) {
+        return conditional(input);
+    }
+    if input.peek(Token![for]) {
+        input.parse::<Token![for]>()?;
+        syn::Pat::parse_multi(input)?;
+        input.parse::<Token![in]>()?;
+        Expr::parse_without_eager_brace(input)?;
+        let body;
+        braced!(body in input);
+        return Ok(RsxNode::Conditional(vec![nodes(&body)?]));
+    }
+    if input.peek(syn::token::Brace) {
+        let body;
+        braced!(body in input);
+        return Ok(RsxNode::Expr(body.parse()?));
+    }
+    let name = input.call(syn::Path::parse_mod_style)?;
+    let body;
+    let brace = braced!(body in input);
+    let mut attributes = Vec::new();
+    let mut values = Vec::new();
+    let mut children = Vec::new();
+    let mut removals = Vec::new();
+    while !body.is_empty() {
+        if body.peek(Token![..]) {
+            // Spread attributes are only known at runtime.
+            body.parse::<Token![..]>()?;
+            values.push(body.parse::<Expr>()?.to_token_stream());
+        } else if let Some((attr, value)) = attribute(&body)? {
+            let start = Position {
+                line: attr.line,
+                column: attr.column,
+            };
+            let mut end = Position {
+                line: attr.end_line,
+                column: attr.end_column,
+            };
+            if let Some(comma) = body.parse::<Option<Token![,]>>()? {
+                end = position(comma.span.end());
+            }
+            if !body.is_empty() {
+                end = position(body.cursor().span().start());
+            }
+            removals.push(Range { start, end });
+            attributes.push(attr);
+            values.extend(value);
+            continue;
+        } else {
+            children.push(node(&body)?);
+        }
+        body.parse::<Option<Token![,]>>()?;
+    }
+    Ok(RsxNode::Element(RsxElement {
+        name,
+        attributes,
+        values,
+        children,
+        body: position(brace.span.open().end()),
+        end: position(brace.span.close().end()),
+        removals,
+    }))
+}
+
+fn position(at: proc_macro2::LineColumn) -> Position {
+    Position {
+        line: at.line,
+        column: at.column,
+    }
+}
+
+/// `if cond { ... } else if cond { ... } else { ... }`, with the content of
+/// every branch.
+fn conditional(input: ParseStream) -> syn::Result<RsxNode> {
+    let mut branches = Vec::new();
+    loop {
+        input.parse::<Token![if]>()?;
+        Expr::parse_without_eager_brace(input)?;
+        let body;
+        braced!(body in input);
+        branches.push(nodes(&body)?);
+        if input.parse::<Option<Token![else]>>()?.is_none() {
+            break;
+        }
+        if !input.peek(Token![if]) {
+            let body;
+            braced!(body in input);
+            branches.push(nodes(&body)?);
+            break;
+        }
+    }
+    Ok(RsxNode::Conditional(branches))
+}
+
+/// An attribute (`name: value`, `"custom-name": value`, or the shorthand
+/// `name` for `name: name`) and the tokens of its value, or `None` if a child
+/// node follows instead.
+fn attribute(
+    input: ParseStream,
+) -> syn::Result<Option<(HtmlAttribute, Option<proc_macro2::TokenStream>)>> {
+    let is_named = input.peek(LitStr) && input.peek2(Token![:]);
+    let is_field =
+        input.peek(Ident::peek_any) && input.peek2(Token![:]) && !input.peek2(Token![::]);
+    let is_shorthand = input.peek(Ident::peek_any) && {
+        let fork = input.fork();
+        fork.call(Ident::parse_any)?;
+        fork.is_empty() || fork.peek(Token![,])
+    };
+    if !(is_named || is_field || is_shorthand) {
+        return Ok(None);
+    }
+    let (key, start) = if is_named {
+        let key: LitStr = input.parse()?;
+        (key.value(), key.span().start())
+    } else {
+        let key = input.call(Ident::parse_any)?;
+        let start = key.span().start();
+        (key.unraw().to_string(), start)
+    };
+    let (value, end, tokens) = if is_shorthand {
+        (AttrValue::Dynamic, input.cursor().span().start(), None)
+    } else {
+        input.parse::<Token![:]>()?;
+        let value: Expr = input.parse()?;
+        let end = value.span().end();
+        let tokens = Some(value.to_token_stream());
+        let value = match &value {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(text),
+                ..
+            }) => format_text(&text.value()).map_or(AttrValue::Dynamic, AttrValue::Static),
+            value => static_expr(value).map_or(AttrValue::Dynamic, AttrValue::Static),
+        };
+        (value, end, tokens)
+    };
+    let name = match attribute_name(key.clone()) {
+        name @ AttributeName::Directive(_) => name,
+        _ if is_named => attribute_name(key),
+        _ => attribute_name(key.replace('_', "-")),
+    };
+    let end = if is_shorthand { start } else { end };
+    let attr = HtmlAttribute {
+        name,
+        value: Some(value),
+        line: start.line,
+        column: start.column,
+        end_line: end.line,
+        end_column: end.column,
+    };
+    Ok(Some((attr, tokens)))
+}
+
+/// The text of a Dioxus format string, or `None` if it interpolates values.
+fn format_text(format: &str) -> Option<String> {
+    let mut text = String::new();
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' if chars.peek() == Some(&c) => {
+                chars.next();
+                text.push(c);
+            }
+            '{' => return None,
+            c => text.push(c),
+        }
+    }
+    Some(text)
+}
+
+/// Append the elements and component usages of `nodes` to `acc`, like
+/// [`super::collect_elements_from_nodes`] does for tags.
+pub(super) fn collect_elements(
+    acc: &mut Collected,
+    nodes: &[RsxNode],
+    file_path: &str,
+    source_macro: &str,
+    in_svg: bool,
+    inside_interactive: bool,
+    classes: &HidingClasses,
+) {
+    for node in nodes {
+        let element = match node {
+            RsxNode::Element(element) => element,
+            RsxNode::Conditional(branches) => {
+                let conditional = acc.conditional();
+                for (arm, nodes) in branches.iter().enumerate() {
+                    acc.branch(conditional, arm, |acc| {
+                        collect_elements(
+                            acc,
+                            nodes,
+                            file_path,
+                            source_macro,
+                            in_svg,
+                            inside_interactive,
+                            classes,
+                        )
+                    });
+                }
+                continue;
+            }
+            RsxNode::Expr(tokens) => {
+                collect_nested(
+                    acc,
+                    tokens.clone(),
+                    file_path,
+                    in_svg,
+                    inside_interactive,
+                    classes,
+                );
+                continue;
+            }
+            RsxNode::Text(_) => continue,
+        };
+        for value in &element.values {
+            detach_nested(acc, value.clone());
+        }
+        let name = path_name(&element.name);
+        let start = element.name.span().start();
+        let tag = Tag::from_str(&name);
+        let is_component = tag.is_err() && is_component_name(&name);
+        if is_component {
+            acc.components.push(ComponentUse {
+                name: name.clone(),
+                line: start.line,
+                column: start.column,
+                macro_index: 0,
+                component: None,
+                parent: acc.parent,
+                children: acc.elements.len(),
+                descendants: 0,
+                call: false,
+            });
+        }
+        let usage = acc.components.len();
+        let children_in_svg = match tag {
+            Ok(Tag::Svg) => true,
+            Ok(Tag::ForeignObject) => false,
+            _ => in_svg,
+        };
+        let children_inside_interactive =
+            inside_interactive || tag.as_ref().is_ok_and(Tag::is_interactive);
+        let is_element = tag.is_ok();
+        if let Ok(mut tag) = tag {
+            if in_svg && tag == Tag::Title {
+                tag = Tag::SvgTitle;
+            }
+            let inner_html = has_inner_html(&element.attributes);
+            let mut html = HtmlElement {
+                tag,
+                attributes: element.attributes.clone(),
+                is_self_closing: element.children.is_empty() && !inner_html,
+                has_children: !element.children.is_empty() || inner_html,
+                text: static_text(&element.children, classes).filter(|_| !inner_html),
+                line: start.line,
+                column: start.column,
+                end_line: element.end.line,
+                end_column: element.end.column,
+                file: file_path.to_string(),
+                source_macro: source_macro.to_string(),
+                macro_index: 0,
+                custom_element: None,
+                class_visibility: ClassVisibility::Visible,
+                inside_interactive,
+                descendants: 0,
+                component: None,
+                syntax: Syntax::Braced {
+                    body: element.body,
+                    removals: element.removals.clone(),
+                },
+                branches: acc.branches.clone(),
+            };
+            if let Some(class) = html.static_attr(AttributeName::Class) {
+                html.class_visibility = classes.visibility(class);
+            }
+            acc.elements.push(html);
+        }
+        let index = acc.elements.len();
+        let outer = acc.parent;
+        if is_element {
+            acc.parent = Some(index - 1);
+        }
+        collect_elements(
+            acc,
+            &element.children,
+            file_path,
+            source_macro,
+            children_in_svg,
+            children_inside_interactive,
+            classes,
+        );
+        acc.parent = outer;
+        if is_element {
+            acc.elements[index - 1].descendants = acc.elements.len() - index;
+        } else if is_component {
+            acc.components[usage - 1].descendants = acc.elements.len() - index;
+        }
+    }
+}
+
+/// `path` as written, e.g. `div` or `ui::Card`.
+fn path_name(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(|s| s.ident.unraw().to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// Static text of `nodes`, as [`super::static_text`] computes it for tags.
+fn static_text(nodes: &[RsxNode], classes: &HidingClasses) -> Option<String> {
+    let mut parts = Vec::new();
+    for node in nodes {
+        let element = match node {
+            RsxNode::Text(text) => {
+                parts.push(text.clone()?);
+                continue;
+            }
+            RsxNode::Conditional(_) | RsxNode::Expr(_) => return None,
+            RsxNode::Element(element) => element,
+        };
+        let name = path_name(&element.name);
+        if Tag::from_str(&name).is_err() && is_component_name(&name) {
+            return None;
+        }
+        let attr = |name: AttributeName| {
+            element
+                .attributes
+                .iter()
+                .find(|a| a.name == name)
+                .map(|a| a.value.as_ref().and_then(|v| v.as_static()))
+        };
+        if attr(AttributeName::Aria(Aria::Hidden)) == Some(Some("true"))
+            || attr(AttributeName::Style)
+                .flatten()
+                .is_some_and(crate::style::is_hidden)
+            || attr(AttributeName::Class)
+                .flatten()
+                .is_some_and(|class| classes.visibility(class) == ClassVisibility::Hidden)
+        {
+            continue;
+        }
+        let label = attr(AttributeName::Aria(Aria::Label))
+            .or_else(|| (name == "img").then(|| attr(AttributeName::Alt)).flatten());
+        match label {
+            Some(label) => parts.push(label?.to_string()),
+            None => parts.push(static_text(&element.children, classes)?),
+        }
+    }
+    Some(parts.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse_source;
+    use super::*;
+
+    #[test]
+    fn test_rsx_syntax() {
+        let elements = parse_source(
+            r#"fn c() { rsx! {
+                div { class: "card", onclick: move |_| open(), "data-id": "{id}",
+                    img { src: "cat.png", alt: "A cat" }
+                    label { r#for: "q", "Search {{all}}" }
+                    input { id: "q", r#type: "search", aria_label: "Search", disabled }
+                    if expanded { p { "Details: {details}" } } else { Spinner {} }
+                    for item in items { li { key: "{item.id}", "{item.name}" } }
+                }
+            } }"#,
+            "a.rs",
+        )
+        .unwrap();
+        let tags: Vec<_> = elements.iter().map(|e| e.tag.to_string()).collect();
+        assert_eq!(tags, ["div", "img", "label", "input", "p", "li"]);
+        let div = &elements[0];
+        assert_eq!((div.line, div.column, div.descendants), (2, 16, 5));
+        assert_eq!((div.end_line, div.end_column), (8, 17));
+        assert_eq!((elements[1].end_line, elements[1].end_column), (3, 56));
+        assert_eq!(div.static_attr(AttributeName::Class), Some("card"));
+        assert!(div.has_event_handler());
+        assert_eq!(div.attributes[2].value, Some(AttrValue::Dynamic));
+        assert_eq!(div.text, None);
+        assert_eq!(elements[1].static_attr(AttributeName::Alt), Some("A cat"));
+        assert_eq!(elements[2].static_attr(AttributeName::For), Some("q"));
+        assert_eq!(elements[2].text.as_deref(), Some("Search {all}"));
+        let input = &elements[3];
+        assert_eq!(input.static_attr(AttributeName::Type), Some("search"));
+        assert_eq!(
+            input.static_attr(AttributeName::Aria(Aria::Label)),
+            Some("Search")
+        );
+        assert!(input.is_self_closing);
+        assert_eq!(elements[4].text, None);
+    }
+
+    #[test]
+    fn test_rsx_in_expressions() {
+        let elements = parse_source(
+            r#"fn c() { rsx! {
+                button { onclick: move |_| show(rsx! { p {} }),
+                    {busy.then(|| rsx! { span { "Saving" } })}
+                }
+            } }"#,
+            "a.rs",
+        )
+        .unwrap();
+        let tags: Vec<_> = elements
+            .iter()
+            .map(|e| (e.tag.to_string(), e.macro_index))
+            .collect();
+        assert_eq!(
+            tags,
+            [("button".into(), 0), ("span".into(), 0), ("p".into(), 1)]
+        );
+        assert_eq!(elements[0].descendants, 1);
+        assert!(elements[1].inside_interactive);
+    }
+
+    #[test]
+    fn test_rsx_conditional_branches() {
+        let elements = parse_source(
+            r#"fn c() { rsx! {
+                div {
+                    if open { button { "Close" } } else if busy { span {} } else { a { href: "/" } }
+                    for item in items { li { "{item}" } }
+                    {match state { State::Busy => rsx! { p {} }, _ => rsx! { hr {} } }}
+                }
+            } }"#,
+            "a.rs",
+        )
+        .unwrap();
+        let tags: Vec<_> = elements.iter().map(|e| e.tag.to_string()).collect();
+        assert_eq!(tags, ["div", "button", "span", "a", "li", "p", "hr"]);
+        assert!(elements[1].excludes(&elements[2]));
+        assert!(elements[1].excludes(&elements[3]));
+        assert!(!elements[3].excludes(&elements[4]));
+        assert!(elements[5].excludes(&elements[6]));
+        assert!(!elements[0].excludes(&elements[1]));
+    }
+
+    #[test]
+    fn test_rsx_directives_and_inner_html() {
+        let elements = parse_source(
+            r#"fn c() { rsx! {
+                a { href: "/", prevent_default: "onclick", dangerous_inner_html: "{html}" }
+            } }"#,
+            "a.rs",
+        )
+        .unwrap();
+        let names: Vec<_> = elements[0].attributes.iter().map(|a| &a.name).collect();
+        assert_eq!(
+            names,
+            [
+                &AttributeName::Href,
+                &AttributeName::Directive("prevent_default".to_string()),
+                &AttributeName::Directive("dangerous_inner_html".to_string()),
+            ]
+        );
+        assert!(elements[0].has_children);
+        assert_eq!(elements[0].text, None);
+    }
+}
@@ -1,4 +1,4 @@
 pub use crate::dom::{Aria, AriaValueType, AttributeName, Role, Tag};
 pub use crate::lints::{LintDiagnostic, Rule, Severity};
-pub use crate::parser::ParseError;
+pub use crate::parser::{Framework, HtmlElement, HtmlElementBuilder, MacroError, ParseError};
 pub use crate::{LintSummary, check_project};
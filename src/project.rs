@@ -0,0 +1,666 @@
+//! Rules that look at every file of a project at once.
+//!
+//! Per-element and per-macro rules only see one macro invocation, so they
+//! cannot tell whether a page has a `<main>` landmark or a skip link
+//! somewhere, or whether an `id` is reused by another component. A
+//! [`ProjectRule`] runs after every file is linted and gets the elements of
+//! all of them as a [`Project`].
+//!
+//! The built-in project rules always run; add others with
+//! [`LintRunner::project_rule`](crate::runner::LintRunner::project_rule).
+//! Their diagnostics take their severity from the config of the file they
+//! are reported in, like any other diagnostic.
+//!
+//! A [`ComponentIndex`] follows component usages like `<EmailField />` into
+//! the file that defines them, so a rule can see what a subtree renders:
+//!
+//! ```
+//! use rsx_a11y::parser::{self, ParseOptions};
+//! use rsx_a11y::project::{Project, ProjectFile};
+//!
+//! let file = |path: &str, source: &str| {
+//!     let parsed = parser::parse_source_with(source, path, &ParseOptions::default()).unwrap();
+//!     ProjectFile {
+//!         path: path.into(),
+//!         elements: parsed.elements,
+//!         components: parsed.components,
+//!         declared_components: parsed.declared_components,
+//!         options: Default::default(),
+//!     }
+//! };
+//! let project = Project {
+//!     files: vec![
+//!         file("src/field.rs", r#"
+//!             #[function_component(EmailField)]
+//!             fn email_field() -> Html { html! { <input id="email" type="email" /> } }
+//!         "#),
+//!         file("src/form.rs", r#"
+//!             #[function_component]
+//!             fn Form() -> Html { html! { <form><label for="email">{"Email"}</label><EmailField /></form> } }
+//!         "#),
+//!     ],
+//! };
+//! let components = project.components();
+//! let ids: Vec<_> = components
+//!     .rendered(1, 0)
+//!     .iter()
+//!     .filter_map(|e| e.static_attr(rsx_a11y::dom::AttributeName::Id))
+//!     .collect();
+//! assert_eq!(ids, ["email"]);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::dom::{AttributeName, Role, Tag};
+use crate::lints::{LintDiagnostic, Rule};
+use crate::options::RuleOptions;
+use crate::parser::{ComponentUse, HtmlElement};
+
+/// A linted file's elements and rule options.
+#[derive(Debug, Clone)]
+pub struct ProjectFile {
+    pub path: PathBuf,
+    /// Elements of every RSX macro in the file, in source order.
+    pub elements: Vec<HtmlElement>,
+    /// Components used in the file's macros.
+    pub components: Vec<ComponentUse>,
+    /// Functions declared as components with an attribute, see
+    /// [`ParsedFile::declared_components`](crate::parser::ParsedFile::declared_components).
+    pub declared_components: Vec<Arc<str>>,
+    /// Rule options resolved for this file.
+    pub options: RuleOptions,
+}
+
+/// Every linted file that contained RSX elements.
+#[derive(Debug, Clone, Default)]
+pub struct Project {
+    /// Files sorted by path.
+    pub files: Vec<ProjectFile>,
+}
+
+impl Project {
+    /// Elements of all files.
+    pub fn elements(&self) -> impl Iterator<Item = &HtmlElement> {
+        self.files.iter().flat_map(|file| &file.elements)
+    }
+
+    /// An index of the components defined in the project.
+    pub fn components(&self) -> ComponentIndex<'_> {
+        ComponentIndex::new(self)
+    }
+}
+
+/// Where each component of a [`Project`] is defined, by name.
+///
+/// Components are matched by name without their module path, so a name
+/// defined in several files refers to all of those definitions.
+#[derive(Debug, Clone)]
+pub struct ComponentIndex<'a> {
+    project: &'a Project,
+    /// Component name to the files defining it and the indices of the
+    /// elements each definition renders.
+    definitions: HashMap<&'a str, Vec<(usize, Vec<usize>)>>,
+}
+
+impl<'a> ComponentIndex<'a> {
+    pub fn new(project: &'a Project) -> Self {
+        let mut definitions: HashMap<&str, Vec<(usize, Vec<usize>)>> = HashMap::new();
+        for (f, file) in project.files.iter().enumerate() {
+            for (i, element) in file.elements.iter().enumerate() {
+                let Some(name) = element.component.as_deref() else {
+                    continue;
+                };
+                let files = definitions.entry(name).or_default();
+                match files.last_mut() {
+                    Some((last, indices)) if *last == f => indices.push(i),
+                    _ => files.push((f, vec![i])),
+                }
+            }
+        }
+        ComponentIndex {
+            project,
+            definitions,
+        }
+    }
+
+    /// Whether a component called `name` is defined in the project.
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.definitions.contains_key(name)
+    }
+
+    /// Elements the component `name` renders itself (not through other
+    /// components), from every file that defines it.
+    pub fn definition(&self, name: &str) -> Vec<&'a HtmlElement> {
+        self.definitions
+            .get(name)
+            .into_iter()
+            .flatten()
+            .flat_map(|(f, indices)| {
+                let elements = &self.project.files[*f].elements;
+                indices.iter().map(move |&i| &elements[i])
+            })
+            .collect()
+    }
+
+    /// Elements rendered in the subtree of the element at `index` of file
+    /// `file`: the element, its descendants, and everything rendered by the
+    /// components used inside it, followed through any number of files.
+    pub fn rendered(&self, file: usize, index: usize) -> Vec<&'a HtmlElement> {
+        let elements = &self.project.files[file].elements;
+        let end = (index + elements[index].descendants).min(elements.len() - 1);
+        let mut rendered: Vec<_> = elements[index..=end].iter().collect();
+        let usages = self.project.files[file]
+            .components
+            .iter()
+            .filter(|usage| usage.parent.is_some_and(|p| (index..=end).contains(&p)))
+            .collect();
+        self.follow(usages, &mut rendered, &mut HashSet::new());
+        rendered
+    }
+
+    /// Elements the component `name` renders, followed through the
+    /// components it uses like [`rendered`](ComponentIndex::rendered).
+    pub fn rendered_by(&self, name: &str) -> Vec<&'a HtmlElement> {
+        let mut rendered = Vec::new();
+        if let Some((name, _)) = self.definitions.get_key_value(name) {
+            self.render(name, &mut rendered, &mut HashSet::new());
+        }
+        rendered
+    }
+
+    /// Usages of the component `name` anywhere in the project, with the
+    /// index of the file they are in.
+    pub fn usages(&self, name: &str) -> Vec<(usize, &'a ComponentUse)> {
+        self.project
+            .files
+            .iter()
+            .enumerate()
+            .flat_map(|(f, file)| file.components.iter().map(move |usage| (f, usage)))
+            .filter(|(_, usage)| usage.short_name() == name)
+            .collect()
+    }
+
+    /// Add what `usages` render to `rendered`, visiting each component once.
+    fn follow(
+        &self,
+        usages: Vec<&'a ComponentUse>,
+        rendered: &mut Vec<&'a HtmlElement>,
+        seen: &mut HashSet<&'a str>,
+    ) {
+        for usage in usages {
+            if let Some((name, _)) = self.definitions.get_key_value(usage.short_name()) {
+                self.render(name, rendered, seen);
+            }
+        }
+    }
+
+    /// Add what the component `name` renders to `rendered`, unless it is in
+    /// `seen`.
+    fn render(
+        &self,
+        name: &'a str,
+        rendered: &mut Vec<&'a HtmlElement>,
+        seen: &mut HashSet<&'a str>,
+    ) {
+        if !seen.insert(name) {
+            return;
+        }
+        for (f, indices) in &self.definitions[name] {
+            let file = &self.project.files[*f];
+            rendered.extend(indices.iter().map(|&i| &file.elements[i]));
+            let nested = file
+                .components
+                .iter()
+                .filter(|usage| usage.component.as_deref() == Some(name))
+                .collect();
+            self.follow(nested, rendered, seen);
+        }
+    }
+}
+
+/// A rule that checks a whole [`Project`].
+pub trait ProjectRule: Sync {
+    /// The rule diagnostics are reported as, used for config levels and
+    /// `--only` / `--skip`.
+    fn rule(&self) -> Rule;
+
+    /// Check the project.
+    fn check_project(&self, project: &Project) -> Vec<LintDiagnostic>;
+}
+
+impl ProjectRule for Rule {
+    fn rule(&self) -> Rule {
+        self.clone()
+    }
+
+    fn check_project(&self, project: &Project) -> Vec<LintDiagnostic> {
+        match self {
+            Rule::PageHasMain => page_has_main(project),
+            Rule::SkipLink => skip_link(project),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// The built-in project rules.
+pub fn builtin() -> &'static [&'static dyn ProjectRule] {
+    &[&Rule::PageHasMain, &Rule::SkipLink]
+}
+
+/// A header, footer or navigation outside of sectioning content, but no
+/// `<main>` landmark, in what one page renders. A page is a component that
+/// no other component of the project uses, or a macro outside components;
+/// what it renders includes the components it uses, through any number of
+/// files. Only functions named like components (`App`) or declared with
+/// `#[component]` / `#[function_component]` are pages: others, like
+/// `fn header() -> Html`, are helpers rendered where they are called.
+fn page_has_main(project: &Project) -> Vec<LintDiagnostic> {
+    let components = project.components();
+    // Elements inside an `<article>`, `<section>`, ... of their page, where a
+    // header or footer belongs to that content rather than to the page.
+    let mut sectioned: HashSet<*const HtmlElement> = HashSet::new();
+    for (f, file) in project.files.iter().enumerate() {
+        for (i, element) in file.elements.iter().enumerate() {
+            let sectioning = matches!(
+                element.tag,
+                Tag::Article | Tag::Aside | Tag::Main | Tag::Nav | Tag::Section
+            ) || matches!(
+                element.explicit_role(),
+                Some((
+                    _,
+                    Role::Article
+                        | Role::Complementary
+                        | Role::Main
+                        | Role::Navigation
+                        | Role::Region
+                ))
+            );
+            if sectioning {
+                let inside = components.rendered(f, i).into_iter().skip(1);
+                sectioned.extend(inside.map(|e| e as *const HtmlElement));
+            }
+        }
+    }
+    let is_layout = |e: &&HtmlElement| {
+        (matches!(e.tag, Tag::Header | Tag::Footer | Tag::Nav)
+            || matches!(
+                e.role(),
+                Some(Role::Banner | Role::ContentInfo | Role::Navigation)
+            ))
+            && !sectioned.contains(&(*e as *const HtmlElement))
+    };
+
+    let mut pages: Vec<Vec<&HtmlElement>> = Vec::new();
+    let mut names = HashSet::new();
+    for file in &project.files {
+        let is_component = |name: &str| {
+            name.starts_with(|c: char| c.is_ascii_uppercase())
+                || file.declared_components.iter().any(|c| &**c == name)
+        };
+        let mut macros = Vec::new();
+        for element in &file.elements {
+            match element.component.as_deref() {
+                Some(name) => {
+                    if names.insert(name)
+                        && is_component(name)
+                        && components.usages(name).is_empty()
+                    {
+                        pages.push(components.rendered_by(name));
+                    }
+                }
+                None => macros.push(element.macro_index),
+            }
+        }
+        macros.dedup();
+        for index in macros {
+            let in_macro =
+                |component: &Option<_>, macro_index| component.is_none() && macro_index == index;
+            let mut rendered: Vec<_> = file
+                .elements
+                .iter()
+                .filter(|e| in_macro(&e.component, e.macro_index))
+                .collect();
+            let usages = file
+                .components
+                .iter()
+                .filter(|u| in_macro(&u.component, u.macro_index))
+                .collect();
+            components.follow(usages, &mut rendered, &mut HashSet::new());
+            pages.push(rendered);
+        }
+    }
+
+    let mut diagnostics = Vec::new();
+    let mut reported = HashSet::new();
+    for page in pages {
+        if page.iter().any(|e| e.role() == Some(Role::Main)) {
+            continue;
+        }
+        let Some(layout) = page.into_iter().find(is_layout) else {
+            continue;
+        };
+        if !reported.insert(layout as *const HtmlElement) {
+            continue;
+        }
+        diagnostics.push(LintDiagnostic {
+            help: Some(
+                "Wrap the page content in <main>, or give its container `role=\"main\"`."
+                    .to_string(),
+            ),
+            ..LintDiagnostic::new(
+                Rule::PageHasMain,
+                layout,
+                format!(
+                    "The app renders page layout (<{}>) but no <main> landmark. Screen reader users rely on <main> to jump to the page's content.",
+                    layout.tag
+                ),
+            )
+        });
+    }
+    diagnostics
+}
+
+/// Navigation and a `<main>` landmark without a same-page link to the main
+/// content.
+fn skip_link(project: &Project) -> Vec<LintDiagnostic> {
+    let has_navigation = project.elements().any(|e| {
+        matches!(e.tag, Tag::Header | Tag::Nav)
+            || matches!(e.role(), Some(Role::Banner | Role::Navigation))
+    });
+    if !has_navigation {
+        return Vec::new();
+    }
+
+    // A skip link may target the landmark or anything rendered inside it,
+    // including by the components it uses.
+    let components = project.components();
+    let mut main = None;
+    let mut targets = HashSet::new();
+    for (f, file) in project.files.iter().enumerate() {
+        for (i, element) in file.elements.iter().enumerate() {
+            if element.role() != Some(Role::Main) {
+                continue;
+            }
+            main.get_or_insert(element);
+            for e in components.rendered(f, i) {
+                if let Some(id) = e.attributes.iter().find(|a| a.name == AttributeName::Id) {
+                    match id.value.as_ref().and_then(|v| v.as_static()) {
+                        Some(value) => targets.insert(value),
+                        // A computed id may be the target of a skip link.
+                        None => return Vec::new(),
+                    };
+                }
+            }
+        }
+    }
+    let Some(main) = main else {
+        return Vec::new();
+    };
+
+    let has_skip_link = project.elements().any(|e| {
+        e.tag == Tag::A
+            && e.static_attr(AttributeName::Href)
+                .and_then(|href| href.strip_prefix('#'))
+                .is_some_and(|id| targets.contains(id))
+    });
+    if has_skip_link {
+        return Vec::new();
+    }
+    vec![LintDiagnostic {
+        help: Some(
+            "Give <main> an id, e.g. `id=\"main\"`, and add `<a href=\"#main\">Skip to content</a>` as the first focusable element of the page.".to_string(),
+        ),
+        ..LintDiagnostic::new(
+            Rule::SkipLink,
+            main,
+            "The app renders navigation and a <main> landmark, but no link to skip to the main content. Keyboard users have to tab through the navigation on every page.",
+        )
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lints::Severity;
+    use crate::runner::LintRunner;
+    use crate::source::MemorySources;
+
+    /// Reports `<iframe>` titles used in more than one file.
+    struct TitleAcrossFiles;
+
+    impl ProjectRule for TitleAcrossFiles {
+        fn rule(&self) -> Rule {
+            Rule::IframeTitleUnique
+        }
+
+        fn check_project(&self, project: &Project) -> Vec<LintDiagnostic> {
+            let title = |element: &HtmlElement| {
+                element
+                    .static_attr(AttributeName::Title)
+                    .map(str::to_string)
+            };
+            let elements: Vec<_> = project.elements().collect();
+            elements
+                .iter()
+                .filter(|e| {
+                    title(e).is_some()
+                        && elements
+                            .iter()
+                            .any(|other| other.file != e.file && title(other) == title(e))
+                })
+                .map(|e| {
+                    LintDiagnostic::new(
+                        Rule::IframeTitleUnique,
+                        e,
+                        "Title is used in another file.",
+                    )
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_project_rule_sees_every_file() {
+        let mut sources = MemorySources::new();
+        let iframe = r#"fn c() { html! { <iframe title="Map" src="m.html" /> } }"#;
+        sources.insert("src/a.rs", iframe);
+        sources.insert("src/b.rs", iframe);
+        sources.insert(
+            "src/c.rs",
+            r#"fn c() { html! { <iframe title="Video" src="v.html" /> } }"#,
+        );
+
+        let summary = LintRunner::new()
+            .sources(&sources)
+            .project_rule(&TitleAcrossFiles)
+            .run_files(&sources.paths());
+        let files: Vec<_> = summary
+            .diagnostics
+            .iter()
+            .filter(|d| d.message == "Title is used in another file.")
+            .map(|d| d.file.as_str())
+            .collect();
+        assert_eq!(files, ["src/a.rs", "src/b.rs"]);
+
+        let summary = LintRunner::new()
+            .sources(&sources)
+            .project_rule(&TitleAcrossFiles)
+            .skip(vec![Rule::IframeTitleUnique])
+            .run_files(&sources.paths());
+        assert!(summary.diagnostics.is_empty());
+        assert_eq!(summary.files_checked, 3);
+    }
+
+    fn project(files: &[(&str, &str)]) -> Project {
+        let files = files
+            .iter()
+            .map(|&(path, source)| {
+                let parsed =
+                    crate::parser::parse_source_with(source, path, &Default::default()).unwrap();
+                ProjectFile {
+                    path: path.into(),
+                    elements: parsed.elements,
+                    components: parsed.components,
+                    declared_components: parsed.declared_components,
+                    options: RuleOptions::default(),
+                }
+            })
+            .collect();
+        Project { files }
+    }
+
+    #[test]
+    fn test_component_index_follows_nested_components() {
+        let project = project(&[
+            (
+                "src/app.rs",
+                "fn App() { view! { <main><Layout /></main><aside></aside> } }",
+            ),
+            (
+                "src/layout.rs",
+                "fn Layout() { view! { <div><Nav /><Layout /></div> } }",
+            ),
+            ("src/nav.rs", "fn Nav() { view! { <nav></nav> } }"),
+        ]);
+        let index = project.components();
+        assert!(index.is_defined("Nav") && !index.is_defined("Footer"));
+        assert_eq!(index.usages("Layout").len(), 2);
+        let tags: Vec<_> = index.rendered(0, 0).iter().map(|e| e.tag.clone()).collect();
+        // Recursion through `<Layout />` stops after the first visit.
+        assert_eq!(tags, [Tag::Main, Tag::Div, Tag::Nav]);
+        let tags: Vec<_> = index
+            .rendered_by("Layout")
+            .iter()
+            .map(|e| e.tag.clone())
+            .collect();
+        assert_eq!(tags, [Tag::Div, Tag::Nav]);
+    }
+
+    fn project_diagnostics(rule: Rule, files: &[(&str, &str)]) -> Vec<LintDiagnostic> {
+        let mut sources = MemorySources::new();
+        for &(path, source) in files {
+            sources.insert(path, source);
+        }
+        LintRunner::new()
+            .sources(&sources)
+            .only(vec![rule])
+            .run_files(&sources.paths())
+            .diagnostics
+    }
+
+    fn skip_link_diagnostics(files: &[(&str, &str)]) -> Vec<LintDiagnostic> {
+        project_diagnostics(Rule::SkipLink, files)
+    }
+
+    #[test]
+    fn test_page_has_main() {
+        let shell = r#"fn App() { html! { <><Header /><div class="content"><Outlet /></div><footer>{"(c)"}</footer></> } }"#;
+        let diagnostics = project_diagnostics(Rule::PageHasMain, &[("src/app.rs", shell)]);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].element, Tag::Footer);
+
+        // A <main> rendered by another page does not count.
+        let with_role = r#"fn Page() { html! { <div role="main"><h1>{"Hi"}</h1></div> } }"#;
+        let files = [("src/app.rs", shell), ("src/page.rs", with_role)];
+        assert_eq!(project_diagnostics(Rule::PageHasMain, &files).len(), 1);
+        // One rendered through a component does.
+        let shell = shell.replace("<Outlet />", "<Page />");
+        let files = [("src/app.rs", shell.as_str()), ("src/page.rs", with_role)];
+        assert!(project_diagnostics(Rule::PageHasMain, &files).is_empty());
+
+        let widget = r#"fn Card() { html! { <section><h2>{"Card"}</h2></section> } }"#;
+        assert!(project_diagnostics(Rule::PageHasMain, &[("src/card.rs", widget)]).is_empty());
+    }
+
+    #[test]
+    fn test_page_has_main_follows_helper_functions() {
+        // A helper function is rendered where it is called, not a page.
+        let app = r#"
+            fn header() -> Html { html! { <header>{"Site"}</header> } }
+            #[function_component]
+            fn App() -> Html { html! { <>{ header() }<main>{"Hi"}</main></> } }
+        "#;
+        assert!(project_diagnostics(Rule::PageHasMain, &[("src/app.rs", app)]).is_empty());
+
+        let app = app.replace("<main>{\"Hi\"}</main>", "<div></div>");
+        let diagnostics = project_diagnostics(Rule::PageHasMain, &[("src/app.rs", &app)]);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].element, Tag::Header);
+
+        // A lowercase function is a page when declared as a component.
+        let root = r#"#[component] fn app() -> impl IntoView { view! { <nav>"Menu"</nav> } }"#;
+        assert_eq!(
+            project_diagnostics(Rule::PageHasMain, &[("src/main.rs", root)]).len(),
+            1
+        );
+        let helper = r#"fn menu() -> impl IntoView { view! { <nav>"Menu"</nav> } }"#;
+        assert!(project_diagnostics(Rule::PageHasMain, &[("src/menu.rs", helper)]).is_empty());
+    }
+
+    #[test]
+    fn test_page_has_main_ignores_sectioning_content() {
+        let post = r#"fn Post() { html! { <article><header><h2>{"Post"}</h2></header><footer>{"By me"}</footer></article> } }"#;
+        assert!(project_diagnostics(Rule::PageHasMain, &[("src/post.rs", post)]).is_empty());
+
+        // A header component used by a layout with <main>, and a footer
+        // component used inside an <article>.
+        let files = [
+            (
+                "src/layout.rs",
+                r#"fn Layout() { view! { <SiteHeader /><main><article><Byline /></article></main> } }"#,
+            ),
+            (
+                "src/header.rs",
+                r#"fn SiteHeader() { view! { <header>"Site"</header> } }"#,
+            ),
+            (
+                "src/byline.rs",
+                r#"fn Byline() { view! { <footer>"By me"</footer> } }"#,
+            ),
+            (
+                "src/feed.rs",
+                r#"fn Feed() { view! { <section><Byline /></section> } }"#,
+            ),
+        ];
+        assert!(project_diagnostics(Rule::PageHasMain, &files).is_empty());
+    }
+
+    #[test]
+    fn test_skip_link_missing() {
+        let shell = r#"fn app() { html! { <><header><nav><a href="/">{"Home"}</a></nav></header><main><Outlet /></main></> } }"#;
+        let diagnostics = skip_link_diagnostics(&[("src/app.rs", shell)]);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].element, Tag::Main);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+
+        // Without navigation there is nothing to skip.
+        let page = r#"fn app() { html! { <main><h1>{"Hi"}</h1></main> } }"#;
+        assert!(skip_link_diagnostics(&[("src/app.rs", page)]).is_empty());
+    }
+
+    #[test]
+    fn test_skip_link_across_files() {
+        let nav =
+            r##"fn nav() { view! { <a href="#content">"Skip to content"</a><nav>"..."</nav> } }"##;
+        let main = r#"fn layout() { view! { <main><h1 id="content">"Title"</h1></main> } }"#;
+        assert!(skip_link_diagnostics(&[("src/nav.rs", nav), ("src/layout.rs", main)]).is_empty());
+
+        // The target is rendered by a component used inside <main>.
+        let main = r#"fn layout() { view! { <main><Article /></main> } }"#;
+        let article = r#"fn Article() { view! { <h1 id="content">"Title"</h1> } }"#;
+        let files = [
+            ("src/nav.rs", nav),
+            ("src/layout.rs", main),
+            ("src/article.rs", article),
+        ];
+        assert!(skip_link_diagnostics(&files).is_empty());
+
+        let elsewhere = r#"fn layout() { view! { <main></main><footer id="content"></footer> } }"#;
+        assert_eq!(
+            skip_link_diagnostics(&[("src/nav.rs", nav), ("src/layout.rs", elsewhere)]).len(),
+            1
+        );
+    }
+}
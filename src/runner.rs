@@ -0,0 +1,911 @@
+//! Configurable project linting with progress reporting.
+//!
+//! [`check_project`](crate::check_project) and
+//! [`check_project_with`](crate::check_project_with) are shorthands for a
+//! [`LintRunner`]; use it directly to restrict rules or observe progress:
+//!
+//! ```rust,no_run
+//! use std::path::Path;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use rsx_a11y::runner::{LintRunner, ProgressHandler};
+//!
+//! #[derive(Default)]
+//! struct Counter(AtomicUsize);
+//!
+//! impl ProgressHandler for Counter {
+//!     fn file_finished(&self, _file: &Path, _diagnostics: usize) {
+//!         self.0.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! let counter = Counter::default();
+//! let summary = LintRunner::new().progress(&counter).run(Path::new("src"));
+//! ```
+//!
+//! Long-running consumers (editors, watch mode) can abort a scan that is no
+//! longer needed with a [`CancellationToken`].
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::LintSummary;
+use crate::bench::Timings;
+use crate::config::{Config, ResolvedConfig, RuleLevel};
+use crate::lints::{self, LintDiagnostic, LintPass, Rule};
+use crate::parser::{self, MacroError, ParseError, ParseOptions};
+use crate::project::{self, Project, ProjectFile, ProjectRule};
+use crate::source::{FileSystem, SourceProvider};
+
+/// Receives progress events while a [`LintRunner`] works through files.
+///
+/// Files may be linted in parallel, so events can arrive from several threads
+/// and out of order. All methods default to doing nothing.
+pub trait ProgressHandler: Sync {
+    /// Called once, before any file is linted.
+    fn start(&self, _total_files: usize) {}
+
+    /// Called before a file is read.
+    fn file_started(&self, _file: &Path) {}
+
+    /// Called after a file is linted, with the number of diagnostics it
+    /// produced (zero if it could not be parsed).
+    fn file_finished(&self, _file: &Path, _diagnostics: usize) {}
+
+    /// Called once, after every file is linted by
+    /// [`run`](LintRunner::run) or [`run_files`](LintRunner::run_files).
+    fn finish(&self, _summary: &LintSummary) {}
+}
+
+/// Cooperative cancellation for a [`LintRunner`] scan.
+///
+/// Clones share the same flag: keep one and hand another to the runner,
+/// then call [`cancel`](CancellationToken::cancel) from any thread.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        CancellationToken::default()
+    }
+
+    /// Ask every runner holding this token to stop. Files already being
+    /// linted finish; the rest are skipped.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Lints files with a [`Config`], optionally restricted to some rules.
+///
+/// Files are linted in parallel when the `rayon` dependency is enabled (it is
+/// with the default `cli` feature), on rayon's global pool unless
+/// [`threads`](LintRunner::threads) is set.
+#[derive(Default)]
+pub struct LintRunner<'a> {
+    config: Config,
+    only: Option<Vec<Rule>>,
+    skip: Option<Vec<Rule>>,
+    progress: Option<&'a dyn ProgressHandler>,
+    project_rules: Vec<&'a dyn ProjectRule>,
+    sources: Option<&'a dyn SourceProvider>,
+    cancellation: Option<CancellationToken>,
+    threads: Option<usize>,
+    max_file_size: Option<u64>,
+    follow_symlinks: bool,
+    include_generated: bool,
+    expand_macro_rules: bool,
+    timings: bool,
+    verbose_passes: bool,
+}
+
+/// Why a file was not linted.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum SkipReason {
+    /// Larger than [`max_file_size`](LintRunner::max_file_size).
+    TooLarge,
+    /// Not UTF-8 text, e.g. a binary artifact with a `.rs` extension.
+    Binary,
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkipReason::TooLarge => write!(f, "larger than the maximum file size"),
+            SkipReason::Binary => write!(f, "not UTF-8 text"),
+        }
+    }
+}
+
+/// A file in [`LintSummary::skipped_files`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct SkippedFile {
+    pub file: String,
+    pub reason: SkipReason,
+}
+
+/// Results for a single file, as yielded by [`LintRunner::iter`].
+#[derive(Debug, Clone)]
+pub struct FileResult {
+    pub file: PathBuf,
+    /// Diagnostics sorted by line and column.
+    pub diagnostics: Vec<LintDiagnostic>,
+    /// Set if the file could not be read or parsed.
+    pub parse_error: Option<ParseError>,
+    /// Why the file was not linted, if it was skipped.
+    pub skipped: Option<SkipReason>,
+    /// Whether the file was skipped for being generated (see
+    /// [`include_generated`](LintRunner::include_generated)).
+    pub generated: bool,
+    /// RSX macros in the file that could not be parsed.
+    pub macro_errors: Vec<MacroError>,
+    /// Whether the file contained any lintable RSX element.
+    pub has_elements: bool,
+    /// Number of RSX elements in the file.
+    pub elements: usize,
+    /// Time spent on the file, if [`timings`](LintRunner::timings) is
+    /// enabled.
+    pub timings: Option<Timings>,
+    /// Why elements passed, if [`verbose_passes`](LintRunner::verbose_passes)
+    /// is enabled.
+    pub passes: Vec<LintPass>,
+}
+
+impl<'a> LintRunner<'a> {
+    /// A runner with the built-in defaults: every rule at its default
+    /// severity.
+    pub fn new() -> Self {
+        LintRunner::default()
+    }
+
+    /// Apply rule levels, overrides, options and macros from `config`.
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Only run these rules (like `--only`).
+    pub fn only(mut self, rules: Vec<Rule>) -> Self {
+        self.only = Some(rules);
+        self
+    }
+
+    /// Never run these rules (like `--skip`).
+    pub fn skip(mut self, rules: Vec<Rule>) -> Self {
+        self.skip = Some(rules);
+        self
+    }
+
+    /// Report progress to `handler`.
+    pub fn progress(mut self, handler: &'a dyn ProgressHandler) -> Self {
+        self.progress = Some(handler);
+        self
+    }
+
+    /// Read files through `sources` instead of from disk. Directories passed
+    /// to [`run`](LintRunner::run) are still walked on disk, so use
+    /// [`run_files`](LintRunner::run_files) for purely virtual files.
+    pub fn sources(mut self, sources: &'a dyn SourceProvider) -> Self {
+        self.sources = Some(sources);
+        self
+    }
+
+    /// Stop linting new files once `token` is cancelled. The summary is then
+    /// partial and has [`cancelled`](LintSummary::cancelled) set.
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Lint on a dedicated pool of `threads` threads instead of rayon's
+    /// global pool. Has no effect without the `rayon` dependency.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Skip files larger than `bytes` (e.g. generated artifacts); they are
+    /// listed in [`skipped_files`](LintSummary::skipped_files).
+    pub fn max_file_size(mut self, bytes: u64) -> Self {
+        self.max_file_size = Some(bytes);
+        self
+    }
+
+    /// Follow symlinks when walking directories in [`run`](LintRunner::run)
+    /// and [`iter`](LintRunner::iter). Off by default; cycles are detected.
+    pub fn follow_symlinks(mut self, follow: bool) -> Self {
+        self.follow_symlinks = follow;
+        self
+    }
+
+    /// Lint generated files too. By default, files matching the config's
+    /// `generated` patterns or marked `@generated` / `DO NOT EDIT` in their
+    /// leading comments are skipped and listed in
+    /// [`LintSummary::generated_files`].
+    pub fn include_generated(mut self, include: bool) -> Self {
+        self.include_generated = include;
+        self
+    }
+
+    /// Also lint the RSX templates of `macro_rules!` definitions (like
+    /// `--expand`), treating their metavariables as dynamic values.
+    pub fn expand_macro_rules(mut self, expand: bool) -> Self {
+        self.expand_macro_rules = expand;
+        self
+    }
+
+    /// Record how long parsing, linting and each rule take, in
+    /// [`LintSummary::timings`] and [`FileResult::timings`]. Timing every
+    /// rule check slows linting down slightly.
+    pub fn timings(mut self, enabled: bool) -> Self {
+        self.timings = enabled;
+        self
+    }
+
+    /// Record why elements passed the rules that can say, such as which
+    /// attribute gave a link its name, in [`LintSummary::passes`] and
+    /// [`FileResult::passes`]. Useful for audits and for debugging
+    /// unexpected passes.
+    pub fn verbose_passes(mut self, enabled: bool) -> Self {
+        self.verbose_passes = enabled;
+        self
+    }
+
+    /// Also run `rule` once every file is linted. The built-in
+    /// [project rules](crate::project) always run.
+    pub fn project_rule(mut self, rule: &'a dyn ProjectRule) -> Self {
+        self.project_rules.push(rule);
+        self
+    }
+
+    /// Run `f` on the configured thread pool.
+    #[cfg(feature = "rayon")]
+    fn install<R: Send>(&self, f: impl FnOnce() -> R + Send) -> R {
+        match self
+            .threads
+            .and_then(|n| rayon::ThreadPoolBuilder::new().num_threads(n).build().ok())
+        {
+            Some(pool) => pool.install(f),
+            None => f(),
+        }
+    }
+
+    fn parse_options(&self) -> ParseOptions {
+        ParseOptions {
+            skip_generated: !self.include_generated,
+            expand_macro_rules: self.expand_macro_rules,
+            ..self.config.parse_options()
+        }
+    }
+
+    /// The config for `file`, restricted by [`only`](LintRunner::only) and
+    /// [`skip`](LintRunner::skip).
+    fn resolve(&self, file: &Path) -> ResolvedConfig {
+        let mut resolved = self.config.resolve(file);
+        if let Some(ref only) = self.only {
+            resolved.apply_only(only);
+        }
+        if let Some(ref skip) = self.skip {
+            resolved.apply_skip(skip);
+        }
+        resolved
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Lint every `.rs` file under `path` (skipping `target/`,
+    /// `node_modules/` and dot-directories).
+    pub fn run(&self, path: &Path) -> LintSummary {
+        self.run_files(&crate::collect_rust_files_with(path, self.follow_symlinks))
+    }
+
+    /// Lint exactly `files`, then run the project rules over all of them.
+    /// Diagnostics are sorted by file, line and column.
+    pub fn run_files(&self, files: &[PathBuf]) -> LintSummary {
+        if let Some(progress) = self.progress {
+            progress.start(files.len());
+        }
+        let parse_options = self.parse_options();
+        let project_rules: Vec<&dyn ProjectRule> = project::builtin()
+            .iter()
+            .chain(&self.project_rules)
+            .copied()
+            .collect();
+        let project_files = Mutex::new(Vec::new());
+        let project = (!project_rules.is_empty()).then_some(&project_files);
+
+        #[cfg(feature = "rayon")]
+        let mut summary = self.install(|| {
+            files
+                .par_iter()
+                .map(|file| summarize(self.lint_file(file, &parse_options, project)))
+                .reduce(LintSummary::default, merge)
+        });
+        #[cfg(not(feature = "rayon"))]
+        let mut summary = files
+            .iter()
+            .map(|file| summarize(self.lint_file(file, &parse_options, project)))
+            .fold(LintSummary::default(), merge);
+
+        summary.cancelled = self.is_cancelled();
+        if !summary.cancelled {
+            let files = project_files
+                .into_inner()
+                .unwrap_or_else(|e| e.into_inner());
+            self.check_project(&project_rules, files, &mut summary);
+        }
+        summary.skipped_files.sort_unstable();
+        summary.generated_files.sort_unstable();
+        summary.diagnostics.sort_unstable_by(|a, b| {
+            a.file
+                .cmp(&b.file)
+                .then(a.line.cmp(&b.line))
+                .then(a.column.cmp(&b.column))
+        });
+        dedup(&mut summary.diagnostics);
+        summary.passes.sort_by(|a, b| {
+            a.file
+                .cmp(&b.file)
+                .then(a.line.cmp(&b.line))
+                .then(a.column.cmp(&b.column))
+        });
+        summary.macro_errors.sort_unstable_by(|a, b| {
+            a.file
+                .cmp(&b.file)
+                .then(a.line.cmp(&b.line))
+                .then(a.column.cmp(&b.column))
+        });
+
+        if let Some(progress) = self.progress {
+            progress.finish(&summary);
+        }
+        summary
+    }
+
+    /// Run `rules` over `files` and add their diagnostics to `summary`.
+    fn check_project(
+        &self,
+        rules: &[&dyn ProjectRule],
+        mut files: Vec<ProjectFile>,
+        summary: &mut LintSummary,
+    ) {
+        files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+        let project = Project { files };
+        let mut resolved: HashMap<String, ResolvedConfig> = HashMap::new();
+        for rule in rules {
+            let start = Instant::now();
+            let diagnostics = rule.check_project(&project);
+            let elapsed = start.elapsed();
+            let diagnostics: Vec<_> = diagnostics
+                .into_iter()
+                .filter_map(|d| {
+                    resolved
+                        .entry(d.file.clone())
+                        .or_insert_with(|| self.resolve(Path::new(&d.file)))
+                        .apply(d)
+                })
+                .collect();
+            if let Some(ref mut timings) = summary.timings {
+                timings.rules.entry(rule.rule()).or_default().time += elapsed;
+                timings.record_hits(&diagnostics);
+            }
+            summary.diagnostics.extend(diagnostics);
+        }
+    }
+
+    /// Lint one file, or return `None` if the scan was cancelled. With
+    /// `project`, the file's elements are kept there for the project rules.
+    fn lint_file(
+        &self,
+        file: &Path,
+        parse_options: &ParseOptions,
+        project: Option<&Mutex<Vec<ProjectFile>>>,
+    ) -> Option<FileResult> {
+        if self.is_cancelled() {
+            return None;
+        }
+        if let Some(progress) = self.progress {
+            progress.file_started(file);
+        }
+
+        let mut result = FileResult {
+            file: file.to_path_buf(),
+            diagnostics: Vec::new(),
+            parse_error: None,
+            skipped: None,
+            generated: false,
+            macro_errors: Vec::new(),
+            has_elements: false,
+            elements: 0,
+            timings: None,
+            passes: Vec::new(),
+        };
+        let sources = self.sources.unwrap_or(&FileSystem);
+        if let Some(max) = self.max_file_size
+            && sources.size(file).is_some_and(|size| size > max)
+        {
+            result.skipped = Some(SkipReason::TooLarge);
+            if let Some(progress) = self.progress {
+                progress.file_finished(file, 0);
+            }
+            return Some(result);
+        }
+        if !self.include_generated && self.config.is_generated(file) {
+            result.generated = true;
+            if let Some(progress) = self.progress {
+                progress.file_finished(file, 0);
+            }
+            return Some(result);
+        }
+        let start = Instant::now();
+        let parsed = parser::parse_file_from(file, parse_options, sources);
+        let mut timings = self.timings.then(|| Timings {
+            parse: start.elapsed(),
+            ..Timings::default()
+        });
+        match parsed {
+            Ok(parsed) => {
+                result.generated = parsed.generated;
+                result.macro_errors = parsed.macro_errors;
+                if !parsed.elements.is_empty() {
+                    result.has_elements = true;
+                    result.elements = parsed.elements.len();
+                    let resolved = self.resolve(file);
+                    let start = Instant::now();
+                    let diagnostics = match timings {
+                        Some(ref mut timings) => lints::run_all_lints_timed(
+                            &parsed.elements,
+                            &resolved.options,
+                            &mut timings.rules,
+                        ),
+                        None => {
+                            lints::run_all_lints_with(&parsed.elements, &resolved.options).collect()
+                        }
+                    };
+                    let mut seen = HashSet::new();
+                    result.diagnostics = diagnostics
+                        .into_iter()
+                        .filter_map(|d| resolved.apply(d))
+                        .filter(|d| seen.insert(d.fingerprint()))
+                        .collect();
+                    if let Some(ref mut timings) = timings {
+                        timings.lint = start.elapsed();
+                        timings.record_hits(&result.diagnostics);
+                    }
+                    result
+                        .diagnostics
+                        .sort_unstable_by(|a, b| a.line.cmp(&b.line).then(a.column.cmp(&b.column)));
+                    if self.verbose_passes {
+                        result.passes = lints::explain_passes(&parsed.elements, &resolved.options)
+                            .into_iter()
+                            .filter(|pass| resolved.level(&pass.rule) != RuleLevel::Off)
+                            .collect();
+                    }
+                    if let Some(project) = project {
+                        let project_file = ProjectFile {
+                            path: file.to_path_buf(),
+                            elements: parsed.elements,
+                            components: parsed.components,
+                            declared_components: parsed.declared_components,
+                            options: resolved.options,
+                        };
+                        project
+                            .lock()
+                            .unwrap_or_else(|e| e.into_inner())
+                            .push(project_file);
+                    }
+                }
+            }
+            Err(ParseError::BinaryFile(_)) => result.skipped = Some(SkipReason::Binary),
+            Err(e) => result.parse_error = Some(e),
+        }
+        result.timings = timings;
+
+        if let Some(progress) = self.progress {
+            progress.file_finished(file, result.diagnostics.len());
+        }
+        Some(result)
+    }
+}
+
+impl LintRunner<'static> {
+    /// Like [`run`](LintRunner::run), but yields each file's results as soon
+    /// as it is linted instead of waiting for the whole scan.
+    ///
+    /// With `rayon`, files are linted on a background thread pool and arrive
+    /// in no particular order. Dropping the iterator stops the scan.
+    /// [`ProgressHandler::finish`] is not called, and project rules do not
+    /// run.
+    pub fn iter(self, path: &Path) -> impl Iterator<Item = FileResult> {
+        let files = crate::collect_rust_files_with(path, self.follow_symlinks);
+        self.iter_files(files)
+    }
+
+    /// Like [`iter`](LintRunner::iter), for exactly `files`.
+    #[cfg(feature = "rayon")]
+    pub fn iter_files(self, files: Vec<PathBuf>) -> impl Iterator<Item = FileResult> {
+        if let Some(progress) = self.progress {
+            progress.start(files.len());
+        }
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let parse_options = self.parse_options();
+            // A failed send means the receiver was dropped; stop early.
+            let _ = self.install(|| {
+                files.par_iter().try_for_each_with(sender, |sender, file| {
+                    match self.lint_file(file, &parse_options, None) {
+                        Some(result) => sender.send(result).map_err(|_| ()),
+                        None => Ok(()),
+                    }
+                })
+            });
+        });
+        receiver.into_iter()
+    }
+
+    /// Like [`iter`](LintRunner::iter), for exactly `files`.
+    #[cfg(not(feature = "rayon"))]
+    pub fn iter_files(self, files: Vec<PathBuf>) -> impl Iterator<Item = FileResult> {
+        if let Some(progress) = self.progress {
+            progress.start(files.len());
+        }
+        let parse_options = self.parse_options();
+        files
+            .into_iter()
+            .map_while(move |file| self.lint_file(&file, &parse_options, None))
+    }
+}
+
+/// A one-file [`LintSummary`], for merging.
+fn summarize(result: Option<FileResult>) -> LintSummary {
+    let mut summary = LintSummary::default();
+    if let Some(result) = result {
+        summary.diagnostics = result.diagnostics;
+        summary.parse_errors.extend(result.parse_error);
+        let display = || result.file.to_string_lossy().replace('\\', "/");
+        if let Some(reason) = result.skipped {
+            summary.skipped_files.push(SkippedFile {
+                file: display(),
+                reason,
+            });
+        }
+        if result.generated {
+            summary.generated_files.push(display());
+        }
+        summary.macro_errors = result.macro_errors;
+        summary.files_checked = usize::from(result.has_elements);
+        if result.has_elements {
+            summary.element_counts.insert(display(), result.elements);
+        }
+        summary.timings = result.timings;
+        summary.passes = result.passes;
+    }
+    summary
+}
+
+/// Drop diagnostics identical to an earlier one, including those found in
+/// the same file through another path (e.g. `src/a.rs` and `./src/a.rs`, or
+/// a symlink).
+fn dedup(diagnostics: &mut Vec<LintDiagnostic>) {
+    let mut canonical: HashMap<String, PathBuf> = HashMap::new();
+    let mut seen = HashSet::new();
+    diagnostics.retain(|d| {
+        let file = canonical
+            .entry(d.file.clone())
+            .or_insert_with(|| crate::config::canonicalize(Path::new(&d.file)))
+            .clone();
+        seen.insert((file, d.fingerprint()))
+    });
+}
+
+fn merge(mut a: LintSummary, b: LintSummary) -> LintSummary {
+    a.diagnostics.extend(b.diagnostics);
+    a.parse_errors.extend(b.parse_errors);
+    a.macro_errors.extend(b.macro_errors);
+    a.files_checked += b.files_checked;
+    a.element_counts.extend(b.element_counts);
+    a.skipped_files.extend(b.skipped_files);
+    a.passes.extend(b.passes);
+    a.generated_files.extend(b.generated_files);
+    a.timings = match (a.timings, b.timings) {
+        (Some(mut a), Some(b)) => {
+            a.merge(&b);
+            Some(a)
+        }
+        (a, b) => a.or(b),
+    };
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl ProgressHandler for Recorder {
+        fn start(&self, total_files: usize) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("start {}", total_files));
+        }
+
+        fn file_finished(&self, _file: &Path, _diagnostics: usize) {
+            self.events.lock().unwrap().push("file".to_string());
+        }
+
+        fn finish(&self, summary: &LintSummary) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("finish {}", summary.files_checked));
+        }
+    }
+
+    #[test]
+    fn test_progress_events() {
+        let recorder = Recorder::default();
+        let files = [
+            PathBuf::from("tests/fixtures/yew_component.rs"),
+            PathBuf::from("tests/fixtures/leptos_component.rs"),
+        ];
+        let summary = LintRunner::new().progress(&recorder).run_files(&files);
+        assert_eq!(summary.files_checked, 2);
+        assert_eq!(
+            *recorder.events.lock().unwrap(),
+            ["start 2", "file", "file", "finish 2"]
+        );
+    }
+
+    #[test]
+    fn test_cancelled_runner_skips_files() {
+        let token = CancellationToken::new();
+        let runner = LintRunner::new().cancellation(token.clone());
+        assert!(!runner.run(Path::new("tests/fixtures")).cancelled);
+
+        token.cancel();
+        let summary = runner.run(Path::new("tests/fixtures"));
+        assert!(summary.cancelled);
+        assert_eq!(summary.files_checked, 0);
+        assert!(summary.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_iter_yields_each_file() {
+        let mut results: Vec<FileResult> = LintRunner::new()
+            .iter(Path::new("tests/fixtures"))
+            .filter(|r| r.has_elements)
+            .collect();
+        results.sort_by(|a, b| a.file.cmp(&b.file));
+        assert_eq!(results.len(), 3);
+        let total: usize = results.iter().map(|r| r.diagnostics.len()).sum();
+        // Project rules only run over a whole scan.
+        let summary = LintRunner::new().run(Path::new("tests/fixtures"));
+        let per_file = summary
+            .diagnostics
+            .iter()
+            .filter(|d| !project::builtin().iter().any(|rule| rule.rule() == d.rule));
+        assert_eq!(total, per_file.count());
+    }
+
+    #[test]
+    fn test_timings_only_when_enabled() {
+        let path = Path::new("tests/fixtures");
+        assert!(LintRunner::new().run(path).timings.is_none());
+
+        let summary = LintRunner::new().timings(true).run(path);
+        let timings = summary.timings.unwrap();
+        assert!(timings.parse > std::time::Duration::ZERO);
+        assert!(timings.rules.contains_key(&Rule::AltText));
+        assert_eq!(summary.diagnostics, LintRunner::new().run(path).diagnostics);
+        let hits: usize = timings.rules.values().map(|stats| stats.hits).sum();
+        assert_eq!(hits, summary.diagnostics.len());
+    }
+
+    #[test]
+    fn test_verbose_passes() {
+        let mut sources = crate::source::MemorySources::new();
+        let file = PathBuf::from("src/nav.rs");
+        sources.insert(
+            &file,
+            r#"fn c() { html! { <nav><a href="/" title="Home"></a><h2>{"Menu"}</h2></nav> } }"#,
+        );
+        let files = std::slice::from_ref(&file);
+        assert!(
+            LintRunner::new()
+                .sources(&sources)
+                .run_files(files)
+                .passes
+                .is_empty()
+        );
+
+        let summary = LintRunner::new()
+            .sources(&sources)
+            .verbose_passes(true)
+            .skip(vec![Rule::HeadingHasContent])
+            .run_files(files);
+        let passes: Vec<_> = summary
+            .passes
+            .iter()
+            .map(|pass| (&pass.rule, pass.reason.as_str()))
+            .collect();
+        assert_eq!(
+            passes,
+            [(&Rule::AnchorHasContent, r#"named via title="Home""#)]
+        );
+    }
+
+    #[test]
+    fn test_max_file_size_and_threads() {
+        let summary = LintRunner::new()
+            .threads(2)
+            .max_file_size(16)
+            .run(Path::new("tests/fixtures"));
+        assert_eq!(summary.files_checked, 0);
+        assert!(summary.skipped_files.contains(&SkippedFile {
+            file: "tests/fixtures/yew_component.rs".to_string(),
+            reason: SkipReason::TooLarge,
+        }));
+
+        let summary = LintRunner::new()
+            .threads(1)
+            .run(Path::new("tests/fixtures"));
+        assert!(summary.skipped_files.is_empty());
+        assert_eq!(summary.files_checked, 3);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_follow_symlinks_terminates_on_cycles() {
+        let dir = std::env::temp_dir().join(format!("rsx-a11y-symlinks-{}", std::process::id()));
+        let sub = dir.join("components");
+        std::fs::create_dir_all(&sub).unwrap();
+        std::fs::write(sub.join("card.rs"), "fn c() { html! { <img /> } }").unwrap();
+        std::os::unix::fs::symlink(&dir, sub.join("loop")).unwrap();
+
+        assert_eq!(crate::collect_rust_files_with(&dir, false).len(), 1);
+        assert_eq!(crate::collect_rust_files_with(&dir, true).len(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_lints_unsaved_buffer() {
+        let mut sources = crate::source::MemorySources::over_disk();
+        let fixture = PathBuf::from("tests/fixtures/yew_component.rs");
+        sources.insert(
+            &fixture,
+            r#"fn c() { html! { <img src="a.png" alt="A cat" /> } }"#,
+        );
+        let summary = LintRunner::new()
+            .sources(&sources)
+            .run_files(std::slice::from_ref(&fixture));
+        assert_eq!(summary.files_checked, 1);
+        assert!(summary.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_lints_configured_macros() {
+        let mut sources = crate::source::MemorySources::new();
+        sources.insert(
+            "src/card.rs",
+            "fn c() { my_html! { <img src=\"a.png\" /> } }",
+        );
+        sources.insert("src/list.rs", "fn c() { other! { <img src=\"a.png\" /> } }");
+        let file: crate::config::ConfigFile = toml::from_str("macros = [\"my_html\"]").unwrap();
+        let config = Config::from_file(file, None, PathBuf::from(".")).unwrap();
+        let summary = LintRunner::new()
+            .sources(&sources)
+            .config(config)
+            .run_files(&sources.paths());
+        assert_eq!(summary.files_checked, 1);
+        assert!(
+            summary
+                .diagnostics
+                .iter()
+                .all(|d| d.file == "src/card.rs" && d.source_macro == "my_html")
+        );
+        assert!(summary.diagnostics.iter().any(|d| d.rule == Rule::AltText));
+    }
+
+    #[test]
+    fn test_skips_binary_files() {
+        let mut sources = crate::source::MemorySources::new();
+        sources.insert("src/blob.rs", "\0\0ELF");
+        let summary = LintRunner::new()
+            .sources(&sources)
+            .run_files(&sources.paths());
+        assert!(summary.parse_errors.is_empty());
+        assert_eq!(
+            summary.skipped_files,
+            [SkippedFile {
+                file: "src/blob.rs".to_string(),
+                reason: SkipReason::Binary,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_skips_generated_files() {
+        let mut sources = crate::source::MemorySources::new();
+        sources.insert("src/app.rs", "fn c() { html! { <img /> } }");
+        sources.insert(
+            "src/bindings.rs",
+            "// @generated by build.rs\nfn c() { html! { <img /> } }",
+        );
+        let files = sources.paths();
+
+        let summary = LintRunner::new().sources(&sources).run_files(&files);
+        assert_eq!(summary.files_checked, 1);
+        assert_eq!(summary.generated_files, ["src/bindings.rs"]);
+
+        let file = crate::config::ConfigFile {
+            generated: vec!["src/app.rs".to_string()],
+            ..Default::default()
+        };
+        let config = Config::from_file(file, None, std::env::current_dir().unwrap()).unwrap();
+        let summary = LintRunner::new()
+            .config(config)
+            .sources(&sources)
+            .run_files(&files);
+        assert_eq!(summary.generated_files.len(), 2);
+
+        let summary = LintRunner::new()
+            .sources(&sources)
+            .include_generated(true)
+            .run_files(&files);
+        assert_eq!(summary.files_checked, 2);
+        assert!(summary.generated_files.is_empty());
+
+        // Parsing on its own does not skip them.
+        let parsed = crate::parser::parse_file_from(
+            Path::new("src/bindings.rs"),
+            &crate::parser::ParseOptions::default(),
+            &sources,
+        )
+        .unwrap();
+        assert!(!parsed.generated && !parsed.elements.is_empty());
+    }
+
+    #[test]
+    fn test_dedups_files_reached_twice() {
+        let files = [
+            PathBuf::from("tests/fixtures/yew_component.rs"),
+            PathBuf::from("tests/fixtures/../fixtures/yew_component.rs"),
+        ];
+        let once = LintRunner::new().run_files(&files[..1]);
+        let twice = LintRunner::new().run_files(&files);
+        assert!(!once.diagnostics.is_empty());
+        assert_eq!(twice.diagnostics.len(), once.diagnostics.len());
+    }
+
+    #[test]
+    fn test_only_restricts_rules() {
+        let summary = LintRunner::new()
+            .only(vec![Rule::AltText])
+            .run(Path::new("tests/fixtures"));
+        assert!(!summary.diagnostics.is_empty());
+        assert!(summary.diagnostics.iter().all(|d| d.rule == Rule::AltText));
+    }
+}
@@ -0,0 +1,173 @@
+//! A single accessibility score for a scan, for quality gates like "the
+//! score must not drop below 95" that raw diagnostic counts make awkward.
+//!
+//! Each diagnostic costs a penalty: its severity's weight (error 5,
+//! warning 2, info 1) times its rule's WCAG level weight (A 3, AA 2, AAA or
+//! none 1). Each element adds [`ELEMENT_WEIGHT`] to the capacity the
+//! penalties are measured against, so the score of a file or project with
+//! `n` elements and penalty `p` is `100 * c / (c + p)` with
+//! `c = n * ELEMENT_WEIGHT`. It is 100 without diagnostics and falls
+//! towards 0 as penalties outgrow the amount of markup.
+//!
+//! ```
+//! use rsx_a11y::check_project;
+//! use rsx_a11y::score;
+//! use std::path::Path;
+//!
+//! let report = score::score(&check_project(Path::new("tests/fixtures")));
+//! assert!(report.score < 100.0);
+//! assert_eq!(report.files.len(), 3);
+//! ```
+
+use crate::LintSummary;
+use crate::lints::{LintDiagnostic, Severity, WcagLevel};
+
+/// Capacity each element adds; see the [module docs](self).
+pub const ELEMENT_WEIGHT: f64 = 10.0;
+
+/// The penalty `diagnostic` costs.
+pub fn penalty(diagnostic: &LintDiagnostic) -> f64 {
+    let severity = match diagnostic.severity {
+        Severity::Error => 5.0,
+        Severity::Warning => 2.0,
+        Severity::Info => 1.0,
+    };
+    let level = match diagnostic.rule.wcag_level() {
+        Some(WcagLevel::A) => 3.0,
+        Some(WcagLevel::AA) => 2.0,
+        Some(WcagLevel::AAA) | None => 1.0,
+    };
+    severity * level
+}
+
+/// The score for `elements` elements with `penalty` in total.
+fn score_of(elements: usize, penalty: f64) -> f64 {
+    let capacity = elements as f64 * ELEMENT_WEIGHT;
+    if capacity + penalty == 0.0 {
+        return 100.0;
+    }
+    100.0 * capacity / (capacity + penalty)
+}
+
+/// The score of one file.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct FileScore {
+    pub file: String,
+    pub score: f64,
+    pub elements: usize,
+    pub diagnostics: usize,
+    pub penalty: f64,
+}
+
+/// Result of [`score`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ScoreReport {
+    /// Score over all files.
+    pub score: f64,
+    pub elements: usize,
+    pub diagnostics: usize,
+    pub penalty: f64,
+    /// Files with elements or diagnostics, by path.
+    pub files: Vec<FileScore>,
+}
+
+/// Score the diagnostics of `summary` against its
+/// [`element_counts`](LintSummary::element_counts).
+pub fn score(summary: &LintSummary) -> ScoreReport {
+    let mut files: std::collections::BTreeMap<&str, FileScore> = summary
+        .element_counts
+        .iter()
+        .map(|(file, &elements)| {
+            let score = FileScore {
+                file: file.clone(),
+                score: 100.0,
+                elements,
+                diagnostics: 0,
+                penalty: 0.0,
+            };
+            (file.as_str(), score)
+        })
+        .collect();
+    for diagnostic in &summary.diagnostics {
+        let file = files
+            .entry(diagnostic.file.as_str())
+            .or_insert_with(|| FileScore {
+                file: diagnostic.file.clone(),
+                score: 100.0,
+                elements: 0,
+                diagnostics: 0,
+                penalty: 0.0,
+            });
+        file.diagnostics += 1;
+        file.penalty += penalty(diagnostic);
+    }
+    let mut report = ScoreReport {
+        score: 100.0,
+        elements: 0,
+        diagnostics: 0,
+        penalty: 0.0,
+        files: Vec::new(),
+    };
+    for (_, mut file) in files {
+        file.score = score_of(file.elements, file.penalty);
+        report.elements += file.elements;
+        report.diagnostics += file.diagnostics;
+        report.penalty += file.penalty;
+        report.files.push(file);
+    }
+    report.score = score_of(report.elements, report.penalty);
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::Tag;
+    use crate::lints::Rule;
+    use crate::parser::HtmlElement;
+
+    fn diagnostic(rule: Rule, severity: Severity, file: &str) -> LintDiagnostic {
+        let element = HtmlElement::builder(Tag::Div).file(file).build();
+        LintDiagnostic {
+            severity,
+            ..LintDiagnostic::new(rule, &element, "")
+        }
+    }
+
+    #[test]
+    fn test_penalty_weights_severity_and_level() {
+        // Level A.
+        assert_eq!(
+            penalty(&diagnostic(Rule::AltText, Severity::Error, "a.rs")),
+            15.0
+        );
+        // Level AA.
+        assert_eq!(
+            penalty(&diagnostic(Rule::ColorContrast, Severity::Warning, "a.rs")),
+            4.0
+        );
+        // No criterion.
+        assert_eq!(
+            penalty(&diagnostic(
+                Rule::AnchorAmbiguousText,
+                Severity::Info,
+                "a.rs"
+            )),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_score_per_file_and_total() {
+        let summary = LintSummary {
+            diagnostics: vec![diagnostic(Rule::AltText, Severity::Error, "a.rs")],
+            element_counts: [("a.rs".to_string(), 3), ("b.rs".to_string(), 2)].into(),
+            ..LintSummary::default()
+        };
+        let report = score(&summary);
+        assert_eq!(report.files[0].score, 100.0 * 30.0 / 45.0);
+        assert_eq!(report.files[1].score, 100.0);
+        assert_eq!(report.score, 100.0 * 50.0 / 65.0);
+        assert_eq!((report.elements, report.diagnostics), (5, 1));
+    }
+}
@@ -0,0 +1,126 @@
+//! Where source text comes from.
+//!
+//! Everything reads files through a [`SourceProvider`], so an editor can
+//! lint unsaved buffers and tests can use a virtual file system:
+//!
+//! ```rust
+//! use std::path::Path;
+//! use rsx_a11y::runner::LintRunner;
+//! use rsx_a11y::source::MemorySources;
+//!
+//! let mut sources = MemorySources::new();
+//! sources.insert("src/app.rs", r#"fn app() { html! { <img src="a.png" /> } }"#);
+//!
+//! let summary = LintRunner::new()
+//!     .sources(&sources)
+//!     .run_files(&sources.paths());
+//! assert_eq!(summary.diagnostics[0].file, "src/app.rs");
+//! ```
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Supplies the contents of source files.
+pub trait SourceProvider: Sync {
+    /// Read the file at `path` as UTF-8 text.
+    fn read(&self, path: &Path) -> io::Result<String>;
+
+    /// Size of the file in bytes, if known without reading it.
+    fn size(&self, path: &Path) -> Option<u64>;
+}
+
+/// Reads files from disk.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileSystem;
+
+impl SourceProvider for FileSystem {
+    fn read(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn size(&self, path: &Path) -> Option<u64> {
+        std::fs::metadata(path).ok().map(|m| m.len())
+    }
+}
+
+/// In-memory files, optionally layered over the disk.
+#[derive(Debug, Clone, Default)]
+pub struct MemorySources {
+    files: BTreeMap<PathBuf, String>,
+    disk_fallback: bool,
+}
+
+impl MemorySources {
+    /// A virtual file system: paths that were not inserted do not exist.
+    pub fn new() -> Self {
+        MemorySources::default()
+    }
+
+    /// Inserted files shadow the disk, e.g. unsaved editor buffers; other
+    /// paths are read from disk.
+    pub fn over_disk() -> Self {
+        MemorySources {
+            files: BTreeMap::new(),
+            disk_fallback: true,
+        }
+    }
+
+    /// Set the contents of `path`, replacing any previous contents.
+    pub fn insert(&mut self, path: impl Into<PathBuf>, source: impl Into<String>) {
+        self.files.insert(path.into(), source.into());
+    }
+
+    /// Forget `path`, e.g. when its buffer is closed.
+    pub fn remove(&mut self, path: &Path) -> Option<String> {
+        self.files.remove(path)
+    }
+
+    /// Paths of every inserted file, in sorted order.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.files.keys().cloned().collect()
+    }
+}
+
+impl SourceProvider for MemorySources {
+    fn read(&self, path: &Path) -> io::Result<String> {
+        match self.files.get(path) {
+            Some(source) => Ok(source.clone()),
+            None if self.disk_fallback => FileSystem.read(path),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "not found in memory sources",
+            )),
+        }
+    }
+
+    fn size(&self, path: &Path) -> Option<u64> {
+        match self.files.get(path) {
+            Some(source) => Some(source.len() as u64),
+            None if self.disk_fallback => FileSystem.size(path),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_sources_shadow_disk() {
+        let fixture = Path::new("tests/fixtures/yew_component.rs");
+
+        let mut sources = MemorySources::over_disk();
+        assert!(sources.read(fixture).unwrap().contains("html!"));
+        sources.insert(fixture, "unsaved");
+        assert_eq!(sources.read(fixture).unwrap(), "unsaved");
+        assert_eq!(sources.size(fixture), Some(7));
+
+        let virtual_fs = MemorySources::new();
+        assert_eq!(
+            virtual_fs.read(fixture).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! Parsing of static inline `style` attributes.
+//!
+//! Only what the rules need: splitting declarations, telling whether they
+//! hide the element (`display: none`, `visibility: hidden`) and whether they
+//! make its text large. Classes are only understood when declared in
+//! [`HidingClasses`].
+
+/// The `property: value` declarations of an inline style, with properties
+/// lowercased and `!important` dropped. Later declarations win, so callers
+/// should look from the end (see [`property`]).
+pub fn declarations(style: &str) -> Vec<(String, String)> {
+    style
+        .split(';')
+        .filter_map(|declaration| {
+            let (property, value) = declaration.split_once(':')?;
+            let property = property.trim().to_ascii_lowercase();
+            let value = value.trim();
+            let value = value
+                .strip_suffix("!important")
+                .map_or(value, str::trim_end)
+                .to_ascii_lowercase();
+            (!property.is_empty() && !value.is_empty()).then_some((property, value))
+        })
+        .collect()
+}
+
+/// The effective value of `name` in an inline style, if set.
+pub fn property(style: &str, name: &str) -> Option<String> {
+    declarations(style)
+        .into_iter()
+        .rev()
+        .find(|(property, _)| property == name)
+        .map(|(_, value)| value)
+}
+
+/// Whether an inline style hides the element from everyone, including
+/// assistive technologies: `display: none` or `visibility: hidden`/`collapse`.
+///
+/// ```
+/// use rsx_a11y::style;
+///
+/// assert!(style::is_hidden("color: red; display: none"));
+/// assert!(!style::is_hidden("display: none; display: block"));
+/// ```
+pub fn is_hidden(style: &str) -> bool {
+    property(style, "display").as_deref() == Some("none")
+        || matches!(
+            property(style, "visibility").as_deref(),
+            Some("hidden" | "collapse")
+        )
+}
+
+/// Whether an inline style makes its text "large" in the WCAG sense: at
+/// least 18pt (24px), or 14pt (about 18.66px) when bold. Only `px` and `pt`
+/// font sizes are understood.
+pub fn is_large_text(style: &str) -> bool {
+    let Some(size) = property(style, "font-size") else {
+        return false;
+    };
+    let points = if let Some(px) = size.strip_suffix("px") {
+        px.trim().parse::<f64>().map(|px| px * 0.75)
+    } else if let Some(pt) = size.strip_suffix("pt") {
+        pt.trim().parse::<f64>()
+    } else {
+        return false;
+    };
+    let Ok(points) = points else {
+        return false;
+    };
+    let bold = property(style, "font-weight").is_some_and(|weight| {
+        matches!(weight.as_str(), "bold" | "bolder")
+            || weight.parse::<u16>().is_ok_and(|w| w >= 700)
+    });
+    points >= 18.0 || (bold && points >= 14.0)
+}
+
+/// Utility classes that hide content, declared under `[classes]` in the
+/// configuration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct HidingClasses {
+    /// Classes that hide content visually but keep it exposed to screen
+    /// readers, like `sr-only` or `visually-hidden`.
+    pub sr_only: Vec<String>,
+    /// Classes that hide content from everyone, like `display: none`.
+    pub hidden: Vec<String>,
+}
+
+impl Default for HidingClasses {
+    fn default() -> Self {
+        HidingClasses {
+            sr_only: vec!["sr-only".to_string(), "visually-hidden".to_string()],
+            hidden: Vec::new(),
+        }
+    }
+}
+
+/// How an element's classes hide it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ClassVisibility {
+    #[default]
+    Visible,
+    /// Hidden visually, but still read by screen readers.
+    ScreenReaderOnly,
+    /// Hidden from everyone.
+    Hidden,
+}
+
+impl HidingClasses {
+    /// The visibility a static `class` attribute gives an element. A
+    /// screen-reader-only class wins over a hiding one.
+    ///
+    /// ```
+    /// use rsx_a11y::style::{ClassVisibility, HidingClasses};
+    ///
+    /// let classes = HidingClasses::default();
+    /// assert_eq!(classes.visibility("btn sr-only"), ClassVisibility::ScreenReaderOnly);
+    /// assert_eq!(classes.visibility("hidden"), ClassVisibility::Visible);
+    /// ```
+    pub fn visibility(&self, class: &str) -> ClassVisibility {
+        let has = |list: &[String]| {
+            class
+                .split_whitespace()
+                .any(|c| list.iter().any(|l| l == c))
+        };
+        if has(&self.sr_only) {
+            ClassVisibility::ScreenReaderOnly
+        } else if has(&self.hidden) {
+            ClassVisibility::Hidden
+        } else {
+            ClassVisibility::Visible
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declarations() {
+        assert_eq!(
+            declarations("COLOR: Red ; display:none !important;;"),
+            [
+                ("color".to_string(), "red".to_string()),
+                ("display".to_string(), "none".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_hidden() {
+        assert!(is_hidden("visibility: hidden"));
+        assert!(is_hidden("display:none!important"));
+        assert!(!is_hidden("visibility: visible; opacity: 0"));
+        assert!(!is_hidden(""));
+    }
+
+    #[test]
+    fn test_is_large_text() {
+        assert!(is_large_text("font-size: 24px"));
+        assert!(is_large_text("font-size: 14pt; font-weight: bold"));
+        assert!(!is_large_text("font-size: 14pt; font-weight: 400"));
+        assert!(!is_large_text("font-size: 2em"));
+    }
+
+    #[test]
+    fn test_class_visibility() {
+        let classes = HidingClasses {
+            hidden: vec!["hidden".to_string()],
+            ..HidingClasses::default()
+        };
+        assert_eq!(classes.visibility("p-2 hidden"), ClassVisibility::Hidden);
+        assert_eq!(
+            classes.visibility("hidden visually-hidden"),
+            ClassVisibility::ScreenReaderOnly
+        );
+        assert_eq!(classes.visibility("hidden-xs"), ClassVisibility::Visible);
+    }
+}
@@ -0,0 +1,298 @@
+//! Helpers for accessibility regression tests in application crates.
+//!
+//! The [`assert_no_violations!`](crate::assert_no_violations) macro lints a
+//! crate (or a path inside it) and panics with a readable list of every
+//! diagnostic found:
+//!
+//! ```rust,no_run
+//! #[test]
+//! fn accessibility() {
+//!     rsx_a11y::assert_no_violations!();
+//!     rsx_a11y::assert_no_violations!("src/components");
+//! }
+//! ```
+//!
+//! Violations are errors and warnings after applying the nearest
+//! `rsx-a11y.toml`, so rules turned off for the project stay off in tests.
+//! Info-level suggestions never fail a test.
+//!
+//! Rule authors can snapshot the full output for a fixture with
+//! [`assert_expected`], which compares against a `.expected` file next to the
+//! fixture. Set `RSX_A11Y_BLESS=1` to write the current output instead.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::lints::{self, LintDiagnostic, Severity};
+use crate::parser::{self, MacroError, ParseError, ParseOptions};
+
+pub use crate::assert_no_violations;
+
+/// Diagnostics and parse errors that should fail an accessibility test.
+#[derive(Debug, Clone, Default)]
+pub struct Violations {
+    pub diagnostics: Vec<LintDiagnostic>,
+    pub parse_errors: Vec<ParseError>,
+    /// RSX macros that could not be parsed.
+    pub macro_errors: Vec<MacroError>,
+}
+
+impl Violations {
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty() && self.parse_errors.is_empty() && self.macro_errors.is_empty()
+    }
+
+    /// Panic with a readable report if there are any violations.
+    #[track_caller]
+    pub fn assert_empty(&self) {
+        if !self.is_empty() {
+            panic!("{}", self.report());
+        }
+    }
+
+    /// Human-readable list of every violation, one per line.
+    pub fn report(&self) -> String {
+        let mut out = format!(
+            "found {} accessibility violation(s)",
+            self.diagnostics.len() + self.parse_errors.len() + self.macro_errors.len()
+        );
+        for diag in &self.diagnostics {
+            let _ = write!(
+                out,
+                "\n  {}:{}:{}: {}[{}]: {}",
+                diag.file, diag.line, diag.column, diag.severity, diag.rule, diag.message
+            );
+            if let Some(ref help) = diag.help {
+                let _ = write!(out, "\n      help: {}", help);
+            }
+        }
+        for err in &self.parse_errors {
+            let _ = write!(out, "\n  parse error: {}", err);
+        }
+        for err in &self.macro_errors {
+            let _ = write!(out, "\n  {}", err);
+        }
+        out
+    }
+}
+
+/// Lint every `.rs` file under `path`, applying the nearest config file.
+///
+/// Panics if a config file is found but cannot be loaded.
+pub fn violations(path: &Path) -> Violations {
+    let config = Config::discover(path).unwrap_or_else(|e| panic!("{}", e));
+    let parse_options = ParseOptions {
+        skip_generated: true,
+        ..config.parse_options()
+    };
+    let mut violations = Violations::default();
+
+    for file in crate::collect_rust_files(path) {
+        match parser::parse_file_with(&file, &parse_options) {
+            Ok(parsed) => {
+                let resolved = config.resolve(&file);
+                violations.macro_errors.extend(parsed.macro_errors);
+                violations.diagnostics.extend(
+                    lints::run_all_lints_with(&parsed.elements, &resolved.options)
+                        .filter_map(|d| resolved.apply(d))
+                        .filter(|d| d.severity != Severity::Info),
+                );
+            }
+            Err(e) => violations.parse_errors.push(e),
+        }
+    }
+
+    violations.diagnostics.sort_unstable_by(|a, b| {
+        a.file
+            .cmp(&b.file)
+            .then(a.line.cmp(&b.line))
+            .then(a.column.cmp(&b.column))
+    });
+    violations
+}
+
+/// Lint a single source string with the default configuration.
+pub fn source_violations(source: &str) -> Violations {
+    match parser::parse_source_with(source, "<source>", &ParseOptions::default()) {
+        Ok(parsed) => Violations {
+            diagnostics: lints::run_all_lints(&parsed.elements)
+                .filter(|d| d.severity != Severity::Info)
+                .collect(),
+            parse_errors: Vec::new(),
+            macro_errors: parsed.macro_errors,
+        },
+        Err(e) => Violations {
+            parse_errors: vec![e],
+            ..Violations::default()
+        },
+    }
+}
+
+/// Panic if `source` has any accessibility violations.
+#[track_caller]
+pub fn assert_source_no_violations(source: &str) {
+    source_violations(source).assert_empty();
+}
+
+/// Environment variable that makes [`assert_expected`] write `.expected`
+/// files instead of comparing against them.
+pub const BLESS_ENV_VAR: &str = "RSX_A11Y_BLESS";
+
+/// Render diagnostics in the `.expected` file format: one
+/// `line:column: severity[rule]: message` line per diagnostic.
+///
+/// File paths are left out so snapshots do not depend on where they run.
+pub fn render_expected(diagnostics: &[LintDiagnostic]) -> String {
+    let mut out = String::new();
+    for diag in diagnostics {
+        let _ = writeln!(
+            out,
+            "{}:{}: {}[{}]: {}",
+            diag.line, diag.column, diag.severity, diag.rule, diag.message
+        );
+    }
+    out
+}
+
+/// Path of the `.expected` file for a fixture (`foo.rs` → `foo.expected`).
+pub fn expected_path(fixture: &Path) -> PathBuf {
+    fixture.with_extension("expected")
+}
+
+/// Lint `fixture` with every rule at its default severity and compare the
+/// diagnostics against its `.expected` file.
+///
+/// When [`BLESS_ENV_VAR`] is set, the `.expected` file is (re)written instead.
+#[track_caller]
+pub fn assert_expected(fixture: &Path) {
+    let elements =
+        parser::parse_file(fixture).unwrap_or_else(|e| panic!("failed to parse fixture: {}", e));
+    let mut diagnostics: Vec<_> = lints::run_all_lints(&elements).collect();
+    diagnostics.sort_unstable_by(|a, b| {
+        a.line
+            .cmp(&b.line)
+            .then(a.column.cmp(&b.column))
+            .then(a.rule.cmp(&b.rule))
+    });
+    let actual = render_expected(&diagnostics);
+    let expected_file = expected_path(fixture);
+
+    if std::env::var_os(BLESS_ENV_VAR).is_some() {
+        std::fs::write(&expected_file, &actual)
+            .unwrap_or_else(|e| panic!("failed to write {}: {}", expected_file.display(), e));
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&expected_file).unwrap_or_else(|e| {
+        panic!(
+            "failed to read {}: {} (run with {}=1 to create it)",
+            expected_file.display(),
+            e,
+            BLESS_ENV_VAR
+        )
+    });
+    // Tolerate files checked out with CRLF line endings.
+    let expected = expected.replace("\r\n", "\n");
+    if expected != actual {
+        panic!(
+            "diagnostics for {} do not match {} (run with {}=1 to update)\n{}",
+            fixture.display(),
+            expected_file.display(),
+            BLESS_ENV_VAR,
+            line_diff(&expected, &actual)
+        );
+    }
+}
+
+/// Minimal line diff: `-` for expected lines that are missing, `+` for
+/// unexpected ones, in the order they appear.
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut out = String::new();
+    for line in &expected {
+        if !actual.contains(line) {
+            let _ = writeln!(out, "- {}", line);
+        }
+    }
+    for line in &actual {
+        if !expected.contains(line) {
+            let _ = writeln!(out, "+ {}", line);
+        }
+    }
+    out
+}
+
+/// Assert that a crate has no accessibility violations.
+///
+/// With no arguments the calling crate's `CARGO_MANIFEST_DIR` is linted.
+/// A relative path is resolved against it.
+#[macro_export]
+macro_rules! assert_no_violations {
+    () => {
+        $crate::testing::violations(::std::path::Path::new(env!("CARGO_MANIFEST_DIR")))
+            .assert_empty()
+    };
+    ($path:expr) => {
+        $crate::testing::violations(&::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path))
+            .assert_empty()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_source_passes() {
+        assert_source_no_violations(r#"fn c() { html! { <img src="a.png" alt="A cat" /> } }"#);
+    }
+
+    #[test]
+    fn test_report_lists_diagnostics() {
+        let violations = source_violations(r#"fn c() { html! { <img src="a.png" /> } }"#);
+        assert!(!violations.is_empty());
+        let report = violations.report();
+        assert!(report.contains("<source>:1:"));
+        assert!(report.contains("error[alt-text]"));
+    }
+
+    #[test]
+    #[should_panic(expected = "accessibility violation")]
+    fn test_assert_panics_on_violation() {
+        assert_source_no_violations(r#"fn c() { html! { <img src="a.png" /> } }"#);
+    }
+
+    #[test]
+    fn test_render_expected_format() {
+        let elements =
+            parser::parse_source(r#"fn c() { html! { <img src="a.png" /> } }"#, "x.rs").unwrap();
+        let diags: Vec<_> = lints::run_all_lints(&elements).collect();
+        let rendered = render_expected(&diags);
+        assert!(rendered.starts_with("1:"));
+        assert!(rendered.contains("error[alt-text]: <img> element is missing an `alt` attribute."));
+        assert!(!rendered.contains("x.rs"));
+    }
+
+    #[test]
+    fn test_line_diff_marks_changes() {
+        let diff = line_diff("a\nb\n", "b\nc\n");
+        assert_eq!(diff, "- a\n+ c\n");
+    }
+
+    #[test]
+    fn test_parse_errors_are_violations() {
+        assert!(!source_violations("fn c( {").is_empty());
+    }
+
+    #[test]
+    fn test_invalid_macro_is_a_violation() {
+        let violations = source_violations(
+            r#"fn c() { html! { <img src="a.png" alt="A cat" /> }; html! { <div> } }"#,
+        );
+        assert!(violations.diagnostics.is_empty());
+        assert_eq!(violations.macro_errors.len(), 1);
+        assert!(violations.report().contains("Invalid RSX in `html!`"));
+    }
+}
@@ -0,0 +1,187 @@
+//! Cargo workspace discovery via `cargo metadata`, used by `cargo a11y` and
+//! the `--workspace` / `--package` flags to select member crates.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Deserialize;
+
+/// A workspace member crate.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct Package {
+    pub name: String,
+    /// Directory containing the crate's `Cargo.toml`.
+    pub root: PathBuf,
+}
+
+impl Package {
+    /// The crate's `src/` directory.
+    pub fn src_dir(&self) -> PathBuf {
+        self.root.join("src")
+    }
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    packages: Vec<MetadataPackage>,
+    workspace_members: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct MetadataPackage {
+    name: String,
+    id: String,
+    manifest_path: PathBuf,
+}
+
+/// Run `cargo metadata` and return the workspace members, sorted by name.
+///
+/// `manifest_path` selects the workspace like `cargo --manifest-path`;
+/// otherwise it is found from the current directory. Package roots are made
+/// relative to the current directory when possible, for cleaner output.
+pub fn members(manifest_path: Option<&Path>) -> Result<Vec<Package>, WorkspaceError> {
+    // Cargo sets `CARGO` when running subcommands; prefer the same binary.
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.args(["metadata", "--format-version", "1", "--no-deps"]);
+    if let Some(manifest_path) = manifest_path {
+        command.arg("--manifest-path").arg(manifest_path);
+    }
+
+    let output = command
+        .output()
+        .map_err(|e| WorkspaceError::CargoError(e.to_string()))?;
+    if !output.status.success() {
+        return Err(WorkspaceError::CargoError(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    let mut members = parse_metadata(&String::from_utf8_lossy(&output.stdout))?;
+
+    let cwd = std::env::current_dir().unwrap_or_default();
+    for package in &mut members {
+        if let Ok(relative) = package.root.strip_prefix(&cwd) {
+            package.root = if relative.as_os_str().is_empty() {
+                PathBuf::from(".")
+            } else {
+                relative.to_path_buf()
+            };
+        }
+    }
+    Ok(members)
+}
+
+/// Keep only the members named in `names` (like `cargo -p`), or all of them
+/// if `names` is empty.
+pub fn select(members: Vec<Package>, names: &[String]) -> Result<Vec<Package>, WorkspaceError> {
+    if let Some(unknown) = names
+        .iter()
+        .find(|name| !members.iter().any(|p| &p.name == *name))
+    {
+        return Err(WorkspaceError::UnknownPackage(unknown.clone()));
+    }
+    Ok(members
+        .into_iter()
+        .filter(|p| names.is_empty() || names.contains(&p.name))
+        .collect())
+}
+
+/// Extract the workspace members from `cargo metadata --format-version 1` output.
+pub fn parse_metadata(json: &str) -> Result<Vec<Package>, WorkspaceError> {
+    let metadata: Metadata =
+        serde_json::from_str(json).map_err(|e| WorkspaceError::InvalidMetadata(e.to_string()))?;
+
+    let mut members: Vec<Package> = metadata
+        .packages
+        .into_iter()
+        .filter(|p| metadata.workspace_members.contains(&p.id))
+        .map(|p| Package {
+            name: p.name,
+            root: p
+                .manifest_path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+        })
+        .collect();
+    members.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(members)
+}
+
+/// Errors that can occur while resolving the workspace.
+#[derive(Debug, Clone)]
+pub enum WorkspaceError {
+    /// `cargo metadata` could not be run or failed.
+    CargoError(String),
+    InvalidMetadata(String),
+    /// A `--package` name that is not a workspace member.
+    UnknownPackage(String),
+}
+
+impl std::fmt::Display for WorkspaceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkspaceError::CargoError(err) => write!(f, "`cargo metadata` failed: {}", err),
+            WorkspaceError::InvalidMetadata(err) => {
+                write!(f, "Invalid `cargo metadata` output: {}", err)
+            }
+            WorkspaceError::UnknownPackage(name) => {
+                write!(f, "Package `{}` is not a member of the workspace", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WorkspaceError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_metadata_keeps_workspace_members() {
+        let json = r#"{
+            "packages": [
+                {"name": "web", "id": "path+file:///ws/web#0.1.0", "manifest_path": "/ws/web/Cargo.toml"},
+                {"name": "app", "id": "path+file:///ws/app#0.1.0", "manifest_path": "/ws/app/Cargo.toml"},
+                {"name": "vendored", "id": "path+file:///vendor#1.0.0", "manifest_path": "/vendor/Cargo.toml"}
+            ],
+            "workspace_members": ["path+file:///ws/web#0.1.0", "path+file:///ws/app#0.1.0"],
+            "workspace_root": "/ws",
+            "version": 1
+        }"#;
+        let members = parse_metadata(json).unwrap();
+        let names: Vec<_> = members.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["app", "web"]);
+        assert_eq!(members[0].src_dir(), Path::new("/ws/app/src"));
+    }
+
+    #[test]
+    fn test_select_packages_by_name() {
+        let members = vec![
+            Package {
+                name: "app".to_string(),
+                root: PathBuf::from("app"),
+            },
+            Package {
+                name: "web".to_string(),
+                root: PathBuf::from("web"),
+            },
+        ];
+        assert_eq!(select(members.clone(), &[]).unwrap().len(), 2);
+        let selected = select(members.clone(), &["web".to_string()]).unwrap();
+        assert_eq!(selected, [members[1].clone()]);
+        assert!(matches!(
+            select(members, &["nope".to_string()]),
+            Err(WorkspaceError::UnknownPackage(name)) if name == "nope"
+        ));
+    }
+
+    #[test]
+    fn test_parse_metadata_invalid_json() {
+        assert!(matches!(
+            parse_metadata("not json"),
+            Err(WorkspaceError::InvalidMetadata(_))
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+//! Test fixture: Dioxus component with various accessibility issues.
+//! This file is used for integration testing.
+
+use dioxus::prelude::*;
+
+#[component]
+fn BadComponent() -> Element {
+    rsx! {
+        div {
+            // missing-alt-text: img without alt
+            img { src: "logo.png" }
+
+            // invalid-aria-attribute: aria-foo is not a valid ARIA attribute
+            div { "aria-foo": "bar", "Content" }
+
+            // invalid-aria-value: aria-hidden expects "true" or "false"
+            div { aria_hidden: "yes", "Hidden?" }
+
+            // invalid-role: "banana" is not a valid ARIA role
+            div { role: "banana", "Role?" }
+
+            // abstract-role: "widget" is an abstract role
+            span { role: "widget", "Abstract" }
+
+            // redundant-role: button already has implicit role "button"
+            button { role: "button", "Click me" }
+
+            // no-access-key: accesskey creates keyboard shortcut conflicts
+            button { accesskey: "s", "Save" }
+
+            // no-autofocus: autofocus can reduce usability
+            input { autofocus: true }
+
+            // click-events-have-key-events: div with onclick but no keyboard handler
+            div { onclick: move |_| {}, "Click this div" }
+
+            // no-positive-tabindex: tabindex > 0 creates unexpected tab order
+            div { tabindex: 5, "Tabbable" }
+
+            // anchor-is-valid: href="#" is not a valid link
+            a { href: "#", prevent_default: "onclick", "Bad link" }
+
+            // no-distracting-elements: marquee should not be used
+            marquee { "Scrolling text!" }
+
+            // iframe-has-title: iframe without title
+            iframe { src: "https://example.com" }
+
+            // no-redundant-alt: alt text contains "image"
+            img { src: "cat.jpg", alt: "image of a cat" }
+
+            // heading-has-content: empty heading
+            h1 {}
+
+            // scope on non-th element
+            td { scope: "row", "Data" }
+
+            // label without associated control
+            label {}
+
+            // button-has-content: text only known at runtime is fine, none is not
+            button { onclick: move |_| {} }
+        }
+    }
+}
+
+#[component]
+fn GoodComponent(count: Signal<i32>, html: String) -> Element {
+    rsx! {
+        div {
+            // Correct: img with alt text
+            img { src: "logo.png", alt: "Company logo" }
+
+            // Correct: valid ARIA attributes
+            div { aria_label: "Main content", aria_hidden: "true", "Content" }
+
+            // Correct: div with both click and keyboard handlers
+            div {
+                role: "button",
+                tabindex: 0,
+                onclick: move |_| count += 1,
+                onkeydown: move |_| count += 1,
+                "Clicked {count} times"
+            }
+
+            // Correct: valid anchor
+            a { href: "/about", "About us" }
+
+            // Correct: input with aria-label
+            input { r#type: "search", aria_label: "Search" }
+
+            // Correct: label with for attribute
+            label { r#for: "email", "Email" }
+            input { id: "email", r#type: "email", autocomplete: "email" }
+
+            // Correct: content set from HTML
+            h2 { dangerous_inner_html: "{html}" }
+
+            // Correct: content in both branches
+            if count() > 0 {
+                button { "Reset" }
+            } else {
+                p { "Nothing yet" }
+            }
+
+            // Correct: th with scope
+            th { scope: "col", "Header" }
+        }
+    }
+}
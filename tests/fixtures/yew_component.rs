@@ -122,3 +122,49 @@ fn good_component() -> Html {
         </div>
     }
 }
+
+#[function_component(YewIdioms)]
+fn yew_idioms() -> Html {
+    let value = use_state(String::new);
+    let open = Callback::from(|_| {});
+
+    html! {
+        <div>
+            // `~value` sets a property; the input still needs a label
+            <input ~value={(*value).clone()} />
+
+            // braced literals are static: positive tabindex
+            <button tabindex={2}>{"Second"}</button>
+
+            // boolean props: a disabled button may be left out of the tab order
+            <button disabled=true tabindex="-1">{"Disabled"}</button>
+
+            // classes! of literals is static, like a plain string
+            <span class={classes!("badge", "badge-new")}>{"New"}</span>
+
+            // Callback::from blocks are handlers: click without keyboard
+            <div role="button" tabindex="0" onclick={Callback::from(move |_| open.emit(()))}>
+                {"Open"}
+            </div>
+
+            // aria-hidden={true} hides focusable content
+            <div aria-hidden={true}><button>{"Hidden"}</button></div>
+        </div>
+    }
+}
+
+#[function_component(NestedFragments)]
+fn nested_fragments() -> Html {
+    let items = vec!["a.png", "b.png"];
+    // each image below lacks alt text and is reported exactly once
+    let rows: Vec<Html> = vec![html! { <img src="row.png" /> }];
+    let item = html_nested! { <ListItem><img src="item.png" /></ListItem> };
+
+    html! {
+        <List renderer={html! { <img src="renderer.png" /> }}>
+            { for items.iter().map(|src| html! { <li><img src={*src} /></li> }) }
+            { item }
+            { for rows }
+        </List>
+    }
+}
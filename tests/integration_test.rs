@@ -2,9 +2,10 @@
 
 use std::path::Path;
 
+use rsx_a11y::config::{Config, ConfigFile};
 use rsx_a11y::lints::{self, LintDiagnostic, Rule, Severity};
 use rsx_a11y::parser;
-use rsx_a11y::{check_project};
+use rsx_a11y::{check_project, check_project_with};
 
 fn lint_fixture(filename: &str) -> Vec<LintDiagnostic> {
     let path = format!("tests/fixtures/{}", filename);
@@ -115,6 +116,36 @@ fn test_yew_has_errors() {
     assert!(!errors.is_empty(), "Expected errors in yew fixture");
 }
 
+#[test]
+fn test_yew_idioms_are_understood() {
+    let diags = lint_fixture("yew_component.rs");
+    let idioms: Vec<(usize, Rule)> = diags
+        .into_iter()
+        .filter(|d| (125..155).contains(&d.line))
+        .map(|d| (d.line, d.rule))
+        .collect();
+    // `~value`, `tabindex={2}`, `Callback::from` and `aria-hidden={true}`.
+    assert!(idioms.contains(&(134, Rule::ControlHasAssociatedLabel)));
+    assert!(idioms.contains(&(137, Rule::TabindexNoPositive)));
+    assert!(idioms.contains(&(146, Rule::ClickEventsHaveKeyEvents)));
+    assert!(idioms.contains(&(151, Rule::NoAriaHiddenOnFocusable)));
+    // Neither the disabled button nor the `classes!` span is reported.
+    assert!(!idioms.iter().any(|(line, _)| (140..=143).contains(line)));
+}
+
+#[test]
+fn test_yew_nested_macros_are_linted_once() {
+    let diags = lint_fixture("yew_component.rs");
+    let mut lines: Vec<usize> = diags
+        .iter()
+        .filter(|d| d.line > 155 && d.rule == Rule::AltText)
+        .map(|d| d.line)
+        .collect();
+    lines.sort();
+    // `vec![html!]`, `html_nested!`, a `renderer` prop and a `{ for }` block.
+    assert_eq!(lines, [160, 161, 164, 165]);
+}
+
 // --- Leptos fixture tests ---
 
 #[test]
@@ -138,8 +169,87 @@ fn test_leptos_invalid_role_detected() {
     assert!(has_lint(&diags, Rule::AriaRole));
 }
 
+// --- Dioxus fixture tests ---
+
+#[test]
+fn test_dioxus_fixture_reports_like_yew() {
+    let diags = lint_fixture("dioxus_component.rs");
+    for rule in [
+        Rule::AltText,
+        Rule::AriaProps,
+        Rule::AriaProptypes,
+        Rule::AriaRole,
+        Rule::NoRedundantRoles,
+        Rule::NoAccessKey,
+        Rule::NoAutofocus,
+        Rule::ClickEventsHaveKeyEvents,
+        Rule::TabindexNoPositive,
+        Rule::AnchorIsValid,
+        Rule::NoDistractingElements,
+        Rule::IframeHasTitle,
+        Rule::ImgRedundantAlt,
+        Rule::HeadingHasContent,
+        Rule::Scope,
+        Rule::LabelHasAssociatedControl,
+        Rule::ButtonHasContent,
+    ] {
+        assert!(has_lint(&diags, rule.clone()), "{} not reported", rule);
+    }
+}
+
+#[test]
+fn test_dioxus_good_component_content_is_understood() {
+    let diags = lint_fixture("dioxus_component.rs");
+    // Interpolated text, `dangerous_inner_html` and both `if` branches
+    // give the good component's elements their content.
+    assert!(!diags.iter().any(|d| d.line > 66
+        && matches!(
+            d.rule,
+            Rule::HeadingHasContent | Rule::ButtonHasContent | Rule::ControlHasAssociatedLabel
+        )));
+}
+
 // --- check_project tests ---
 
+#[test]
+fn test_dioxus_fixes_keep_the_syntax_valid() {
+    let path = "tests/fixtures/dioxus_component.rs";
+    let source = std::fs::read_to_string(path).unwrap();
+    let fixes: Vec<_> = lint_fixture("dioxus_component.rs")
+        .into_iter()
+        .flat_map(|d| d.fixes)
+        .collect();
+    assert!(fixes.len() >= 3);
+    for fix in fixes {
+        let fixed = rsx_a11y::fix::apply(&source, &fix.edits).unwrap();
+        let parsed =
+            parser::parse_source_with(&fixed, path, &parser::ParseOptions::default()).unwrap();
+        assert!(parsed.macro_errors.is_empty(), "{}: {fixed}", fix.message);
+    }
+}
+
+#[test]
+fn test_dioxus_fix_dry_run() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_rsx-a11y"))
+        .args(["--fix", "--dry-run", "tests/fixtures/dioxus_component.rs"])
+        .output()
+        .unwrap();
+    let diff = String::from_utf8(output.stdout).unwrap();
+    assert!(diff.contains("-            button { role: \"button\", \"Click me\" }\n"));
+    assert!(diff.contains("+            button { \"Click me\" }\n"));
+}
+
+#[test]
+fn test_json_report_is_an_object() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_rsx-a11y"))
+        .args(["--format", "json", "tests/fixtures/dioxus_component.rs"])
+        .output()
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(!report["diagnostics"].as_array().unwrap().is_empty());
+    assert!(report.get("timings").is_none());
+}
+
 #[test]
 fn test_check_project_finds_fixtures() {
     let summary = check_project(Path::new("tests/fixtures"));
@@ -170,7 +280,12 @@ fn test_check_project_diagnostics_are_sorted() {
         assert!(
             (a.file.as_str(), a.line, a.column) <= (b.file.as_str(), b.line, b.column),
             "Diagnostics not sorted: ({}, {}:{}) came before ({}, {}:{})",
-            a.file, a.line, a.column, b.file, b.line, b.column
+            a.file,
+            a.line,
+            a.column,
+            b.file,
+            b.line,
+            b.column
         );
     }
 }
@@ -187,6 +302,17 @@ fn test_check_project_single_file() {
     );
 }
 
+#[test]
+fn test_check_project_with_config_turns_rules_off() {
+    let file: ConfigFile = toml::from_str("[rules]\nalt-text = \"off\"").unwrap();
+    let config = Config::from_file(file, None, Path::new(".").to_path_buf()).unwrap();
+    let summary = check_project_with(Path::new("tests/fixtures"), &config);
+
+    assert!(summary.files_checked > 0);
+    assert!(!summary.diagnostics.is_empty());
+    assert!(summary.diagnostics.iter().all(|d| d.rule != Rule::AltText));
+}
+
 #[test]
 fn test_check_project_filter_by_severity() {
     let summary = check_project(Path::new("tests/fixtures"));
@@ -204,7 +330,16 @@ fn test_check_project_filter_by_severity() {
 
     assert!(!errors.is_empty(), "Expected some errors in fixtures");
     assert!(!warnings.is_empty(), "Expected some warnings in fixtures");
-    assert_eq!(errors.len() + warnings.len() + summary.diagnostics.iter().filter(|d| d.severity == Severity::Info).count(), summary.diagnostics.len());
+    assert_eq!(
+        errors.len()
+            + warnings.len()
+            + summary
+                .diagnostics
+                .iter()
+                .filter(|d| d.severity == Severity::Info)
+                .count(),
+        summary.diagnostics.len()
+    );
 }
 
 #[test]
@@ -222,3 +357,54 @@ fn test_check_project_filter_by_rule() {
         "Expected alt-text diagnostics in fixtures"
     );
 }
+
+// --- testing helper tests ---
+
+#[test]
+fn test_violations_reports_fixture_issues() {
+    let violations = rsx_a11y::testing::violations(Path::new("tests/fixtures"));
+    assert!(!violations.is_empty());
+    assert!(
+        violations
+            .diagnostics
+            .iter()
+            .all(|d| d.severity != Severity::Info)
+    );
+}
+
+#[test]
+#[should_panic(expected = "error[alt-text]")]
+fn test_assert_no_violations_macro_panics_with_report() {
+    rsx_a11y::assert_no_violations!("tests/fixtures");
+}
+
+#[test]
+fn test_yew_fixture_matches_expected() {
+    rsx_a11y::testing::assert_expected(Path::new("tests/fixtures/yew_component.rs"));
+}
+
+#[test]
+fn test_leptos_fixture_matches_expected() {
+    rsx_a11y::testing::assert_expected(Path::new("tests/fixtures/leptos_component.rs"));
+}
+
+#[test]
+fn test_dioxus_fixture_matches_expected() {
+    rsx_a11y::testing::assert_expected(Path::new("tests/fixtures/dioxus_component.rs"));
+}
+
+#[test]
+fn test_print_ast_shows_tree_and_value_kinds() {
+    let parsed = parser::parse_file_with(
+        Path::new("tests/fixtures/yew_component.rs"),
+        &parser::ParseOptions::default(),
+    )
+    .unwrap();
+    let mut out = Vec::new();
+    rsx_a11y::diagnostics::print_ast(&parsed, &mut out);
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.starts_with("html! #0 in tests/fixtures/yew_component.rs"));
+    assert!(out.contains("\n    <img> 13:13 role=img\n      @src = \"logo.png\" static"));
+    assert!(out.contains("@aria-foo = \"bar\" static 16:17-16:31 (unrecognized name)"));
+    assert!(out.contains("@onclick = dynamic"));
+}